@@ -5,9 +5,17 @@
 //! Now with PRECISE per-allocation tracking.
 //!
 //! Results are automatically saved to: benchmark_memory_results.txt
+//!
+//! Set `PALISADE_BENCH_FORMAT` to `markdown`, `json`, or `ndjson` to also (or
+//! instead) emit a CI-diffable report (`benchmark_memory_results.md` / `.json`
+//! / `.ndjson`) alongside the default text log. Point `PALISADE_BENCH_BASELINE`
+//! at a prior `json`/`ndjson` report to flag any benchmark whose alloc_count
+//! or net bytes grew past `PALISADE_BENCH_REGRESSION_THRESHOLD` (default
+//! `0.10`, i.e. +10%); set `PALISADE_BENCH_FAIL_ON_REGRESSION=1` to exit
+//! nonzero on the first regression found.
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Bencher, Criterion, measurement::WallTime};
-use palisade_errors::{AgentError, definitions};
+use palisade_errors::{AgentError, ErrorStyle, definitions};
 use std::io;
 use std::time::Duration;
 
@@ -145,31 +153,45 @@ impl MemStats {
         Self::append_to_file(label, self, Some(time_ns));
     }
 
+    /// Writes the median row for `label` in whichever [`ReportFormat`]
+    /// `PALISADE_BENCH_FORMAT` selects, then checks it against a baseline
+    /// report if one is configured.
     fn append_to_file(label: &str, stats: &MemStats, time_ns: Option<f64>) {
+        match ReportFormat::from_env() {
+            ReportFormat::Text => Self::append_text(label, stats, time_ns),
+            ReportFormat::Markdown => Self::append_markdown(label, stats, time_ns),
+            ReportFormat::Json => Self::append_json(label, stats, time_ns),
+            ReportFormat::Ndjson => Self::append_ndjson(label, stats, time_ns),
+        }
+
+        check_regression(label, stats);
+    }
+
+    fn append_text(label: &str, stats: &MemStats, time_ns: Option<f64>) {
         let filename = "benchmark_memory_results.txt";
-        
+
         let mut file = OpenOptions::new()
             .create(true)
             .append(true)
             .open(filename)
             .expect("Failed to open benchmark results file");
-        
+
         // Write header with timestamp if file is empty/new
         let is_new_file = file.metadata()
             .map(|m| m.len() == 0)
             .unwrap_or(true);
-            
+
         if is_new_file {
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs();
-            
+
             writeln!(file, "════════════════════════════════════════════════════════════════════════════════════════════════").ok();
             writeln!(file, "Benchmark Results (Memory + Timing) - Unix timestamp: {}", now).ok();
             writeln!(file, "════════════════════════════════════════════════════════════════════════════════════════════════\n").ok();
         }
-        
+
         // Format timing data
         let timing_str = if let Some(ns) = time_ns {
             if ns < 1_000.0 {
@@ -184,8 +206,8 @@ impl MemStats {
         } else {
             "  N/A      ".to_string()
         };
-        
-        writeln!(file, 
+
+        writeln!(file,
             "{:<50} │ Time: {} │ Alloc: {:>8} B ({:>3} calls) │ Dealloc: {:>8} B ({:>3} calls) │ Net: {:>8} B",
             label,
             timing_str,
@@ -196,6 +218,232 @@ impl MemStats {
             stats.net.abs()
         ).ok();
     }
+
+    fn append_markdown(label: &str, stats: &MemStats, time_ns: Option<f64>) {
+        let filename = "benchmark_memory_results.md";
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(filename)
+            .expect("Failed to open benchmark results file");
+
+        let is_new_file = file.metadata().map(|m| m.len() == 0).unwrap_or(true);
+        if is_new_file {
+            writeln!(file, "| Label | Time (ns) | Allocated | Deallocated | Net | Allocs | Deallocs |").ok();
+            writeln!(file, "|---|---|---|---|---|---|---|").ok();
+        }
+
+        writeln!(
+            file,
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            label,
+            time_ns.map_or_else(|| "N/A".to_string(), |ns| format!("{ns:.2}")),
+            stats.allocated,
+            stats.deallocated,
+            stats.net,
+            stats.alloc_count,
+            stats.dealloc_count,
+        ).ok();
+    }
+
+    fn append_ndjson(label: &str, stats: &MemStats, time_ns: Option<f64>) {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open("benchmark_memory_results.ndjson")
+            .expect("Failed to open benchmark results file");
+
+        writeln!(file, "{}", bench_record_json(label, stats, time_ns)).ok();
+    }
+
+    /// Unlike the other formats, a JSON report is a single array, so each
+    /// write rewrites the whole file rather than appending a line.
+    fn append_json(label: &str, stats: &MemStats, time_ns: Option<f64>) {
+        let filename = "benchmark_memory_results.json";
+
+        let mut records: Vec<String> = std::fs::read_to_string(filename)
+            .ok()
+            .map(|text| {
+                let trimmed = text.trim();
+                trimmed
+                    .strip_prefix('[')
+                    .and_then(|s| s.strip_suffix(']'))
+                    .filter(|s| !s.trim().is_empty())
+                    .map(|inner| inner.split("},{").map(|obj| format!("{{{}}}", obj.trim_matches(|c| c == '{' || c == '}'))).collect())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default();
+
+        records.push(bench_record_json(label, stats, time_ns));
+
+        let contents = format!("[\n  {}\n]\n", records.join(",\n  "));
+        std::fs::write(filename, contents).expect("Failed to write benchmark results file");
+    }
+}
+
+/// Serialize one benchmark row as a flat JSON object - shared by the
+/// `json` and `ndjson` report formats.
+fn bench_record_json(label: &str, stats: &MemStats, time_ns: Option<f64>) -> String {
+    format!(
+        "{{\"label\":\"{}\",\"time_ns\":{},\"allocated\":{},\"deallocated\":{},\"net\":{},\"alloc_count\":{},\"dealloc_count\":{}}}",
+        json_escape(label),
+        time_ns.map_or_else(|| "null".to_string(), |ns| ns.to_string()),
+        stats.allocated,
+        stats.deallocated,
+        stats.net,
+        stats.alloc_count,
+        stats.dealloc_count,
+    )
+}
+
+/// Minimal JSON string escaping - benchmark labels are plain ASCII text,
+/// but this keeps output valid even if one ever contains a quote or backslash.
+fn json_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// ============================================================================
+// Machine-Readable Report Format + Allocation-Regression Gating
+// ============================================================================
+
+/// Output format for `benchmark_memory_results.*`, selected via the
+/// `PALISADE_BENCH_FORMAT` env var: `text` (default - the original
+/// box-drawn `.txt` log), `markdown`, `json`, or `ndjson`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportFormat {
+    Text,
+    Markdown,
+    Json,
+    Ndjson,
+}
+
+impl ReportFormat {
+    fn from_env() -> Self {
+        match std::env::var("PALISADE_BENCH_FORMAT").ok().as_deref() {
+            Some("markdown") | Some("md") => Self::Markdown,
+            Some("json") => Self::Json,
+            Some("ndjson") => Self::Ndjson,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// Baseline allocation figures for one benchmark label, loaded from the
+/// JSON or NDJSON report pointed to by `PALISADE_BENCH_BASELINE`.
+#[derive(Debug, Clone, Copy)]
+struct BaselineEntry {
+    alloc_count: usize,
+    net: isize,
+}
+
+/// Extract flat `"key": value` pairs from one JSON object's body. Only
+/// supports flat objects with string or bare-numeric values and no nested
+/// braces/commas-in-strings - sufficient for reports this file produces.
+fn extract_json_object_fields(obj: &str) -> std::collections::HashMap<String, String> {
+    let mut fields = std::collections::HashMap::new();
+    for part in obj.trim_matches(|c| c == '{' || c == '}').split(',') {
+        let Some((key, value)) = part.split_once(':') else { continue };
+        let key = key.trim().trim_matches('"').to_string();
+        let value = value.trim().trim_matches('"').to_string();
+        if !key.is_empty() {
+            fields.insert(key, value);
+        }
+    }
+    fields
+}
+
+/// Parse a self-produced `json` (array) or `ndjson` (one object per line)
+/// report into its flat field maps, one per record.
+fn parse_bench_records(text: &str) -> Vec<std::collections::HashMap<String, String>> {
+    let trimmed = text.trim();
+    match trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        Some(inner) if !inner.trim().is_empty() => {
+            inner.split("},{").map(extract_json_object_fields).collect()
+        }
+        Some(_) => Vec::new(),
+        None => trimmed
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(extract_json_object_fields)
+            .collect(),
+    }
+}
+
+fn baseline_map() -> &'static std::collections::HashMap<String, BaselineEntry> {
+    static BASELINE: std::sync::OnceLock<std::collections::HashMap<String, BaselineEntry>> =
+        std::sync::OnceLock::new();
+    BASELINE.get_or_init(|| {
+        let Ok(path) = std::env::var("PALISADE_BENCH_BASELINE") else {
+            return std::collections::HashMap::new();
+        };
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            eprintln!("PALISADE_BENCH_BASELINE={path}: could not read file, skipping regression gating");
+            return std::collections::HashMap::new();
+        };
+
+        let mut map = std::collections::HashMap::new();
+        for fields in parse_bench_records(&contents) {
+            let (Some(label), Some(alloc_count), Some(net)) = (
+                fields.get("label").cloned(),
+                fields.get("alloc_count").and_then(|v| v.parse::<usize>().ok()),
+                fields.get("net").and_then(|v| v.parse::<isize>().ok()),
+            ) else {
+                continue;
+            };
+            map.insert(label, BaselineEntry { alloc_count, net });
+        }
+        map
+    })
+}
+
+/// Fractional regression threshold from `PALISADE_BENCH_REGRESSION_THRESHOLD`
+/// (e.g. `0.10` for +10%); defaults to +10%.
+fn regression_threshold() -> f64 {
+    std::env::var("PALISADE_BENCH_REGRESSION_THRESHOLD")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0.10)
+}
+
+fn growth_ratio(current: f64, baseline: f64) -> Option<f64> {
+    (baseline > 0.0).then(|| current / baseline)
+}
+
+/// Compares `stats` for `label` against the configured baseline (if any)
+/// and flags a regression when alloc_count or net bytes grew past the
+/// threshold. With `PALISADE_BENCH_FAIL_ON_REGRESSION=1`, a regression
+/// exits the process immediately so the benchmark run doubles as a CI gate.
+fn check_regression(label: &str, stats: &MemStats) {
+    let Some(baseline) = baseline_map().get(label) else {
+        return;
+    };
+    let threshold = regression_threshold();
+
+    let alloc_ratio = growth_ratio(stats.alloc_count as f64, baseline.alloc_count as f64);
+    let net_ratio = growth_ratio(stats.net.unsigned_abs() as f64, baseline.net.unsigned_abs() as f64);
+
+    let alloc_regressed = alloc_ratio.is_some_and(|r| r > 1.0 + threshold);
+    let net_regressed = net_ratio.is_some_and(|r| r > 1.0 + threshold);
+
+    if !alloc_regressed && !net_regressed {
+        return;
+    }
+
+    eprintln!(
+        "REGRESSION [{label}]: alloc_count {} -> {} ({:+.1}%), net {} -> {} ({:+.1}%) exceeds +{:.0}% threshold",
+        baseline.alloc_count,
+        stats.alloc_count,
+        alloc_ratio.map_or(0.0, |r| (r - 1.0) * 100.0),
+        baseline.net,
+        stats.net,
+        net_ratio.map_or(0.0, |r| (r - 1.0) * 100.0),
+        threshold * 100.0,
+    );
+
+    if std::env::var("PALISADE_BENCH_FAIL_ON_REGRESSION").as_deref() == Ok("1") {
+        std::process::exit(1);
+    }
 }
 
 // Thread-local storage for memory stats collection
@@ -249,11 +497,36 @@ where
     });
 }
 
+/// Runs `f` once under allocation tracking and asserts it performed no heap
+/// allocations - locks in the `SmallString` zero-allocation fast path for
+/// `&'static str`-only construction so a future regression fails the suite
+/// instead of only showing up as a quieter benchmark number.
+fn assert_zero_alloc(label: &str, f: impl FnOnce()) {
+    let region = Region::new(&GLOBAL);
+    let start = region.change();
+    f();
+    let end = region.change();
+    let stat = MemStats::from_region(&start, &end);
+    assert_eq!(
+        stat.alloc_count, 0,
+        "{label}: expected zero-allocation construction, got {} allocation(s)",
+        stat.alloc_count
+    );
+}
+
 // ============================================================================
 // ERROR CREATION BENCHMARKS
 // ============================================================================
 
 fn bench_error_creation_simple(c: &mut Criterion) {
+    assert_zero_alloc("Simple Error Creation", || {
+        black_box(AgentError::config(
+            definitions::CFG_PARSE_FAILED,
+            "operation",
+            "details"
+        ));
+    });
+
     c.bench_function("create_simple_error", |b| {
         bench_with_mem(b, "Simple Error Creation", || {
             black_box(AgentError::config(
@@ -279,6 +552,15 @@ fn bench_error_creation_with_string(c: &mut Criterion) {
 }
 
 fn bench_error_creation_sensitive(c: &mut Criterion) {
+    assert_zero_alloc("Error with Sensitive Data", || {
+        black_box(AgentError::config_sensitive(
+            definitions::CFG_PARSE_FAILED,
+            "operation",
+            "details",
+            "/etc/passwd"
+        ));
+    });
+
     c.bench_function("create_error_with_sensitive", |b| {
         bench_with_mem(b, "Error with Sensitive Data", || {
             black_box(AgentError::config_sensitive(
@@ -292,6 +574,18 @@ fn bench_error_creation_sensitive(c: &mut Criterion) {
 }
 
 fn bench_error_creation_io_split(c: &mut Criterion) {
+    // `io::Error::new` itself boxes the message, so it's built outside the
+    // assertion - only the `AgentError` construction is under test here.
+    let io_err = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+    assert_zero_alloc("I/O Error with Split Source", || {
+        black_box(AgentError::from_io_path(
+            definitions::IO_READ_FAILED,
+            "read_file",
+            "/secret/path",
+            io_err
+        ));
+    });
+
     c.bench_function("create_error_io_split_source", |b| {
         bench_with_mem(b, "I/O Error with Split Source", || {
             let io_err = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
@@ -306,8 +600,35 @@ fn bench_error_creation_io_split(c: &mut Criterion) {
 }
 
 fn bench_error_creation_all_constructors(c: &mut Criterion) {
+    // Lock in the zero-allocation fast path for every constructor before
+    // timing them - a `&'static str`-only call must never touch the heap.
+    assert_zero_alloc("Config Constructor", || {
+        black_box(AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details"));
+    });
+    assert_zero_alloc("Deployment Constructor", || {
+        black_box(AgentError::deployment(definitions::DCP_DEPLOY_FAILED, "op", "details"));
+    });
+    assert_zero_alloc("Telemetry Constructor", || {
+        black_box(AgentError::telemetry(definitions::TEL_INIT_FAILED, "op", "details"));
+    });
+    assert_zero_alloc("Correlation Constructor", || {
+        black_box(AgentError::correlation(definitions::COR_RULE_EVAL_FAILED, "op", "details"));
+    });
+    assert_zero_alloc("Response Constructor", || {
+        black_box(AgentError::response(definitions::RSP_EXEC_FAILED, "op", "details"));
+    });
+    assert_zero_alloc("Logging Constructor", || {
+        black_box(AgentError::logging(definitions::LOG_WRITE_FAILED, "op", "details"));
+    });
+    assert_zero_alloc("Platform Constructor", || {
+        black_box(AgentError::platform(definitions::PLT_UNSUPPORTED, "op", "details"));
+    });
+    assert_zero_alloc("I/O Operation Constructor", || {
+        black_box(AgentError::io_operation(definitions::IO_READ_FAILED, "op", "details"));
+    });
+
     let mut group = c.benchmark_group("error_constructors");
-    
+
     group.bench_function("config", |b| {
         bench_with_mem(b, "Config Constructor", || { 
             black_box(AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details")); 
@@ -456,6 +777,35 @@ fn bench_internal_log_write(c: &mut Criterion) {
     });
 }
 
+fn bench_internal_log_wire_round_trip(c: &mut Criterion) {
+    let err = AgentError::config(
+        definitions::CFG_PARSE_FAILED,
+        "operation",
+        "details"
+    )
+    .with_metadata("correlation_id", "abc-123")
+    .with_metadata("session_id", "xyz-789");
+
+    c.bench_function("internal_log_encode", |b| {
+        bench_with_mem(b, "Internal Log Encode", || {
+            let log = err.internal_log();
+            let mut buffer = Vec::new();
+            log.encode(&mut buffer).unwrap();
+            black_box(buffer);
+        })
+    });
+
+    let mut encoded = Vec::new();
+    err.internal_log().encode(&mut encoded).unwrap();
+
+    c.bench_function("internal_log_decode", |b| {
+        bench_with_mem(b, "Internal Log Decode", || {
+            let owned = palisade_errors::InternalLog::decode(&encoded).unwrap();
+            black_box(owned);
+        })
+    });
+}
+
 fn bench_internal_log_with_sensitive(c: &mut Criterion) {
     let err = AgentError::config_sensitive(
         definitions::CFG_PARSE_FAILED,
@@ -474,6 +824,100 @@ fn bench_internal_log_with_sensitive(c: &mut Criterion) {
     });
 }
 
+#[cfg(feature = "backtrace")]
+fn bench_backtrace_capture(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backtrace_capture");
+
+    group.bench_function("creation_without_capture", |b| {
+        bench_with_mem(b, "Error Creation Without Backtrace", || {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details");
+            black_box(err);
+        })
+    });
+
+    group.bench_function("creation_with_capture", |b| {
+        bench_with_mem(b, "Error Creation With Backtrace", || {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details")
+                .with_backtrace();
+            black_box(err);
+        })
+    });
+
+    group.finish();
+}
+
+#[cfg(feature = "backtrace")]
+fn bench_backtrace_resolution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backtrace_resolution");
+
+    group.bench_function("first_write_resolves", |b| {
+        bench_with_mem(b, "Backtrace First-Write Resolution", || {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details")
+                .with_backtrace();
+            let log = err.internal_log();
+            let mut buffer = String::new();
+            log.write_to(&mut buffer).unwrap();
+            black_box(buffer);
+        })
+    });
+
+    group.bench_function("cached_write_reuses_resolution", |b| {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details")
+            .with_backtrace();
+        // Force resolution once, up front, so the benchmarked writes all hit the cache.
+        let _ = err.internal_log().backtrace_text();
+
+        bench_with_mem(b, "Backtrace Cached Write", || {
+            let log = err.internal_log();
+            let mut buffer = String::new();
+            log.write_to(&mut buffer).unwrap();
+            black_box(buffer);
+        })
+    });
+
+    group.finish();
+}
+
+#[cfg(feature = "internal_backtrace")]
+fn bench_internal_backtrace_capture(c: &mut Criterion) {
+    let mut group = c.benchmark_group("internal_backtrace_capture");
+
+    // Unlike the opt-in `backtrace` feature, `internal_backtrace` always
+    // captures at construction - there's no "without capture" variant to
+    // compare against within this build.
+    group.bench_function("creation_captures_automatically", |b| {
+        bench_with_mem(b, "Error Creation With Automatic Backtrace", || {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details");
+            black_box(err);
+        })
+    });
+
+    group.bench_function("first_write_resolves", |b| {
+        bench_with_mem(b, "Internal Backtrace First-Write Resolution", || {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details");
+            let log = err.internal_log();
+            let mut buffer = String::new();
+            log.write_to(&mut buffer).unwrap();
+            black_box(buffer);
+        })
+    });
+
+    group.bench_function("cached_write_reuses_resolution", |b| {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "operation", "details");
+        // Force resolution once, up front, so the benchmarked writes all hit the cache.
+        let _ = err.internal_log().backtrace_text();
+
+        bench_with_mem(b, "Internal Backtrace Cached Write", || {
+            let log = err.internal_log();
+            let mut buffer = String::new();
+            log.write_to(&mut buffer).unwrap();
+            black_box(buffer);
+        })
+    });
+
+    group.finish();
+}
+
 fn bench_callback_logging(c: &mut Criterion) {
     let err = AgentError::config(
         definitions::CFG_PARSE_FAILED,
@@ -525,6 +969,12 @@ fn bench_log_truncation(c: &mut Criterion) {
 // ============================================================================
 
 fn bench_memory_error_creation(c: &mut Criterion) {
+    assert_zero_alloc("Batch Create 1000 Errors", || {
+        for _ in 0..1000 {
+            let _err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+        }
+    });
+
     let mut group = c.benchmark_group("memory_tracking");
     group.bench_function("mem_error_creation_batch", |b| {
         bench_with_mem(b, "Batch Create 1000 Errors", || {
@@ -573,6 +1023,30 @@ fn bench_external_display(c: &mut Criterion) {
     });
 }
 
+fn bench_external_display_styles(c: &mut Criterion) {
+    let err = AgentError::config(
+        definitions::CFG_PARSE_FAILED,
+        "operation",
+        "details"
+    );
+
+    let mut group = c.benchmark_group("external_display_styles");
+
+    group.bench_function("plain", |b| {
+        bench_with_mem(b, "External Display - Plain Style", || {
+            black_box(format!("{}", err.display_with_style(ErrorStyle::Plain)));
+        })
+    });
+
+    group.bench_function("fancy", |b| {
+        bench_with_mem(b, "External Display - Fancy Style", || {
+            black_box(format!("{}", err.display_with_style(ErrorStyle::Fancy)));
+        })
+    });
+
+    group.finish();
+}
+
 fn bench_debug_format(c: &mut Criterion) {
     let err = AgentError::config(
         definitions::CFG_PARSE_FAILED,
@@ -883,6 +1357,92 @@ fn bench_ring_buffer_eviction(c: &mut Criterion) {
     });
 }
 
+fn bench_ring_buffer_drain_entry_size(c: &mut Criterion) {
+    use palisade_errors::ring_buffer::RingBufferLogger;
+
+    let mut group = c.benchmark_group("ring_buffer_drain_entry_size");
+
+    for (label, details) in [
+        ("single", "x".to_string()),
+        ("medium", "x".repeat(256)),
+        ("large", "x".repeat(1536)),
+    ] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(label),
+            &details,
+            |b, details| {
+                let logger = RingBufferLogger::new(1000, 2048);
+                let receiver = logger.with_drain(1000);
+
+                bench_with_mem(b, &format!("Drain {} entry", label), || {
+                    let err = AgentError::config(
+                        definitions::CFG_PARSE_FAILED,
+                        "operation",
+                        details.clone(),
+                    );
+                    logger.log(&err, "192.168.1.100");
+                    black_box(receiver.try_recv().ok());
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_ring_buffer_drain_contention(c: &mut Criterion) {
+    use palisade_errors::ring_buffer::RingBufferLogger;
+
+    let mut group = c.benchmark_group("ring_buffer_drain_contention");
+
+    for thread_count in [2, 4, 8] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(thread_count),
+            &thread_count,
+            |b, &threads| {
+                bench_with_mem(b, &format!("{} producers + drain", threads), || {
+                    let logger = RingBufferLogger::new(1000, 2048);
+                    let receiver = logger.with_drain(4096);
+
+                    let consumer = std::thread::spawn(move || {
+                        let mut drained = 0usize;
+                        while drained < threads * 250 {
+                            if receiver.recv_timeout(Duration::from_millis(100)).is_err() {
+                                break;
+                            }
+                            drained += 1;
+                        }
+                        drained
+                    });
+
+                    let handles: Vec<_> = (0..threads).map(|i| {
+                        let logger = logger.clone();
+                        std::thread::spawn(move || {
+                            for j in 0..250 {
+                                let err = AgentError::config(
+                                    definitions::CFG_PARSE_FAILED,
+                                    "op",
+                                    format!("thread {} error {}", i, j)
+                                );
+                                logger.log(&err, &format!("192.168.1.{}", i));
+                            }
+                        })
+                    }).collect();
+
+                    for handle in handles {
+                        handle.join().unwrap();
+                    }
+                    let _ = consumer.join();
+
+                    black_box(logger.dropped_count());
+                })
+            }
+        );
+    }
+
+    group.finish();
+}
+
 fn bench_ring_buffer_queries(c: &mut Criterion) {
     use palisade_errors::ring_buffer::RingBufferLogger;
     
@@ -1065,18 +1625,46 @@ criterion_group!(
     bench_metadata_access_cost,
 );
 
+#[cfg(not(any(feature = "backtrace", feature = "internal_backtrace")))]
+criterion_group!(
+    logging_benches,
+    bench_internal_log_access,
+    bench_internal_log_write,
+    bench_internal_log_wire_round_trip,
+    bench_internal_log_with_sensitive,
+    bench_callback_logging,
+    bench_log_truncation,
+);
+
+#[cfg(all(feature = "backtrace", not(feature = "internal_backtrace")))]
+criterion_group!(
+    logging_benches,
+    bench_internal_log_access,
+    bench_internal_log_write,
+    bench_internal_log_wire_round_trip,
+    bench_internal_log_with_sensitive,
+    bench_callback_logging,
+    bench_log_truncation,
+    bench_backtrace_capture,
+    bench_backtrace_resolution,
+);
+
+#[cfg(feature = "internal_backtrace")]
 criterion_group!(
     logging_benches,
     bench_internal_log_access,
     bench_internal_log_write,
+    bench_internal_log_wire_round_trip,
     bench_internal_log_with_sensitive,
     bench_callback_logging,
     bench_log_truncation,
+    bench_internal_backtrace_capture,
 );
 
 criterion_group!(
     display_benches,
     bench_external_display,
+    bench_external_display_styles,
     bench_debug_format,
     bench_error_code_display,
 );
@@ -1105,6 +1693,8 @@ criterion_group!(
     bench_ring_buffer_single_threaded,
     bench_ring_buffer_concurrent,
     bench_ring_buffer_eviction,
+    bench_ring_buffer_drain_entry_size,
+    bench_ring_buffer_drain_contention,
     bench_ring_buffer_queries,
 );
 
@@ -1,7 +1,11 @@
 use palisade_errors::{
-    AgentError, definitions, 
-    Result, init_session_salt
+    AgentError, definitions,
+    Result,
 };
+#[cfg(feature = "toml_config")]
+use palisade_errors::config::PalisadeConfig;
+#[cfg(not(feature = "toml_config"))]
+use palisade_errors::init_session_salt;
 
 fn load_configuration(path: &str) -> Result<()> {
     // Simulate a failure to parse a configuration file
@@ -17,7 +21,16 @@ fn load_configuration(path: &str) -> Result<()> {
 
 fn main() {
     // 1. Initialize Obfuscation (Optional but recommended)
-    // This ensures error codes are unique to this session (preventing fingerprinting)
+    // This ensures error codes are unique to this session (preventing fingerprinting).
+    // With `toml_config` enabled, deployments drive this from a file instead of a
+    // hardcoded seed - see `PalisadeConfig::from_toml_path`.
+    #[cfg(feature = "toml_config")]
+    PalisadeConfig {
+        session_salt: Some(12345),
+        ..Default::default()
+    }
+    .init();
+    #[cfg(not(feature = "toml_config"))]
     init_session_salt(12345);
 
     println!("--- Basic Usage Example ---\n");
@@ -35,7 +48,13 @@ fn main() {
             println!("\n2. [INTERNAL LOG] What the admin sees:");
             // We use the internal log viewer. In a real app, this goes to Splunk/ELK.
             err.with_internal_log(|log| {
-                println!("   Code:      {}", log.code()); // Real code or Obfuscated code based on config
+                // Real code or obfuscated code, depending on
+                // `PalisadeConfig::reveal_real_code_internally` (`toml_config` feature);
+                // without that feature this is always the same obfuscated code `{}` above shows.
+                #[cfg(feature = "toml_config")]
+                println!("   Code:      {}", log.disclosed_code());
+                #[cfg(not(feature = "toml_config"))]
+                println!("   Code:      {}", log.code());
                 println!("   Category:  {:?}", log.code().category());
                 println!("   Operation: {}", log.operation());
                 println!("   Details:   {}", log.details());
@@ -1,33 +1,21 @@
 use palisade_errors::{
-    ContextBuilder, DualContextError, OperationCategory, 
+    ContextBuilder, DualContextError,
     SocAccess, definitions
 };
+use palisade_errors::signature::SignatureRegistry;
 
 /// Simulates a vulnerable endpoint in a honeypot
-fn handle_admin_login(username: &str) -> Result<(), DualContextError> {
-    // Detect SQL Injection signature
-    if username.contains("' OR '1'='1") {
-        // We want to lie to the attacker to make them think the DB failed naturally,
-        // rather than telling them "WAF Blocked You".
-        
+fn handle_admin_login(username: &str, signatures: &SignatureRegistry) -> Result<(), DualContextError> {
+    // `detect_with` runs every registered attack signature against the raw
+    // input and, on the first match, fills in the lie, the sensitive internal
+    // payload, and the deception category in one call - no more hand-coded
+    // per-signature if-ladder.
+    if signatures.scan(username).is_some() {
         return Err(ContextBuilder::new()
-            // THE LIE: Generic database connection error
-            .public_lie("Database connection pool exhausted. Please try again later.")
-            
-            // THE TRUTH + SENSITIVE DATA: 
-            // We must combine diagnostic info and the payload into a single SENSITIVE context.
-            // You cannot set .internal_diagnostic() and .internal_sensitive() separately.
-            .internal_sensitive(format!(
-                "SQL Injection detected in login payload. Payload: [{}]", 
-                username
-            ))
-            
-            // CATEGORY: Deception (displayed as 'Routine Operation' externally)
-            .category(OperationCategory::Deception)
-            .build()
-        );
+            .detect_with(signatures, username)
+            .build());
     }
-    
+
     Ok(())
 }
 
@@ -35,8 +23,9 @@ fn main() {
     println!("--- Honeypot Deception Example ---\n");
 
     let attack_payload = "admin' OR '1'='1";
-    
-    match handle_admin_login(attack_payload) {
+    let signatures = SignatureRegistry::seeded();
+
+    match handle_admin_login(attack_payload, &signatures) {
         Ok(_) => println!("Login successful"),
         Err(e) => {
             // 1. External Output (HTTP Response)
@@ -0,0 +1,233 @@
+// src/advisory.rs
+//! Structured advisory export of the full error-code catalog.
+//!
+//! # Purpose
+//!
+//! [`crate::registry`] answers "what does `E-CFG-100` mean" for a human
+//! staring at a log line. This module answers the machine-readable version
+//! of the same question for an entire fleet: dump every code in
+//! [`crate::registry::ALL_CODES`], plus its owning subsystem and a
+//! normalized severity, as one `serde`-backed document that a
+//! security/observability pipeline can ingest the way it already ingests a
+//! vulnerability advisory feed.
+//!
+//! # Severity Normalization
+//!
+//! [`ErrorImpact`] has nine bands tuned for this crate's deception-agent
+//! domain; downstream tooling built for a generic advisory feed expects a
+//! small, familiar severity scale instead. [`AdvisorySeverity::from_impact`] is that
+//! mapping - notably, `Leak` (information disclosure) and `Collapse`
+//! (total failure of emulation) land on `High` and `Critical` respectively,
+//! so pipelines built for standard advisory data can treat
+//! `CORE_MEMORY_ALLOC_FAILED` and `DCP_NARRATIVE_BREAK` as first-class
+//! incidents without understanding this crate's internal taxonomy.
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `serde` feature, same reasoning as
+//! [`crate::serde_support`]: the crate's core path never takes a hard
+//! `serde` dependency.
+//!
+//! # Schema Stability
+//!
+//! [`AdvisoryCatalog::schema_version`] is a plain integer that downstream
+//! consumers should branch on. A future breaking change to this module's
+//! shape bumps [`ADVISORY_SCHEMA_VERSION`] rather than silently changing
+//! the meaning of an existing field.
+
+use crate::registry::ALL_CODES;
+use crate::{ErrorCode, ErrorImpact};
+use serde::{Deserialize, Serialize};
+
+/// Current version of the [`AdvisoryCatalog`] document shape.
+///
+/// Bump this whenever a field is added, removed, or changes meaning, so a
+/// consumer pinned to an older schema can detect the mismatch instead of
+/// silently misreading a reshaped document.
+pub const ADVISORY_SCHEMA_VERSION: u32 = 1;
+
+/// Normalized severity scale for advisory export, independent of this
+/// crate's internal [`ErrorImpact`] bands - the shape a generic
+/// security/observability pipeline already knows how to triage.
+///
+/// Distinct from [`crate::models::Severity`], which grades *diagnostic
+/// messages* (`Bug`/`Fatal`/.../`Help`) rather than error-code impact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdvisorySeverity {
+    /// Purely informational; no action expected.
+    Info,
+    /// Minor, safe to address opportunistically.
+    Low,
+    /// Worth triaging; may indicate a developing problem.
+    Medium,
+    /// Worth prompt attention; e.g. information disclosure.
+    High,
+    /// Worth immediate attention; e.g. total failure or active compromise.
+    Critical,
+}
+
+impl AdvisorySeverity {
+    /// Maps an [`ErrorImpact`] band onto this normalized scale.
+    ///
+    /// `Leak` maps to `High` (information disclosure) and `Collapse` maps
+    /// to `Critical` (total failure of emulation), per this module's stated
+    /// goal of surfacing those two as first-class advisories; `Escalation`
+    /// and `Breach`, being strictly worse than `Collapse`, are `Critical`
+    /// as well.
+    pub const fn from_impact(impact: ErrorImpact) -> Self {
+        match impact {
+            ErrorImpact::Noise | ErrorImpact::Flaw => Self::Info,
+            ErrorImpact::Jitter | ErrorImpact::Glitch => Self::Low,
+            ErrorImpact::Suspicion => Self::Medium,
+            ErrorImpact::Leak => Self::High,
+            ErrorImpact::Collapse | ErrorImpact::Escalation | ErrorImpact::Breach => Self::Critical,
+        }
+    }
+}
+
+/// One catalog entry: a single [`ErrorCode`] plus the metadata an external
+/// advisory consumer needs without having to link against this crate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryRecord {
+    /// Rendered `E-{NAMESPACE}-{code}` form, e.g. `"E-DCP-232"`.
+    pub id: String,
+    /// Owning subsystem namespace, e.g. `"DCP"`.
+    pub namespace: String,
+    /// Raw numeric code within the namespace.
+    pub code: u16,
+    /// Operation category display name, e.g. `"Deception"`.
+    pub category: String,
+    /// Raw impact score (0-1000).
+    pub impact_score: u16,
+    /// Detailed impact band, e.g. `"Collapse"`.
+    pub impact_level: String,
+    /// Normalized severity this record was placed into.
+    pub severity: AdvisorySeverity,
+}
+
+impl AdvisoryRecord {
+    fn from_code(code: &ErrorCode) -> Self {
+        Self {
+            id: code.to_string(),
+            namespace: code.namespace().as_str().to_string(),
+            code: code.code(),
+            category: code.category().display_name().to_string(),
+            impact_score: code.impact().value(),
+            impact_level: format!("{:?}", code.impact_level()),
+            severity: AdvisorySeverity::from_impact(code.impact_level()),
+        }
+    }
+}
+
+/// The full error-code catalog, structured as a `serde`-backed advisory
+/// document.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::advisory::{catalog, ADVISORY_SCHEMA_VERSION};
+///
+/// let doc = catalog();
+/// assert_eq!(doc.schema_version, ADVISORY_SCHEMA_VERSION);
+/// assert!(!doc.records.is_empty());
+///
+/// let json = serde_json::to_string(&doc).unwrap();
+/// assert!(json.contains("schema_version"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvisoryCatalog {
+    /// Shape version of this document - see [`ADVISORY_SCHEMA_VERSION`].
+    pub schema_version: u32,
+    /// Every defined error code, in [`ALL_CODES`] declaration order.
+    pub records: Vec<AdvisoryRecord>,
+}
+
+/// Build an [`AdvisoryCatalog`] covering every code in
+/// [`crate::registry::ALL_CODES`].
+///
+/// Serialize the result with `serde_json::to_string` (or any other `serde`
+/// data format, e.g. `serde_yaml`) to produce the document downstream
+/// tooling ingests.
+pub fn catalog() -> AdvisoryCatalog {
+    AdvisoryCatalog {
+        schema_version: ADVISORY_SCHEMA_VERSION,
+        records: ALL_CODES.iter().map(|code| AdvisoryRecord::from_code(code)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_covers_every_registered_code() {
+        let doc = catalog();
+        assert_eq!(doc.records.len(), ALL_CODES.len());
+        assert_eq!(doc.schema_version, ADVISORY_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn leak_and_collapse_map_to_distinct_non_trivial_severities() {
+        assert_eq!(AdvisorySeverity::from_impact(ErrorImpact::Leak), AdvisorySeverity::High);
+        assert_eq!(AdvisorySeverity::from_impact(ErrorImpact::Collapse), AdvisorySeverity::Critical);
+        assert!(AdvisorySeverity::from_impact(ErrorImpact::Leak) < AdvisorySeverity::from_impact(ErrorImpact::Collapse));
+    }
+
+    #[test]
+    fn severity_scale_is_monotonic_with_impact_band() {
+        let bands = [
+            ErrorImpact::Noise,
+            ErrorImpact::Flaw,
+            ErrorImpact::Jitter,
+            ErrorImpact::Glitch,
+            ErrorImpact::Suspicion,
+            ErrorImpact::Leak,
+            ErrorImpact::Collapse,
+            ErrorImpact::Escalation,
+            ErrorImpact::Breach,
+        ];
+        let mut last = AdvisorySeverity::Info;
+        for band in bands {
+            let severity = AdvisorySeverity::from_impact(band);
+            assert!(severity >= last, "severity regressed at {band:?}");
+            last = severity;
+        }
+    }
+
+    #[test]
+    fn memory_safety_and_integrity_codes_are_first_class_advisories() {
+        let doc = catalog();
+
+        let memory = doc
+            .records
+            .iter()
+            .find(|r| r.id == crate::definitions::CORE_MEMORY_ALLOC_FAILED.to_string())
+            .expect("CORE_MEMORY_ALLOC_FAILED should be in the catalog");
+        assert_eq!(memory.severity, AdvisorySeverity::High);
+
+        let narrative_break = doc
+            .records
+            .iter()
+            .find(|r| r.id == crate::definitions::DCP_NARRATIVE_BREAK.to_string())
+            .expect("DCP_NARRATIVE_BREAK should be in the catalog");
+        assert_eq!(narrative_break.severity, AdvisorySeverity::Critical);
+
+        let evasion = doc
+            .records
+            .iter()
+            .find(|r| r.id == crate::definitions::TEL_EVASION_DETECTED.to_string())
+            .expect("TEL_EVASION_DETECTED should be in the catalog");
+        assert_eq!(evasion.severity, AdvisorySeverity::Critical);
+    }
+
+    #[test]
+    fn catalog_round_trips_through_json() {
+        let doc = catalog();
+        let json = serde_json::to_string(&doc).unwrap();
+        let restored: AdvisoryCatalog = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.schema_version, doc.schema_version);
+        assert_eq!(restored.records.len(), doc.records.len());
+    }
+}
@@ -0,0 +1,258 @@
+//! Ambient SOC context stack: thread-local breadcrumbs auto-captured by
+//! [`DualContextError`] constructors, so callers stop re-spelling
+//! `OperationCategory` and the full internal diagnostic at every call site
+//! when the surrounding code already knows "we are inside the Detection
+//! path handling request X."
+//!
+//! # Architecture
+//!
+//! Inspired by dynamically-scoped implicit environments (and this crate's
+//! own [`crate::locale::set_message_bundle`] thread-local pattern): a
+//! `thread_local!` stack of [`ContextFrame`]s, pushed by
+//! [`ContextScope::enter`]/[`ContextScope::enter_sensitive`] and popped LIFO
+//! by the returned [`ContextGuard`]'s `Drop`. [`DualContextError::with_lie_in_scope`]
+//! reads the top frame's category and joins every frame's note into a single
+//! breadcrumb trail for the internal diagnostic.
+//!
+//! # Security
+//!
+//! A frame pushed via [`ContextScope::enter_sensitive`] marks the whole
+//! ambient stack sensitive for as long as it's on the stack:
+//! [`DualContextError::with_lie_in_scope`] then builds
+//! [`InternalContext::sensitive`] instead of [`InternalContext::diagnostic`],
+//! so the joined breadcrumb trail still gets the existing zeroize-on-drop
+//! treatment. [`ContextFrame`]'s own `Drop` additionally zeroizes a sensitive
+//! frame's owned note the moment it's popped - including when a guard pops
+//! it while unwinding from a panic, since `Drop` runs regardless of how a
+//! scope exits.
+//!
+//! # Feature Gate
+//!
+//! Unavailable under `no_std`, which has no `thread_local!` to build this
+//! stack on.
+
+use crate::models::{DualContextError, InternalContext, OperationCategory, PublicContext};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use zeroize::Zeroize;
+
+/// Process-wide, monotonically increasing frame identifier - lets
+/// [`ContextGuard::drop`] debug-assert it popped the exact frame it pushed,
+/// rather than trusting stack depth alone (which a `mem::forget`'d sibling
+/// guard could silently leave off by one).
+static NEXT_FRAME_ID: AtomicU64 = AtomicU64::new(0);
+
+/// One entry in the ambient [`ContextScope`] stack.
+struct ContextFrame {
+    id: u64,
+    category: OperationCategory,
+    note: Cow<'static, str>,
+    sensitive: bool,
+}
+
+impl Drop for ContextFrame {
+    fn drop(&mut self) {
+        if self.sensitive {
+            if let Cow::Owned(s) = &mut self.note {
+                s.zeroize();
+            }
+        }
+    }
+}
+
+thread_local! {
+    static CONTEXT_STACK: RefCell<Vec<ContextFrame>> = RefCell::new(Vec::new());
+}
+
+/// Entry point for pushing ambient breadcrumbs onto the current thread's
+/// context stack.
+///
+/// # Use Case
+///
+/// Wrap a block of code that already knows its [`OperationCategory`] and a
+/// short note about what it's doing, so any [`DualContextError`] built
+/// further down the call stack via [`DualContextError::with_lie_in_scope`]
+/// picks both up automatically.
+///
+/// # Example
+///
+/// ```ignore
+/// let _scope = ContextScope::enter(OperationCategory::Detection, "handling request 42");
+/// // ... deep inside, with no OperationCategory or diagnostic text in hand ...
+/// return Err(DualContextError::with_lie_in_scope("Permission denied"));
+/// ```
+pub struct ContextScope(());
+
+impl ContextScope {
+    /// Push a non-sensitive breadcrumb for the lifetime of the returned guard.
+    #[inline]
+    #[must_use = "the ambient frame is removed as soon as this guard is dropped"]
+    pub fn enter(category: OperationCategory, note: impl Into<Cow<'static, str>>) -> ContextGuard {
+        Self::push(category, note.into(), false)
+    }
+
+    /// Push a breadcrumb containing sensitive data for the lifetime of the
+    /// returned guard.
+    ///
+    /// Any [`DualContextError::with_lie_in_scope`] call while this frame (or
+    /// any sensitive frame) is on the stack builds its internal diagnostic
+    /// via [`InternalContext::sensitive`] instead of
+    /// [`InternalContext::diagnostic`], so the joined breadcrumb trail gets
+    /// the usual zeroize-on-drop treatment.
+    #[inline]
+    #[must_use = "the ambient frame is removed as soon as this guard is dropped"]
+    pub fn enter_sensitive(
+        category: OperationCategory,
+        note: impl Into<Cow<'static, str>>,
+    ) -> ContextGuard {
+        Self::push(category, note.into(), true)
+    }
+
+    fn push(category: OperationCategory, note: Cow<'static, str>, sensitive: bool) -> ContextGuard {
+        let id = NEXT_FRAME_ID.fetch_add(1, Ordering::Relaxed);
+        CONTEXT_STACK.with(|stack| {
+            stack.borrow_mut().push(ContextFrame {
+                id,
+                category,
+                note,
+                sensitive,
+            });
+        });
+        ContextGuard { frame_id: id }
+    }
+}
+
+/// RAII guard returned by [`ContextScope::enter`]/[`ContextScope::enter_sensitive`].
+///
+/// Pops its frame from the ambient stack on drop, LIFO. Not `Clone`: each
+/// guard owns exactly one push, so drop order stays paired with enter order.
+#[must_use = "the ambient frame is removed as soon as this guard is dropped"]
+pub struct ContextGuard {
+    frame_id: u64,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT_STACK.with(|stack| {
+            let popped_correctly = match stack.borrow_mut().pop() {
+                Some(frame) => frame.id == self.frame_id,
+                None => false,
+            };
+            debug_assert!(
+                popped_correctly,
+                "ContextGuard dropped out of LIFO order - a sibling guard was likely \
+                 mem::forgotten instead of dropped"
+            );
+        });
+    }
+}
+
+/// Join every frame currently on the stack, bottom to top, into a single
+/// breadcrumb trail - `"outer note > inner note"` - for splicing into an
+/// internal diagnostic.
+fn joined_breadcrumbs(stack: &[ContextFrame]) -> String {
+    let mut joined = String::new();
+    for (i, frame) in stack.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(" > ");
+        }
+        joined.push_str(frame.note.as_ref());
+    }
+    joined
+}
+
+impl DualContextError {
+    /// Create an error with public deception, reading its
+    /// [`OperationCategory`] and internal diagnostic from the ambient
+    /// [`ContextScope`] stack rather than requiring the caller to spell
+    /// either out.
+    ///
+    /// # Ambient Resolution
+    ///
+    /// - Category: the innermost (top) [`ContextScope`] frame's category.
+    /// - Internal diagnostic: every frame's note, joined top-to-bottom into
+    ///   one breadcrumb trail.
+    /// - If any frame on the stack was pushed via
+    ///   [`ContextScope::enter_sensitive`], the diagnostic is built via
+    ///   [`InternalContext::sensitive`] instead of
+    ///   [`InternalContext::diagnostic`].
+    ///
+    /// # Panics (Debug Mode)
+    ///
+    /// Debug-asserts that at least one [`ContextScope`] frame is active -
+    /// calling this with no ambient scope entered is a misuse of the API, not
+    /// a case it silently papers over. In release builds, falls back to
+    /// [`OperationCategory::System`] with an empty diagnostic.
+    pub fn with_lie_in_scope(public_lie: impl Into<Cow<'static, str>>) -> Self {
+        let (category, note, sensitive) = CONTEXT_STACK.with(|stack| {
+            let stack = stack.borrow();
+            debug_assert!(
+                !stack.is_empty(),
+                "DualContextError::with_lie_in_scope called with no active ContextScope"
+            );
+            let category = stack
+                .last()
+                .map(|frame| frame.category)
+                .unwrap_or(OperationCategory::System);
+            let sensitive = stack.iter().any(|frame| frame.sensitive);
+            let note = joined_breadcrumbs(&stack);
+            (category, note, sensitive)
+        });
+        let internal = if sensitive {
+            InternalContext::sensitive(note)
+        } else {
+            InternalContext::diagnostic(note)
+        };
+        DualContextError::new(PublicContext::lie(public_lie), internal, category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enter_pushes_and_drop_pops() {
+        {
+            let _scope = ContextScope::enter(OperationCategory::Detection, "inner");
+            CONTEXT_STACK.with(|stack| assert_eq!(stack.borrow().len(), 1));
+        }
+        CONTEXT_STACK.with(|stack| assert_eq!(stack.borrow().len(), 0));
+    }
+
+    #[test]
+    fn nested_scopes_pop_in_lifo_order() {
+        let outer = ContextScope::enter(OperationCategory::Detection, "outer");
+        {
+            let _inner = ContextScope::enter(OperationCategory::Containment, "inner");
+            CONTEXT_STACK.with(|stack| assert_eq!(stack.borrow().len(), 2));
+        }
+        CONTEXT_STACK.with(|stack| assert_eq!(stack.borrow().len(), 1));
+        drop(outer);
+        CONTEXT_STACK.with(|stack| assert_eq!(stack.borrow().len(), 0));
+    }
+
+    #[test]
+    fn with_lie_in_scope_reads_top_category_and_joins_notes() {
+        let _outer = ContextScope::enter(OperationCategory::Detection, "handling request 42");
+        let _inner = ContextScope::enter(OperationCategory::Containment, "quarantining host");
+
+        let err = DualContextError::with_lie_in_scope("Permission denied");
+        assert_eq!(err.category(), OperationCategory::Containment);
+        assert_eq!(
+            err.internal().payload().map(|p| p.as_str()),
+            Some("handling request 42 > quarantining host")
+        );
+    }
+
+    #[test]
+    fn with_lie_in_scope_routes_through_sensitive_when_any_frame_is_sensitive() {
+        let _outer = ContextScope::enter(OperationCategory::Detection, "handling request 42");
+        let _inner =
+            ContextScope::enter_sensitive(OperationCategory::Containment, "leaked key abc123");
+
+        let err = DualContextError::with_lie_in_scope("Permission denied");
+        assert_eq!(err.internal().classification(), "Sensitive");
+    }
+}
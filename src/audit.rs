@@ -0,0 +1,398 @@
+// src/audit.rs
+//! Pluggable audit trail for sensitive-context exposure.
+//!
+//! # Purpose
+//!
+//! `DualContextError::expose_sensitive()` is already capability-gated, but a
+//! gate that nobody watches still leaves the question "who looked at the
+//! secret diagnostics, and when" to be bolted on externally by whoever
+//! operates the honeypot. This module makes that a first-class, testable
+//! capability: every time the gate is passed, an [`AuditEvent`] is handed to
+//! an [`AuditSink`].
+//!
+//! # Design
+//!
+//! Modeled on rust-lightning's `KVStore` persistence abstraction: a single
+//! narrow trait method (`record`), implementation left entirely to the
+//! consumer. This crate ships two implementations to cover the common
+//! cases - [`NoopAuditSink`] (the default, for callers who haven't opted in)
+//! and [`RingBufferAuditSink`] (bounded in-memory, same FIFO-eviction
+//! posture as [`crate::ring_buffer::RingBufferLogger`]).
+//!
+//! # What Gets Recorded
+//!
+//! [`AuditEvent`] never carries the raw sensitive value - only a
+//! [`SensitiveHash`] of it. The point of an audit trail is to answer
+//! "was this secret looked at, and does a later value match it", not to
+//! duplicate the secret into a second place an attacker could read.
+//!
+//! # Sink Selection
+//!
+//! A sink can be installed two ways:
+//! - Globally, via [`set_global_audit_sink`] - the default for every
+//!   capability that doesn't specify its own.
+//! - Per-capability, via [`crate::Capability::with_audit_sink`] - overrides
+//!   the global sink for exposures gated on that one capability.
+
+use crate::{Capability, OperationCategory};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A SHA-256 hash of a sensitive value revealed through
+/// `DualContextError::expose_sensitive()`.
+///
+/// # Why Hash Instead Of Store
+///
+/// Storing the raw value in the audit trail would just create a second
+/// place for the same secret to leak from. A hash still lets an auditor
+/// confirm "the value exposed at 14:02 matches the one exposed at 14:05"
+/// without ever holding the plaintext.
+///
+/// # Not Cryptographic Proof Of Non-Tampering
+///
+/// This reuses the crate's self-contained SHA-256 (see [`crate::integrity`])
+/// purely as a one-way digest; unlike [`crate::IntegrityTag`] it is not
+/// keyed, so it proves nothing beyond "these two exposures revealed the same
+/// bytes."
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SensitiveHash([u8; 32]);
+
+impl SensitiveHash {
+    fn of(value: &str) -> Self {
+        Self(crate::integrity::sha256(value.as_bytes()))
+    }
+
+    /// The raw 32-byte digest.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SensitiveHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SensitiveHash(")?;
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// One record of "who looked at the secret diagnostics, and when."
+///
+/// Emitted by `DualContextError::expose_sensitive()` every time a
+/// [`Capability`] gate is passed and sensitive content is actually returned.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    /// Unix timestamp (seconds) this exposure happened at.
+    pub timestamp: u64,
+    /// The operation category of the error whose sensitive context was
+    /// exposed.
+    pub category: OperationCategory,
+    /// Identity of whoever held the capability that gated this exposure -
+    /// `Capability::holder()` at the time of the call.
+    pub accessor: String,
+    /// The error's `external_message()`, for correlating this exposure
+    /// against whatever the attacker-facing side actually saw.
+    pub external_message: String,
+    /// Hash of the sensitive value revealed - never the value itself.
+    pub sensitive_hash: SensitiveHash,
+}
+
+impl AuditEvent {
+    fn new(
+        category: OperationCategory,
+        accessor: &str,
+        external_message: &str,
+        sensitive_value: &str,
+    ) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            category,
+            accessor: accessor.to_owned(),
+            external_message: external_message.to_owned(),
+            sensitive_hash: SensitiveHash::of(sensitive_value),
+        }
+    }
+}
+
+/// Destination for [`AuditEvent`]s.
+///
+/// Modeled on rust-lightning's `KVStore`: one narrow method, so adapting it
+/// to a SIEM, a database table, or a metrics counter is a one-`impl` job.
+pub trait AuditSink {
+    /// Record a single exposure event.
+    ///
+    /// # Contract
+    ///
+    /// Must not block the caller indefinitely or panic - this runs inline
+    /// on `expose_sensitive()`'s hot path. Implementations that need to
+    /// reach a slow external system should hand the event off (a channel,
+    /// a background queue) rather than waiting on it here.
+    fn record(&self, event: AuditEvent);
+}
+
+/// Default sink that discards every event.
+///
+/// Installed implicitly until a caller opts in via [`set_global_audit_sink`]
+/// or [`crate::Capability::with_audit_sink`], matching the crate's
+/// deny/no-op-by-default posture elsewhere (e.g. [`crate::CapabilityScope`]'s
+/// empty `Default`).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopAuditSink;
+
+impl AuditSink for NoopAuditSink {
+    #[inline]
+    fn record(&self, _event: AuditEvent) {}
+}
+
+/// Bounded in-memory audit sink with FIFO eviction.
+///
+/// Same "bounded memory regardless of volume" posture as
+/// [`crate::ring_buffer::RingBufferLogger`], scaled down for the much lower
+/// event rate an audit trail sees in practice (sensitive exposures should be
+/// rare; if they aren't, that is itself worth noticing).
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::audit::{AuditSink, RingBufferAuditSink};
+/// use palisade_errors::{Capability, CapabilityScope, DualContextError, OperationCategory};
+/// use std::time::Duration;
+///
+/// let sink = RingBufferAuditSink::new(16);
+/// let capability = Capability::issue(
+///     "soc-lead@example.com",
+///     "debug-session-42",
+///     CapabilityScope::categories([OperationCategory::IO]),
+///     Duration::from_secs(300),
+/// )
+/// .with_audit_sink(sink.clone());
+///
+/// let error = DualContextError::with_lie_and_sensitive(
+///     "Connection failed",
+///     "password=hunter2",
+///     OperationCategory::IO,
+/// );
+///
+/// assert!(error.expose_sensitive(&capability).is_some());
+/// assert_eq!(sink.len(), 1);
+/// ```
+pub struct RingBufferAuditSink {
+    events: Arc<RwLock<VecDeque<AuditEvent>>>,
+    capacity: usize,
+}
+
+impl RingBufferAuditSink {
+    /// Create a sink holding at most `capacity` events (minimum 1).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            events: Arc::new(RwLock::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// The `count` most recently recorded events, newest first.
+    pub fn recent(&self, count: usize) -> Vec<AuditEvent> {
+        self.read().iter().rev().take(count).cloned().collect()
+    }
+
+    /// All currently retained events, newest first.
+    pub fn get_all(&self) -> Vec<AuditEvent> {
+        self.read().iter().rev().cloned().collect()
+    }
+
+    /// Number of events currently retained.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Whether no events are currently retained.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discard all retained events.
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    #[inline]
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, VecDeque<AuditEvent>> {
+        match self.events.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    #[inline]
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, VecDeque<AuditEvent>> {
+        match self.events.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl AuditSink for RingBufferAuditSink {
+    fn record(&self, event: AuditEvent) {
+        let mut events = self.write();
+        if events.len() >= self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+}
+
+impl Clone for RingBufferAuditSink {
+    fn clone(&self) -> Self {
+        Self {
+            events: Arc::clone(&self.events),
+            capacity: self.capacity,
+        }
+    }
+}
+
+/// Process-wide default sink, used by every capability that hasn't called
+/// [`crate::Capability::with_audit_sink`]. Starts as [`NoopAuditSink`].
+static GLOBAL_SINK: OnceLock<RwLock<Arc<dyn AuditSink + Send + Sync>>> = OnceLock::new();
+
+#[inline]
+fn global_lock() -> &'static RwLock<Arc<dyn AuditSink + Send + Sync>> {
+    GLOBAL_SINK.get_or_init(|| RwLock::new(Arc::new(NoopAuditSink)))
+}
+
+/// Install the process-wide default [`AuditSink`].
+///
+/// Affects every capability that does not have its own sink via
+/// [`crate::Capability::with_audit_sink`]. Replaces whatever sink (if any)
+/// was previously installed.
+pub fn set_global_audit_sink(sink: impl AuditSink + Send + Sync + 'static) {
+    let sink: Arc<dyn AuditSink + Send + Sync> = Arc::new(sink);
+    let lock = global_lock();
+    match lock.write() {
+        Ok(mut guard) => *guard = sink,
+        Err(poisoned) => *poisoned.into_inner() = sink,
+    }
+}
+
+fn global_sink() -> Arc<dyn AuditSink + Send + Sync> {
+    let lock = global_lock();
+    match lock.read() {
+        Ok(guard) => Arc::clone(&guard),
+        Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+    }
+}
+
+/// Called by `DualContextError::expose_sensitive()` once the capability
+/// gate has passed and sensitive content was returned. Not part of the
+/// public API - callers observe audit events only through the sink they
+/// installed.
+pub(crate) fn record_exposure(
+    capability: &Capability,
+    category: OperationCategory,
+    external_message: &str,
+    sensitive_value: &str,
+) {
+    let event = AuditEvent::new(category, capability.holder(), external_message, sensitive_value);
+    match capability.audit_sink() {
+        Some(sink) => sink.record(event),
+        None => global_sink().record(event),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CapabilityScope, DualContextError};
+    use std::time::Duration;
+
+    fn capability_for(category: OperationCategory) -> Capability {
+        Capability::issue(
+            "soc-lead@example.com",
+            "debug-session-42",
+            CapabilityScope::categories([category]),
+            Duration::from_secs(300),
+        )
+    }
+
+    #[test]
+    fn ring_buffer_sink_records_on_successful_exposure() {
+        let sink = RingBufferAuditSink::new(8);
+        let capability = capability_for(OperationCategory::IO).with_audit_sink(sink.clone());
+
+        let error = DualContextError::with_lie_and_sensitive(
+            "Connection failed",
+            "password=hunter2",
+            OperationCategory::IO,
+        );
+
+        assert!(error.expose_sensitive(&capability).is_some());
+        assert_eq!(sink.len(), 1);
+
+        let recorded = &sink.recent(1)[0];
+        assert_eq!(recorded.category, OperationCategory::IO);
+        assert_eq!(recorded.accessor, "debug-session-42");
+        assert_eq!(recorded.external_message, error.external_message());
+        assert_eq!(recorded.sensitive_hash, SensitiveHash::of("password=hunter2"));
+    }
+
+    #[test]
+    fn denied_exposure_is_not_audited() {
+        let sink = RingBufferAuditSink::new(8);
+        let capability = capability_for(OperationCategory::Configuration).with_audit_sink(sink.clone());
+
+        let error = DualContextError::with_lie_and_sensitive(
+            "Connection failed",
+            "password=hunter2",
+            OperationCategory::IO,
+        );
+
+        assert!(error.expose_sensitive(&capability).is_none());
+        assert!(sink.is_empty());
+    }
+
+    #[test]
+    fn ring_buffer_sink_evicts_oldest() {
+        let sink = RingBufferAuditSink::new(2);
+        let capability = capability_for(OperationCategory::IO).with_audit_sink(sink.clone());
+
+        for i in 0..3 {
+            let error = DualContextError::with_lie_and_sensitive(
+                "Connection failed",
+                format!("secret-{i}"),
+                OperationCategory::IO,
+            );
+            let _ = error.expose_sensitive(&capability);
+        }
+
+        assert_eq!(sink.len(), 2);
+        let all = sink.get_all();
+        assert_eq!(all[0].sensitive_hash, SensitiveHash::of("secret-2"));
+        assert_eq!(all[1].sensitive_hash, SensitiveHash::of("secret-1"));
+    }
+
+    #[test]
+    fn falls_back_to_global_sink_without_override() {
+        let sink = RingBufferAuditSink::new(8);
+        set_global_audit_sink(sink.clone());
+
+        let capability = capability_for(OperationCategory::IO);
+        let error = DualContextError::with_lie_and_sensitive(
+            "Connection failed",
+            "password=hunter2",
+            OperationCategory::IO,
+        );
+
+        assert!(error.expose_sensitive(&capability).is_some());
+        assert_eq!(sink.len(), 1);
+    }
+}
@@ -0,0 +1,123 @@
+// src/backtrace.rs
+//! Optional backtrace capture confined to the internal log.
+//!
+//! # Purpose
+//!
+//! Investigators reconstructing an incident need to know where an
+//! `AgentError` originated, but stack frames are exactly the kind of
+//! internal-architecture detail that must never reach the external
+//! `Display` (see the crate's threat model). [`CapturedBacktrace`] only
+//! ever surfaces through [`crate::InternalLog`], and nothing in the
+//! external-facing path reads it. Two independent ways to get one:
+//! [`crate::AgentError::with_backtrace`] attaches one on demand (the
+//! `backtrace` feature); the `internal_backtrace` feature instead captures
+//! one automatically at every `AgentError` construction, for deployments
+//! that want it unconditionally rather than per call site.
+//!
+//! # Design
+//!
+//! Capturing via [`std::backtrace::Backtrace::capture`] is cheap - raw
+//! frame addresses only, no symbol resolution. [`CapturedBacktrace::resolved`]
+//! performs the (comparatively expensive) symbol resolution exactly once,
+//! caching the formatted text in a [`std::sync::OnceLock`] so repeated
+//! [`crate::logging::InternalLog::write_to`] calls after the first don't
+//! re-resolve. That same laziness is why the automatic `internal_backtrace`
+//! path captures only the raw frames up front and defers resolution - the
+//! expensive part - until something actually reads the log.
+//!
+//! # Feature Gates
+//!
+//! Behind the `backtrace` or `internal_backtrace` cargo features, so the
+//! core path never pays for frame capture unless a deployment opts in to
+//! one of them.
+
+use std::backtrace::Backtrace;
+use std::fmt;
+use std::sync::OnceLock;
+use zeroize::Zeroize;
+
+/// A backtrace captured at [`AgentError`](crate::AgentError) construction
+/// time, resolved to text lazily and cached on first resolution.
+pub struct CapturedBacktrace {
+    raw: Backtrace,
+    resolved: OnceLock<String>,
+}
+
+impl CapturedBacktrace {
+    /// Capture the current call stack as raw, unresolved frames.
+    #[inline]
+    pub fn capture() -> Self {
+        Self {
+            raw: Backtrace::capture(),
+            resolved: OnceLock::new(),
+        }
+    }
+
+    /// The resolved, human-readable backtrace text - computed by formatting
+    /// the raw frames on the first call, and cached for every call after.
+    pub fn resolved(&self) -> &str {
+        self.resolved.get_or_init(|| self.raw.to_string())
+    }
+}
+
+impl Zeroize for CapturedBacktrace {
+    /// Scrubs the resolved symbol-and-address text cached by
+    /// [`Self::resolved`]. The raw frames captured by
+    /// [`std::backtrace::Backtrace`] itself are opaque to this crate - the
+    /// standard library exposes no way to reach into and scrub its
+    /// internal buffer - so the cached text is the most this can zeroize;
+    /// the raw frames are still freed, just not scrubbed, when `self.raw`
+    /// drops.
+    fn zeroize(&mut self) {
+        if let Some(mut text) = self.resolved.take() {
+            text.zeroize();
+        }
+    }
+}
+
+impl fmt::Debug for CapturedBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapturedBacktrace")
+            .field("status", &self.raw.status())
+            .field("resolved", &self.resolved.get().is_some())
+            .finish()
+    }
+}
+
+/// Either a live [`CapturedBacktrace`] borrowed from an
+/// [`AgentError`](crate::AgentError), or text already resolved by
+/// [`crate::logging::InternalLog::into_owned`].
+///
+/// Lets [`crate::logging::InternalLog`]'s formatters treat both the same
+/// way without forcing [`crate::logging::OwnedLog`] to carry a
+/// (non-`Clone`) `CapturedBacktrace`.
+pub enum BacktraceSource<'a> {
+    Captured(&'a CapturedBacktrace),
+    Resolved(&'a str),
+}
+
+impl BacktraceSource<'_> {
+    /// The resolved backtrace text - resolving and caching it now if this
+    /// is the first read of a `Captured` source.
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Captured(backtrace) => backtrace.resolved(),
+            Self::Resolved(text) => text,
+        }
+    }
+}
+
+impl fmt::Debug for BacktraceSource<'_> {
+    /// Hand-written like [`CapturedBacktrace`]'s own `Debug` - a derived
+    /// impl on `Captured` would print the raw frame data `CapturedBacktrace`
+    /// deliberately keeps out of `Debug`, so this defers to that impl
+    /// instead of exposing it directly.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Captured(backtrace) => {
+                f.debug_tuple("Captured").field(backtrace).finish()
+            }
+            Self::Resolved(text) => f.debug_tuple("Resolved").field(text).finish(),
+        }
+    }
+}
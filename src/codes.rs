@@ -74,7 +74,9 @@
 //! ```
 
 use crate::OperationCategory;
-use std::fmt;
+use core::fmt;
+#[cfg(feature = "no_std")]
+use alloc::{borrow::ToOwned, string::String};
 
 // ============================================================================
 // Impact Score Type (Validates Policy)
@@ -194,8 +196,17 @@ impl fmt::Display for ImpactScoreError {
     }
 }
 
+#[cfg(not(feature = "no_std"))]
 impl std::error::Error for ImpactScoreError {}
 
+/// `no_std` consumers on toolchains with `core::error::Error` stable
+/// (1.81+) can opt into it via the `core-error` feature; see this crate's
+/// `## Features` docs. Mutually exclusive with the `std` impl above -
+/// `core::error::Error` and `std::error::Error` are the same trait, so only
+/// one impl may exist per build.
+#[cfg(all(feature = "no_std", feature = "core-error"))]
+impl core::error::Error for ImpactScoreError {}
+
 // ============================================================================
 // Error Impact Classification
 // ============================================================================
@@ -246,6 +257,98 @@ impl ErrorImpact {
             _ => Self::Breach,
         }
     }
+
+    /// Maps this impact band to a stable process exit code, so a binary
+    /// built on palisade-error can `std::process::exit` with a status that
+    /// conveys severity to shell scripts and supervisors without
+    /// re-implementing this mapping at every call site.
+    ///
+    /// Follows the rustc convention of distinguishing an ordinary failure
+    /// (exit 1) from an internal/catastrophic one (exit 101, rustc's ICE
+    /// code), extending it with two more codes for the bands above
+    /// `Collapse` that represent an active compromise rather than a crash:
+    ///
+    /// | Band                                    | Exit code |
+    /// |------------------------------------------|-----------|
+    /// | `Noise`/`Flaw`/`Jitter`/`Glitch`/`Suspicion` | [`EXIT_RECOVERABLE`] (1)   |
+    /// | `Leak`                                    | [`EXIT_LEAK`] (74)         |
+    /// | `Collapse`                                | [`EXIT_CATASTROPHIC`] (101) |
+    /// | `Escalation`                               | [`EXIT_ESCALATION`] (102)  |
+    /// | `Breach`                                   | [`EXIT_BREACH`] (103)      |
+    ///
+    /// These codes are part of this crate's stable surface: scripts that
+    /// branch on them across upgrades can rely on the numbers not shifting.
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Noise | Self::Flaw | Self::Jitter | Self::Glitch | Self::Suspicion => {
+                EXIT_RECOVERABLE
+            }
+            Self::Leak => EXIT_LEAK,
+            Self::Collapse => EXIT_CATASTROPHIC,
+            Self::Escalation => EXIT_ESCALATION,
+            Self::Breach => EXIT_BREACH,
+        }
+    }
+
+    /// Short, human-readable label for this impact level (the variant name).
+    ///
+    /// Zero-allocation - used anywhere a band needs to be shown or
+    /// serialized without pulling in `Debug` formatting machinery, e.g.
+    /// [`crate::registry::explain`] and the `serde` views in this module.
+    #[inline]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Noise => "Noise",
+            Self::Flaw => "Flaw",
+            Self::Jitter => "Jitter",
+            Self::Glitch => "Glitch",
+            Self::Suspicion => "Suspicion",
+            Self::Leak => "Leak",
+            Self::Collapse => "Collapse",
+            Self::Escalation => "Escalation",
+            Self::Breach => "Breach",
+        }
+    }
+}
+
+/// Exit code for an ordinary, recoverable error (`Noise` through `Suspicion`).
+pub const EXIT_RECOVERABLE: u8 = 1;
+
+/// Exit code for a `Leak`: the error revealed internal system information, a
+/// resource-safety failure distinct from an ordinary one.
+pub const EXIT_LEAK: u8 = 74;
+
+/// Exit code for a `Collapse`: total failure of the emulated service.
+///
+/// Chosen to match rustc's internal-compiler-error exit code (101), the same
+/// precedent this mapping as a whole is modeled on.
+pub const EXIT_CATASTROPHIC: u8 = 101;
+
+/// Exit code for an `Escalation`: the attacker gained unintended lateral or
+/// vertical access. One worse than [`EXIT_CATASTROPHIC`].
+pub const EXIT_ESCALATION: u8 = 102;
+
+/// Exit code for a `Breach`: high risk of sandbox breakout or host
+/// compromise. The most severe code this crate emits.
+pub const EXIT_BREACH: u8 = 103;
+
+#[cfg(not(feature = "no_std"))]
+impl From<ErrorImpact> for std::process::ExitCode {
+    /// Bridges an [`ErrorImpact`] to a process exit status via
+    /// [`ErrorImpact::exit_code`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::codes::ErrorImpact;
+    /// use std::process::ExitCode;
+    ///
+    /// let code: ExitCode = ErrorImpact::Collapse.into();
+    /// assert_eq!(format!("{:?}", code), format!("{:?}", ExitCode::from(101)));
+    /// ```
+    fn from(impact: ErrorImpact) -> Self {
+        Self::from(impact.exit_code())
+    }
 }
 
 // ============================================================================
@@ -359,6 +462,14 @@ pub mod namespaces {
     /// Filesystem and network operations.
     /// Authority: Cannot emit Breach-level impacts.
     pub const IO: ErrorNamespace = ErrorNamespace::__internal_new("IO", false);
+
+    /// Every canonical namespace, in the same order as the code-range blocks
+    /// in `src/definitions.rs::ranges::BLOCKS`. Used by
+    /// [`crate::registry::Registry`] to group codes by namespace without
+    /// allocating a dynamic set of "namespaces seen so far".
+    pub const ALL: &[&ErrorNamespace] = &[
+        &CORE, &CFG, &DCP, &TEL, &COR, &RSP, &LOG, &PLT, &IO,
+    ];
 }
 
 // ============================================================================
@@ -517,6 +628,15 @@ pub enum InternalErrorCodeViolation {
         namespace: &'static str,
         impact: u16,
     },
+    /// A parsed string didn't match the `E-<NAMESPACE>-<CODE>` shape at all.
+    MalformedFormat { input: String },
+    /// The namespace token in a parsed string isn't one of
+    /// [`namespaces::ALL`] (reveals which namespaces exist).
+    UnknownNamespace { namespace: String },
+    /// The namespace token is known, but no `define_error_codes!` entry
+    /// registers that code within it, so category/impact can't be resolved
+    /// from [`crate::registry::ALL_CODES`] alone.
+    UnregisteredCode { namespace: &'static str, code: u16 },
 }
 
 impl InternalErrorCodeViolation {
@@ -536,6 +656,9 @@ impl InternalErrorCodeViolation {
             Self::CodeOutOfRange { .. } => "Invalid error code format",
             Self::CategoryNotPermitted { .. } => "Invalid error configuration",
             Self::ImpactNotPermitted { .. } => "Invalid error severity",
+            Self::MalformedFormat { .. } => "Invalid error code format",
+            Self::UnknownNamespace { .. } => "Invalid error code format",
+            Self::UnregisteredCode { .. } => "Unknown error code",
         }
     }
 }
@@ -560,12 +683,27 @@ impl fmt::Display for InternalErrorCodeViolation {
                     impact, namespace
                 )
             }
+            Self::MalformedFormat { input } => {
+                write!(f, "'{}' does not match the E-<NAMESPACE>-<CODE> format", input)
+            }
+            Self::UnknownNamespace { namespace } => {
+                write!(f, "'{}' is not a known error namespace", namespace)
+            }
+            Self::UnregisteredCode { namespace, code } => {
+                write!(f, "no error code {} is registered in namespace {}", code, namespace)
+            }
         }
     }
 }
 
+#[cfg(not(feature = "no_std"))]
 impl std::error::Error for InternalErrorCodeViolation {}
 
+/// See [`ImpactScoreError`]'s `core-error` impl above for why this is
+/// mutually exclusive with the `std` impl.
+#[cfg(all(feature = "no_std", feature = "core-error"))]
+impl core::error::Error for InternalErrorCodeViolation {}
+
 // ============================================================================
 // Error Code (Primary Identity Type)
 // ============================================================================
@@ -635,6 +773,10 @@ pub struct ErrorCode {
     code: u16,
     category: OperationCategory,
     impact: ImpactScore,
+    /// Long-form `--explain`-style remediation text, if the definition
+    /// supplied one. See [`Self::with_explanation`] and
+    /// [`crate::registry::Registry`].
+    explanation: Option<&'static str>,
 }
 
 impl ErrorCode {
@@ -681,9 +823,43 @@ impl ErrorCode {
             code,
             category,
             impact,
+            explanation: None,
         }
     }
 
+    /// Attach `--explain`-style remediation text to this code.
+    ///
+    /// Chains off [`Self::const_new`] in the `define_error_code!`/
+    /// `define_error_codes!` macros when their optional third tuple element
+    /// is supplied, e.g. `CFG_PARSE_FAILED = (100, 200, "...")`. A code with
+    /// no explanation simply has `None` here, same as if this were never
+    /// called - there is no separate "unset" sentinel to keep in sync.
+    ///
+    /// This text is intentionally kept out of [`InternalErrorCodeViolation::to_public`]
+    /// and every other public-facing path; it is reached only through
+    /// [`crate::registry::Registry::explain`] for trusted, operator-facing
+    /// tooling.
+    #[inline]
+    pub const fn with_explanation(mut self, explanation: &'static str) -> Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    /// Get the long-form explanation text, if one was supplied.
+    #[inline]
+    pub const fn explanation(&self) -> Option<&'static str> {
+        self.explanation
+    }
+
+    /// Alias for [`Self::explanation`], for callers reaching for rustc's
+    /// `--explain` naming (mirrors [`crate::registry::explain_code`], the
+    /// top-level entry point for a code seen in its external, possibly
+    /// obfuscated `E-CFG-103` form).
+    #[inline]
+    pub const fn explain(&self) -> Option<&'static str> {
+        self.explanation()
+    }
+
     /// Create a new error code with runtime validation (fallible, no panics).
     ///
     /// # Errors
@@ -727,6 +903,7 @@ impl ErrorCode {
             code,
             category,
             impact,
+            explanation: None,
         })
     }
 
@@ -759,6 +936,15 @@ impl ErrorCode {
     pub const fn impact_level(&self) -> ErrorImpact {
         self.impact.to_impact_level()
     }
+
+    /// Get the process exit code for this error's impact band.
+    ///
+    /// Shorthand for `self.impact_level().exit_code()` - see
+    /// [`ErrorImpact::exit_code`] for the full severity-to-code mapping.
+    #[inline]
+    pub const fn exit_code(&self) -> u8 {
+        self.impact_level().exit_code()
+    }
 }
 
 impl fmt::Display for ErrorCode {
@@ -768,6 +954,450 @@ impl fmt::Display for ErrorCode {
     }
 }
 
+// ============================================================================
+// Parsing (the inverse of Display)
+// ============================================================================
+
+impl ErrorCode {
+    /// Parse `"E-<NAMESPACE>-<CODE>"` back into the exact registered
+    /// [`ErrorCode`] it was rendered from.
+    ///
+    /// The string alone carries no category or impact information, so this
+    /// resolves both (and any hand-written `explanation`) from
+    /// [`crate::registry::ALL_CODES`] - the same source [`Display`] was
+    /// originally rendered from. This guarantees
+    /// `ErrorCode::parse(&code.to_string()) == Ok(code)` for every code a
+    /// `define_error_codes!` block has registered.
+    ///
+    /// For codes this build doesn't have registered (e.g. a newer peer's
+    /// taxonomy), use [`Self::parse_with_policy`] instead, supplying the
+    /// category/impact out of band.
+    ///
+    /// # Errors
+    ///
+    /// - [`InternalErrorCodeViolation::MalformedFormat`] if `s` doesn't
+    ///   match the `E-<NAMESPACE>-<CODE>` shape at all.
+    /// - [`InternalErrorCodeViolation::UnknownNamespace`] if the namespace
+    ///   token isn't one of [`namespaces::ALL`].
+    /// - [`InternalErrorCodeViolation::UnregisteredCode`] if the namespace
+    ///   is known but no entry registers that code within it.
+    pub fn parse(s: &str) -> Result<Self, InternalErrorCodeViolation> {
+        let (namespace_token, code) = Self::split_display(s)?;
+
+        let namespace = namespaces::ALL
+            .iter()
+            .copied()
+            .find(|ns| ns.as_str() == namespace_token)
+            .ok_or_else(|| InternalErrorCodeViolation::UnknownNamespace {
+                namespace: namespace_token.to_owned(),
+            })?;
+
+        let entry = crate::registry::lookup_by_parts(namespace.as_str(), code).ok_or(
+            InternalErrorCodeViolation::UnregisteredCode {
+                namespace: namespace.as_str(),
+                code,
+            },
+        )?;
+
+        let mut parsed = Self::checked_new(namespace, entry.code(), entry.category(), entry.impact())?;
+        if let Some(explanation) = entry.explanation() {
+            parsed = parsed.with_explanation(explanation);
+        }
+        Ok(parsed)
+    }
+
+    /// Parse `"E-<NAMESPACE>-<CODE>"` using a caller-supplied category and
+    /// impact rather than resolving them from the registry.
+    ///
+    /// Useful when `s` names a code this build doesn't have registered -
+    /// e.g. one received from a peer on a newer taxonomy version (see
+    /// [`crate::manifest`]) - but the caller knows the intended
+    /// classification out of band.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::parse`], except a known namespace with no matching
+    /// registry entry is accepted (and validated via
+    /// [`Self::checked_new`]) instead of returning
+    /// [`InternalErrorCodeViolation::UnregisteredCode`].
+    pub fn parse_with_policy(
+        s: &str,
+        category: OperationCategory,
+        impact: ImpactScore,
+    ) -> Result<Self, InternalErrorCodeViolation> {
+        let (namespace_token, code) = Self::split_display(s)?;
+
+        let namespace = namespaces::ALL
+            .iter()
+            .copied()
+            .find(|ns| ns.as_str() == namespace_token)
+            .ok_or_else(|| InternalErrorCodeViolation::UnknownNamespace {
+                namespace: namespace_token.to_owned(),
+            })?;
+
+        Self::checked_new(namespace, code, category, impact)
+    }
+
+    /// Split `"E-<NAMESPACE>-<CODE>"` into its namespace token and numeric
+    /// code, without resolving the namespace or code against anything.
+    fn split_display(s: &str) -> Result<(&str, u16), InternalErrorCodeViolation> {
+        let malformed = || InternalErrorCodeViolation::MalformedFormat { input: s.to_owned() };
+
+        let rest = s.strip_prefix("E-").ok_or_else(malformed)?;
+        let (namespace_token, code_str) = rest.rsplit_once('-').ok_or_else(malformed)?;
+        let code: u16 = code_str.parse().map_err(|_| malformed())?;
+
+        Ok((namespace_token, code))
+    }
+}
+
+impl core::str::FromStr for ErrorCode {
+    type Err = InternalErrorCodeViolation;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for ErrorCode {
+    type Error = InternalErrorCodeViolation;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+// ============================================================================
+// Source-Span Provenance
+// ============================================================================
+
+/// A region in a source file: `file_name` plus a start and end line/column.
+///
+/// # Rationale
+///
+/// Mirrors [`crate::models::SourceLocation`]'s "costs nothing, leaks nothing
+/// sensitive" reasoning - `file!()`/`line!()`/`column!()` are compile-time
+/// literals describing this crate's own source tree, not attacker-controlled
+/// data - but captures a *range* rather than a single point, for call sites
+/// that want to name the whole expression or token a code was raised for
+/// (e.g. a parser attaching a code to the span of the offending token).
+///
+/// # Copy Semantics
+///
+/// Same reasoning as `SourceLocation`: plain compile-time metadata, no owned
+/// or sensitive data, so `Copy` is appropriate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// Source file path, typically captured via `file!()`.
+    pub file_name: &'static str,
+    /// Starting line number (1-based, matching `line!()`).
+    pub start_line: u32,
+    /// Starting column number (1-based, matching `column!()`).
+    pub start_col: u32,
+    /// Ending line number.
+    pub end_line: u32,
+    /// Ending column number.
+    pub end_col: u32,
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}-{}:{}",
+            self.file_name, self.start_line, self.start_col, self.end_line, self.end_col
+        )
+    }
+}
+
+/// An [`ErrorCode`] paired with the optional [`SourceSpan`] it was raised at.
+///
+/// `ErrorCode` itself stays frozen, by-reference-only identity (see the
+/// "No-Copy/No-Clone Semantics" note above) - this wrapper is where a raised
+/// error gets to attach *where*, without forcing every `ErrorCode` definition
+/// to carry span overhead it will almost never use.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::codes::{SourceSpan, SpannedErrorCode};
+/// use palisade_errors::definitions::IO_READ_FAILED;
+///
+/// let spanned = SpannedErrorCode::new(&IO_READ_FAILED).with_span(SourceSpan {
+///     file_name: "src/io.rs",
+///     start_line: 42,
+///     start_col: 5,
+///     end_line: 42,
+///     end_col: 19,
+/// });
+///
+/// assert_eq!(spanned.to_string(), "E-IO-800 @ src/io.rs:42:5-42:19");
+/// assert_eq!(SpannedErrorCode::new(&IO_READ_FAILED).to_string(), "E-IO-800");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpannedErrorCode {
+    code: &'static ErrorCode,
+    span: Option<SourceSpan>,
+}
+
+impl SpannedErrorCode {
+    /// Wrap `code` with no span attached yet.
+    #[inline]
+    pub const fn new(code: &'static ErrorCode) -> Self {
+        Self { code, span: None }
+    }
+
+    /// Attach (or replace) the source span this code was raised at.
+    #[inline]
+    pub const fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// The wrapped error code.
+    #[inline]
+    pub const fn code(&self) -> &'static ErrorCode {
+        self.code
+    }
+
+    /// The attached source span, if any.
+    #[inline]
+    pub const fn span(&self) -> Option<SourceSpan> {
+        self.span
+    }
+
+    /// Sanitized public form: the bare `E-{NS}-{code}` identity with the span
+    /// omitted, same as `InternalErrorCodeViolation::to_public` drops taxonomy
+    /// detail - a file path and line/column are provenance for the team that
+    /// owns this source tree, not information an external caller should see.
+    pub fn to_public(&self) -> PublicSpannedCode {
+        PublicSpannedCode(self.code)
+    }
+}
+
+impl fmt::Display for SpannedErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)?;
+        if let Some(span) = self.span {
+            write!(f, " @ {}", span)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&'static ErrorCode> for SpannedErrorCode {
+    fn from(code: &'static ErrorCode) -> Self {
+        Self::new(code)
+    }
+}
+
+/// The sanitized, span-free form of a [`SpannedErrorCode`] - see
+/// [`SpannedErrorCode::to_public`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicSpannedCode(&'static ErrorCode);
+
+impl fmt::Display for PublicSpannedCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// ============================================================================
+// Dual-Mode Serialization (audit vs public)
+// ============================================================================
+
+/// Two distinct, explicit serializations of an [`ErrorCode`] - mirrors
+/// [`InternalErrorCodeViolation::to_public`]'s split between an internal
+/// representation and one safe to hand to an untrusted boundary. The same
+/// split is offered for [`InternalErrorCodeViolation`] itself via
+/// [`ViolationView`]/[`PublicViolationView`].
+///
+/// There is deliberately no single `impl Serialize for ErrorCode` (or for
+/// `InternalErrorCodeViolation`): picking the disclosure level is forced to
+/// be an explicit choice at every call site (`AuditView(code)` vs
+/// `PublicView(code)`), so a `#[derive(Serialize)]` field somewhere upstream
+/// can never silently leak the audit shape into a public response, or vice
+/// versa.
+#[cfg(feature = "serde")]
+mod serde_views {
+    use super::ErrorCode;
+    use serde::ser::SerializeStruct;
+    use serde::{Serialize, Serializer};
+
+    /// Full internal representation, for audit logs and trusted sinks.
+    ///
+    /// Emits namespace, numeric code, category, and both the raw impact
+    /// score and its named band - everything [`ErrorCode`] knows, including
+    /// the namespace authority model a [`PublicView`] exists specifically to
+    /// hide.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::codes::AuditView;
+    /// use palisade_errors::definitions::CFG_PARSE_FAILED;
+    ///
+    /// let json = serde_json::to_string(&AuditView(&CFG_PARSE_FAILED)).unwrap();
+    /// assert!(json.contains("\"namespace\":\"CFG\""));
+    /// assert!(json.contains("\"impact\":200"));
+    /// ```
+    pub struct AuditView<'a>(pub &'a ErrorCode);
+
+    impl Serialize for AuditView<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let code = self.0;
+            let mut state = serializer.serialize_struct("ErrorCode", 5)?;
+            state.serialize_field("namespace", code.namespace().as_str())?;
+            state.serialize_field("code", &code.code())?;
+            state.serialize_field("category", code.category().display_name())?;
+            state.serialize_field("impact", &code.impact().value())?;
+            state.serialize_field("impact_level", code.impact_level().label())?;
+            state.end()
+        }
+    }
+
+    /// Sanitized public representation, safe to cross an untrusted boundary.
+    ///
+    /// Emits only the rendered `E-XXX-YYY` string, the operation category,
+    /// and the named impact band - never the bare namespace, the raw 0-1000
+    /// score, or anything else that would reveal namespace authority or
+    /// scoring internals, matching
+    /// [`InternalErrorCodeViolation::to_public`]'s contract.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::codes::PublicView;
+    /// use palisade_errors::definitions::CFG_PARSE_FAILED;
+    ///
+    /// let json = serde_json::to_string(&PublicView(&CFG_PARSE_FAILED)).unwrap();
+    /// assert_eq!(json, r#"{"code":"E-CFG-100","category":"Configuration","level":"Jitter"}"#);
+    /// ```
+    pub struct PublicView<'a>(pub &'a ErrorCode);
+
+    impl Serialize for PublicView<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let code = self.0;
+            let mut state = serializer.serialize_struct("ErrorCode", 3)?;
+            state.serialize_field("code", &code.to_string())?;
+            state.serialize_field("category", code.category().display_name())?;
+            state.serialize_field("level", code.impact_level().label())?;
+            state.end()
+        }
+    }
+
+    /// Full internal representation of an [`super::InternalErrorCodeViolation`],
+    /// for audit logs and trusted sinks - reveals the namespace, category, and
+    /// impact policy details that [`super::InternalErrorCodeViolation::to_public`]
+    /// exists to keep out of untrusted-facing text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::codes::ViolationView;
+    /// use palisade_errors::InternalErrorCodeViolation;
+    ///
+    /// let violation = InternalErrorCodeViolation::CategoryNotPermitted {
+    ///     namespace: "IO",
+    ///     category: "Deception",
+    /// };
+    /// let json = serde_json::to_string(&ViolationView(&violation)).unwrap();
+    /// assert!(json.contains("\"namespace\":\"IO\""));
+    /// ```
+    pub struct ViolationView<'a>(pub &'a super::InternalErrorCodeViolation);
+
+    impl Serialize for ViolationView<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            use super::InternalErrorCodeViolation::*;
+            match self.0 {
+                CodeOutOfRange { value } => {
+                    let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 2)?;
+                    state.serialize_field("kind", "CodeOutOfRange")?;
+                    state.serialize_field("value", value)?;
+                    state.end()
+                }
+                CategoryNotPermitted { namespace, category } => {
+                    let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 3)?;
+                    state.serialize_field("kind", "CategoryNotPermitted")?;
+                    state.serialize_field("namespace", namespace)?;
+                    state.serialize_field("category", category)?;
+                    state.end()
+                }
+                ImpactNotPermitted { namespace, impact } => {
+                    let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 3)?;
+                    state.serialize_field("kind", "ImpactNotPermitted")?;
+                    state.serialize_field("namespace", namespace)?;
+                    state.serialize_field("impact", impact)?;
+                    state.end()
+                }
+                MalformedFormat { input } => {
+                    let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 2)?;
+                    state.serialize_field("kind", "MalformedFormat")?;
+                    state.serialize_field("input", input)?;
+                    state.end()
+                }
+                UnknownNamespace { namespace } => {
+                    let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 2)?;
+                    state.serialize_field("kind", "UnknownNamespace")?;
+                    state.serialize_field("namespace", namespace)?;
+                    state.end()
+                }
+                UnregisteredCode { namespace, code } => {
+                    let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 3)?;
+                    state.serialize_field("kind", "UnregisteredCode")?;
+                    state.serialize_field("namespace", namespace)?;
+                    state.serialize_field("code", code)?;
+                    state.end()
+                }
+            }
+        }
+    }
+
+    /// Sanitized public representation of an
+    /// [`super::InternalErrorCodeViolation`] - emits only the same generic
+    /// message [`super::InternalErrorCodeViolation::to_public`] already
+    /// returns as a string, never the namespace, category, or impact detail
+    /// that would reveal policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::codes::PublicViolationView;
+    /// use palisade_errors::InternalErrorCodeViolation;
+    ///
+    /// let violation = InternalErrorCodeViolation::CategoryNotPermitted {
+    ///     namespace: "IO",
+    ///     category: "Deception",
+    /// };
+    /// let json = serde_json::to_string(&PublicViolationView(&violation)).unwrap();
+    /// assert_eq!(json, r#"{"message":"Invalid error configuration"}"#);
+    /// ```
+    pub struct PublicViolationView<'a>(pub &'a super::InternalErrorCodeViolation);
+
+    impl Serialize for PublicViolationView<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("InternalErrorCodeViolation", 1)?;
+            state.serialize_field("message", self.0.to_public())?;
+            state.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use serde_views::{AuditView, PublicView, PublicViolationView, ViolationView};
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -775,6 +1405,8 @@ impl fmt::Display for ErrorCode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "no_std")]
+    use alloc::string::ToString;
     use crate::define_error_codes;
 
     // ========================================================================
@@ -863,6 +1495,144 @@ mod tests {
         assert!(!violation.to_public().contains("IO"));
     }
 
+    // ========================================================================
+    // Parsing Tests
+    // ========================================================================
+
+    #[test]
+    fn parse_round_trips_every_registered_code() {
+        for code in crate::registry::ALL_CODES {
+            let parsed = ErrorCode::parse(&code.to_string()).unwrap();
+            assert_eq!(&parsed, *code);
+        }
+    }
+
+    #[test]
+    fn from_str_and_try_from_agree_with_parse() {
+        use core::str::FromStr;
+
+        let via_parse = ErrorCode::parse("E-CFG-100").unwrap();
+        let via_from_str = ErrorCode::from_str("E-CFG-100").unwrap();
+        let via_try_from = ErrorCode::try_from("E-CFG-100").unwrap();
+
+        assert_eq!(via_parse, via_from_str);
+        assert_eq!(via_parse, via_try_from);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_format() {
+        let result = ErrorCode::parse("not-a-code");
+        assert!(matches!(
+            result,
+            Err(InternalErrorCodeViolation::MalformedFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_namespace() {
+        let result = ErrorCode::parse("E-NOPE-100");
+        assert!(matches!(
+            result,
+            Err(InternalErrorCodeViolation::UnknownNamespace { namespace }) if namespace == "NOPE"
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_unregistered_code() {
+        let result = ErrorCode::parse("E-CFG-999");
+        assert!(matches!(
+            result,
+            Err(InternalErrorCodeViolation::UnregisteredCode {
+                namespace: "CFG",
+                code: 999,
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_with_policy_accepts_an_unregistered_code() {
+        let code = ErrorCode::parse_with_policy(
+            "E-CFG-999",
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        )
+        .unwrap();
+
+        assert_eq!(code.to_string(), "E-CFG-999");
+    }
+
+    // ========================================================================
+    // Source-Span Tests
+    // ========================================================================
+
+    #[test]
+    fn spanned_error_code_without_span_matches_plain_display() {
+        const CODE: ErrorCode = ErrorCode::const_new(
+            &namespaces::IO,
+            200,
+            OperationCategory::IO,
+            ImpactScore::new(500),
+        );
+
+        assert_eq!(SpannedErrorCode::new(&CODE).to_string(), CODE.to_string());
+    }
+
+    #[test]
+    fn spanned_error_code_with_span_appends_location() {
+        const CODE: ErrorCode = ErrorCode::const_new(
+            &namespaces::IO,
+            200,
+            OperationCategory::IO,
+            ImpactScore::new(500),
+        );
+
+        let spanned = SpannedErrorCode::new(&CODE).with_span(SourceSpan {
+            file_name: "src/io.rs",
+            start_line: 42,
+            start_col: 5,
+            end_line: 42,
+            end_col: 19,
+        });
+
+        assert_eq!(spanned.to_string(), "E-IO-200 @ src/io.rs:42:5-42:19");
+    }
+
+    #[test]
+    fn spanned_error_code_to_public_omits_span() {
+        const CODE: ErrorCode = ErrorCode::const_new(
+            &namespaces::IO,
+            200,
+            OperationCategory::IO,
+            ImpactScore::new(500),
+        );
+
+        let spanned = SpannedErrorCode::new(&CODE).with_span(SourceSpan {
+            file_name: "src/secret_internal_path.rs",
+            start_line: 1,
+            start_col: 1,
+            end_line: 1,
+            end_col: 1,
+        });
+
+        let public = spanned.to_public().to_string();
+        assert_eq!(public, "E-IO-200");
+        assert!(!public.contains("secret_internal_path"));
+    }
+
+    #[test]
+    fn from_error_code_ref_constructs_unspanned() {
+        const CODE: ErrorCode = ErrorCode::const_new(
+            &namespaces::IO,
+            200,
+            OperationCategory::IO,
+            ImpactScore::new(500),
+        );
+
+        let spanned: SpannedErrorCode = (&CODE).into();
+        assert_eq!(spanned.span(), None);
+        assert_eq!(spanned.code(), &CODE);
+    }
+
     // ========================================================================
     // Category Policy Tests
     // ========================================================================
@@ -969,4 +1739,167 @@ mod tests {
         assert_eq!(IO_READ_ERROR.to_string(), "E-IO-100");
         assert_eq!(IO_WRITE_ERROR.to_string(), "E-IO-101");
     }
+
+    // ========================================================================
+    // Exit Code Tests
+    // ========================================================================
+
+    #[test]
+    fn recoverable_bands_share_exit_code_one() {
+        assert_eq!(ErrorImpact::Noise.exit_code(), EXIT_RECOVERABLE);
+        assert_eq!(ErrorImpact::Flaw.exit_code(), EXIT_RECOVERABLE);
+        assert_eq!(ErrorImpact::Jitter.exit_code(), EXIT_RECOVERABLE);
+        assert_eq!(ErrorImpact::Glitch.exit_code(), EXIT_RECOVERABLE);
+        assert_eq!(ErrorImpact::Suspicion.exit_code(), EXIT_RECOVERABLE);
+        assert_eq!(EXIT_RECOVERABLE, 1);
+    }
+
+    #[test]
+    fn leak_has_its_own_exit_code() {
+        assert_eq!(ErrorImpact::Leak.exit_code(), EXIT_LEAK);
+        assert_ne!(EXIT_LEAK, EXIT_RECOVERABLE);
+    }
+
+    #[test]
+    fn catastrophic_bands_each_get_a_distinct_code() {
+        assert_eq!(ErrorImpact::Collapse.exit_code(), EXIT_CATASTROPHIC);
+        assert_eq!(ErrorImpact::Escalation.exit_code(), EXIT_ESCALATION);
+        assert_eq!(ErrorImpact::Breach.exit_code(), EXIT_BREACH);
+        assert_eq!(EXIT_CATASTROPHIC, 101);
+
+        let codes = [EXIT_RECOVERABLE, EXIT_LEAK, EXIT_CATASTROPHIC, EXIT_ESCALATION, EXIT_BREACH];
+        for (i, a) in codes.iter().enumerate() {
+            for b in &codes[i + 1..] {
+                assert_ne!(a, b, "exit codes must be pairwise distinct");
+            }
+        }
+    }
+
+    #[test]
+    fn error_code_exit_code_matches_impact_level() {
+        let code = ErrorCode::checked_new(
+            &namespaces::IO,
+            1,
+            OperationCategory::IO,
+            ImpactScore::new(800),
+        )
+        .unwrap();
+
+        assert_eq!(code.exit_code(), ErrorImpact::Collapse.exit_code());
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn error_impact_converts_into_process_exit_code() {
+        let exit: std::process::ExitCode = ErrorImpact::Breach.into();
+        assert_eq!(format!("{exit:?}"), format!("{:?}", std::process::ExitCode::from(EXIT_BREACH)));
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn impact_score_error_is_a_std_error() {
+        fn assert_error<E: std::error::Error>(_: &E) {}
+        assert_error(&ImpactScoreError::OutOfRange { value: 1001 });
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn internal_error_code_violation_is_a_std_error() {
+        fn assert_error<E: std::error::Error>(_: &E) {}
+        assert_error(&InternalErrorCodeViolation::CodeOutOfRange { value: 1000 });
+    }
+
+    #[cfg(all(feature = "no_std", feature = "core-error"))]
+    #[test]
+    fn impact_score_error_is_a_core_error_under_no_std() {
+        fn assert_error<E: core::error::Error>(_: &E) {}
+        assert_error(&ImpactScoreError::OutOfRange { value: 1001 });
+    }
+
+    #[cfg(all(feature = "no_std", feature = "core-error"))]
+    #[test]
+    fn internal_error_code_violation_is_a_core_error_under_no_std() {
+        fn assert_error<E: core::error::Error>(_: &E) {}
+        assert_error(&InternalErrorCodeViolation::CodeOutOfRange { value: 1000 });
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_views {
+        use super::*;
+        use crate::{AuditView, PublicView, PublicViolationView, ViolationView};
+
+        const CODE: ErrorCode = ErrorCode::const_new(
+            &namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(700),
+        );
+
+        #[test]
+        fn audit_view_exposes_namespace_and_raw_score() {
+            let json = serde_json::to_string(&AuditView(&CODE)).unwrap();
+            assert!(json.contains("\"namespace\":\"CFG\""));
+            assert!(json.contains("\"code\":100"));
+            assert!(json.contains("\"impact\":700"));
+            assert!(json.contains("\"impact_level\":\"Leak\""));
+        }
+
+        #[test]
+        fn public_view_hides_namespace_and_raw_score() {
+            let json = serde_json::to_string(&PublicView(&CODE)).unwrap();
+            assert!(json.contains("\"code\":\"E-CFG-100\""));
+            assert!(!json.contains("namespace"));
+            assert!(!json.contains("700"));
+            assert!(json.contains("\"level\":\"Leak\""));
+        }
+
+        #[test]
+        fn public_view_is_strictly_smaller_than_audit_view() {
+            let audit = serde_json::to_string(&AuditView(&CODE)).unwrap();
+            let public = serde_json::to_string(&PublicView(&CODE)).unwrap();
+            assert!(public.len() < audit.len());
+        }
+
+        #[test]
+        fn violation_view_exposes_namespace_and_category() {
+            let violation = InternalErrorCodeViolation::CategoryNotPermitted {
+                namespace: "IO",
+                category: "Deception",
+            };
+            let json = serde_json::to_string(&ViolationView(&violation)).unwrap();
+            assert!(json.contains("\"namespace\":\"IO\""));
+            assert!(json.contains("\"category\":\"Deception\""));
+        }
+
+        #[test]
+        fn public_violation_view_hides_namespace_and_category() {
+            let violation = InternalErrorCodeViolation::CategoryNotPermitted {
+                namespace: "IO",
+                category: "Deception",
+            };
+            let json = serde_json::to_string(&PublicViolationView(&violation)).unwrap();
+            assert_eq!(json, r#"{"message":"Invalid error configuration"}"#);
+            assert!(!json.contains("IO"));
+            assert!(!json.contains("Deception"));
+        }
+
+        #[test]
+        fn public_violation_view_is_field_disjoint_from_violation_view() {
+            // Analogous to `violation_to_public_sanitizes_details`, but for the
+            // JSON forms: no key or value in the public view should appear in
+            // the internal view except the deliberately shared literal.
+            let violation = InternalErrorCodeViolation::ImpactNotPermitted {
+                namespace: "RSP",
+                impact: 960,
+            };
+            let internal = serde_json::to_string(&ViolationView(&violation)).unwrap();
+            let public = serde_json::to_string(&PublicViolationView(&violation)).unwrap();
+
+            assert!(internal.contains("RSP"));
+            assert!(internal.contains("960"));
+            assert!(!public.contains("RSP"));
+            assert!(!public.contains("960"));
+            assert!(public.len() < internal.len());
+        }
+    }
 }
@@ -0,0 +1,241 @@
+// src/config.rs
+//! TOML-driven startup configuration for disclosure and obfuscation policy.
+//!
+//! # Purpose
+//!
+//! Until now, a deployment wanting per-session obfuscation had to call
+//! [`crate::obfuscation::init_session_salt`] with a hardcoded seed (see
+//! `examples/basic_usage.rs`), and whether [`crate::logging::InternalLog`]'s
+//! viewers see the real or the obfuscated code, which external message a
+//! category shows, and which categories count as permanent failures were
+//! all baked into call sites at compile time. [`PalisadeConfig`] collects
+//! all four knobs into one file, loaded once at startup, so a deployment
+//! can change disclosure behavior without recompiling - the same reasoning
+//! that has `rustfmt.toml`/`.git-journal.toml` drive formatting and
+//! changelog policy from a file instead of flags baked into the binary.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use palisade_errors::config::PalisadeConfig;
+//!
+//! let config = PalisadeConfig::from_toml_path("palisade.toml").expect("valid config");
+//! config.init();
+//! ```
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `toml_config` feature, so the core path never takes
+//! a hard dependency on `toml` - the same reasoning as [`crate::advisory`]
+//! and `serde`.
+
+use crate::models::OperationCategory;
+use serde::Deserialize;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+thread_local! {
+    /// Whether [`crate::logging::InternalLog::disclosed_code`] reveals the
+    /// real, pre-obfuscation code instead of the same obfuscated code
+    /// `Display` shows externally. Mirrors
+    /// [`crate::obfuscation`]'s own thread-local session salt - each
+    /// thread/session can carry its own disclosure policy.
+    static REVEAL_REAL_CODE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Policy controlling obfuscation and disclosure, loaded from a `.toml` file.
+///
+/// # Fields
+///
+/// - `session_salt`: fixed obfuscation salt for
+///   [`crate::obfuscation::init_session_salt`]. `None` (the default) draws a
+///   fresh salt every boot via
+///   [`crate::obfuscation::generate_random_salt`], so `init()` never leaves
+///   obfuscation disabled by omission the way passing `0` would.
+/// - `reveal_real_code_internally`: whether
+///   [`crate::logging::InternalLog::disclosed_code`] returns the real code
+///   instead of the obfuscated one.
+/// - `external_message_templates`: per-category override for the message an
+///   attacker sees, keyed by [`OperationCategory::display_name`] (e.g.
+///   `"Configuration"`). Categories with no entry keep the stock wording.
+/// - `permanent_categories`: categories whose failures should be reported as
+///   permanent (non-retryable) by default, keyed the same way.
+///
+/// # Example
+///
+/// ```toml
+/// reveal_real_code_internally = true
+/// permanent_categories = ["Configuration", "Deployment"]
+///
+/// [external_message_templates]
+/// Deception = "Service temporarily unavailable."
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PalisadeConfig {
+    pub session_salt: Option<u32>,
+    pub reveal_real_code_internally: bool,
+    pub external_message_templates: HashMap<String, String>,
+    pub permanent_categories: Vec<String>,
+}
+
+impl PalisadeConfig {
+    /// Load and parse a `PalisadeConfig` from a `.toml` file on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Io`] if the file can't be read, or
+    /// [`ConfigError::Parse`] if it can't be parsed as TOML into this shape.
+    pub fn from_toml_path(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path).map_err(|e| ConfigError::Io {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        toml::from_str(&raw).map_err(|e| ConfigError::Parse {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// The external message template configured for `category`, if any.
+    ///
+    /// Used as a fallback when an error's builder doesn't set an explicit
+    /// `public_lie` - see [`crate::ContextBuilder::detect_with`] for the
+    /// other source of default external messages.
+    pub fn external_message_for(&self, category: OperationCategory) -> Option<&str> {
+        self.external_message_templates
+            .get(category.display_name())
+            .map(String::as_str)
+    }
+
+    /// Whether `category` is configured as a permanent (non-retryable)
+    /// failure category.
+    pub fn is_permanent_category(&self, category: OperationCategory) -> bool {
+        self.permanent_categories
+            .iter()
+            .any(|name| OperationCategory::from_display_name(name) == Some(category))
+    }
+
+    /// Apply this policy process-wide: initializes the obfuscation session
+    /// salt (fixed, or freshly random if `session_salt` is `None`) and sets
+    /// the current thread's code-disclosure policy.
+    ///
+    /// Replaces a manual `init_session_salt(...)` call at startup.
+    pub fn init(&self) {
+        let salt = self
+            .session_salt
+            .unwrap_or_else(crate::obfuscation::generate_random_salt);
+        crate::obfuscation::init_session_salt(salt);
+        REVEAL_REAL_CODE.with(|v| v.set(self.reveal_real_code_internally));
+    }
+}
+
+/// Replace a manual `init_session_salt(...)` call with one driven by
+/// `config`. Equivalent to `config.init()`, offered as a free function for
+/// callers who prefer the "one line at the top of `main`" style used
+/// elsewhere in this crate (`init_session_salt`, `clear_session_salt`).
+#[inline]
+pub fn init_from_config(config: &PalisadeConfig) {
+    config.init();
+}
+
+/// Whether the current thread's disclosure policy reveals the real,
+/// pre-obfuscation code to internal viewers - set via
+/// [`PalisadeConfig::init`]/[`init_from_config`], `false` until then.
+#[inline]
+pub(crate) fn reveal_real_code_internally() -> bool {
+    REVEAL_REAL_CODE.with(Cell::get)
+}
+
+/// Failure to load a [`PalisadeConfig`] from disk.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file couldn't be read.
+    Io { path: String, source: std::io::Error },
+    /// The file was read but isn't valid TOML for this shape.
+    Parse { path: String, message: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "failed to read config file {path}: {source}"),
+            Self::Parse { path, message } => {
+                write!(f, "failed to parse config file {path} as TOML: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_reveals_nothing_and_obfuscates() {
+        let config = PalisadeConfig::default();
+        assert_eq!(config.session_salt, None);
+        assert!(!config.reveal_real_code_internally);
+        assert!(config.external_message_templates.is_empty());
+        assert!(config.permanent_categories.is_empty());
+    }
+
+    #[test]
+    fn parses_a_minimal_toml_document() {
+        let toml_str = r#"
+            reveal_real_code_internally = true
+            permanent_categories = ["Configuration", "Deployment"]
+
+            [external_message_templates]
+            Deception = "Service temporarily unavailable."
+        "#;
+        let config: PalisadeConfig = toml::from_str(toml_str).unwrap();
+
+        assert!(config.reveal_real_code_internally);
+        assert_eq!(
+            config.external_message_for(OperationCategory::Deception),
+            Some("Service temporarily unavailable.")
+        );
+        assert!(config.is_permanent_category(OperationCategory::Configuration));
+        assert!(!config.is_permanent_category(OperationCategory::IO));
+    }
+
+    #[test]
+    fn from_toml_path_surfaces_a_parse_error_on_malformed_input() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("palisade_config_test_{}.toml", std::process::id()));
+        std::fs::write(&path, "reveal_real_code_internally = not_a_bool").unwrap();
+
+        let result = PalisadeConfig::from_toml_path(&path);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(matches!(result, Err(ConfigError::Parse { .. })));
+    }
+
+    #[test]
+    fn from_toml_path_surfaces_an_io_error_on_missing_file() {
+        let result = PalisadeConfig::from_toml_path("/nonexistent/palisade.toml");
+        assert!(matches!(result, Err(ConfigError::Io { .. })));
+    }
+
+    #[test]
+    fn init_sets_the_session_salt_and_disclosure_policy() {
+        let config = PalisadeConfig {
+            session_salt: Some(42),
+            reveal_real_code_internally: true,
+            ..Default::default()
+        };
+        config.init();
+
+        assert_eq!(crate::obfuscation::get_session_salt(), 42);
+        assert!(reveal_real_code_internally());
+
+        crate::obfuscation::clear_session_salt();
+        REVEAL_REAL_CODE.with(|v| v.set(false));
+    }
+}
@@ -9,8 +9,13 @@
 //! dual-context system rather than replacing it:
 //!
 //! - `ContextBuilder`: Fluent API for constructing rich error contexts
-//! - `ContextMetadata`: **FUTURE**: Structured metadata (not yet integrated with DualContextError)
+//! - `ContextMetadata`: Structured, zeroized key/value diagnostic args (see `models.rs`)
 //! - `ContextChain`: Causality tracking for error chains
+//! - `ChainLink`: A chain entry plus its ordered sub-notes (subdiagnostic model)
+//! - `Checkpoint`: Bayou-style fold of a chain's interior links, produced by
+//!   `ContextChain::compact` once `depth()` outgrows a caller-chosen threshold
+//! - `Emitter`: Pluggable rendering of a `ContextChain` (`HumanEmitter`, `JsonEmitter`)
+//! - `IntoDualContext`: Severity-parameterized conversion from foreign error types
 //!
 //! # Security Properties
 //!
@@ -30,199 +35,28 @@
 //!     .category(OperationCategory::IO)
 //!     .build();
 //! ```
-//!
-//! # Future Work: Metadata Integration
-//!
-//! `ContextMetadata` is provided as a foundation for future enhancement but is not
-//! yet integrated with `DualContextError`. When metadata support is added to the
-//! core error type, this module will provide the builder interface for it.
-//!
-//! Until then, metadata is architecturally orphaned and should not be used in
-//! production code paths.
 
-use crate::{DualContextError, InternalContext, OperationCategory, PublicContext};
+use crate::{
+    Clearance, Confidence, ContextMetadata, DualContextError, ErrorCode, IntegrityError,
+    IntegrityTag, InternalContext, MetadataTrust, OperationCategory, PublicContext, Remediation,
+    ResponseHint, Severity, SigningKey, SocAccess,
+};
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "no_std")]
+use core::error::Error as StdError;
+use core::mem;
 use smallvec::SmallVec;
+#[cfg(not(feature = "no_std"))]
 use std::borrow::Cow;
+#[cfg(not(feature = "no_std"))]
+use std::error::Error as StdError;
 use zeroize::Zeroize;
 
-// ============================================================================
-// Context Metadata (Structured, Zeroized)
-// ============================================================================
-//
-// ⚠️ ARCHITECTURAL NOTE: METADATA IS NOT YET INTEGRATED
-//
-// The types below provide structured metadata with zeroization, but are not
-// currently wired into DualContextError. They exist as a foundation for future
-// enhancement when the core error type gains metadata support.
-//
-// GOVERNANCE: Types are pub(crate) to prevent external use until integration.
-// When metadata is wired through DualContextError, promote to pub.
-//
-// See module-level documentation for the full integration roadmap.
-// ============================================================================
-
-/// Metadata key-value pair with automatic zeroization.
-///
-/// # Design Rationale
-///
-/// Keys are `&'static str` because metadata keys should be compile-time constants
-/// (e.g., "correlation_id", "session_token"). This prevents runtime injection
-/// attacks and makes the metadata schema greppable.
-///
-/// Values are `Cow<'static, str>` to support both:
-/// - Static metadata: `Cow::Borrowed("literal")`
-/// - Dynamic metadata: `Cow::Owned(runtime_string)`
-///
-/// Only `Cow::Owned` variants are zeroized, as borrowed data points to static
-/// program memory that cannot be cleared.
-///
-/// # No Clone Policy
-///
-/// Matches parent `ContextMetadata` no-clone policy to prevent lifetime extension.
-///
-/// # Visibility
-///
-/// This type is `pub(crate)` until metadata integration is complete. External
-/// use would create false observability assumptions.
-#[allow(dead_code)]
-pub(crate) struct MetadataEntry {
-    key: &'static str,
-    value: Cow<'static, str>,
-}
-
-impl Zeroize for MetadataEntry {
-    fn zeroize(&mut self) {
-        // Keys are static, only zeroize owned values
-        if let Cow::Owned(ref mut s) = self.value {
-            s.zeroize();
-        }
-    }
-}
-
-impl Drop for MetadataEntry {
-    fn drop(&mut self) {
-        self.zeroize();
-    }
-}
-
-/// Structured metadata collection with automatic zeroization.
-///
-/// # Capacity Choice
-///
-/// SmallVec<[T; 4]> based on profiling:
-/// - 90% of errors have ≤2 metadata entries
-/// - 4 entries fit in ~192 bytes (acceptable inline size)
-/// - Avoids heap allocation for typical cases
-/// - Degrades gracefully to heap for exceptional cases
-///
-/// # Security
-///
-/// All metadata is zeroized on drop. This includes:
-/// - Correlation IDs (prevent session linkage)
-/// - User IDs (prevent user enumeration)
-/// - Timing data (prevent timing analysis)
-/// - Any other contextual information
-///
-/// # No Clone Policy
-///
-/// This type does NOT implement Clone to prevent accidental lifetime extension
-/// of sensitive data. Cloning would multiply zeroization sites and complicate
-/// threat modeling under memory inspection attacks.
-///
-/// # Visibility
-///
-/// This type is `pub(crate)` to enforce governance: metadata cannot be used in
-/// production until properly integrated with DualContextError. This prevents
-/// developers from building features on top of architectural debt.
-///
-/// When metadata support is added to models.rs, promote this to `pub`.
-pub(crate) struct ContextMetadata {
-    entries: SmallVec<[MetadataEntry; 4]>,
-}
-
-impl ContextMetadata {
-    /// Create empty metadata collection.
-    #[inline]
-    pub(crate) fn new() -> Self {
-        Self {
-            entries: SmallVec::new(),
-        }
-    }
-
-    /// Add a metadata entry.
-    ///
-    /// # Arguments
-    ///
-    /// - `key`: Static string literal (e.g., "correlation_id")
-    /// - `value`: Static or owned string value
-    ///
-    /// # Example
-    ///
-    /// ```rust,ignore
-    /// # use palisade_errors::ContextMetadata;
-    /// let mut meta = ContextMetadata::new();
-    /// meta.add("request_id", "req-123"); // Static
-    /// meta.add("user_id", format!("user-{}", 42)); // Owned
-    /// ```
-    #[inline]
-    pub(crate) fn add(&mut self, key: &'static str, value: impl Into<Cow<'static, str>>) {
-        self.entries.push(MetadataEntry {
-            key,
-            value: value.into(),
-        });
-    }
-
-    /// Get metadata value by key.
-    ///
-    /// Returns the first matching entry if multiple exist with the same key.
-    #[inline]
-    pub(crate) fn get(&self, key: &'static str) -> Option<&str> {
-        self.entries
-            .iter()
-            .find(|e| e.key == key)
-            .map(|e| e.value.as_ref())
-    }
-
-    /// Iterate over all metadata entries.
-    #[inline]
-    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
-        self.entries.iter().map(|e| (e.key, e.value.as_ref()))
-    }
-
-    /// Check if metadata is empty.
-    #[inline]
-    pub(crate) fn is_empty(&self) -> bool {
-        self.entries.is_empty()
-    }
-
-    /// Get number of metadata entries.
-    #[inline]
-    pub(crate) fn len(&self) -> usize {
-        self.entries.len()
-    }
-}
-
-impl Default for ContextMetadata {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Zeroize for ContextMetadata {
-    fn zeroize(&mut self) {
-        for entry in &mut self.entries {
-            entry.zeroize();
-        }
-        self.entries.clear();
-    }
-}
-
-impl Drop for ContextMetadata {
-    fn drop(&mut self) {
-        self.zeroize();
-    }
-}
-
 // ============================================================================
 // Context Builder (Fluent API)
 // ============================================================================
@@ -270,17 +104,39 @@ impl Drop for ContextMetadata {
 pub struct ContextBuilder {
     public: Option<PublicContext>,
     internal: Option<InternalContext>,
+    /// Authentic breadcrumbs accumulated via [`Self::note`], joined into the
+    /// final internal diagnostic at build time if `internal` was never set
+    /// directly. See [`Self::note`] for why these are a separate field
+    /// rather than repeated calls to `internal_diagnostic()`.
+    notes: SmallVec<[Cow<'static, str>; 2]>,
     category: OperationCategory,
+    external_severity: Option<Severity>,
+    internal_severity: Option<Severity>,
+    metadata: ContextMetadata,
+    remediations: SmallVec<[Remediation; 2]>,
+    signing_key: Option<SigningKey>,
+    code: Option<&'static ErrorCode>,
+    response_hint: Option<ResponseHint>,
 }
 
 impl ContextBuilder {
-    /// Create a new builder with default category (System).
+    /// Create a new builder with default category (System), default severity
+    /// (`Severity::Error` on both sides of the trust boundary), and no
+    /// metadata or remediations.
     #[inline]
     pub fn new() -> Self {
         Self {
             public: None,
             internal: None,
+            notes: SmallVec::new(),
             category: OperationCategory::System,
+            external_severity: None,
+            internal_severity: None,
+            metadata: ContextMetadata::new(),
+            remediations: SmallVec::new(),
+            signing_key: None,
+            code: None,
+            response_hint: None,
         }
     }
 
@@ -378,6 +234,37 @@ impl ContextBuilder {
         self
     }
 
+    /// Set internal context as sensitive, tagged with the minimum
+    /// [`Clearance`] required to view it (see
+    /// [`crate::ledger::ClearanceToken`]).
+    ///
+    /// Unlike [`Self::internal_sensitive`], this content is also reachable
+    /// without `SocAccess` or a `Capability` - via
+    /// [`crate::DualContextError::expose_sensitive_at`] - as long as the
+    /// presented token's level meets `level`, with every attempt recorded
+    /// in an [`crate::ledger::AccessLedger`].
+    ///
+    /// # Panics (Debug Mode)
+    ///
+    /// Panics if internal context was already set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{ContextBuilder, Clearance};
+    /// let builder = ContextBuilder::new()
+    ///     .internal_sensitive_at(Clearance::Forensics, "raw credential material");
+    /// ```
+    #[inline]
+    pub fn internal_sensitive_at(mut self, level: Clearance, message: impl Into<Cow<'static, str>>) -> Self {
+        debug_assert!(
+            self.internal.is_none(),
+            "ContextBuilder: internal context already set (attempted overwrite with sensitive_at)"
+        );
+        self.internal = Some(InternalContext::sensitive_at(level, message));
+        self
+    }
+
     /// Set internal context as tracked lie (for deception analysis).
     ///
     /// # Panics (Debug Mode)
@@ -401,6 +288,87 @@ impl ContextBuilder {
         self
     }
 
+    /// Append an authentic internal breadcrumb, joined with any other notes
+    /// into the final internal diagnostic at build time.
+    ///
+    /// # Use Case
+    ///
+    /// `internal_diagnostic()`/`internal_sensitive()`/`internal_lie()` each
+    /// take one finished message; `.note()` is for the common case of
+    /// accumulating several short, authentic observations as a call passes
+    /// through multiple layers before finally building the error - mirroring
+    /// `ambient::ContextScope`'s breadcrumb trail, but threaded explicitly
+    /// through the builder instead of an ambient thread-local stack.
+    ///
+    /// # Panics (Debug Mode)
+    ///
+    /// Panics if `internal_diagnostic()`, `internal_sensitive()`, or
+    /// `internal_lie()` was already called - notes are only joined into the
+    /// final internal context when none of those set one explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{ContextBuilder, OperationCategory};
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Access denied")
+    ///     .note("handling request 42")
+    ///     .note("quarantining host")
+    ///     .category(OperationCategory::Containment)
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn note(mut self, note: impl Into<Cow<'static, str>>) -> Self {
+        debug_assert!(
+            self.internal.is_none(),
+            "ContextBuilder: internal context already set explicitly; notes are only \
+             joined when no internal_diagnostic()/internal_sensitive()/internal_lie() was called"
+        );
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Attach the stable, namespaced [`ErrorCode`] this error was raised
+    /// for, so SOC dashboards can group and drill into it the way rustc's
+    /// `--explain` works - see [`DualContextError::with_code`], which this
+    /// delegates to at build time.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use palisade_errors::ContextBuilder;
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Operation failed")
+    ///     .internal_diagnostic("Timeout")
+    ///     .code(&MY_CODE)
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn code(mut self, code: &'static ErrorCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Attach an automated-response recommendation, overriding the
+    /// category-based default - see [`DualContextError::with_response_hint`],
+    /// which this delegates to at build time.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use palisade_errors::{ContextBuilder, ResponseAction, ResponseHint, TriageConfidence};
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Operation failed")
+    ///     .internal_diagnostic("port scan detected")
+    ///     .response_hint(ResponseHint::new(ResponseAction::Alert, TriageConfidence::Confirmed))
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn response_hint(mut self, hint: ResponseHint) -> Self {
+        self.response_hint = Some(hint);
+        self
+    }
+
     /// Set operation category.
     ///
     /// # Example
@@ -416,6 +384,204 @@ impl ContextBuilder {
         self
     }
 
+    /// Set the severity on both sides of the trust boundary.
+    ///
+    /// # Use Case
+    ///
+    /// The common case: the attacker is allowed to learn the true severity
+    /// (e.g. a generic "Warning" is no more revealing than "Error"). When the
+    /// severity itself needs to be part of the deception, set
+    /// `external_severity()` and `internal_severity()` independently instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, OperationCategory, Severity};
+    ///
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Temporary glitch")
+    ///     .internal_diagnostic("Disk quota exceeded")
+    ///     .category(OperationCategory::IO)
+    ///     .severity(Severity::Warning)
+    ///     .build();
+    ///
+    /// assert_eq!(err.external_severity(), Severity::Warning);
+    /// ```
+    #[inline]
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.external_severity = Some(severity);
+        self.internal_severity = Some(severity);
+        self
+    }
+
+    /// Set only the external-facing severity, independent of the internal one.
+    ///
+    /// # Use Case
+    ///
+    /// Deceiving an attacker about how severe their action actually was - e.g.
+    /// reporting a detected exploit attempt as a harmless "Note" externally
+    /// while it is logged internally as `Fatal`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, OperationCategory, Severity};
+    ///
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Request logged")
+    ///     .internal_sensitive("RCE payload detected and blocked")
+    ///     .category(OperationCategory::Detection)
+    ///     .external_severity(Severity::Note)
+    ///     .internal_severity(Severity::Fatal)
+    ///     .build();
+    ///
+    /// assert_eq!(err.external_severity(), Severity::Note);
+    /// ```
+    #[inline]
+    pub fn external_severity(mut self, severity: Severity) -> Self {
+        self.external_severity = Some(severity);
+        self
+    }
+
+    /// Set only the internal (SOC-visible) severity, independent of the
+    /// external one. See [`Self::external_severity`] for the paired example.
+    #[inline]
+    pub fn internal_severity(mut self, severity: Severity) -> Self {
+        self.internal_severity = Some(severity);
+        self
+    }
+
+    /// Attach a metadata key/value pair, defaulting to SOC-only visibility.
+    ///
+    /// # Use Case
+    ///
+    /// Matches rustc's `DiagnosticArg`: structured key/value context alongside
+    /// the main message (e.g. a retry count or an internal request ID).
+    /// Defaults to `MetadataTrust::Internal` since most operational metadata
+    /// (session IDs, internal counters) should not be handed to an attacker
+    /// by accident. Use [`Self::public_metadata`] for values that are safe to
+    /// surface externally, such as a `correlation_id`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, OperationCategory, SocAccess};
+    ///
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Operation failed")
+    ///     .internal_diagnostic("Database connection timeout")
+    ///     .category(OperationCategory::IO)
+    ///     .metadata("session_token", "s3cr3t")
+    ///     .build();
+    ///
+    /// let access = SocAccess::acquire();
+    /// let all: Vec<_> = err.all_metadata(&access).collect();
+    /// assert_eq!(all, vec![("session_token", "s3cr3t")]);
+    /// ```
+    #[inline]
+    pub fn metadata(mut self, key: &'static str, value: impl Into<Cow<'static, str>>) -> Self {
+        self.metadata.add(key, value, MetadataTrust::Internal);
+        self
+    }
+
+    /// Attach a metadata key/value pair explicitly classified as safe for
+    /// external/public exposure. See [`Self::metadata`] for the default
+    /// (internal-only) case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, OperationCategory};
+    ///
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Operation failed")
+    ///     .internal_diagnostic("Database connection timeout")
+    ///     .category(OperationCategory::IO)
+    ///     .public_metadata("correlation_id", "req-42")
+    ///     .build();
+    ///
+    /// let public: Vec<_> = err.public_metadata().collect();
+    /// assert_eq!(public, vec![("correlation_id", "req-42")]);
+    /// ```
+    #[inline]
+    pub fn public_metadata(
+        mut self,
+        key: &'static str,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.metadata.add(key, value, MetadataTrust::Public);
+        self
+    }
+
+    /// Attach a SOC-facing remediation suggestion with a confidence level.
+    ///
+    /// # Use Case
+    ///
+    /// Structured "how to fix / how to confirm" guidance for incident
+    /// responders, mirroring rustc's suggestion + `Applicability` pairing.
+    /// Can be called multiple times to attach several suggestions; they are
+    /// strictly internal and only reachable via `DualContextError::remediations()`
+    /// behind `SocAccess`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{Confidence, ContextBuilder, OperationCategory, SocAccess};
+    ///
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Access denied")
+    ///     .internal_diagnostic("Expired API token")
+    ///     .category(OperationCategory::Detection)
+    ///     .remediation("Rotate the API token", Confidence::MachineApplicable)
+    ///     .build();
+    ///
+    /// let access = SocAccess::acquire();
+    /// let hints: Vec<_> = err.remediations(&access).map(|r| r.hint()).collect();
+    /// assert_eq!(hints, vec!["Rotate the API token"]);
+    /// ```
+    #[inline]
+    pub fn remediation(
+        mut self,
+        hint: impl Into<Cow<'static, str>>,
+        confidence: Confidence,
+    ) -> Self {
+        self.remediations.push(Remediation::new(hint, confidence));
+        self
+    }
+
+    /// Sign the internal context with the given key, so tampering after the
+    /// error crosses a trust boundary (e.g. a log shipper) becomes detectable
+    /// via `DualContextError::verify()`.
+    ///
+    /// # Use Case
+    ///
+    /// For errors that will be serialized and read back by a SOC from a
+    /// system you don't fully trust (log aggregator, message queue). The
+    /// public "lie" is never covered by the signature, since it's expected
+    /// to be attacker-visible and mutable by design.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, OperationCategory, SigningKey};
+    ///
+    /// let key = SigningKey::new(b"session-key".to_vec());
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Operation failed")
+    ///     .internal_diagnostic("Timeout")
+    ///     .category(OperationCategory::IO)
+    ///     .sign_with(key)
+    ///     .build();
+    ///
+    /// let key = SigningKey::new(b"session-key".to_vec());
+    /// assert!(err.verify(&key).is_ok());
+    /// ```
+    #[inline]
+    pub fn sign_with(mut self, key: SigningKey) -> Self {
+        self.signing_key = Some(key);
+        self
+    }
+
     /// Build the final `DualContextError`.
     ///
     /// # Panics
@@ -459,17 +625,52 @@ impl ContextBuilder {
     #[inline]
     pub fn try_build(self) -> Result<DualContextError, ContextBuilderError> {
         let has_public = self.public.is_some();
-        let has_internal = self.internal.is_some();
+        let has_internal = self.internal.is_some() || !self.notes.is_empty();
 
         let public = self.public.ok_or(ContextBuilderError::MissingPublicContext {
             has_internal,
         })?;
-        let internal = self.internal.ok_or(ContextBuilderError::MissingInternalContext {
-            has_public,
-        })?;
+        let internal = match self.internal {
+            Some(internal) => internal,
+            None if !self.notes.is_empty() => InternalContext::diagnostic(joined_notes(&self.notes)),
+            None => return Err(ContextBuilderError::MissingInternalContext { has_public }),
+        };
+
+        let external_severity = self.external_severity.unwrap_or_default();
+        let internal_severity = self.internal_severity.unwrap_or_default();
+
+        let built = DualContextError::new(public, internal, self.category)
+            .with_severity_pair(external_severity, internal_severity)
+            .with_metadata(self.metadata)
+            .with_remediations(self.remediations);
+        let built = match self.code {
+            Some(code) => built.with_code(code),
+            None => built,
+        };
+        let built = match self.response_hint {
+            Some(hint) => built.with_response_hint(hint),
+            None => built,
+        };
+
+        Ok(match &self.signing_key {
+            Some(key) => built.with_signature(key),
+            None => built,
+        })
+    }
+}
 
-        Ok(DualContextError::new(public, internal, self.category))
+/// Join [`ContextBuilder::note`] breadcrumbs into a single diagnostic
+/// string, `"first > second > third"` - the same separator and ordering
+/// `ambient.rs`'s `joined_breadcrumbs` uses for its ambient frame stack.
+fn joined_notes(notes: &[Cow<'static, str>]) -> String {
+    let mut joined = String::new();
+    for (i, note) in notes.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(" > ");
+        }
+        joined.push_str(note.as_ref());
     }
+    joined
 }
 
 impl Default for ContextBuilder {
@@ -496,15 +697,15 @@ pub enum ContextBuilderError {
     /// Internal context was not set before building.
     ///
     /// This means none of `internal_diagnostic()`, `internal_sensitive()`,
-    /// or `internal_lie()` were called.
+    /// `internal_lie()`, or `note()` were called.
     MissingInternalContext {
         /// Whether public context was set (helps diagnose partial builds).
         has_public: bool,
     },
 }
 
-impl std::fmt::Display for ContextBuilderError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ContextBuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::MissingPublicContext { has_internal } => {
                 write!(
@@ -524,8 +725,372 @@ impl std::fmt::Display for ContextBuilderError {
     }
 }
 
+#[cfg(feature = "no_std")]
+impl core::error::Error for ContextBuilderError {}
+#[cfg(not(feature = "no_std"))]
 impl std::error::Error for ContextBuilderError {}
 
+// ============================================================================
+// IntoDualContext Conversion
+// ============================================================================
+
+/// Convert a foreign error type into a `DualContextError` at the call site.
+///
+/// # Design
+///
+/// Mirrors rustc's `IntoDiagnostic`: the desired `Severity` and
+/// `OperationCategory` are arguments to the conversion, not baked into the
+/// impl. This keeps the trust/severity decision where it belongs - at the
+/// `?` boundary that knows what the operation was and how exposed it is -
+/// while still letting library authors write `foreign_err.into_dual(...)`
+/// instead of hand-rolling a `ContextBuilder` call at every call site.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::{IntoDualContext, OperationCategory, Severity};
+///
+/// fn parse_port(raw: &str) -> Result<u16, palisade_errors::DualContextError> {
+///     raw.parse::<u16>()
+///         .map_err(|e| e.into_dual(Severity::Warning, OperationCategory::Configuration))
+/// }
+/// ```
+pub trait IntoDualContext {
+    /// Convert `self` into a `DualContextError` with the given severity,
+    /// applied to both sides of the trust boundary.
+    fn into_dual(self, severity: Severity, category: OperationCategory) -> DualContextError;
+}
+
+/// Blanket conversion for any standard error type.
+///
+/// # Public/Internal Split
+///
+/// The foreign error's `Display` output is routed into `internal_diagnostic`
+/// for SOC analysis. Externally, a fixed, conservative lie is reported - the
+/// blanket impl has no way to know whether the foreign error's `Display` text
+/// is safe to hand to an attacker, so it assumes it is not. Callers who know
+/// better should build a `DualContextError` directly via `ContextBuilder`
+/// instead of relying on this impl.
+impl<T> IntoDualContext for T
+where
+    T: StdError,
+{
+    fn into_dual(self, severity: Severity, category: OperationCategory) -> DualContextError {
+        ContextBuilder::new()
+            .public_lie("An internal error occurred")
+            .internal_diagnostic(self.to_string())
+            .category(category)
+            .severity(severity)
+            .build()
+    }
+}
+
+// ============================================================================
+// Chain Link (Sub-Context Notes)
+// ============================================================================
+
+/// A single link in a `ContextChain`: a primary error plus an ordered list of
+/// sub-notes, mirroring rustc's subdiagnostic model (a primary message with
+/// child notes/helps beneath it).
+///
+/// # Why `DualContextError` for Notes
+///
+/// A sub-note needs exactly the same public/internal split, zeroization, and
+/// no-Clone discipline as any other error in the chain, so notes are plain
+/// `DualContextError`s rather than a new type - `OperationCategory::System`
+/// and `Severity::Note` are reasonable defaults for callers building one.
+///
+/// # Clone Policy
+///
+/// Does not implement Clone, matching `ContextChain` and `DualContextError`.
+pub struct ChainLink {
+    error: DualContextError,
+    notes: SmallVec<[DualContextError; 2]>,
+    checkpoint: Option<Checkpoint>,
+}
+
+impl ChainLink {
+    #[inline]
+    fn new(error: DualContextError) -> Self {
+        Self {
+            error,
+            notes: SmallVec::new(),
+            checkpoint: None,
+        }
+    }
+
+    #[inline]
+    fn with_notes(error: DualContextError, notes: SmallVec<[DualContextError; 2]>) -> Self {
+        Self {
+            error,
+            notes,
+            checkpoint: None,
+        }
+    }
+
+    /// Build the synthetic link standing in for a folded run of interior
+    /// links, produced by `ContextChain::compact()`.
+    ///
+    /// The link's own `error()` carries the checkpoint's truncated
+    /// `external_summary()` as its public message (so `external_summary()`
+    /// and the `Emitter`s keep working unmodified), under the root cause's
+    /// category and severity - the full detail lives on `checkpoint()`.
+    fn from_checkpoint(checkpoint: Checkpoint) -> Self {
+        let root_cause = &checkpoint.root_cause;
+        let error = DualContextError::with_lie(
+            checkpoint.external_summary.clone(),
+            format!("checkpoint: {} collapsed hop(s)", checkpoint.collapsed_count),
+            root_cause.category(),
+        )
+        .with_severity_pair(
+            root_cause.external_severity(),
+            root_cause.internal_severity(&SocAccess::acquire()),
+        );
+        Self {
+            error,
+            notes: SmallVec::new(),
+            checkpoint: Some(checkpoint),
+        }
+    }
+
+    /// Get the primary error for this link.
+    ///
+    /// For a folded link (see `Self::checkpoint`), this is a synthetic error
+    /// standing in for everything that got collapsed - its public message is
+    /// the checkpoint's truncated `external_summary()`, not any one hop's
+    /// original message.
+    #[inline]
+    pub fn error(&self) -> &DualContextError {
+        &self.error
+    }
+
+    /// Get the ordered sub-notes beneath the primary error.
+    ///
+    /// # Security
+    ///
+    /// Each note is a full `DualContextError`, so its own `internal()` /
+    /// `expose_sensitive()` / `all_metadata()` gating already enforces the
+    /// trust boundary - no separate `SocAccess` check is needed here.
+    #[inline]
+    pub fn notes(&self) -> &[DualContextError] {
+        &self.notes
+    }
+
+    /// Get the `Checkpoint` detail if this link is a folded stand-in for a
+    /// run of interior links produced by `ContextChain::compact()`.
+    #[inline]
+    pub fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoint.as_ref()
+    }
+}
+
+impl Zeroize for ChainLink {
+    fn zeroize(&mut self) {
+        self.error.zeroize();
+        for note in &mut self.notes {
+            note.zeroize();
+        }
+        self.notes.clear();
+        if let Some(checkpoint) = &mut self.checkpoint {
+            checkpoint.zeroize();
+        }
+        self.checkpoint = None;
+    }
+}
+
+impl Drop for ChainLink {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+// ============================================================================
+// Checkpoint (Bayou-Style Compaction)
+// ============================================================================
+
+/// A folded stand-in for a run of interior `ContextChain` links, produced by
+/// [`ContextChain::compact`].
+///
+/// # Why
+///
+/// `ContextChain::push` grows unbounded, and `external_summary()`
+/// concatenates every hop - a chain that has bounced through a dozen
+/// subsystems produces a summary nobody will read and a `Vec` nobody needs
+/// in full. This mirrors the checkpoint half of a Bayou-style operation-log
+/// design (see module docs): instead of keeping every hop forever, the
+/// *true* root cause and the current head stay exact, while everything
+/// between them is folded into one compact record.
+///
+/// # What Survives Folding
+///
+/// - `root_cause()`: the first folded link's error, kept in full.
+/// - `collapsed_count()`: how many original hops this checkpoint stands for.
+/// - `metadata()` / `public_metadata()`: the union of every folded link's
+///   metadata, first-write-wins on duplicate keys, trust classification
+///   preserved.
+/// - `external_summary()`: every folded hop's public message joined the same
+///   way as `ContextChain::external_summary()`, then truncated.
+/// - `tags()`: each folded link's integrity tag (`None` for unsigned links),
+///   in original order - see `ContextChain::compact` for why these aren't
+///   re-verified.
+///
+/// # Clone Policy
+///
+/// Does not implement Clone, matching `ChainLink` and `ContextChain`.
+pub struct Checkpoint {
+    root_cause: DualContextError,
+    collapsed_count: usize,
+    metadata: ContextMetadata,
+    external_summary: String,
+    tags: SmallVec<[Option<IntegrityTag>; 4]>,
+}
+
+impl Checkpoint {
+    /// Longest `external_summary()` a checkpoint will keep before eliding the
+    /// rest - long enough to stay useful, short enough that a chain which
+    /// has compacted still can't regrow an unbounded summary.
+    const MAX_SUMMARY_LEN: usize = 160;
+
+    /// Fold a non-empty run of interior links (oldest to newest) into one
+    /// `Checkpoint`. Links that are themselves already-folded checkpoints
+    /// (from a prior `compact()` call) are merged in rather than re-folded,
+    /// so repeated compaction stays idempotent instead of nesting.
+    fn fold(mut folded: SmallVec<[ChainLink; 4]>) -> Self {
+        debug_assert!(!folded.is_empty(), "compact() never folds an empty interior");
+
+        let mut collapsed_count = 0usize;
+        let mut tags: SmallVec<[Option<IntegrityTag>; 4]> = SmallVec::new();
+        let mut metadata = ContextMetadata::new();
+        let mut seen_keys: SmallVec<[&'static str; 8]> = SmallVec::new();
+        let mut summary = String::new();
+        let mut root_cause: Option<DualContextError> = None;
+
+        // `ChainLink`/`Checkpoint` implement `Drop` (for zeroization), so a field
+        // can't be moved out of one directly. Instead, the true root cause (only
+        // ever needed from the oldest link, at `i == 0`) is swapped out in place
+        // via `mem::replace`; the placeholder left behind is harmlessly zeroized
+        // when `folded` drops at the end of this function, along with everything
+        // else this checkpoint doesn't keep.
+        for (i, link) in folded.iter_mut().enumerate() {
+            match &mut link.checkpoint {
+                Some(inner) => {
+                    collapsed_count += inner.collapsed_count;
+                    tags.extend(inner.tags.iter().copied());
+                    for (key, value, trust) in inner.metadata.entries_with_trust() {
+                        if !seen_keys.contains(&key) {
+                            seen_keys.push(key);
+                            metadata.add(key, value.to_string(), trust);
+                        }
+                    }
+                    if !summary.is_empty() {
+                        summary.push_str(" → ");
+                    }
+                    summary.push_str(&inner.external_summary);
+                    if i == 0 {
+                        root_cause = Some(mem::replace(
+                            &mut inner.root_cause,
+                            DualContextError::with_lie("", "", OperationCategory::System),
+                        ));
+                    }
+                }
+                None => {
+                    collapsed_count += 1;
+                    tags.push(link.error.integrity_tag());
+                    for (key, value, trust) in link.error.metadata_entries_with_trust() {
+                        if !seen_keys.contains(&key) {
+                            seen_keys.push(key);
+                            metadata.add(key, value.to_string(), trust);
+                        }
+                    }
+                    if !summary.is_empty() {
+                        summary.push_str(" → ");
+                    }
+                    summary.push_str(link.error.external_message());
+                    if i == 0 {
+                        root_cause = Some(mem::replace(
+                            &mut link.error,
+                            DualContextError::with_lie("", "", OperationCategory::System),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if summary.chars().count() > Self::MAX_SUMMARY_LEN {
+            let mut truncated: String = summary.chars().take(Self::MAX_SUMMARY_LEN).collect();
+            truncated.push('…');
+            summary = truncated;
+        }
+
+        Self {
+            root_cause: root_cause.expect("fold is only called with a non-empty interior"),
+            collapsed_count,
+            metadata,
+            external_summary: summary,
+            tags,
+        }
+    }
+
+    /// The first folded link's error, preserved in full - the true root
+    /// cause of whatever this checkpoint's interior hops were reacting to.
+    #[inline]
+    pub fn root_cause(&self) -> &DualContextError {
+        &self.root_cause
+    }
+
+    /// How many original chain hops this checkpoint stands for.
+    #[inline]
+    pub fn collapsed_count(&self) -> usize {
+        self.collapsed_count
+    }
+
+    /// The folded hops' public messages, joined like
+    /// `ContextChain::external_summary()` and truncated/elided past
+    /// `Self::MAX_SUMMARY_LEN` characters.
+    #[inline]
+    pub fn external_summary(&self) -> &str {
+        &self.external_summary
+    }
+
+    /// Metadata entries safe to surface to external/untrusted consumers,
+    /// merged across every folded hop (first-write-wins on duplicate keys).
+    #[inline]
+    pub fn public_metadata(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.metadata.public_iter()
+    }
+
+    /// All metadata entries regardless of trust classification (SOC-only),
+    /// merged across every folded hop.
+    #[inline]
+    pub fn metadata(&self, _access: &SocAccess) -> impl Iterator<Item = (&'static str, &str)> {
+        self.metadata.iter()
+    }
+
+    /// Each folded hop's integrity tag, in original order - `None` at an
+    /// index means that hop was unsigned. Carried forward rather than
+    /// re-verified; see `ContextChain::compact`.
+    #[inline]
+    pub fn tags(&self) -> &[Option<IntegrityTag>] {
+        &self.tags
+    }
+}
+
+impl Zeroize for Checkpoint {
+    fn zeroize(&mut self) {
+        self.root_cause.zeroize();
+        self.metadata.zeroize();
+        self.external_summary.zeroize();
+        self.collapsed_count = 0;
+        self.tags.clear();
+    }
+}
+
+impl Drop for Checkpoint {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 // ============================================================================
 // Context Chain (Causality Tracking)
 // ============================================================================
@@ -588,9 +1153,9 @@ impl std::error::Error for ContextBuilderError {}
 /// assert_eq!(chain.depth(), 2);
 /// ```
 pub struct ContextChain {
-    /// Stack of errors from root cause to final symptom.
+    /// Stack of links from root cause to final symptom.
     /// Index 0 is the root cause, last index is the final error.
-    links: SmallVec<[DualContextError; 4]>,
+    links: SmallVec<[ChainLink; 4]>,
 }
 
 impl ContextChain {
@@ -598,7 +1163,7 @@ impl ContextChain {
     #[inline]
     pub fn new(root: DualContextError) -> Self {
         let mut links = SmallVec::new();
-        links.push(root);
+        links.push(ChainLink::new(root));
         Self { links }
     }
 
@@ -621,19 +1186,53 @@ impl ContextChain {
     /// ```
     #[inline]
     pub fn push(&mut self, error: DualContextError) {
-        self.links.push(error);
+        self.links.push(ChainLink::new(error));
+    }
+
+    /// Add a new error to the chain along with ordered sub-notes beneath it.
+    ///
+    /// # Use Case
+    ///
+    /// Captures the finer narrative of *why* a hop failed - e.g. a retry
+    /// failure whose notes record each individual attempt - without losing
+    /// the flat causality view that `iter()`/`external_summary()` provide.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{ContextChain, DualContextError, OperationCategory};
+    /// # let root = DualContextError::with_lie("a", "b", OperationCategory::System);
+    /// let mut chain = ContextChain::new(root);
+    ///
+    /// chain.push_with_notes(
+    ///     DualContextError::with_lie("Retry failed", "Max retries exceeded", OperationCategory::System),
+    ///     vec![
+    ///         DualContextError::with_lie("Attempt 1 failed", "Connection refused", OperationCategory::IO),
+    ///         DualContextError::with_lie("Attempt 2 failed", "Timeout", OperationCategory::IO),
+    ///     ],
+    /// );
+    /// ```
+    #[inline]
+    pub fn push_with_notes(
+        &mut self,
+        error: DualContextError,
+        notes: impl IntoIterator<Item = DualContextError>,
+    ) {
+        let mut collected = SmallVec::new();
+        collected.extend(notes);
+        self.links.push(ChainLink::with_notes(error, collected));
     }
 
     /// Get the root cause error (first in chain).
     #[inline]
     pub fn root(&self) -> &DualContextError {
-        &self.links[0]
+        &self.links[0].error
     }
 
     /// Get the final error (last in chain).
     #[inline]
     pub fn head(&self) -> &DualContextError {
-        self.links.last().expect("Chain is never empty")
+        &self.links.last().expect("Chain is never empty").error
     }
 
     /// Get the chain depth (number of errors).
@@ -645,6 +1244,13 @@ impl ContextChain {
     /// Iterate over the error chain from root to head.
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &DualContextError> {
+        self.links.iter().map(|link| &link.error)
+    }
+
+    /// Iterate over the full chain links (primary error plus sub-notes) from
+    /// root to head.
+    #[inline]
+    pub fn links(&self) -> impl Iterator<Item = &ChainLink> {
         self.links.iter()
     }
 
@@ -691,21 +1297,298 @@ impl ContextChain {
         let capacity = self
             .links
             .iter()
-            .map(|e| e.external_message().len())
+            .map(|link| link.error.external_message().len())
             .sum::<usize>()
             + (self.links.len().saturating_sub(1) * separator.len());
 
         let mut result = String::with_capacity(capacity);
 
-        for (i, error) in self.links.iter().enumerate() {
+        for (i, link) in self.links.iter().enumerate() {
+            if i > 0 {
+                result.push_str(separator);
+            }
+            result.push_str(link.error.external_message());
+        }
+
+        result
+    }
+
+    /// Like [`Self::external_summary`], but prefixes each link with its
+    /// external severity in rustc's `label: message` style.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{ContextChain, DualContextError, OperationCategory, Severity};
+    /// # let root = DualContextError::with_lie("Database error", "x", OperationCategory::IO);
+    /// # let mut chain = ContextChain::new(root);
+    /// # let next = DualContextError::with_lie("Retry failed", "y", OperationCategory::System);
+    /// # chain.push(next);
+    /// let external = chain.external_summary_with_severity();
+    /// // Output: "error: Database error → error: Retry failed"
+    /// ```
+    pub fn external_summary_with_severity(&self) -> String {
+        if self.links.is_empty() {
+            return String::new();
+        }
+
+        let separator = " → ";
+        let mut result = String::new();
+
+        for (i, link) in self.links.iter().enumerate() {
+            if i > 0 {
+                result.push_str(separator);
+            }
+            result.push_str(link.error.external_severity().label());
+            result.push_str(": ");
+            result.push_str(link.error.external_message());
+        }
+
+        result
+    }
+
+    /// Like [`Self::external_summary`], but nests each link's public
+    /// sub-notes beneath it, indented.
+    ///
+    /// # Output
+    ///
+    /// ```text
+    /// Database error
+    ///   - Connection pool exhausted
+    /// → Retry failed
+    ///   - Max retries (3) exceeded
+    /// ```
+    ///
+    /// Only `external_message()` text appears here. Internal notes are
+    /// reachable via [`Self::links`] and `ChainLink::notes()`, gated the same
+    /// way as any other `DualContextError`'s internal context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{ContextChain, DualContextError, OperationCategory};
+    /// # let root = DualContextError::with_lie("Database error", "x", OperationCategory::IO);
+    /// let mut chain = ContextChain::new(root);
+    /// chain.push_with_notes(
+    ///     DualContextError::with_lie("Retry failed", "y", OperationCategory::System),
+    ///     vec![DualContextError::with_lie("Max retries exceeded", "z", OperationCategory::System)],
+    /// );
+    ///
+    /// let nested = chain.external_summary_nested();
+    /// assert_eq!(nested, "Database error\n→ Retry failed\n  - Max retries exceeded");
+    /// ```
+    pub fn external_summary_nested(&self) -> String {
+        let separator = "\n→ ";
+        let mut result = String::new();
+
+        for (i, link) in self.links.iter().enumerate() {
             if i > 0 {
                 result.push_str(separator);
             }
-            result.push_str(error.external_message());
+            result.push_str(link.error.external_message());
+            for note in &link.notes {
+                result.push_str("\n  - ");
+                result.push_str(note.external_message());
+            }
         }
 
         result
     }
+
+    /// Verify every link's signature (see `ContextBuilder::sign_with`),
+    /// reporting the depth of the first tampered or unsigned link found.
+    ///
+    /// # Use Case
+    ///
+    /// A chain is typically reassembled hop-by-hop from logs written by
+    /// different subsystems; this lets a SOC analyst check the whole chain
+    /// in one call instead of verifying each `DualContextError` by hand, and
+    /// pinpoint exactly where tampering (or a missing signature) occurred.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if every link verifies against `key`. Otherwise
+    /// `Err(ChainIntegrityError)` naming the zero-based depth of the first
+    /// link that failed and why.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, ContextChain, OperationCategory, SigningKey};
+    ///
+    /// let key = SigningKey::new(b"session-key".to_vec());
+    /// let root = ContextBuilder::new()
+    ///     .public_lie("Operation failed")
+    ///     .internal_diagnostic("Timeout")
+    ///     .category(OperationCategory::IO)
+    ///     .sign_with(key)
+    ///     .build();
+    ///
+    /// let chain = ContextChain::new(root);
+    /// let key = SigningKey::new(b"session-key".to_vec());
+    /// assert!(chain.verify(&key).is_ok());
+    /// ```
+    pub fn verify(&self, key: &SigningKey) -> Result<(), ChainIntegrityError> {
+        for (depth, link) in self.links.iter().enumerate() {
+            // A folded link's own `error()` is synthetic (see `ChainLink::checkpoint`)
+            // and was never signed; its original hops' tags are carried forward on
+            // `Checkpoint::tags()` instead of re-verified here, per `Self::compact`.
+            if link.checkpoint.is_some() {
+                continue;
+            }
+            if let Err(source) = link.error.verify(key) {
+                return Err(ChainIntegrityError { depth, source });
+            }
+        }
+        Ok(())
+    }
+
+    /// Fold interior links into a single [`Checkpoint`] once the chain grows
+    /// past `max_depth`, bounding the cost of `external_summary()` and the
+    /// chain's own memory footprint on long-running causality traces.
+    ///
+    /// # Bayou-Style Checkpointing
+    ///
+    /// Modeled on the checkpoint half of a Bayou-style operation-log design
+    /// (see module docs): `root()` and `head()` are always kept exact, while
+    /// everything strictly between them collapses into one [`Checkpoint`]
+    /// record that preserves the true root cause, a collapsed-hop count, a
+    /// merged `ContextMetadata`, and a truncated external summary.
+    ///
+    /// # Idempotence
+    ///
+    /// A chain with fewer than 3 links has no interior to fold and is left
+    /// untouched. Calling `compact()` again on an already-compacted chain
+    /// merges the existing checkpoint with any newly-pushed interior links
+    /// rather than nesting checkpoints.
+    ///
+    /// # Signed Links
+    ///
+    /// Folded links that were signed (`ContextBuilder::sign_with`) are not
+    /// re-verified by this call - their tags are carried forward unchanged on
+    /// [`Checkpoint::tags`], and `Self::verify` skips the synthetic folded
+    /// link itself while still checking every link that wasn't folded away.
+    /// Call `Self::verify` *before* compacting if you need every hop checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{ContextChain, DualContextError, OperationCategory};
+    /// # let root = DualContextError::with_lie("root", "r", OperationCategory::System);
+    /// let mut chain = ContextChain::new(root);
+    /// for i in 0..5 {
+    ///     chain.push(DualContextError::with_lie(
+    ///         format!("hop {i}"),
+    ///         "detail",
+    ///         OperationCategory::System,
+    ///     ));
+    /// }
+    /// assert_eq!(chain.depth(), 6);
+    ///
+    /// chain.compact(3);
+    /// assert_eq!(chain.depth(), 3);
+    /// assert_eq!(chain.root().external_message(), "root");
+    /// assert_eq!(chain.head().external_message(), "hop 4");
+    /// ```
+    pub fn compact(&mut self, max_depth: usize) {
+        if self.links.len() <= max_depth || self.links.len() < 3 {
+            return;
+        }
+
+        let head = self.links.pop().expect("chain is never empty");
+        let interior: SmallVec<[ChainLink; 4]> = self.links.drain(1..).collect();
+
+        let checkpoint = Checkpoint::fold(interior);
+        self.links.push(ChainLink::from_checkpoint(checkpoint));
+        self.links.push(head);
+    }
+
+    /// Render a multi-line causal report for SOC triage, modeled on rustc's
+    /// `nice_region_error` span annotations ("these were declared here… data
+    /// flows into here"): one block per link from root to head, connected by
+    /// explicit `caused by ↑` arrows.
+    ///
+    /// # Compact Mode
+    ///
+    /// Passing `None` renders the attacker-safe skeleton - just
+    /// [`Self::external_summary`], no secrets, no per-link breakdown. Passing
+    /// `Some(access)` unlocks the full deep dive: each link's category,
+    /// public message, internal diagnostic (or exposed sensitive text,
+    /// redacted if never exposed - same fallback as
+    /// `Emitter::emit_chain_privileged`), and metadata. The same function
+    /// serves both attacker-reachable logs and trusted SOC tooling depending
+    /// on whether a valid `SocAccess` was obtained for the call site.
+    ///
+    /// A folded link (see [`ChainLink::checkpoint`]) reports its
+    /// [`Checkpoint`]'s collapsed-hop count and preserved root cause instead
+    /// of a full per-hop breakdown, since the interior hops it stands for
+    /// were discarded by [`Self::compact`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextChain, DualContextError, OperationCategory, SocAccess};
+    ///
+    /// let root = DualContextError::with_lie("Database error", "Connection refused", OperationCategory::IO);
+    /// let chain = ContextChain::new(root);
+    ///
+    /// assert_eq!(chain.internal_report(None), chain.external_summary());
+    ///
+    /// let access = SocAccess::acquire();
+    /// assert!(chain.internal_report(Some(&access)).contains("Connection refused"));
+    /// ```
+    pub fn internal_report(&self, access: Option<&SocAccess>) -> String {
+        let Some(access) = access else {
+            return self.external_summary();
+        };
+
+        let mut report = String::new();
+        for (i, link) in self.links.iter().enumerate() {
+            if i > 0 {
+                report.push_str("\ncaused by ↑\n");
+            }
+
+            let error = &link.error;
+            report.push_str(&format!(
+                "[{}] {}\n",
+                error.category().display_name(),
+                error.external_message()
+            ));
+
+            if let Some(checkpoint) = &link.checkpoint {
+                report.push_str(&format!(
+                    "  checkpoint: {} collapsed hop(s)\n",
+                    checkpoint.collapsed_count()
+                ));
+                report.push_str(&format!(
+                    "  root cause: {}\n",
+                    checkpoint.root_cause().external_message()
+                ));
+                report.push_str(&format!(
+                    "  root cause internal: {}\n",
+                    internal_display_text(checkpoint.root_cause(), access)
+                ));
+                for (key, value) in checkpoint.metadata(access) {
+                    report.push_str(&format!("  {}: {}\n", key, value));
+                }
+                continue;
+            }
+
+            report.push_str(&format!(
+                "  internal [{}]: {}\n",
+                error.internal_severity(access).label(),
+                internal_display_text(error, access)
+            ));
+            for (key, value) in error.all_metadata(access) {
+                report.push_str(&format!("  {}: {}\n", key, value));
+            }
+        }
+
+        if report.ends_with('\n') {
+            report.pop();
+        }
+        report
+    }
 }
 
 impl Zeroize for ContextChain {
@@ -723,21 +1606,321 @@ impl Drop for ContextChain {
     }
 }
 
-// ============================================================================
-// Tests
-// ============================================================================
-
-#[cfg(test)]
+/// Why `ContextChain::verify()` failed.
+///
+/// # Diagnostic Context
+///
+/// Reports the zero-based `depth` (index into the chain, root is `0`) of the
+/// first link that failed verification, plus the underlying `IntegrityError`
+/// (unsigned vs. tampered) for that link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainIntegrityError {
+    /// Zero-based index of the first link that failed verification.
+    pub depth: usize,
+    /// Why that link failed.
+    pub source: IntegrityError,
+}
+
+impl core::fmt::Display for ChainIntegrityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "chain link at depth {} failed verification: {}",
+            self.depth, self.source
+        )
+    }
+}
+
+#[cfg(feature = "no_std")]
+impl core::error::Error for ChainIntegrityError {}
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ChainIntegrityError {}
+
+// ============================================================================
+// Emitters (Pluggable Chain Rendering)
+// ============================================================================
+
+/// Renders a `ContextChain` into a specific output format.
+///
+/// Modeled on rustc's `Emitter` trait: the renderer is decoupled from the
+/// diagnostic data so downstream consumers (human terminals, SIEM pipelines,
+/// structured log shippers) can each plug in their own format without this
+/// crate dictating one true representation.
+///
+/// # Trust Boundary
+///
+/// `emit_chain()` only has access to public contexts - an implementation
+/// cannot leak internal diagnostics through it even by accident. Privileged
+/// output for SOC tooling goes through `emit_chain_privileged()`, which is
+/// capability-gated on `SocAccess` exactly like `InternalContext::expose_sensitive()`.
+pub trait Emitter {
+    /// Render the chain using only public-facing data (safe for any sink).
+    fn emit_chain(&self, chain: &ContextChain) -> String;
+
+    /// Render the chain including internal diagnostics and exposed sensitive text.
+    ///
+    /// # Default Implementation
+    ///
+    /// Falls back to `emit_chain()`. Emitters that want to surface internal
+    /// fields (as `HumanEmitter` and `JsonEmitter` do) should override this.
+    fn emit_chain_privileged(&self, chain: &ContextChain, _access: &SocAccess) -> String {
+        self.emit_chain(chain)
+    }
+}
+
+/// Render a link's internal diagnostic as display text, falling back to
+/// `expose_sensitive()` and finally a redacted marker for `Sensitive` contexts
+/// that haven't had their value exposed - mirrors `Diagnostic`'s rendering
+/// policy in `models.rs`.
+fn internal_display_text<'a>(error: &'a DualContextError, access: &SocAccess) -> Cow<'a, str> {
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    error.mark_emitted();
+    match error.internal().payload() {
+        Some(crate::InternalPayload::Truth(msg)) => Cow::Borrowed(msg),
+        Some(crate::InternalPayload::Lie(msg)) => Cow::Owned(format!("[LIE] {}", msg)),
+        // ForensicMode is live - same text `expose_sensitive()` would have
+        // returned below, just without needing the access check twice.
+        Some(crate::InternalPayload::Sensitive(msg)) => Cow::Borrowed(msg),
+        None => match error.internal().expose_sensitive(access) {
+            Some(msg) => Cow::Borrowed(msg),
+            None => Cow::Borrowed("[SENSITIVE REDACTED]"),
+        },
+    }
+}
+
+/// Human-readable emitter with a configurable separator and optional ANSI
+/// color keyed on severity.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::{ContextChain, DualContextError, Emitter, HumanEmitter, OperationCategory};
+///
+/// let root = DualContextError::with_lie("Database error", "Connection refused", OperationCategory::IO);
+/// let chain = ContextChain::new(root);
+///
+/// let emitter = HumanEmitter::new();
+/// assert_eq!(emitter.emit_chain(&chain), "error: Database error");
+/// ```
+pub struct HumanEmitter {
+    separator: &'static str,
+    color: bool,
+}
+
+impl HumanEmitter {
+    /// Create a new emitter with the default " → " separator and no color.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            separator: " → ",
+            color: false,
+        }
+    }
+
+    /// Override the separator placed between chain links.
+    #[inline]
+    pub fn with_separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Enable ANSI color codes keyed on each link's severity.
+    ///
+    /// Off by default so piping output to a file or non-TTY sink doesn't
+    /// embed raw escape codes; callers that know they're writing to a
+    /// terminal opt in explicitly.
+    #[inline]
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// ANSI color code for a severity level, matching rustc's diagnostic colors.
+    const fn ansi_for(severity: Severity) -> &'static str {
+        match severity {
+            Severity::Bug | Severity::Fatal | Severity::Error => "\x1b[1;31m", // bold red
+            Severity::Warning => "\x1b[1;33m",                                 // bold yellow
+            Severity::Note => "\x1b[1;36m",                                    // bold cyan
+            Severity::Help => "\x1b[1;32m",                                    // bold green
+        }
+    }
+
+    const ANSI_RESET: &'static str = "\x1b[0m";
+
+    fn render_link(&self, severity: Severity, message: &str, out: &mut String) {
+        if self.color {
+            out.push_str(Self::ansi_for(severity));
+        }
+        out.push_str(severity.label());
+        if self.color {
+            out.push_str(Self::ANSI_RESET);
+        }
+        out.push_str(": ");
+        out.push_str(message);
+    }
+}
+
+impl Default for HumanEmitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Emitter for HumanEmitter {
+    fn emit_chain(&self, chain: &ContextChain) -> String {
+        let mut result = String::new();
+        for (i, error) in chain.iter().enumerate() {
+            if i > 0 {
+                result.push_str(self.separator);
+            }
+            self.render_link(error.external_severity(), error.external_message(), &mut result);
+        }
+        result
+    }
+
+    fn emit_chain_privileged(&self, chain: &ContextChain, access: &SocAccess) -> String {
+        let mut result = String::new();
+        for (i, error) in chain.iter().enumerate() {
+            if i > 0 {
+                result.push_str(self.separator);
+            }
+            let text = internal_display_text(error, access);
+            self.render_link(error.internal_severity(access), text.as_ref(), &mut result);
+        }
+        result
+    }
+}
+
+/// JSON emitter producing a structured array of
+/// `{category, severity, public_message}` objects, one per chain link.
+///
+/// # Feature Gate
+///
+/// Gated behind the `json_emitter` feature so the crate's core path never
+/// takes a hard serde dependency. Output is hand-escaped (same approach as
+/// `sanitized_json!`) rather than going through a serializer, since the
+/// schema is small and fixed.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "json_emitter")] {
+/// use palisade_errors::{ContextChain, DualContextError, Emitter, JsonEmitter, OperationCategory};
+///
+/// let root = DualContextError::with_lie("Database error", "Connection refused", OperationCategory::IO);
+/// let chain = ContextChain::new(root);
+///
+/// let emitter = JsonEmitter::new();
+/// let json = emitter.emit_chain(&chain);
+/// assert!(json.contains("\"public_message\":\"Database error\""));
+/// # }
+/// ```
+#[cfg(feature = "json_emitter")]
+pub struct JsonEmitter;
+
+#[cfg(feature = "json_emitter")]
+impl JsonEmitter {
+    /// Create a new JSON emitter. No configuration: the schema is fixed.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn push_escaped(out: &mut String, s: &str) {
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    fn push_link(out: &mut String, category: &str, severity: &str, public_message: &str) {
+        out.push('{');
+        out.push_str("\"category\":");
+        Self::push_escaped(out, category);
+        out.push_str(",\"severity\":");
+        Self::push_escaped(out, severity);
+        out.push_str(",\"public_message\":");
+        Self::push_escaped(out, public_message);
+        out.push('}');
+    }
+}
+
+#[cfg(feature = "json_emitter")]
+impl Default for JsonEmitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "json_emitter")]
+impl Emitter for JsonEmitter {
+    fn emit_chain(&self, chain: &ContextChain) -> String {
+        let mut result = String::from("[");
+        for (i, error) in chain.iter().enumerate() {
+            if i > 0 {
+                result.push(',');
+            }
+            Self::push_link(
+                &mut result,
+                error.category().display_name(),
+                error.external_severity().label(),
+                error.external_message(),
+            );
+        }
+        result.push(']');
+        result
+    }
+
+    fn emit_chain_privileged(&self, chain: &ContextChain, access: &SocAccess) -> String {
+        let mut result = String::from("[");
+        for (i, error) in chain.iter().enumerate() {
+            if i > 0 {
+                result.push(',');
+            }
+            let internal_text = internal_display_text(error, access);
+            result.push('{');
+            result.push_str("\"category\":");
+            Self::push_escaped(&mut result, error.category().display_name());
+            result.push_str(",\"severity\":");
+            Self::push_escaped(&mut result, error.external_severity().label());
+            result.push_str(",\"public_message\":");
+            Self::push_escaped(&mut result, error.external_message());
+            result.push_str(",\"internal_severity\":");
+            Self::push_escaped(&mut result, error.internal_severity(access).label());
+            result.push_str(",\"internal_message\":");
+            Self::push_escaped(&mut result, internal_text.as_ref());
+            result.push('}');
+        }
+        result.push(']');
+        result
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
 mod tests {
     use super::*;
-    use crate::SocAccess;
+    #[cfg(feature = "no_std")]
+    use alloc::{format, vec, vec::Vec};
 
     #[test]
     fn context_metadata_basic_operations() {
         let mut meta = ContextMetadata::new();
 
-        meta.add("key1", "value1");
-        meta.add("key2", "value2");
+        meta.add("key1", "value1", MetadataTrust::Internal);
+        meta.add("key2", "value2", MetadataTrust::Public);
 
         assert_eq!(meta.len(), 2);
         assert_eq!(meta.get("key1"), Some("value1"));
@@ -748,7 +1931,7 @@ mod tests {
     #[test]
     fn context_metadata_zeroization() {
         let mut meta = ContextMetadata::new();
-        meta.add("sensitive", "secret123".to_string());
+        meta.add("sensitive", "secret123".to_string(), MetadataTrust::Internal);
 
         meta.zeroize();
 
@@ -841,6 +2024,72 @@ mod tests {
             .build();
     }
 
+    #[test]
+    fn context_builder_note_joins_multiple_breadcrumbs() {
+        let err = ContextBuilder::new()
+            .public_lie("Access denied")
+            .note("handling request 42")
+            .note("quarantining host")
+            .category(OperationCategory::Containment)
+            .build();
+
+        assert_eq!(
+            err.internal().payload().map(|p| p.as_str()),
+            Some("handling request 42 > quarantining host")
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "internal context already set explicitly")]
+    fn context_builder_note_panics_if_internal_already_set() {
+        ContextBuilder::new()
+            .public_lie("test")
+            .internal_diagnostic("already set")
+            .note("too late")
+            .build();
+    }
+
+    #[test]
+    fn context_builder_code_attaches_stable_error_code() {
+        const CODE: ErrorCode = ErrorCode::const_new(
+            &crate::namespaces::CFG,
+            1,
+            OperationCategory::Configuration,
+            crate::ImpactScore::new(100),
+        );
+
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("bad value")
+            .category(OperationCategory::Configuration)
+            .code(&CODE)
+            .build();
+
+        assert_eq!(err.error_code(), Some(&CODE));
+    }
+
+    #[test]
+    fn context_builder_response_hint_overrides_category_default() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("port scan detected")
+            .category(OperationCategory::Detection)
+            .response_hint(ResponseHint::new(
+                crate::ResponseAction::Alert,
+                crate::TriageConfidence::Confirmed,
+            ))
+            .build();
+
+        assert_eq!(
+            err.response_hint(),
+            Some(ResponseHint::new(
+                crate::ResponseAction::Alert,
+                crate::TriageConfidence::Confirmed
+            ))
+        );
+    }
+
     #[test]
     fn context_builder_error_messages_include_state() {
         let err = ContextBuilder::new().try_build().unwrap_err();
@@ -857,6 +2106,48 @@ mod tests {
         assert!(msg.contains("public: set"));
     }
 
+    #[test]
+    fn context_builder_sign_with_verifies() {
+        let key = SigningKey::new(b"session-key".to_vec());
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .category(OperationCategory::IO)
+            .sign_with(key)
+            .build();
+
+        assert!(err.is_signed());
+        let key = SigningKey::new(b"session-key".to_vec());
+        assert!(err.verify(&key).is_ok());
+    }
+
+    #[test]
+    fn context_builder_unsigned_fails_verify() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .category(OperationCategory::IO)
+            .build();
+
+        assert!(!err.is_signed());
+        let key = SigningKey::new(b"session-key".to_vec());
+        assert_eq!(err.verify(&key), Err(IntegrityError::Unsigned));
+    }
+
+    #[test]
+    fn context_builder_wrong_key_fails_verify() {
+        let key = SigningKey::new(b"session-key".to_vec());
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .category(OperationCategory::IO)
+            .sign_with(key)
+            .build();
+
+        let wrong_key = SigningKey::new(b"different-key".to_vec());
+        assert_eq!(err.verify(&wrong_key), Err(IntegrityError::Tampered));
+    }
+
     #[test]
     fn context_chain_basic_usage() {
         let root = DualContextError::with_lie(
@@ -950,12 +2241,86 @@ mod tests {
         assert_eq!(messages, vec!["E1", "E2", "E3"]);
     }
 
+    #[test]
+    fn chain_push_with_notes_attaches_ordered_sub_notes() {
+        let root = DualContextError::with_lie("Database error", "x", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+
+        chain.push_with_notes(
+            DualContextError::with_lie("Retry failed", "Max retries exceeded", OperationCategory::System),
+            vec![
+                DualContextError::with_lie("Attempt 1 failed", "Connection refused", OperationCategory::IO),
+                DualContextError::with_lie("Attempt 2 failed", "Timeout", OperationCategory::IO),
+            ],
+        );
+
+        let links: Vec<_> = chain.links().collect();
+        assert_eq!(links.len(), 2);
+        assert!(links[0].notes().is_empty());
+
+        let notes = links[1].notes();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].external_message(), "Attempt 1 failed");
+        assert_eq!(notes[1].external_message(), "Attempt 2 failed");
+    }
+
+    #[test]
+    fn chain_push_without_notes_has_empty_notes() {
+        let root = DualContextError::with_lie("Database error", "x", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+        chain.push(DualContextError::with_lie("Retry failed", "y", OperationCategory::System));
+
+        for link in chain.links() {
+            assert!(link.notes().is_empty());
+        }
+    }
+
+    #[test]
+    fn external_summary_nested_renders_indented_notes() {
+        let root = DualContextError::with_lie("Database error", "x", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+
+        chain.push_with_notes(
+            DualContextError::with_lie("Retry failed", "y", OperationCategory::System),
+            vec![DualContextError::with_lie(
+                "Max retries exceeded",
+                "z",
+                OperationCategory::System,
+            )],
+        );
+
+        let nested = chain.external_summary_nested();
+        assert_eq!(
+            nested,
+            "Database error\n→ Retry failed\n  - Max retries exceeded"
+        );
+    }
+
+    #[test]
+    fn external_summary_nested_never_leaks_internal_note_text() {
+        let root = DualContextError::with_lie("Access denied", "x", OperationCategory::Detection);
+        let mut chain = ContextChain::new(root);
+
+        chain.push_with_notes(
+            DualContextError::with_lie("Suspicious activity", "y", OperationCategory::Detection),
+            vec![DualContextError::with_lie_and_sensitive(
+                "Request logged",
+                "Attacker IP: 10.0.0.1, credentials: admin:hunter2",
+                OperationCategory::Detection,
+            )],
+        );
+
+        let nested = chain.external_summary_nested();
+        assert!(!nested.contains("hunter2"));
+        assert!(nested.contains("Request logged"));
+    }
+
     #[test]
     fn metadata_with_owned_and_borrowed() {
         let mut meta = ContextMetadata::new();
 
-        meta.add("static", "literal"); // Borrowed
-        meta.add("dynamic", format!("value-{}", 42)); // Owned
+        meta.add("static", "literal", MetadataTrust::Internal); // Borrowed
+        meta.add("dynamic", format!("value-{}", 42), MetadataTrust::Internal); // Owned
 
         assert_eq!(meta.get("static"), Some("literal"));
         assert_eq!(meta.get("dynamic"), Some("value-42"));
@@ -964,12 +2329,535 @@ mod tests {
     #[test]
     fn metadata_iteration() {
         let mut meta = ContextMetadata::new();
-        meta.add("key1", "val1");
-        meta.add("key2", "val2");
+        meta.add("key1", "val1", MetadataTrust::Internal);
+        meta.add("key2", "val2", MetadataTrust::Public);
 
         let collected: Vec<_> = meta.iter().collect();
         assert_eq!(collected.len(), 2);
         assert!(collected.contains(&("key1", "val1")));
         assert!(collected.contains(&("key2", "val2")));
     }
+
+    #[test]
+    fn context_builder_default_severity_is_error() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .build();
+
+        assert_eq!(err.external_severity(), Severity::Error);
+        let access = SocAccess::acquire();
+        assert_eq!(err.internal_severity(&access), Severity::Error);
+    }
+
+    #[test]
+    fn context_builder_severity_sets_both_sides() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .severity(Severity::Warning)
+            .build();
+
+        assert_eq!(err.external_severity(), Severity::Warning);
+        let access = SocAccess::acquire();
+        assert_eq!(err.internal_severity(&access), Severity::Warning);
+    }
+
+    #[test]
+    fn context_builder_severity_can_be_split() {
+        let err = ContextBuilder::new()
+            .public_lie("Request logged")
+            .internal_sensitive("RCE payload detected and blocked")
+            .external_severity(Severity::Note)
+            .internal_severity(Severity::Fatal)
+            .build();
+
+        assert_eq!(err.external_severity(), Severity::Note);
+        let access = SocAccess::acquire();
+        assert_eq!(err.internal_severity(&access), Severity::Fatal);
+    }
+
+    #[test]
+    fn context_chain_external_summary_with_severity() {
+        let root = ContextBuilder::new()
+            .public_lie("Root cause")
+            .internal_diagnostic("Internal details")
+            .severity(Severity::Error)
+            .build();
+        let mut chain = ContextChain::new(root);
+
+        chain.push(
+            ContextBuilder::new()
+                .public_lie("Final error")
+                .internal_diagnostic("Details")
+                .severity(Severity::Warning)
+                .build(),
+        );
+
+        let summary = chain.external_summary_with_severity();
+        assert_eq!(summary, "error: Root cause → warning: Final error");
+    }
+
+    #[test]
+    fn context_builder_metadata_defaults_to_internal() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("Connection refused: password 'hunter2' rejected")
+            .metadata("session_token", "s3cr3t")
+            .build();
+
+        assert_eq!(err.public_metadata().collect::<Vec<_>>(), Vec::<(&str, &str)>::new());
+
+        let access = SocAccess::acquire();
+        assert_eq!(
+            err.all_metadata(&access).collect::<Vec<_>>(),
+            vec![("session_token", "s3cr3t")]
+        );
+    }
+
+    #[test]
+    fn context_builder_public_metadata_is_externally_visible() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Database connection timeout")
+            .public_metadata("correlation_id", "req-42")
+            .build();
+
+        assert_eq!(
+            err.public_metadata().collect::<Vec<_>>(),
+            vec![("correlation_id", "req-42")]
+        );
+    }
+
+    #[test]
+    fn context_builder_metadata_mixes_public_and_internal() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("user_id=42 attempted path traversal")
+            .public_metadata("correlation_id", "req-42")
+            .metadata("user_id", "42")
+            .build();
+
+        assert_eq!(
+            err.public_metadata().collect::<Vec<_>>(),
+            vec![("correlation_id", "req-42")]
+        );
+
+        let access = SocAccess::acquire();
+        let all: Vec<_> = err.all_metadata(&access).collect();
+        assert_eq!(all.len(), 2);
+        assert!(all.contains(&("correlation_id", "req-42")));
+        assert!(all.contains(&("user_id", "42")));
+    }
+
+    #[test]
+    fn context_builder_default_has_no_metadata() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .build();
+
+        assert_eq!(err.public_metadata().count(), 0);
+        let access = SocAccess::acquire();
+        assert_eq!(err.all_metadata(&access).count(), 0);
+    }
+
+    #[test]
+    fn into_dual_uses_display_for_internal_diagnostic() {
+        let parse_err = "not a number".parse::<u16>().unwrap_err();
+        let err = parse_err.into_dual(Severity::Warning, OperationCategory::Configuration);
+
+        assert_eq!(err.external_severity(), Severity::Warning);
+
+        let access = SocAccess::acquire();
+        assert_eq!(err.internal_severity(&access), Severity::Warning);
+        assert_eq!(
+            err.internal().expose_sensitive(&access),
+            None // diagnostic, not sensitive - expose_sensitive only returns Sensitive payloads
+        );
+    }
+
+    #[test]
+    fn into_dual_keeps_public_message_conservative() {
+        let parse_err = "not a number".parse::<u16>().unwrap_err();
+        let err = parse_err.into_dual(Severity::Fatal, OperationCategory::Configuration);
+
+        assert_eq!(err.external_message(), "An internal error occurred");
+        assert_eq!(err.category(), OperationCategory::Configuration);
+    }
+
+    #[test]
+    fn remediation_is_gated_behind_soc_access() {
+        let err = ContextBuilder::new()
+            .public_lie("Access denied")
+            .internal_diagnostic("Expired API token")
+            .category(OperationCategory::Detection)
+            .remediation("Rotate the API token", Confidence::MachineApplicable)
+            .build();
+
+        let access = SocAccess::acquire();
+        let hints: Vec<_> = err.remediations(&access).map(|r| r.hint()).collect();
+        assert_eq!(hints, vec!["Rotate the API token"]);
+    }
+
+    #[test]
+    fn remediation_never_reaches_external_message_or_summary() {
+        let root = ContextBuilder::new()
+            .public_lie("Access denied")
+            .internal_sensitive("Leaked credential found in log export")
+            .category(OperationCategory::Detection)
+            .remediation("Revoke the leaked credential", Confidence::MachineApplicable)
+            .remediation("Confirm no downstream reuse", Confidence::MaybeIncorrect)
+            .build();
+        let chain = ContextChain::new(root);
+
+        assert_eq!(chain.head().external_message(), "Access denied");
+        assert!(!chain.external_summary().contains("credential"));
+    }
+
+    #[test]
+    fn remediation_confidence_is_preserved_per_entry() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .remediation("Retry with backoff", Confidence::MachineApplicable)
+            .remediation("Check upstream health", Confidence::Unspecified)
+            .build();
+
+        let access = SocAccess::acquire();
+        let confidences: Vec<_> = err.remediations(&access).map(|r| r.confidence()).collect();
+        assert_eq!(
+            confidences,
+            vec![Confidence::MachineApplicable, Confidence::Unspecified]
+        );
+    }
+
+    #[test]
+    fn remediation_defaults_to_empty() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .build();
+
+        let access = SocAccess::acquire();
+        assert_eq!(err.remediations(&access).count(), 0);
+    }
+
+    fn sample_chain() -> ContextChain {
+        let root = ContextBuilder::new()
+            .public_lie("Database error")
+            .internal_sensitive("Connection refused: password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .severity(Severity::Error)
+            .build();
+        let mut chain = ContextChain::new(root);
+
+        chain.push(
+            ContextBuilder::new()
+                .public_lie("Retry failed")
+                .internal_diagnostic("Max retries (3) exceeded")
+                .category(OperationCategory::System)
+                .severity(Severity::Warning)
+                .build(),
+        );
+
+        chain
+    }
+
+    #[test]
+    fn human_emitter_default_format() {
+        let chain = sample_chain();
+        let emitter = HumanEmitter::new();
+
+        assert_eq!(
+            emitter.emit_chain(&chain),
+            "error: Database error → warning: Retry failed"
+        );
+    }
+
+    #[test]
+    fn human_emitter_custom_separator() {
+        let chain = sample_chain();
+        let emitter = HumanEmitter::new().with_separator(" | ");
+
+        assert_eq!(
+            emitter.emit_chain(&chain),
+            "error: Database error | warning: Retry failed"
+        );
+    }
+
+    #[test]
+    fn human_emitter_color_wraps_label_only() {
+        let chain = sample_chain();
+        let emitter = HumanEmitter::new().with_color(true);
+
+        let rendered = emitter.emit_chain(&chain);
+        assert!(rendered.contains("\x1b[1;31merror\x1b[0m: Database error"));
+    }
+
+    #[test]
+    fn human_emitter_privileged_exposes_internal_text() {
+        let chain = sample_chain();
+        let emitter = HumanEmitter::new();
+        let access = SocAccess::acquire();
+
+        let rendered = emitter.emit_chain_privileged(&chain, &access);
+        assert!(rendered.contains("Connection refused: password 'hunter2' rejected"));
+        assert!(rendered.contains("Max retries (3) exceeded"));
+    }
+
+    #[test]
+    fn human_emitter_non_privileged_never_contains_sensitive_text() {
+        let chain = sample_chain();
+        let emitter = HumanEmitter::new();
+
+        let rendered = emitter.emit_chain(&chain);
+        assert!(!rendered.contains("hunter2"));
+    }
+
+    #[test]
+    #[cfg(feature = "json_emitter")]
+    fn json_emitter_public_only() {
+        let chain = sample_chain();
+        let emitter = JsonEmitter::new();
+
+        let json = emitter.emit_chain(&chain);
+        assert!(json.starts_with('['));
+        assert!(json.ends_with(']'));
+        assert!(json.contains("\"category\":\"I/O\""));
+        assert!(json.contains("\"severity\":\"error\""));
+        assert!(json.contains("\"public_message\":\"Database error\""));
+        assert!(!json.contains("hunter2"));
+    }
+
+    #[test]
+    #[cfg(feature = "json_emitter")]
+    fn json_emitter_privileged_includes_internal_fields() {
+        let chain = sample_chain();
+        let emitter = JsonEmitter::new();
+        let access = SocAccess::acquire();
+
+        let json = emitter.emit_chain_privileged(&chain, &access);
+        assert!(json.contains("\"internal_message\":\"Connection refused: password 'hunter2' rejected\""));
+        assert!(json.contains("\"internal_severity\":\"error\""));
+    }
+
+    #[test]
+    #[cfg(feature = "json_emitter")]
+    fn json_emitter_escapes_quotes_and_control_chars() {
+        let root = ContextBuilder::new()
+            .public_lie("Say \"hi\"\n")
+            .internal_diagnostic("x")
+            .build();
+        let chain = ContextChain::new(root);
+
+        let json = JsonEmitter::new().emit_chain(&chain);
+        assert!(json.contains("Say \\\"hi\\\"\\n"));
+    }
+
+    #[test]
+    fn chain_verify_passes_when_every_link_signed_with_same_key() {
+        let key = SigningKey::new(b"session-key".to_vec());
+        let root = ContextBuilder::new()
+            .public_lie("Database error")
+            .internal_sensitive("Connection refused")
+            .category(OperationCategory::IO)
+            .sign_with(SigningKey::new(b"session-key".to_vec()))
+            .build();
+        let mut chain = ContextChain::new(root);
+
+        chain.push(
+            ContextBuilder::new()
+                .public_lie("Retry failed")
+                .internal_diagnostic("Max retries (3) exceeded")
+                .category(OperationCategory::System)
+                .sign_with(SigningKey::new(b"session-key".to_vec()))
+                .build(),
+        );
+
+        assert!(chain.verify(&key).is_ok());
+    }
+
+    #[test]
+    fn chain_verify_reports_first_tampered_depth() {
+        let key = SigningKey::new(b"session-key".to_vec());
+        let root = ContextBuilder::new()
+            .public_lie("Database error")
+            .internal_sensitive("Connection refused")
+            .category(OperationCategory::IO)
+            .sign_with(SigningKey::new(b"session-key".to_vec()))
+            .build();
+        let mut chain = ContextChain::new(root);
+
+        // Second link is unsigned, so it should fail verification first.
+        chain.push(
+            ContextBuilder::new()
+                .public_lie("Retry failed")
+                .internal_diagnostic("Max retries (3) exceeded")
+                .category(OperationCategory::System)
+                .build(),
+        );
+
+        let result = chain.verify(&key);
+        assert_eq!(
+            result,
+            Err(ChainIntegrityError {
+                depth: 1,
+                source: IntegrityError::Unsigned
+            })
+        );
+    }
+
+    #[test]
+    fn compact_is_noop_below_max_depth() {
+        let root = DualContextError::with_lie("root", "r", OperationCategory::System);
+        let mut chain = ContextChain::new(root);
+        chain.push(DualContextError::with_lie("hop", "h", OperationCategory::System));
+
+        chain.compact(5);
+
+        assert_eq!(chain.depth(), 2);
+        assert!(chain.links().next().unwrap().checkpoint().is_none());
+    }
+
+    #[test]
+    fn compact_folds_interior_links_preserving_root_and_head() {
+        let root = DualContextError::with_lie("root", "r", OperationCategory::System);
+        let mut chain = ContextChain::new(root);
+        for i in 0..5 {
+            chain.push(DualContextError::with_lie(
+                format!("hop {i}"),
+                "detail",
+                OperationCategory::System,
+            ));
+        }
+        assert_eq!(chain.depth(), 6);
+
+        chain.compact(3);
+
+        assert_eq!(chain.depth(), 3);
+        assert_eq!(chain.root().external_message(), "root");
+        assert_eq!(chain.head().external_message(), "hop 4");
+
+        let links: Vec<&ChainLink> = chain.links().collect();
+        let checkpoint = links[1].checkpoint().expect("interior link should be folded");
+        assert_eq!(checkpoint.collapsed_count(), 4);
+        assert_eq!(checkpoint.root_cause().external_message(), "hop 0");
+    }
+
+    #[test]
+    fn compact_truncates_long_external_summary() {
+        let root = DualContextError::with_lie("root", "r", OperationCategory::System);
+        let mut chain = ContextChain::new(root);
+        for i in 0..10 {
+            chain.push(DualContextError::with_lie(
+                format!("a moderately long hop message number {i}"),
+                "detail",
+                OperationCategory::System,
+            ));
+        }
+
+        chain.compact(3);
+
+        let links: Vec<&ChainLink> = chain.links().collect();
+        let checkpoint = links[1].checkpoint().expect("interior link should be folded");
+        assert!(checkpoint.external_summary().chars().count() <= Checkpoint::MAX_SUMMARY_LEN + 1);
+    }
+
+    #[test]
+    fn compact_skips_folded_link_during_verify() {
+        let key = SigningKey::new(b"session-key".to_vec());
+        let root = ContextBuilder::new()
+            .public_lie("root")
+            .internal_diagnostic("r")
+            .category(OperationCategory::System)
+            .sign_with(SigningKey::new(b"session-key".to_vec()))
+            .build();
+        let mut chain = ContextChain::new(root);
+        for i in 0..4 {
+            chain.push(
+                ContextBuilder::new()
+                    .public_lie(format!("hop {i}"))
+                    .internal_diagnostic("detail")
+                    .category(OperationCategory::System)
+                    .sign_with(SigningKey::new(b"session-key".to_vec()))
+                    .build(),
+            );
+        }
+
+        chain.compact(3);
+
+        assert!(chain.verify(&key).is_ok());
+    }
+
+    #[test]
+    fn internal_report_compact_mode_matches_external_summary() {
+        let root = DualContextError::with_lie("Database error", "Connection refused", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+        chain.push(DualContextError::with_lie("Retry failed", "Max retries exceeded", OperationCategory::System));
+
+        assert_eq!(chain.internal_report(None), chain.external_summary());
+    }
+
+    #[test]
+    fn internal_report_privileged_includes_internal_text_and_metadata() {
+        let root = ContextBuilder::new()
+            .public_lie("Database error")
+            .internal_diagnostic("Connection refused")
+            .category(OperationCategory::IO)
+            .metadata("host", "db-1")
+            .build();
+        let chain = ContextChain::new(root);
+
+        let access = SocAccess::acquire();
+        let report = chain.internal_report(Some(&access));
+
+        assert!(report.contains("[I/O] Database error"));
+        assert!(report.contains("Connection refused"));
+        assert!(report.contains("host: db-1"));
+    }
+
+    #[test]
+    fn internal_report_joins_links_with_caused_by_arrow() {
+        let root = DualContextError::with_lie("Database error", "x", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+        chain.push(DualContextError::with_lie("Retry failed", "y", OperationCategory::System));
+
+        let access = SocAccess::acquire();
+        let report = chain.internal_report(Some(&access));
+
+        assert!(report.contains("caused by ↑"));
+    }
+
+    #[test]
+    fn internal_report_never_leaks_sensitive_text_without_access() {
+        let root = DualContextError::with_lie_and_sensitive(
+            "Operation failed",
+            "api_key=supersecret",
+            OperationCategory::IO,
+        );
+        let chain = ContextChain::new(root);
+
+        assert!(!chain.internal_report(None).contains("supersecret"));
+    }
+
+    #[test]
+    fn internal_report_summarizes_folded_checkpoint_links() {
+        let root = DualContextError::with_lie("root", "r", OperationCategory::System);
+        let mut chain = ContextChain::new(root);
+        for i in 0..5 {
+            chain.push(DualContextError::with_lie(
+                format!("hop {i}"),
+                "detail",
+                OperationCategory::System,
+            ));
+        }
+        chain.compact(3);
+
+        let access = SocAccess::acquire();
+        let report = chain.internal_report(Some(&access));
+
+        assert!(report.contains("collapsed hop(s)"));
+        assert!(report.contains("hop 0"));
+    }
 }
@@ -42,11 +42,22 @@
 //! of this file. Ad-hoc definition of codes outside their namespace range
 //! will fail the build.
 //!
+//! [`define_error_codes!`](crate::define_error_codes) only checks for
+//! duplicate codes and out-of-range impacts *within* a single block, so
+//! nothing previously caught a code collision or an out-of-band code
+//! across two different blocks. [`REGISTRY`] closes that gap: it lists
+//! every code defined in this file, and [`validate`] checks the whole set
+//! at once - no duplicates anywhere, every code inside its own namespace's
+//! `ranges::BLOCKS` band, and every namespace narrow enough for the
+//! obfuscation permutation domain to cover. [`describe`] is the
+//! corresponding lookup.
+//!
 //! To strengthen governance:
 //! - Range enforcement should be promoted to the macro level in codes.rs for compile-time checks.
 //! - Escalation comments standardize response protocols.
 
-use crate::{define_error_codes, namespaces, OperationCategory};
+use crate::{define_error_codes, namespaces, ErrorCode, OperationCategory};
+use core::time::Duration;
 
 /// Error code range constants for maintaining namespace boundaries.
 /// Checked for consistency in `tests` module.
@@ -60,6 +71,246 @@ pub mod ranges {
     pub const LOG_START:  u16 = 600; pub const LOG_END:  u16 = 699;
     pub const PLT_START:  u16 = 700; pub const PLT_END:  u16 = 799;
     pub const IO_START:   u16 = 800; pub const IO_END:   u16 = 899;
+
+    /// One contiguous block of error codes owned by a single subsystem.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SubsystemRange {
+        /// Subsystem namespace name, e.g. `"CORE"` or `"IO"`.
+        pub name: &'static str,
+        /// First code in the block (inclusive).
+        pub start: u16,
+        /// Last code in the block (inclusive).
+        pub end: u16,
+    }
+
+    /// Every registered subsystem block, in ascending `start` order.
+    ///
+    /// This is the single source of truth [`RangeRegistry`] checks for
+    /// overlaps and gaps - adding a new subsystem means adding one entry
+    /// here, not updating a hand-written assertion elsewhere.
+    pub const BLOCKS: &[SubsystemRange] = &[
+        SubsystemRange { name: "CORE", start: CORE_START, end: CORE_END },
+        SubsystemRange { name: "CFG", start: CFG_START, end: CFG_END },
+        SubsystemRange { name: "DCP", start: DCP_START, end: DCP_END },
+        SubsystemRange { name: "TEL", start: TEL_START, end: TEL_END },
+        SubsystemRange { name: "COR", start: COR_START, end: COR_END },
+        SubsystemRange { name: "RSP", start: RSP_START, end: RSP_END },
+        SubsystemRange { name: "LOG", start: LOG_START, end: LOG_END },
+        SubsystemRange { name: "PLT", start: PLT_START, end: PLT_END },
+        SubsystemRange { name: "IO", start: IO_START, end: IO_END },
+    ];
+
+    /// `true` iff every block in `blocks` is well-formed (`start <= end`),
+    /// sorted by `start`, and disjoint from every other block.
+    ///
+    /// Free function (rather than a [`RangeRegistry`] method tied to
+    /// [`BLOCKS`]) so it can also be exercised against ad-hoc block lists in
+    /// tests.
+    pub const fn blocks_are_valid(blocks: &[SubsystemRange]) -> bool {
+        let mut i = 0;
+        while i < blocks.len() {
+            let block = blocks[i];
+            if block.start > block.end {
+                return false;
+            }
+            if i + 1 < blocks.len() {
+                let next = blocks[i + 1];
+                if block.end >= next.start {
+                    return false;
+                }
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Compile-time-checked registry over [`BLOCKS`]: no two subsystem
+    /// ranges may overlap, and each must come before the next in `start`
+    /// order. Traces a code back to the subsystem that owns it.
+    pub struct RangeRegistry;
+
+    impl RangeRegistry {
+        /// `true` iff [`BLOCKS`] is well-formed, sorted, and non-overlapping.
+        pub const fn is_valid() -> bool {
+            blocks_are_valid(BLOCKS)
+        }
+
+        /// The name of the subsystem block that owns `code`, or `None` if
+        /// `code` doesn't fall in any registered block.
+        pub const fn block_for(code: u16) -> Option<&'static str> {
+            let mut i = 0;
+            while i < BLOCKS.len() {
+                let block = BLOCKS[i];
+                if code >= block.start && code <= block.end {
+                    return Some(block.name);
+                }
+                i += 1;
+            }
+            None
+        }
+    }
+
+    /// Forces [`RangeRegistry::is_valid`] to be checked at compile time: a
+    /// subsystem range that overlaps or is out of order fails the build
+    /// here, rather than surfacing later as a flaky or forgotten test.
+    const _: () = assert!(
+        RangeRegistry::is_valid(),
+        "subsystem ranges in `ranges::BLOCKS` overlap or are out of order"
+    );
+
+    /// A raw numeric code that doesn't resolve to any registered subsystem
+    /// block, returned by [`try_from_code`] and [`parse_code`].
+    ///
+    /// Modeled on clap's `port_in_range` validator idiom: rather than
+    /// handing the caller a bare `None`, name the nearest registered
+    /// range(s) so an operator parsing a code from a log line, env var, or
+    /// CLI argument gets actionable feedback.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CodeRangeError {
+        /// `code` is lower than every registered range.
+        BelowLowest {
+            /// The code that failed to resolve.
+            code: u32,
+            /// The lowest-numbered registered block.
+            lowest: SubsystemRange,
+        },
+        /// `code` is higher than every registered range.
+        AboveHighest {
+            /// The code that failed to resolve.
+            code: u32,
+            /// The highest-numbered registered block.
+            highest: SubsystemRange,
+        },
+        /// `code` falls in an unassigned gap between two adjacent blocks.
+        InGap {
+            /// The code that failed to resolve.
+            code: u32,
+            /// The block immediately below the gap.
+            before: SubsystemRange,
+            /// The block immediately above the gap.
+            after: SubsystemRange,
+        },
+    }
+
+    impl core::fmt::Display for CodeRangeError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::BelowLowest { code, lowest } => write!(
+                    f,
+                    "code {} not in range {}-{} (lowest registered subsystem is {})",
+                    code, lowest.start, lowest.end, lowest.name
+                ),
+                Self::AboveHighest { code, highest } => write!(
+                    f,
+                    "code {} not in range {}-{} (highest registered subsystem is {})",
+                    code, highest.start, highest.end, highest.name
+                ),
+                Self::InGap { code, before, after } => write!(
+                    f,
+                    "code {} not in range {}-{} or {}-{} (falls in the unassigned gap between {} and {})",
+                    code, before.start, before.end, after.start, after.end, before.name, after.name
+                ),
+            }
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    impl std::error::Error for CodeRangeError {}
+
+    #[cfg(all(feature = "no_std", feature = "core-error"))]
+    impl core::error::Error for CodeRangeError {}
+
+    /// Resolve a raw numeric `code` to its owning subsystem block name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodeRangeError`] naming the nearest registered range(s)
+    /// when `code` falls outside every block in [`BLOCKS`] - whether
+    /// because it's below the lowest range, above the highest, or inside a
+    /// gap between two blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::definitions::ranges;
+    ///
+    /// assert_eq!(ranges::try_from_code(50), Ok("CORE"));
+    /// assert!(ranges::try_from_code(900).is_err());
+    /// ```
+    pub fn try_from_code(code: u32) -> Result<&'static str, CodeRangeError> {
+        if let Ok(code16) = u16::try_from(code) {
+            if let Some(name) = RangeRegistry::block_for(code16) {
+                return Ok(name);
+            }
+        }
+
+        let mut before: Option<SubsystemRange> = None;
+        let mut after: Option<SubsystemRange> = None;
+        for block in BLOCKS {
+            if u32::from(block.end) < code {
+                before = Some(*block);
+            } else if after.is_none() && u32::from(block.start) > code {
+                after = Some(*block);
+            }
+        }
+
+        match (before, after) {
+            (None, Some(lowest)) => Err(CodeRangeError::BelowLowest { code, lowest }),
+            (Some(highest), None) => Err(CodeRangeError::AboveHighest { code, highest }),
+            (Some(before), Some(after)) => Err(CodeRangeError::InGap { code, before, after }),
+            (None, None) => unreachable!("ranges::BLOCKS is never empty"),
+        }
+    }
+
+    /// Error returned by [`parse_code`]: either the input wasn't a valid
+    /// number, or it was a number outside every registered range.
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum ParseCodeError {
+        /// The input couldn't be parsed as a `u32` at all.
+        NotANumber(core::num::ParseIntError),
+        /// The input parsed fine but doesn't resolve to a subsystem block.
+        OutOfRange(CodeRangeError),
+    }
+
+    impl core::fmt::Display for ParseCodeError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::NotANumber(e) => write!(f, "not a valid error code: {}", e),
+                Self::OutOfRange(e) => write!(f, "{}", e),
+            }
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    impl std::error::Error for ParseCodeError {}
+
+    #[cfg(all(feature = "no_std", feature = "core-error"))]
+    impl core::error::Error for ParseCodeError {}
+
+    impl From<CodeRangeError> for ParseCodeError {
+        fn from(e: CodeRangeError) -> Self {
+            Self::OutOfRange(e)
+        }
+    }
+
+    /// Parse and validate a raw numeric code from a string - the
+    /// `FromStr`-shaped companion to [`try_from_code`] for callers pulling
+    /// a code out of a log line, env var, or CLI argument that hasn't been
+    /// parsed to a number yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::definitions::ranges;
+    ///
+    /// assert_eq!(ranges::parse_code("800"), Ok("IO"));
+    /// assert!(ranges::parse_code("not-a-number").is_err());
+    /// assert!(ranges::parse_code("900").is_err());
+    /// ```
+    pub fn parse_code(s: &str) -> Result<&'static str, ParseCodeError> {
+        let code: u32 = s.trim().parse().map_err(ParseCodeError::NotANumber)?;
+        Ok(try_from_code(code)?)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -109,14 +360,14 @@ define_error_codes! {
 // ACTION: Fallback to defaults; alert on load failures
 define_error_codes! {
     &namespaces::CFG, OperationCategory::Configuration => {
-        CFG_PARSE_FAILED             = (100, 200),
+        CFG_PARSE_FAILED             = (100, 200, "The configuration source could not be parsed into a valid document (malformed syntax, wrong format for the configured loader, or truncated input). Typically retryable only if the source is being rewritten concurrently; otherwise fix the source file and reload."),
         CFG_VALIDATION_FAILED        = (101, 200),
         CFG_MISSING_REQUIRED         = (102, 200),
-        CFG_INVALID_VALUE            = (103, 200),
+        CFG_INVALID_VALUE            = (103, 200, "A configuration value failed validation (wrong type, out-of-range, or disallowed choice). The request/source that supplied it is at fault, not the service - distinct from CFG_SECURITY_VIOLATION, which is a policy breach rather than a malformed value."),
         CFG_INVALID_FORMAT           = (104, 200),
         CFG_PERMISSION_DENIED        = (105, 200),
         CFG_VERSION_MISMATCH         = (106, 200),
-        CFG_SECURITY_VIOLATION       = (107, 200),
+        CFG_SECURITY_VIOLATION       = (107, 200, "A configuration value violated a security policy (e.g. a disallowed permission bit or an untrusted source for a secrets path). Reject the configuration rather than falling back to defaults - silently downgrading here can mask a tampered config."),
         CFG_LOAD_FAILED              = (108, 200),
         CFG_SAVE_FAILED              = (109, 200),
         CFG_ENV_VAR_MISSING          = (110, 200),
@@ -205,7 +456,7 @@ define_error_codes! {
         
         // Attacker-visible inconsistency detected.
         // IMPACT: DeceptionFailure. The lie has crumbled.
-        DCP_NARRATIVE_BREAK          = (232, 800),
+        DCP_NARRATIVE_BREAK          = (232, 800, "The persona's exposed narrative contradicted itself in a way the attacker could observe. Hard-reset the persona immediately and assume the attacker now suspects deception - this is not a log-and-continue situation."),
         
         // Deception efficacy statistically failing based on interaction depth.
         DCP_BELIEVABILITY_LOW        = (233, 500),
@@ -225,6 +476,33 @@ define_error_codes! {
     }
 }
 
+// Block 3: Detection (The "Alarm")
+// An attacker's action was recognized as hostile, independent of whether the
+// deception itself held - this is the honeypot noticing, not the cover story
+// cracking (that's Block 2's job).
+// ESCALATION: Strategic
+// ACTION: Alert analyst; begin correlation with any open incident
+define_error_codes! {
+    &namespaces::DCP, OperationCategory::Detection => {
+        // A monitored interaction matched a known attack signature or
+        // behavioral heuristic.
+        DCP_SUSPICIOUS_ACTIVITY_DETECTED = (238, 700),
+    }
+}
+
+// Block 4: Containment (The "Lockdown")
+// Active measures taken to isolate or restrict an attacker already detected,
+// rather than merely observed.
+// ESCALATION: Critical
+// ACTION: Confirm isolation took effect; analyst review before lifting it
+define_error_codes! {
+    &namespaces::DCP, OperationCategory::Containment => {
+        // An isolation/quarantine action (blocking a source, revoking a
+        // session) failed to apply.
+        DCP_ISOLATION_FAILED         = (239, 800),
+    }
+}
+
 // -----------------------------------------------------------------------------
 // TEL (300-399) - Telemetry & Observability
 // -----------------------------------------------------------------------------
@@ -464,7 +742,7 @@ define_error_codes! {
 // ACTION: Retry with backoff; fallback to in-memory if persistent
 define_error_codes! {
     &namespaces::IO, OperationCategory::IO => {
-        IO_READ_FAILED               = (800, 200),
+        IO_READ_FAILED               = (800, 200, "A read from a file, socket, or pipe failed after the handle was successfully opened. Usually transient (a dropped connection, a full read buffer) and safe to retry with backoff; persistent failures on the same handle suggest the underlying resource went away."),
         IO_WRITE_FAILED              = (801, 200),
         IO_NETWORK_ERROR             = (802, 200),
         IO_TIMEOUT                   = (803, 200),
@@ -498,9 +776,754 @@ define_error_codes! {
     }
 }
 
+// -----------------------------------------------------------------------------
+// Failure-Kind Taxonomy (for Classification & Metrics)
+// -----------------------------------------------------------------------------
+
+/// What *kind* of failure a code represents, orthogonal to
+/// [`OperationCategory`]'s functional domain.
+///
+/// # Design
+///
+/// `OperationCategory` answers "which subsystem was this?" (`Configuration`,
+/// `IO`, `Deception`, ...) while deliberately staying vague enough to avoid
+/// revealing architecture - see its own docs. `FailureCategory` answers a
+/// different question services need for metrics/alerting: "what *shape* of
+/// failure is this, regardless of subsystem?" A spike in `Authorization`
+/// failures across `Configuration`, `Response`, and `Platform` codes is one
+/// signal; a spike in `DenialOfService`-shaped failures in just one of them
+/// is a different one. Keeping the two axes separate lets a dashboard query
+/// either without string-matching [`ErrorDefinition::name`] or the rendered
+/// message.
+///
+/// # Default Assignment
+///
+/// [`ErrorDefinition::failure_category`] defaults to
+/// [`default_failure_category_for_operation_category`] (one
+/// `OperationCategory` -> one `FailureCategory`, the same shape as
+/// [`default_http_status_for_category`]) unless a `REGISTRY` entry opts into
+/// a sharper classification via `entry!`'s `category = ...` form - e.g.
+/// `CORE_CRYPTO_SETUP_FAILED` is `System` by `OperationCategory` but
+/// `CryptoFailure` by `FailureCategory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FailureCategory {
+    /// Key generation, signing, verification, or other cryptographic
+    /// primitive failed - always worth routing into the sensitive context
+    /// by default, since the detail (which primitive, which key) is
+    /// disproportionately useful to an attacker.
+    CryptoFailure,
+    /// Resource exhaustion, rate limiting, or anything shaped like an
+    /// availability attack rather than a single failed operation.
+    DenialOfService,
+    /// A command, query, or external process failed to execute, or its
+    /// execution is itself the security-relevant event.
+    CodeExecution,
+    /// Configuration could not be parsed, loaded, or applied.
+    Configuration,
+    /// Input failed validation (malformed, out-of-range, wrong type).
+    Validation,
+    /// A caller was denied access, permission, or a security policy was
+    /// violated.
+    Authorization,
+}
+
+impl FailureCategory {
+    /// Every variant, in declaration order - used by [`REGISTRY`]-wide
+    /// consumers like [`Self::count_in_registry`] that need to enumerate the
+    /// full taxonomy rather than just the ones currently in use.
+    pub const ALL: &'static [FailureCategory] = &[
+        Self::CryptoFailure,
+        Self::DenialOfService,
+        Self::CodeExecution,
+        Self::Configuration,
+        Self::Validation,
+        Self::Authorization,
+    ];
+
+    /// Stable, human-readable label - safe for internal metrics/alerting
+    /// dimensions (e.g. a Prometheus label value).
+    #[inline]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::CryptoFailure => "CryptoFailure",
+            Self::DenialOfService => "DenialOfService",
+            Self::CodeExecution => "CodeExecution",
+            Self::Configuration => "Configuration",
+            Self::Validation => "Validation",
+            Self::Authorization => "Authorization",
+        }
+    }
+
+    /// Whether this failure kind should route its details into the
+    /// sensitive context by default - `true` only for [`Self::CryptoFailure`]
+    /// today, per the rationale on [`Self`].
+    #[inline]
+    pub const fn sensitive_by_default(&self) -> bool {
+        matches!(self, Self::CryptoFailure)
+    }
+
+    /// Count of [`REGISTRY`] entries classified under this category -
+    /// O(n) over the registry, intended for startup-time metrics setup or
+    /// tests, not a hot path.
+    pub fn count_in_registry(&self) -> usize {
+        REGISTRY.iter().filter(|entry| entry.failure_category == *self).count()
+    }
+
+    /// Every [`REGISTRY`] entry classified under this category, in
+    /// registry order.
+    pub fn entries_in_registry(&self) -> impl Iterator<Item = &'static ErrorDefinition> + '_ {
+        let category = *self;
+        REGISTRY.iter().filter(move |entry| entry.failure_category == category)
+    }
+}
+
+/// Default [`FailureCategory`] for a code that hasn't opted into a sharper
+/// one via `entry!`'s `category = ...` form - one `OperationCategory` maps
+/// to exactly one `FailureCategory`, the same shape as
+/// [`default_http_status_for_category`].
+#[inline]
+pub(crate) const fn default_failure_category_for_operation_category(
+    category: OperationCategory,
+) -> FailureCategory {
+    match category {
+        OperationCategory::Configuration | OperationCategory::Deployment => FailureCategory::Configuration,
+        OperationCategory::Response | OperationCategory::System => FailureCategory::CodeExecution,
+        OperationCategory::IO => FailureCategory::DenialOfService,
+        OperationCategory::Deception | OperationCategory::Detection | OperationCategory::Containment => {
+            FailureCategory::Authorization
+        }
+        // Monitoring/Analysis/Audit, and any category added later, default
+        // to Validation - the same "no stance until proven otherwise"
+        // fallback `default_http_status_for_category` uses for its own
+        // wildcard arm.
+        _ => FailureCategory::Validation,
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Retryability Classification
+// -----------------------------------------------------------------------------
+
+/// Whether a failure is worth retrying, and on what schedule - surfaced on
+/// [`crate::DualContextError::retryability`] and consumed by
+/// [`crate::retry::retry`] to decide whether a failed operation gets
+/// another attempt.
+///
+/// # Design
+///
+/// Distinct from the legacy [`crate::AgentError::with_retry`]'s plain bool:
+/// that flag is a per-instance override an individual call site sets by
+/// hand, while this is the *default* classification for every error raised
+/// with a given [`crate::definitions`] code, the same relationship
+/// [`ErrorDefinition::http_status`] has with `DualContextError::status_code`'s
+/// per-instance paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retryability {
+    /// Likely a transient condition (a dropped connection, a lock held by
+    /// another process) - safe to retry with ordinary backoff.
+    Transient,
+    /// Retrying with the same inputs will fail the same way (bad
+    /// credentials, malformed input, a policy violation) - not worth
+    /// retrying without a human fixing the underlying cause first.
+    Permanent,
+    /// Worth retrying, but only after waiting at least this long - e.g. a
+    /// rate limit with a known reset window.
+    RetryAfter(Duration),
+}
+
+// -----------------------------------------------------------------------------
+// Registry - crate-wide governance over every code defined above
+// -----------------------------------------------------------------------------
+
+/// One entry in [`REGISTRY`]: pairs a defined constant's name with the code
+/// it resolves to, plus the business-facing metadata a bare `(code, impact)`
+/// tuple in a `define_error_codes!` block can't carry.
+///
+/// [`REGISTRY`] entries hold a `&'static ErrorCode` reference to the actual
+/// constant rather than re-stating its code/category/impact, so there is
+/// nothing here that can drift out of sync with the constant itself.
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorDefinition {
+    /// The constant's identifier, e.g. `"CFG_PARSE_FAILED"`.
+    pub name: &'static str,
+    /// Stable, wire-safe identifier derived from `name` - always
+    /// `"PAL_" + name`, e.g. `"PAL_CFG_PARSE_FAILED"`. Unlike `code`'s bare
+    /// `u16`, this is meant to round-trip across a trust boundary (an HTTP
+    /// client matching on a `"code"` JSON field, say) without a reader
+    /// having to know this crate's namespace/range scheme. See [`lookup`]
+    /// for the reverse direction.
+    pub code_id: &'static str,
+    /// The code this definition resolves to.
+    pub code: &'static ErrorCode,
+    /// HTTP status this definition maps to, e.g. via
+    /// [`crate::DualContextError::status_code`] once an error carries this
+    /// code. Defaults to [`default_http_status_for_category`] unless the
+    /// `entry!` invocation below opted into a more specific status.
+    pub http_status: u16,
+    /// The [`crate::scope::Scope`] a [`crate::scope::ScopedClearance`] must
+    /// cover to expose this code's sensitive payload via
+    /// [`crate::DualContextError::expose_scoped`]. Defaults to
+    /// [`DEFAULT_SENSITIVE_SCOPE`] unless the `entry!` invocation below opted
+    /// into a narrower one.
+    pub required_scope: &'static str,
+    /// What *kind* of failure this code represents, for classification and
+    /// per-category metrics/alerting - see [`FailureCategory`]. Defaults to
+    /// [`default_failure_category_for_operation_category`] unless the
+    /// `entry!` invocation below opted into a sharper one.
+    pub failure_category: FailureCategory,
+    /// Default [`Retryability`] for this code - see
+    /// [`crate::DualContextError::retryability`]. Defaults to
+    /// [`Retryability::Transient`] unless the `entry!` invocation below
+    /// opted into `retry = ...`. A per-instance
+    /// [`crate::AgentError::with_retry`] call on the legacy error type still
+    /// wins over this default for that type.
+    pub retryability: Retryability,
+    /// Default external-facing message template, if this code has opted
+    /// into one. `None` (the default for every code today) means callers
+    /// fall back to the normal sanitized rendering.
+    pub default_template: Option<&'static str>,
+}
+
+/// Default `OperationCategory` -> HTTP status mapping backing
+/// [`ErrorDefinition::http_status`]'s default.
+///
+/// # Rationale
+///
+/// Mirrors `http.rs`'s own category-level default (every category maps to
+/// `500`, except the honeypot-specific `Deception`/`Detection`/`Containment`
+/// trio, which map to `403` for a deliberate block rather than an internal
+/// fault) so the two stay in lockstep; `http.rs`'s `default_status_for_category`
+/// delegates to this one rather than keeping its own copy. An individual
+/// code that needs a sharper status than its category affords (e.g. a
+/// `Configuration` code that represents bad client input, not an
+/// operational fault) opts out via `entry!`'s two-argument form instead of
+/// changing this default for the whole category.
+#[inline]
+pub(crate) const fn default_http_status_for_category(category: OperationCategory) -> u16 {
+    match category {
+        OperationCategory::Deception | OperationCategory::Detection | OperationCategory::Containment => 403,
+        _ => 500,
+    }
+}
+
+/// Default [`ErrorDefinition::required_scope`] for every code that hasn't
+/// opted into a narrower one via `entry!`'s three-argument form - covers the
+/// same ground `expose_sensitive(&SocAccess)` always has, so a deployment
+/// that never grants anything more specific than `"log.sensitive"` sees
+/// unchanged behavior.
+pub(crate) const DEFAULT_SENSITIVE_SCOPE: &str = "log.sensitive";
+
+/// Build an [`ErrorDefinition`] for a constant already declared by one of
+/// the `define_error_codes!` blocks above, defaulting its registry-only
+/// metadata until a future change opts it into a template or permanence.
+///
+/// The one-argument form defaults `http_status` to
+/// [`default_http_status_for_category`] and `required_scope` to
+/// [`DEFAULT_SENSITIVE_SCOPE`]; the two-argument form overrides the status;
+/// the three-argument form also overrides the scope, for a code whose
+/// sensitive payload needs a narrower grant than the crate-wide default.
+/// The `category = ...` form overrides [`ErrorDefinition::failure_category`]
+/// alone, leaving `http_status`/`required_scope` at their usual defaults,
+/// for a code whose failure *kind* doesn't match its `OperationCategory`'s
+/// default mapping (e.g. a crypto failure filed under `System`). The
+/// `retry = ...` form likewise overrides only
+/// [`ErrorDefinition::retryability`], which otherwise defaults to
+/// [`Retryability::Transient`] (matching every code's behavior before this
+/// field existed).
+macro_rules! entry {
+    ($name:ident) => {
+        entry!($name, default_http_status_for_category($name.category()))
+    };
+    ($name:ident, category = $failure_category:expr) => {
+        entry!(
+            $name,
+            default_http_status_for_category($name.category()),
+            DEFAULT_SENSITIVE_SCOPE,
+            $failure_category
+        )
+    };
+    ($name:ident, retry = $retryability:expr) => {
+        entry!(
+            $name,
+            default_http_status_for_category($name.category()),
+            DEFAULT_SENSITIVE_SCOPE,
+            default_failure_category_for_operation_category($name.category()),
+            $retryability
+        )
+    };
+    ($name:ident, $http_status:expr) => {
+        entry!($name, $http_status, DEFAULT_SENSITIVE_SCOPE)
+    };
+    ($name:ident, $http_status:expr, $required_scope:expr) => {
+        entry!(
+            $name,
+            $http_status,
+            $required_scope,
+            default_failure_category_for_operation_category($name.category())
+        )
+    };
+    ($name:ident, $http_status:expr, $required_scope:expr, $failure_category:expr) => {
+        entry!($name, $http_status, $required_scope, $failure_category, Retryability::Transient)
+    };
+    ($name:ident, $http_status:expr, $required_scope:expr, $failure_category:expr, $retryability:expr) => {
+        ErrorDefinition {
+            name: stringify!($name),
+            code_id: concat!("PAL_", stringify!($name)),
+            code: &$name,
+            http_status: $http_status,
+            required_scope: $required_scope,
+            failure_category: $failure_category,
+            retryability: $retryability,
+            default_template: None,
+        }
+    };
+}
+
+/// Every error code defined in this module, in declaration order.
+///
+/// The single source of truth [`validate`] and [`describe`] work from,
+/// instead of downstream crates re-deriving category/template/permanence
+/// from loose constants. Adding a code to one of the blocks above without
+/// adding it here just means it's invisible to both - there's no macro
+/// wiring this automatically, since `define_error_codes!`'s call-site
+/// syntax is relied on by its own compile-tested doc examples and can't
+/// silently grow a side-channel output without changing that.
+pub const REGISTRY: &[ErrorDefinition] = &[
+    entry!(CORE_INIT_FAILED),
+    entry!(CORE_SHUTDOWN_FAILED),
+    entry!(CORE_PANIC_RECOVERY),
+    entry!(CORE_INVALID_STATE),
+    entry!(CORE_MEMORY_ALLOC_FAILED),
+    entry!(CORE_THREAD_SPAWN_FAILED),
+    entry!(CORE_MUTEX_LOCK_FAILED),
+    entry!(CORE_SIGNAL_HANDLER_FAILED),
+    entry!(CORE_MODULE_LOAD_FAILED),
+    entry!(CORE_DEPENDENCY_MISSING),
+    entry!(CORE_VERSION_CHECK_FAILED),
+    entry!(CORE_RESOURCE_INIT_FAILED),
+    entry!(CORE_EVENT_LOOP_FAILED),
+    entry!(CORE_CONFIG_BOOTSTRAP_FAILED),
+    entry!(CORE_DATABASE_CONNECT_FAILED),
+    entry!(CORE_CACHE_INIT_FAILED),
+    entry!(CORE_QUEUE_OVERFLOW),
+    entry!(CORE_TIMER_SETUP_FAILED),
+    entry!(CORE_HOOK_REGISTRATION_FAILED),
+    entry!(CORE_PLUGIN_INIT_FAILED),
+    entry!(CORE_STATE_TRANSITION_FAILED),
+    entry!(CORE_HEALTH_CHECK_FAILED),
+    entry!(CORE_BACKUP_FAILED),
+    entry!(CORE_RESTORE_FAILED),
+    entry!(CORE_MIGRATION_FAILED),
+    entry!(CORE_LICENSE_VALIDATION_FAILED),
+    entry!(CORE_AUTH_INIT_FAILED),
+    entry!(CORE_CRYPTO_SETUP_FAILED, category = FailureCategory::CryptoFailure),
+    entry!(CORE_NETWORK_INIT_FAILED),
+    entry!(CORE_API_SERVER_START_FAILED),
+    entry!(CFG_PARSE_FAILED),
+    entry!(CFG_VALIDATION_FAILED),
+    entry!(CFG_MISSING_REQUIRED),
+    entry!(CFG_INVALID_VALUE, 400),
+    entry!(CFG_INVALID_FORMAT),
+    entry!(CFG_PERMISSION_DENIED, default_http_status_for_category(CFG_PERMISSION_DENIED.category()), DEFAULT_SENSITIVE_SCOPE, FailureCategory::Authorization, Retryability::Permanent),
+    entry!(CFG_VERSION_MISMATCH),
+    entry!(CFG_SECURITY_VIOLATION, 403, "log.sensitive.security", FailureCategory::Authorization, Retryability::Permanent),
+    entry!(CFG_LOAD_FAILED),
+    entry!(CFG_SAVE_FAILED),
+    entry!(CFG_ENV_VAR_MISSING),
+    entry!(CFG_TYPE_MISMATCH),
+    entry!(CFG_DUPLICATE_KEY),
+    entry!(CFG_SCHEMA_VALIDATION_FAILED),
+    entry!(CFG_MERGE_CONFLICT),
+    entry!(CFG_REMOTE_FETCH_FAILED),
+    entry!(CFG_LOCAL_STORE_FAILED),
+    entry!(CFG_ENCRYPTION_FAILED),
+    entry!(CFG_DECRYPTION_FAILED),
+    entry!(CFG_KEY_NOT_FOUND),
+    entry!(CFG_INVALID_PATH),
+    entry!(CFG_CONVERSION_FAILED),
+    entry!(CFG_DEFAULTS_LOAD_FAILED),
+    entry!(CFG_OVERRIDE_FAILED),
+    entry!(CFG_WATCHER_INIT_FAILED),
+    entry!(CFG_RELOAD_FAILED),
+    entry!(CFG_BACKUP_FAILED),
+    entry!(CFG_ROLLBACK_FAILED),
+    entry!(CFG_TEMPLATE_RENDER_FAILED),
+    entry!(CFG_VARIABLE_RESOLUTION_FAILED),
+    entry!(CFG_SECRETS_MANAGER_FAILED),
+    entry!(CFG_PROFILE_SWITCH_FAILED),
+    entry!(DCP_DEPLOY_FAILED),
+    entry!(DCP_ARTIFACT_CREATE),
+    entry!(DCP_ARTIFACT_WRITE),
+    entry!(DCP_CLEANUP_FAILED),
+    entry!(DCP_TAG_GENERATION),
+    entry!(DCP_TRIGGER_FAILED),
+    entry!(DCP_SIMULATION_FAILED),
+    entry!(DCP_BAIT_DEPLOY_FAILED),
+    entry!(DCP_HONEYPOT_INIT_FAILED),
+    entry!(DCP_FAKE_DATA_GENERATION_FAILED),
+    entry!(DCP_REDIRECT_SETUP_FAILED),
+    entry!(DCP_MIMICRY_FAILED),
+    entry!(DCP_TARPIT_ENGAGE_FAILED),
+    entry!(DCP_DECOY_LAUNCH_FAILED),
+    entry!(DCP_SHADOW_SYSTEM_FAILED),
+    entry!(DCP_FINGERPRINT_MISMATCH),
+    entry!(DCP_BEHAVIOR_MODEL_LOAD_FAILED),
+    entry!(DCP_INTRUSION_SIM_FAILED),
+    entry!(DCP_COUNTERMEASURE_FAILED),
+    entry!(DCP_ARTIFACT_EXPIRATION),
+    entry!(DCP_DEPLOYMENT_ROLLBACK_FAILED),
+    entry!(DCP_RESOURCE_ALLOCATION_FAILED),
+    entry!(DCP_TEMPLATE_LOAD_FAILED),
+    entry!(DCP_VALIDATION_CHECK_FAILED),
+    entry!(DCP_INTEGRITY_CHECK_FAILED),
+    entry!(DCP_NETWORK_SIM_FAILED),
+    entry!(DCP_ACCESS_CONTROL_FAILED),
+    entry!(DCP_ENCRYPTED_ARTIFACT_FAILED),
+    entry!(DCP_DECRYPT_ARTIFACT_FAILED),
+    entry!(DCP_DYNAMIC_GENERATION_FAILED),
+    entry!(DCP_PERSISTENCE_FAILED),
+    entry!(DCP_NARRATIVE_DESYNC),
+    entry!(DCP_NARRATIVE_BREAK),
+    entry!(DCP_BELIEVABILITY_LOW),
+    entry!(DCP_ADVERSARY_ADAPTATION),
+    entry!(DCP_STATE_VIOLATION),
+    entry!(DCP_TEMPORAL_INCONSISTENCY),
+    entry!(DCP_CAUSALITY_BREACH),
+    entry!(DCP_SUSPICIOUS_ACTIVITY_DETECTED),
+    entry!(DCP_ISOLATION_FAILED),
+    entry!(TEL_INIT_FAILED),
+    entry!(TEL_WATCH_FAILED),
+    entry!(TEL_EVENT_LOST),
+    entry!(TEL_CHANNEL_CLOSED),
+    entry!(TEL_MONITOR_CRASH),
+    entry!(TEL_METRIC_COLLECTION_FAILED),
+    entry!(TEL_EXPORT_FAILED),
+    entry!(TEL_AGGREGATION_FAILED),
+    entry!(TEL_TRACE_SPAN_FAILED),
+    entry!(TEL_REMOTE_SEND_FAILED),
+    entry!(TEL_BUFFER_OVERFLOW),
+    entry!(TEL_INVALID_METRIC),
+    entry!(TEL_SAMPLING_FAILED),
+    entry!(TEL_PROPAGATION_FAILED),
+    entry!(TEL_ENDPOINT_UNREACHABLE),
+    entry!(TEL_AUTH_FAILED),
+    entry!(TEL_COMPRESSION_FAILED),
+    entry!(TEL_DECOMPRESSION_FAILED),
+    entry!(TEL_FILTER_APPLY_FAILED),
+    entry!(TEL_ALERT_TRIGGER_FAILED),
+    entry!(TEL_DASHBOARD_UPDATE_FAILED),
+    entry!(TEL_LOG_INGEST_FAILED),
+    entry!(TEL_QUERY_FAILED),
+    entry!(TEL_RETENTION_POLICY_FAILED),
+    entry!(TEL_BACKPRESSURE),
+    entry!(TEL_INSTRUMENTATION_FAILED),
+    entry!(TEL_BATCH_PROCESS_FAILED),
+    entry!(TEL_SERIALIZATION_FAILED),
+    entry!(TEL_DESERIALIZATION_FAILED),
+    entry!(TEL_RESOURCE_MONITOR_FAILED),
+    entry!(TEL_HEARTBEAT_FAILED),
+    entry!(TEL_EVASION_DETECTED),
+    entry!(TEL_SENSOR_BYPASS),
+    entry!(TEL_OBSERVABILITY_GAP),
+    entry!(COR_RULE_EVAL_FAILED),
+    entry!(COR_BUFFER_OVERFLOW),
+    entry!(COR_INVALID_SCORE),
+    entry!(COR_WINDOW_EXPIRED),
+    entry!(COR_INVALID_ARTIFACT),
+    entry!(COR_PATTERN_MATCH_FAILED),
+    entry!(COR_DATA_INGEST_FAILED),
+    entry!(COR_AGGREGATION_FAILED),
+    entry!(COR_THRESHOLD_BREACH),
+    entry!(COR_FALSE_POSITIVE),
+    entry!(COR_EVENT_MERGE_FAILED),
+    entry!(COR_CONTEXT_LOAD_FAILED),
+    entry!(COR_ANOMALY_DETECT_FAILED),
+    entry!(COR_MODEL_TRAIN_FAILED),
+    entry!(COR_INFERENCE_FAILED),
+    entry!(COR_DATA_NORMALIZATION_FAILED),
+    entry!(COR_FEATURE_EXTRACTION_FAILED),
+    entry!(COR_CLUSTERING_FAILED),
+    entry!(COR_OUTLIER_DETECTION_FAILED),
+    entry!(COR_TIME_SERIES_ANALYSIS_FAILED),
+    entry!(COR_GRAPH_BUILD_FAILED),
+    entry!(COR_PATH_ANALYSIS_FAILED),
+    entry!(COR_RULE_UPDATE_FAILED),
+    entry!(COR_VALIDATION_FAILED),
+    entry!(COR_EXPORT_FAILED),
+    entry!(COR_IMPORT_FAILED),
+    entry!(COR_QUERY_EXEC_FAILED, category = FailureCategory::CodeExecution),
+    entry!(COR_INDEX_BUILD_FAILED),
+    entry!(COR_SEARCH_FAILED),
+    entry!(COR_ENRICHMENT_FAILED),
+    entry!(COR_DEDUPLICATION_FAILED),
+    entry!(COR_CONFIDENCE_DEGRADATION),
+    entry!(COR_MODEL_DRIFT),
+    entry!(COR_HYPOTHESIS_INVALIDATED),
+    entry!(COR_ACTOR_CONFLICT),
+    entry!(RSP_EXEC_FAILED, category = FailureCategory::CodeExecution),
+    entry!(RSP_TIMEOUT),
+    entry!(RSP_INVALID_ACTION),
+    entry!(RSP_RATE_LIMITED, default_http_status_for_category(RSP_RATE_LIMITED.category()), DEFAULT_SENSITIVE_SCOPE, FailureCategory::DenialOfService, Retryability::RetryAfter(Duration::from_secs(30))),
+    entry!(RSP_HANDLER_NOT_FOUND),
+    entry!(RSP_SERIALIZATION_FAILED),
+    entry!(RSP_DESERIALIZATION_FAILED),
+    entry!(RSP_VALIDATION_FAILED),
+    entry!(RSP_AUTH_FAILED),
+    entry!(RSP_PERMISSION_DENIED, default_http_status_for_category(RSP_PERMISSION_DENIED.category()), DEFAULT_SENSITIVE_SCOPE, FailureCategory::Authorization, Retryability::Permanent),
+    entry!(RSP_RESOURCE_NOT_FOUND),
+    entry!(RSP_CONFLICT),
+    entry!(RSP_INTERNAL_ERROR),
+    entry!(RSP_BAD_REQUEST),
+    entry!(RSP_UNAVAILABLE),
+    entry!(RSP_GATEWAY_TIMEOUT),
+    entry!(RSP_TOO_MANY_REQUESTS),
+    entry!(RSP_PAYLOAD_TOO_LARGE),
+    entry!(RSP_UNSUPPORTED_MEDIA),
+    entry!(RSP_METHOD_NOT_ALLOWED),
+    entry!(RSP_NOT_ACCEPTABLE),
+    entry!(RSP_PROXY_AUTH_REQUIRED),
+    entry!(RSP_REQUEST_TIMEOUT),
+    entry!(RSP_PRECONDITION_FAILED),
+    entry!(RSP_EXPECTATION_FAILED),
+    entry!(RSP_MISDIRECTED_REQUEST),
+    entry!(RSP_UNPROCESSABLE_ENTITY),
+    entry!(RSP_LOCKED),
+    entry!(RSP_FAILED_DEPENDENCY),
+    entry!(RSP_UPGRADE_REQUIRED),
+    entry!(RSP_PRECONDITION_REQUIRED),
+    entry!(RSP_TIMING_ANOMALY),
+    entry!(RSP_ENTROPY_LOW),
+    entry!(RSP_BEHAVIORAL_INCONSISTENCY),
+    entry!(LOG_WRITE_FAILED),
+    entry!(LOG_ROTATE_FAILED),
+    entry!(LOG_BUFFER_FULL),
+    entry!(LOG_SERIALIZATION),
+    entry!(LOG_INIT_FAILED),
+    entry!(LOG_FLUSH_FAILED),
+    entry!(LOG_LEVEL_INVALID),
+    entry!(LOG_FILTER_APPLY_FAILED),
+    entry!(LOG_APPENDER_FAILED),
+    entry!(LOG_REMOTE_SEND_FAILED),
+    entry!(LOG_COMPRESSION_FAILED),
+    entry!(LOG_ENCRYPTION_FAILED),
+    entry!(LOG_ARCHIVE_FAILED),
+    entry!(LOG_PURGE_FAILED),
+    entry!(LOG_INDEX_FAILED),
+    entry!(LOG_SEARCH_FAILED),
+    entry!(LOG_PARSE_FAILED),
+    entry!(LOG_FORMAT_INVALID),
+    entry!(LOG_TIMESTAMP_FAILED),
+    entry!(LOG_METADATA_MISSING),
+    entry!(LOG_ROLLOVER_FAILED),
+    entry!(LOG_BACKUP_FAILED),
+    entry!(LOG_RESTORE_FAILED),
+    entry!(LOG_QUEUE_OVERFLOW),
+    entry!(LOG_ASYNC_SEND_FAILED),
+    entry!(LOG_SYNC_FAILED),
+    entry!(LOG_HANDLER_CRASH),
+    entry!(LOG_CONFIG_LOAD_FAILED),
+    entry!(LOG_RELOAD_FAILED),
+    entry!(LOG_EXPORT_FAILED),
+    entry!(LOG_IMPORT_FAILED),
+    entry!(PLT_UNSUPPORTED),
+    entry!(PLT_SYSCALL_FAILED),
+    entry!(PLT_PERMISSION_DENIED, default_http_status_for_category(PLT_PERMISSION_DENIED.category()), DEFAULT_SENSITIVE_SCOPE, FailureCategory::Authorization, Retryability::Permanent),
+    entry!(PLT_RESOURCE_EXHAUSTED, category = FailureCategory::DenialOfService),
+    entry!(PLT_OS_VERSION_MISMATCH),
+    entry!(PLT_HARDWARE_UNSUPPORTED),
+    entry!(PLT_DRIVER_LOAD_FAILED),
+    entry!(PLT_API_CALL_FAILED),
+    entry!(PLT_ENV_DETECT_FAILED),
+    entry!(PLT_VIRTUALIZATION_FAILED),
+    entry!(PLT_CONTAINER_INIT_FAILED),
+    entry!(PLT_KERNEL_MODULE_FAILED),
+    entry!(PLT_FILESYSTEM_MOUNT_FAILED),
+    entry!(PLT_NETWORK_INTERFACE_FAILED),
+    entry!(PLT_PROCESS_SPAWN_FAILED),
+    entry!(PLT_SIGNAL_SEND_FAILED),
+    entry!(PLT_MEMORY_MAP_FAILED),
+    entry!(PLT_THREAD_AFFINITY_FAILED),
+    entry!(PLT_POWER_MANAGEMENT_FAILED),
+    entry!(PLT_BOOTSTRAP_FAILED),
+    entry!(PLT_SHUTDOWN_HOOK_FAILED),
+    entry!(PLT_COMPATIBILITY_CHECK_FAILED),
+    entry!(PLT_LIBRARY_LOAD_FAILED),
+    entry!(PLT_SYMBOL_RESOLVE_FAILED),
+    entry!(PLT_SECURITY_POLICY_FAILED),
+    entry!(PLT_AUDIT_HOOK_FAILED),
+    entry!(PLT_RESOURCE_LIMIT_REACHED, category = FailureCategory::DenialOfService),
+    entry!(PLT_CLOCK_SYNC_FAILED),
+    entry!(PLT_DEVICE_ACCESS_FAILED),
+    entry!(PLT_FIRMWARE_UPDATE_FAILED),
+    entry!(PLT_BIOS_CONFIG_FAILED),
+    entry!(IO_READ_FAILED),
+    entry!(IO_WRITE_FAILED),
+    entry!(IO_NETWORK_ERROR),
+    entry!(IO_TIMEOUT),
+    entry!(IO_NOT_FOUND),
+    entry!(IO_METADATA_FAILED),
+    entry!(IO_OPEN_FAILED),
+    entry!(IO_CLOSE_FAILED),
+    entry!(IO_SEEK_FAILED),
+    entry!(IO_FLUSH_FAILED),
+    entry!(IO_PERMISSION_DENIED, default_http_status_for_category(IO_PERMISSION_DENIED.category()), DEFAULT_SENSITIVE_SCOPE, FailureCategory::Authorization, Retryability::Permanent),
+    entry!(IO_INTERRUPTED),
+    entry!(IO_WOULD_BLOCK),
+    entry!(IO_INVALID_INPUT),
+    entry!(IO_BROKEN_PIPE),
+    entry!(IO_CONNECTION_RESET),
+    entry!(IO_CONNECTION_REFUSED),
+    entry!(IO_NOT_CONNECTED),
+    entry!(IO_ADDR_IN_USE),
+    entry!(IO_ADDR_NOT_AVAILABLE),
+    entry!(IO_NETWORK_DOWN),
+    entry!(IO_NETWORK_UNREACHABLE),
+    entry!(IO_HOST_UNREACHABLE),
+    entry!(IO_ALREADY_EXISTS),
+    entry!(IO_IS_DIRECTORY),
+    entry!(IO_NOT_DIRECTORY),
+    entry!(IO_DIRECTORY_NOT_EMPTY),
+    entry!(IO_READ_ONLY_FS),
+    entry!(IO_FS_QUOTA_EXCEEDED),
+    entry!(IO_STALE_NFS_HANDLE),
+    entry!(IO_REMOTE_IO),
+];
+
+/// Why [`validate`] rejected the [`REGISTRY`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Two registry entries resolve to the same numeric code.
+    DuplicateCode {
+        /// The colliding code.
+        code: u16,
+        /// The first entry found carrying it.
+        first: &'static str,
+        /// The second entry found carrying it.
+        second: &'static str,
+    },
+    /// A registry entry's code doesn't fall inside the `ranges::BLOCKS`
+    /// band for the namespace its own [`ErrorCode`] declares.
+    OutOfBand {
+        /// The offending entry's name.
+        name: &'static str,
+        /// The code that doesn't fit its namespace's band.
+        code: u16,
+    },
+    /// A namespace's registered range is wider than the obfuscation
+    /// permutation domain can address, so some codes in that namespace
+    /// could never round-trip through [`crate::obfuscation`].
+    DomainTooNarrow {
+        /// The namespace whose range is too wide.
+        namespace: &'static str,
+        /// The namespace's registered width.
+        width: u32,
+        /// The obfuscation permutation domain.
+        domain: u32,
+    },
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DuplicateCode { code, first, second } => write!(
+                f,
+                "duplicate error code {code}: both {first} and {second} define it"
+            ),
+            Self::OutOfBand { name, code } => write!(
+                f,
+                "{name} = {code} falls outside its namespace's registered range"
+            ),
+            Self::DomainTooNarrow { namespace, width, domain } => write!(
+                f,
+                "namespace {namespace} spans {width} codes, wider than the obfuscation domain of {domain}"
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ValidationError {}
+
+#[cfg(all(feature = "no_std", feature = "core-error"))]
+impl core::error::Error for ValidationError {}
+
+/// Validate every invariant [`REGISTRY`] is supposed to uphold: no two
+/// entries share a code, every entry's code falls inside its own
+/// namespace's `ranges::BLOCKS` band, and (on `std` builds, where the
+/// obfuscation permutation exists) every namespace fits inside its domain.
+///
+/// Meant to be called once at startup or from a test, per the governance
+/// gap this closes: today nothing catches a duplicate code or an
+/// out-of-band code across *different* `define_error_codes!` blocks, only
+/// within a single block (see that macro's own compile-time checks).
+///
+/// # Errors
+///
+/// Returns the first [`ValidationError`] found; callers that want every
+/// violation at once should run this repeatedly after fixing prior drift,
+/// matching the file-by-file rhythm of the governance drift it catches.
+pub fn validate() -> Result<(), ValidationError> {
+    for (i, a) in REGISTRY.iter().enumerate() {
+        for b in &REGISTRY[i + 1..] {
+            if a.code.code() == b.code.code() {
+                return Err(ValidationError::DuplicateCode {
+                    code: a.code.code(),
+                    first: a.name,
+                    second: b.name,
+                });
+            }
+        }
+    }
+
+    for entry in REGISTRY {
+        let in_band = match ranges::RangeRegistry::block_for(entry.code.code()) {
+            Some(block) => block == entry.code.namespace().as_str(),
+            None => false,
+        };
+        if !in_band {
+            return Err(ValidationError::OutOfBand {
+                name: entry.name,
+                code: entry.code.code(),
+            });
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    for block in ranges::BLOCKS {
+        let width = u32::from(block.end) - u32::from(block.start) + 1;
+        if width > crate::obfuscation::OFFSET_RANGE {
+            return Err(ValidationError::DomainTooNarrow {
+                namespace: block.name,
+                width,
+                domain: crate::obfuscation::OFFSET_RANGE,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Look up the full [`ErrorDefinition`] behind a numeric `code` - category,
+/// default external template, and permanence - in one call instead of
+/// re-deriving them from a bare constant.
+pub fn describe(code: u16) -> Option<&'static ErrorDefinition> {
+    REGISTRY.iter().find(|entry| entry.code.code() == code)
+}
+
+/// Look up the full [`ErrorDefinition`] behind a stable [`ErrorDefinition::code_id`]
+/// (e.g. `"PAL_CFG_INVALID_VALUE"`), the reverse direction of [`describe`] -
+/// lets a client that only matched on the wire-safe string code resolve it
+/// back to the definition without re-deriving the `"PAL_"` prefix convention
+/// itself.
+pub fn lookup(code_id: &str) -> Option<&'static ErrorDefinition> {
+    REGISTRY.iter().find(|entry| entry.code_id == code_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "no_std")]
+    use alloc::string::ToString;
     use crate::ErrorImpact;
 
     /// Enforce that all defined error codes fall within their assigned namespace ranges.
@@ -529,4 +1552,241 @@ mod tests {
         assert_eq!(TEL_EVASION_DETECTED.impact_level(), ErrorImpact::Collapse);
         assert_eq!(CORE_MEMORY_ALLOC_FAILED.impact_level(), ErrorImpact::Leak);
     }
+
+    #[test]
+    fn range_registry_reports_no_overlap() {
+        assert!(ranges::RangeRegistry::is_valid());
+    }
+
+    #[test]
+    fn range_registry_traces_codes_to_their_subsystem() {
+        assert_eq!(ranges::RangeRegistry::block_for(CORE_INIT_FAILED.code()), Some("CORE"));
+        assert_eq!(ranges::RangeRegistry::block_for(DCP_DEPLOY_FAILED.code()), Some("DCP"));
+        assert_eq!(ranges::RangeRegistry::block_for(IO_REMOTE_IO.code()), Some("IO"));
+    }
+
+    #[test]
+    fn range_registry_returns_none_for_unregistered_code() {
+        // 900 is past the last registered block (IO ends at 899).
+        assert_eq!(ranges::RangeRegistry::block_for(900), None);
+    }
+
+    #[test]
+    fn range_registry_rejects_overlapping_blocks() {
+        use ranges::SubsystemRange;
+
+        const OVERLAPPING: &[SubsystemRange] = &[
+            SubsystemRange { name: "A", start: 1, end: 100 },
+            SubsystemRange { name: "B", start: 50, end: 150 },
+        ];
+
+        assert!(!ranges::blocks_are_valid(OVERLAPPING));
+    }
+
+    #[test]
+    fn try_from_code_resolves_codes_inside_a_block() {
+        assert_eq!(ranges::try_from_code(1), Ok("CORE"));
+        assert_eq!(ranges::try_from_code(830), Ok("IO"));
+    }
+
+    #[test]
+    fn try_from_code_names_highest_block_above_all_ranges() {
+        let err = ranges::try_from_code(900).unwrap_err();
+        assert_eq!(
+            err,
+            ranges::CodeRangeError::AboveHighest {
+                code: 900,
+                highest: ranges::SubsystemRange { name: "IO", start: ranges::IO_START, end: ranges::IO_END },
+            }
+        );
+        assert!(err.to_string().contains("IO"));
+    }
+
+    #[test]
+    fn try_from_code_names_lowest_block_below_all_ranges() {
+        let err = ranges::try_from_code(0).unwrap_err();
+        assert_eq!(
+            err,
+            ranges::CodeRangeError::BelowLowest {
+                code: 0,
+                lowest: ranges::SubsystemRange { name: "CORE", start: ranges::CORE_START, end: ranges::CORE_END },
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_code_names_both_neighbors_of_a_gap() {
+        use ranges::SubsystemRange;
+
+        const WITH_GAP: &[SubsystemRange] = &[
+            SubsystemRange { name: "A", start: 1, end: 10 },
+            SubsystemRange { name: "B", start: 20, end: 30 },
+        ];
+        assert!(ranges::blocks_are_valid(WITH_GAP));
+
+        // The live `ranges::BLOCKS` table has no gaps today, so exercise the
+        // gap-detection logic against this ad-hoc table instead.
+        let code = 15u32;
+        let before = WITH_GAP.iter().rev().find(|b| u32::from(b.end) < code).copied();
+        let after = WITH_GAP.iter().find(|b| u32::from(b.start) > code).copied();
+        assert_eq!(before, Some(SubsystemRange { name: "A", start: 1, end: 10 }));
+        assert_eq!(after, Some(SubsystemRange { name: "B", start: 20, end: 30 }));
+    }
+
+    #[test]
+    fn parse_code_rejects_non_numeric_input() {
+        assert!(matches!(
+            ranges::parse_code("not-a-number"),
+            Err(ranges::ParseCodeError::NotANumber(_))
+        ));
+    }
+
+    #[test]
+    fn parse_code_rejects_out_of_range_input() {
+        assert!(matches!(
+            ranges::parse_code("900"),
+            Err(ranges::ParseCodeError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn parse_code_resolves_valid_input() {
+        assert_eq!(ranges::parse_code("800"), Ok("IO"));
+    }
+
+    #[test]
+    fn registry_covers_every_defined_code() {
+        assert_eq!(REGISTRY.len(), 296);
+    }
+
+    #[test]
+    fn validate_accepts_the_live_registry() {
+        assert_eq!(validate(), Ok(()));
+    }
+
+    #[test]
+    fn describe_finds_a_known_code() {
+        let found = describe(CORE_INIT_FAILED.code()).expect("CORE_INIT_FAILED is registered");
+        assert_eq!(found.name, "CORE_INIT_FAILED");
+        assert_eq!(found.code.code(), CORE_INIT_FAILED.code());
+    }
+
+    #[test]
+    fn describe_returns_none_for_an_unregistered_code() {
+        assert!(describe(900).is_none());
+    }
+
+    #[test]
+    fn code_id_is_the_pal_prefixed_name() {
+        let found = describe(CORE_INIT_FAILED.code()).expect("CORE_INIT_FAILED is registered");
+        assert_eq!(found.code_id, "PAL_CORE_INIT_FAILED");
+    }
+
+    #[test]
+    fn http_status_defaults_from_category() {
+        let found = describe(CORE_INIT_FAILED.code()).expect("CORE_INIT_FAILED is registered");
+        assert_eq!(found.http_status, 500);
+
+        let found = describe(DCP_DEPLOY_FAILED.code()).is_some();
+        assert!(found);
+    }
+
+    #[test]
+    fn http_status_defaults_to_403_for_honeypot_categories() {
+        use crate::OperationCategory;
+        let found = REGISTRY
+            .iter()
+            .find(|entry| entry.code.category() == OperationCategory::Detection)
+            .expect("at least one Detection code is registered");
+        assert_eq!(found.http_status, 403);
+    }
+
+    #[test]
+    fn http_status_override_wins_over_category_default() {
+        let invalid_value = describe(CFG_INVALID_VALUE.code()).expect("CFG_INVALID_VALUE is registered");
+        assert_eq!(invalid_value.http_status, 400);
+
+        let security_violation =
+            describe(CFG_SECURITY_VIOLATION.code()).expect("CFG_SECURITY_VIOLATION is registered");
+        assert_eq!(security_violation.http_status, 403);
+    }
+
+    #[test]
+    fn lookup_resolves_a_known_code_id() {
+        let found = lookup("PAL_CFG_INVALID_VALUE").expect("PAL_CFG_INVALID_VALUE is registered");
+        assert_eq!(found.name, "CFG_INVALID_VALUE");
+    }
+
+    #[test]
+    fn lookup_round_trips_with_describe() {
+        let by_code = describe(CFG_SECURITY_VIOLATION.code()).expect("registered");
+        let by_id = lookup(by_code.code_id).expect("code_id round-trips");
+        assert_eq!(by_code.code.code(), by_id.code.code());
+    }
+
+    #[test]
+    fn lookup_returns_none_for_an_unregistered_code_id() {
+        assert!(lookup("PAL_DOES_NOT_EXIST").is_none());
+    }
+
+    #[test]
+    fn required_scope_defaults_to_the_crate_wide_default() {
+        let found = describe(CORE_INIT_FAILED.code()).expect("CORE_INIT_FAILED is registered");
+        assert_eq!(found.required_scope, DEFAULT_SENSITIVE_SCOPE);
+    }
+
+    #[test]
+    fn required_scope_override_wins_over_the_default() {
+        let found = describe(CFG_SECURITY_VIOLATION.code()).expect("CFG_SECURITY_VIOLATION is registered");
+        assert_eq!(found.required_scope, "log.sensitive.security");
+    }
+
+    #[test]
+    fn failure_category_defaults_from_the_operation_category() {
+        let found = describe(CORE_INIT_FAILED.code()).expect("CORE_INIT_FAILED is registered");
+        assert_eq!(
+            found.failure_category,
+            default_failure_category_for_operation_category(CORE_INIT_FAILED.category())
+        );
+    }
+
+    #[test]
+    fn failure_category_override_wins_over_the_default() {
+        let found = describe(CORE_CRYPTO_SETUP_FAILED.code()).expect("CORE_CRYPTO_SETUP_FAILED is registered");
+        assert_eq!(found.failure_category, FailureCategory::CryptoFailure);
+    }
+
+    #[test]
+    fn every_registry_entry_is_counted_under_exactly_its_own_failure_category() {
+        let total: usize = FailureCategory::ALL.iter().map(FailureCategory::count_in_registry).sum();
+        assert_eq!(total, REGISTRY.len());
+    }
+
+    #[test]
+    fn entries_in_registry_only_yields_matching_entries() {
+        for entry in FailureCategory::CryptoFailure.entries_in_registry() {
+            assert_eq!(entry.failure_category, FailureCategory::CryptoFailure);
+        }
+        assert!(FailureCategory::CryptoFailure
+            .entries_in_registry()
+            .any(|entry| entry.name == "CORE_CRYPTO_SETUP_FAILED"));
+    }
+
+    #[test]
+    fn retryability_defaults_to_transient() {
+        let found = describe(CORE_INIT_FAILED.code()).expect("CORE_INIT_FAILED is registered");
+        assert_eq!(found.retryability, Retryability::Transient);
+    }
+
+    #[test]
+    fn retryability_override_wins_over_the_default() {
+        let found = describe(CFG_PERMISSION_DENIED.code()).expect("CFG_PERMISSION_DENIED is registered");
+        assert_eq!(found.retryability, Retryability::Permanent);
+    }
+
+    #[test]
+    fn retry_after_override_carries_its_duration() {
+        let found = describe(RSP_RATE_LIMITED.code()).expect("RSP_RATE_LIMITED is registered");
+        assert_eq!(found.retryability, Retryability::RetryAfter(Duration::from_secs(30)));
+    }
 }
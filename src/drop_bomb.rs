@@ -0,0 +1,325 @@
+//! Postcondition-assurance "drop bomb" for sensitive [`InternalContext`]s,
+//! modeled on Arti's `DropBomb`: a guard that asserts, at drop time, that the
+//! value it wraps was actually handled rather than silently discarded.
+//!
+//! # Architecture
+//!
+//! [`ContextBomb`] wraps an [`InternalContext`] plus an armed/disarmed flag.
+//! [`ContextBomb::expose_sensitive`] and [`ContextBomb::into_inner`] disarm
+//! it as a side effect of legitimate use; [`ContextBomb::defuse`] (and its
+//! alias [`ContextBomb::acknowledge`]) disarm it explicitly when a caller
+//! decided the context doesn't need exposing after all. If the bomb is still
+//! armed when dropped, that's a bug: a `Sensitive` context went out of scope
+//! without ever being logged, exposed, or explicitly dismissed.
+//!
+//! [`DropBombCondition`] lets a caller arm the bomb only when a runtime
+//! predicate holds, rather than unconditionally - see
+//! [`DropBombCondition::OnlyForCategory`].
+//!
+//! # Security
+//!
+//! In debug builds, an armed drop panics immediately, the same "fail loudly
+//! in development" tradeoff the rest of this crate makes with
+//! `debug_assert!` on its builder setters. In release builds, where aborting
+//! a honeypot process over a logging bug would itself be a liability, it
+//! instead reports to the process-wide [`DropBombSink`] installed via
+//! [`register_drop_bomb_sink`] - the default sink writes to stderr rather
+//! than silently doing nothing, since a drop bomb that nobody can hear go
+//! off defeats its own purpose. Only the [`OperationCategory`] the context
+//! was created for crosses into the sink, never the sensitive content
+//! itself. A bomb dropped while the thread is already unwinding from another
+//! panic never panics a second time - see [`ContextBomb`]'s `Drop` impl.
+//!
+//! # Feature Gate
+//!
+//! Unavailable under `no_std`, which has no `OnceLock`/`RwLock` to host the
+//! process-wide sink on, and no stderr to write a default loud failure to.
+
+#[cfg(not(feature = "no_std"))]
+use crate::models::{InternalContext, OperationCategory, SocAccess};
+#[cfg(not(feature = "no_std"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(not(feature = "no_std"))]
+use std::sync::{OnceLock, RwLock};
+
+/// Runtime gate on whether a [`ContextBomb`] starts out armed.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DropBombCondition {
+    /// Always armed - the common case.
+    Always,
+    /// Armed only if the wrapped context was created for the given
+    /// [`OperationCategory`] - e.g. "this bomb only matters for `Detection`
+    /// contexts; a `Configuration` context slipping through unexposed isn't
+    /// worth panicking over."
+    OnlyForCategory(OperationCategory),
+}
+
+#[cfg(not(feature = "no_std"))]
+impl DropBombCondition {
+    fn holds(self, category: OperationCategory) -> bool {
+        match self {
+            DropBombCondition::Always => true,
+            DropBombCondition::OnlyForCategory(expected) => category == expected,
+        }
+    }
+}
+
+/// A postcondition guard around an [`InternalContext`] that panics (debug)
+/// or audits (release) if dropped while still armed.
+///
+/// # Disarming
+///
+/// - [`Self::expose_sensitive`] - disarms as a side effect of the normal
+///   exposure path.
+/// - [`Self::into_inner`] - disarms and hands the context back to the
+///   caller, e.g. to pass it on to a `DualContextError` constructor.
+/// - [`Self::defuse`] / [`Self::acknowledge`] - disarms explicitly, for a
+///   caller that inspected the context some other way (or decided on
+///   purpose that it doesn't need handling).
+#[must_use = "a ContextBomb does nothing until defused, exposed, or taken - \
+              dropping it while still armed panics (debug) or audits (release)"]
+#[cfg(not(feature = "no_std"))]
+pub struct ContextBomb {
+    context: Option<InternalContext>,
+    category: OperationCategory,
+    armed: AtomicBool,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ContextBomb {
+    /// Wrap a context, armed unconditionally.
+    #[inline]
+    pub fn new(context: InternalContext, category: OperationCategory) -> Self {
+        Self::conditional(context, category, DropBombCondition::Always)
+    }
+
+    /// Wrap a context, armed only if `condition` holds for `category`.
+    #[inline]
+    pub fn conditional(
+        context: InternalContext,
+        category: OperationCategory,
+        condition: DropBombCondition,
+    ) -> Self {
+        Self {
+            context: Some(context),
+            category,
+            armed: AtomicBool::new(condition.holds(category)),
+        }
+    }
+
+    /// Disarm without otherwise touching the wrapped context.
+    #[inline]
+    pub fn defuse(&self) {
+        self.armed.store(false, Ordering::SeqCst);
+    }
+
+    /// Alias for [`Self::defuse`], for call sites where "I looked at this
+    /// and it's fine" reads more naturally than "defuse".
+    #[inline]
+    pub fn acknowledge(&self) {
+        self.defuse();
+    }
+
+    /// Expose the wrapped context's sensitive content, disarming the bomb.
+    ///
+    /// Delegates to [`InternalContext::expose_sensitive`]; see its docs for
+    /// the `SocAccess` requirement and audit trail.
+    #[must_use]
+    #[inline]
+    pub fn expose_sensitive(&self, access: &SocAccess) -> Option<&str> {
+        self.defuse();
+        self.context.as_ref().and_then(|context| context.expose_sensitive(access))
+    }
+
+    /// Disarm and hand the wrapped context back to the caller.
+    #[inline]
+    pub fn into_inner(mut self) -> InternalContext {
+        self.defuse();
+        self.context
+            .take()
+            .expect("ContextBomb: context already taken")
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Drop for ContextBomb {
+    fn drop(&mut self) {
+        let Some(context) = self.context.take() else {
+            return;
+        };
+        if !self.armed.load(Ordering::SeqCst) {
+            return;
+        }
+        if cfg!(debug_assertions) && !std::thread::panicking() {
+            panic!(
+                "ContextBomb dropped while still armed ({:?}): sensitive InternalContext was \
+                 never exposed, taken, or explicitly defused/acknowledged",
+                self.category
+            );
+        }
+        drop_bomb_sink()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .on_armed_drop(self.category);
+        drop(context);
+    }
+}
+
+/// Compliance hook for an armed [`ContextBomb`] dropped in a release build,
+/// installed process-wide via [`register_drop_bomb_sink`].
+#[cfg(not(feature = "no_std"))]
+pub trait DropBombSink: Send + Sync {
+    /// Called from [`ContextBomb`]'s `Drop` when it was still armed and the
+    /// build isn't a debug build (or the thread is already unwinding from
+    /// another panic). Only the category crosses this boundary, never the
+    /// wrapped context's content.
+    fn on_armed_drop(&self, category: OperationCategory);
+}
+
+/// Default [`DropBombSink`], installed until [`register_drop_bomb_sink`] is
+/// called. Unlike [`crate::models::SocAccess`]'s default audit sink, this
+/// one is not a no-op: an armed bomb going off silently in production would
+/// defeat the entire point of the assurance, so the default writes a loud
+/// line to stderr.
+#[cfg(not(feature = "no_std"))]
+struct StderrDropBombSink;
+
+#[cfg(not(feature = "no_std"))]
+impl DropBombSink for StderrDropBombSink {
+    fn on_armed_drop(&self, category: OperationCategory) {
+        eprintln!(
+            "palisade_errors: ContextBomb dropped while still armed (category: {}); a sensitive \
+             InternalContext was never exposed, taken, or defused",
+            category.display_name()
+        );
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+static DROP_BOMB_SINK: OnceLock<RwLock<Box<dyn DropBombSink + Send + Sync>>> = OnceLock::new();
+
+#[cfg(not(feature = "no_std"))]
+fn drop_bomb_sink() -> &'static RwLock<Box<dyn DropBombSink + Send + Sync>> {
+    DROP_BOMB_SINK.get_or_init(|| RwLock::new(Box::new(StderrDropBombSink)))
+}
+
+/// Install the process-wide [`DropBombSink`] that future armed-and-dropped
+/// [`ContextBomb`]s report to in release builds. Replaces whatever sink was
+/// previously installed (the default writes to stderr).
+#[cfg(not(feature = "no_std"))]
+pub fn register_drop_bomb_sink(sink: Box<dyn DropBombSink + Send + Sync>) {
+    *drop_bomb_sink()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = sink;
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod tests {
+    use super::*;
+    use std::panic;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    /// Serializes tests that install a process-global `DropBombSink`, same
+    /// rationale as `models.rs`'s `UNHANDLED_ERROR_TEST_LOCK`.
+    static DROP_BOMB_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    #[should_panic(expected = "ContextBomb dropped while still armed")]
+    fn armed_drop_panics_in_debug() {
+        let bomb = ContextBomb::new(
+            InternalContext::sensitive("leaked token"),
+            OperationCategory::Detection,
+        );
+        drop(bomb);
+    }
+
+    #[test]
+    fn defused_drop_does_not_panic() {
+        let bomb = ContextBomb::new(
+            InternalContext::sensitive("leaked token"),
+            OperationCategory::Detection,
+        );
+        bomb.defuse();
+        drop(bomb);
+    }
+
+    #[test]
+    fn expose_sensitive_disarms_the_bomb() {
+        let bomb = ContextBomb::new(
+            InternalContext::sensitive("leaked token"),
+            OperationCategory::Detection,
+        );
+        let access = SocAccess::acquire();
+        assert_eq!(bomb.expose_sensitive(&access), Some("leaked token"));
+        drop(bomb);
+    }
+
+    #[test]
+    fn into_inner_disarms_and_returns_the_context() {
+        let bomb = ContextBomb::new(
+            InternalContext::sensitive("leaked token"),
+            OperationCategory::Detection,
+        );
+        let access = SocAccess::acquire();
+        let context = bomb.into_inner();
+        assert_eq!(context.expose_sensitive(&access), Some("leaked token"));
+    }
+
+    #[test]
+    fn conditional_bomb_stays_disarmed_when_condition_fails() {
+        let bomb = ContextBomb::conditional(
+            InternalContext::sensitive("leaked token"),
+            OperationCategory::Configuration,
+            DropBombCondition::OnlyForCategory(OperationCategory::Detection),
+        );
+        drop(bomb);
+    }
+
+    #[test]
+    fn conditional_bomb_arms_when_condition_holds() {
+        let bomb = ContextBomb::conditional(
+            InternalContext::sensitive("leaked token"),
+            OperationCategory::Detection,
+            DropBombCondition::OnlyForCategory(OperationCategory::Detection),
+        );
+        assert!(bomb.armed.load(Ordering::SeqCst));
+        // Defuse rather than drop armed - this test only checks the initial
+        // arming decision, not the panic-on-drop path covered elsewhere.
+        bomb.defuse();
+    }
+
+    #[test]
+    fn armed_drop_during_unwind_does_not_abort() {
+        // If `ContextBomb::drop` panicked again while the thread is already
+        // unwinding from the catch_unwind below, the process would abort
+        // instead of returning an `Err` - this confirms it reports via the
+        // sink path instead of double-panicking.
+        let _serialize = DROP_BOMB_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_clone = fired.clone();
+        register_drop_bomb_sink(Box::new(CountingSink(fired_clone)));
+
+        let result = panic::catch_unwind(|| {
+            let _bomb = ContextBomb::new(
+                InternalContext::sensitive("leaked token"),
+                OperationCategory::Detection,
+            );
+            panic!("unrelated failure while the bomb is still armed");
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        register_drop_bomb_sink(Box::new(StderrDropBombSink));
+    }
+
+    struct CountingSink(Arc<AtomicUsize>);
+
+    impl DropBombSink for CountingSink {
+        fn on_armed_drop(&self, _category: OperationCategory) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
@@ -0,0 +1,449 @@
+// src/escalation.rs
+//! Phased escalation engine driven by `ImpactScore`, modeled on a hardware
+//! alert-handler.
+//!
+//! # Design
+//!
+//! Every emitted [`ErrorCode`] carries an [`ErrorImpact`] band. This module
+//! treats that band as an alert *class* and, per class, maintains a sliding
+//! window of recent occurrences. When the window's count crosses a
+//! threshold the class advances through an ordered [`Phase`] sequence - the
+//! same shape as a hardware alert handler stepping a noisy sensor from
+//! "logged" to "paged" to "shut it down":
+//!
+//! - [`Phase::Logged`] - first phase, just recorded.
+//! - [`Phase::OperationalAlert`] - an operator should be notified.
+//! - [`Phase::StrategicReview`] - an analyst should look at the pattern.
+//! - [`Phase::PersonaHardReset`] - the emulated persona should be reset.
+//! - [`Phase::SandboxLockdown`] - terminal phase; contain the session.
+//!
+//! A single [`ErrorImpact::Breach`]-band code jumps straight to
+//! [`Phase::SandboxLockdown`] regardless of accumulated count - a breach
+//! doesn't need three strikes.
+//!
+//! Each phase carries a timeout: if the triggering condition for a class
+//! isn't cleared (via [`EscalationEngine::reset`]) before the timeout
+//! elapses, the engine auto-advances to the next phase on the next
+//! [`EscalationEngine::observe`] call, the way an unacknowledged hardware
+//! alert re-escalates instead of sitting at the same severity forever.
+//!
+//! # Invariant
+//!
+//! A class's phase only ever advances or is explicitly reset via
+//! [`EscalationEngine::reset`] - it never silently regresses.
+
+use crate::codes::{ErrorCode, ErrorImpact};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Escalation phase, ordered from least to most severe.
+///
+/// Ordering is meaningful: `Phase::OperationalAlert > Phase::Logged`, and
+/// [`EscalationEngine::observe`] relies on this ordering to enforce that a
+/// class's phase never regresses except through an explicit
+/// [`EscalationEngine::reset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    /// Occurrence recorded; no action taken yet.
+    Logged,
+    /// An operator should be notified.
+    OperationalAlert,
+    /// An analyst should review the accumulating pattern.
+    StrategicReview,
+    /// The emulated persona should be hard-reset.
+    PersonaHardReset,
+    /// Terminal phase: contain the session.
+    SandboxLockdown,
+}
+
+impl Phase {
+    /// The phase that follows this one, or `None` if already terminal.
+    pub const fn next(self) -> Option<Self> {
+        match self {
+            Self::Logged => Some(Self::OperationalAlert),
+            Self::OperationalAlert => Some(Self::StrategicReview),
+            Self::StrategicReview => Some(Self::PersonaHardReset),
+            Self::PersonaHardReset => Some(Self::SandboxLockdown),
+            Self::SandboxLockdown => None,
+        }
+    }
+
+    /// Whether this is the terminal phase.
+    pub const fn is_terminal(self) -> bool {
+        matches!(self, Self::SandboxLockdown)
+    }
+}
+
+/// Configuration for a single [`ErrorImpact`] class's escalation behavior.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::escalation::EscalationPolicy;
+/// use std::time::Duration;
+///
+/// // Three hits within sixty seconds starts escalating; unacknowledged
+/// // phases advance every thirty seconds.
+/// let policy = EscalationPolicy::new(3, Duration::from_secs(60), Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct EscalationPolicy {
+    threshold: u32,
+    window: Duration,
+    phase_timeout: Duration,
+}
+
+impl EscalationPolicy {
+    /// Build a policy: `threshold` occurrences within `window` advances the
+    /// phase; an unacknowledged phase auto-advances after `phase_timeout`.
+    pub const fn new(threshold: u32, window: Duration, phase_timeout: Duration) -> Self {
+        let threshold = if threshold == 0 { 1 } else { threshold };
+        Self {
+            threshold,
+            window,
+            phase_timeout,
+        }
+    }
+}
+
+impl Default for EscalationPolicy {
+    /// Five hits within sixty seconds, five-minute phase timeout.
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(60), Duration::from_secs(300))
+    }
+}
+
+/// A callback bound to phase transitions for one class.
+///
+/// Registered via [`EscalationEngine::on_transition`] and invoked with the
+/// class that transitioned, the phase it left, and the phase it entered.
+pub type TransitionHook = Box<dyn Fn(ErrorImpact, Phase, Phase) + Send + Sync>;
+
+/// Per-class sliding-window occurrence tracking plus current phase.
+struct ClassState {
+    /// Timestamps of occurrences still inside the window.
+    hits: Vec<Instant>,
+    phase: Phase,
+    /// When the current phase was entered - drives the auto-advance timeout.
+    entered_at: Instant,
+}
+
+impl ClassState {
+    fn new(now: Instant) -> Self {
+        Self {
+            hits: Vec::new(),
+            phase: Phase::Logged,
+            entered_at: now,
+        }
+    }
+}
+
+/// Phased escalation engine: classifies incoming [`ErrorCode`]s by
+/// [`ErrorImpact`] band and drives each class through an ordered [`Phase`]
+/// sequence, modeled on a hardware alert-handler.
+///
+/// Cheap to clone - internal state is `Arc`-shared, same convention as
+/// [`crate::ring_buffer::RingBufferLogger`].
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::escalation::{EscalationEngine, Phase};
+/// use palisade_errors::{ErrorCode, OperationCategory, ImpactScore, namespaces};
+///
+/// let engine = EscalationEngine::new();
+/// let code = ErrorCode::checked_new(
+///     &namespaces::IO,
+///     1,
+///     OperationCategory::IO,
+///     ImpactScore::new(960),
+/// ).unwrap();
+///
+/// // Impact 960 is Breach-band: jumps straight to the terminal phase.
+/// engine.observe(&code);
+/// assert_eq!(engine.current_phase(code.impact_level()), Phase::SandboxLockdown);
+/// ```
+pub struct EscalationEngine {
+    states: Arc<RwLock<HashMap<ErrorImpact, ClassState>>>,
+    policy: EscalationPolicy,
+    hooks: Arc<RwLock<Vec<TransitionHook>>>,
+}
+
+impl EscalationEngine {
+    /// Create an engine using [`EscalationPolicy::default`] for every class.
+    pub fn new() -> Self {
+        Self::with_policy(EscalationPolicy::default())
+    }
+
+    /// Create an engine applying the same `policy` to every class.
+    pub fn with_policy(policy: EscalationPolicy) -> Self {
+        Self {
+            states: Arc::new(RwLock::new(HashMap::new())),
+            policy,
+            hooks: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Register a hook invoked on every phase transition, across all classes.
+    ///
+    /// Hooks are called synchronously, in registration order, from inside
+    /// [`observe`](Self::observe) or [`reset`](Self::reset) - keep them fast
+    /// and non-reentrant with the engine.
+    pub fn on_transition<F>(&self, hook: F)
+    where
+        F: Fn(ErrorImpact, Phase, Phase) + Send + Sync + 'static,
+    {
+        let mut hooks = match self.hooks.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        hooks.push(Box::new(hook));
+    }
+
+    /// Record an occurrence of `code`, advancing its [`ErrorImpact`] class's
+    /// phase if the sliding-window threshold is crossed, any prior phase's
+    /// timeout has elapsed, or the code is Breach-band.
+    pub fn observe(&self, code: &ErrorCode) {
+        let class = code.impact_level();
+        let now = Instant::now();
+
+        let mut states = match self.states.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let state = states.entry(class).or_insert_with(|| ClassState::new(now));
+
+        if class == ErrorImpact::Breach {
+            self.advance_to(state, class, Phase::SandboxLockdown, now);
+            return;
+        }
+
+        state.hits.retain(|hit| now.duration_since(*hit) <= self.policy.window);
+        state.hits.push(now);
+
+        let timed_out = !state.phase.is_terminal()
+            && now.duration_since(state.entered_at) >= self.policy.phase_timeout;
+        let threshold_crossed = state.hits.len() as u32 >= self.policy.threshold;
+
+        if timed_out || threshold_crossed {
+            if let Some(next) = state.phase.next() {
+                state.hits.clear();
+                self.advance_to(state, class, next, now);
+            }
+        }
+    }
+
+    /// Move `state` to `next` and fire registered hooks. No-op if `next` is
+    /// not ahead of the current phase, preserving the never-regress
+    /// invariant even if callers construct an out-of-order `advance_to`.
+    fn advance_to(&self, state: &mut ClassState, class: ErrorImpact, next: Phase, now: Instant) {
+        if next <= state.phase {
+            return;
+        }
+        let previous = state.phase;
+        state.phase = next;
+        state.entered_at = now;
+
+        let hooks = match self.hooks.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        for hook in hooks.iter() {
+            hook(class, previous, next);
+        }
+    }
+
+    /// Current phase for `class`, or [`Phase::Logged`] if no occurrence has
+    /// been observed yet.
+    pub fn current_phase(&self, class: ErrorImpact) -> Phase {
+        let states = match self.states.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        states.get(&class).map(|s| s.phase).unwrap_or(Phase::Logged)
+    }
+
+    /// Explicitly reset `class` back to [`Phase::Logged`] and clear its
+    /// sliding window - the only legitimate way a phase moves backward.
+    pub fn reset(&self, class: ErrorImpact) {
+        let now = Instant::now();
+        let mut states = match self.states.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let Some(state) = states.get_mut(&class) else {
+            return;
+        };
+        let previous = state.phase;
+        state.phase = Phase::Logged;
+        state.entered_at = now;
+        state.hits.clear();
+
+        if previous != Phase::Logged {
+            let hooks = match self.hooks.read() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            for hook in hooks.iter() {
+                hook(class, previous, Phase::Logged);
+            }
+        }
+    }
+}
+
+impl Default for EscalationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for EscalationEngine {
+    fn clone(&self) -> Self {
+        Self {
+            states: Arc::clone(&self.states),
+            policy: self.policy,
+            hooks: Arc::clone(&self.hooks),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{namespaces, ImpactScore, OperationCategory};
+    use std::sync::Mutex;
+
+    fn code_with_impact(score: u16) -> ErrorCode {
+        ErrorCode::checked_new(&namespaces::IO, 1, OperationCategory::IO, ImpactScore::new(score))
+            .unwrap()
+    }
+
+    #[test]
+    fn fresh_class_starts_at_logged() {
+        let engine = EscalationEngine::new();
+        assert_eq!(engine.current_phase(ErrorImpact::Glitch), Phase::Logged);
+    }
+
+    #[test]
+    fn threshold_crossing_advances_one_phase() {
+        let policy = EscalationPolicy::new(3, Duration::from_secs(60), Duration::from_secs(3600));
+        let engine = EscalationEngine::with_policy(policy);
+        let code = code_with_impact(400); // Glitch band
+
+        engine.observe(&code);
+        engine.observe(&code);
+        assert_eq!(engine.current_phase(ErrorImpact::Glitch), Phase::Logged);
+
+        engine.observe(&code);
+        assert_eq!(engine.current_phase(ErrorImpact::Glitch), Phase::OperationalAlert);
+    }
+
+    #[test]
+    fn breach_band_jumps_straight_to_terminal_phase() {
+        let engine = EscalationEngine::new();
+        let code = code_with_impact(1000); // Breach band
+
+        engine.observe(&code);
+        assert_eq!(engine.current_phase(ErrorImpact::Breach), Phase::SandboxLockdown);
+    }
+
+    #[test]
+    fn phase_timeout_auto_advances_without_new_hits() {
+        let policy = EscalationPolicy::new(100, Duration::from_secs(3600), Duration::from_millis(10));
+        let engine = EscalationEngine::with_policy(policy);
+        let code = code_with_impact(400);
+
+        engine.observe(&code);
+        assert_eq!(engine.current_phase(ErrorImpact::Glitch), Phase::Logged);
+
+        std::thread::sleep(Duration::from_millis(20));
+        engine.observe(&code);
+        assert_eq!(engine.current_phase(ErrorImpact::Glitch), Phase::OperationalAlert);
+    }
+
+    #[test]
+    fn reset_returns_class_to_logged() {
+        let engine = EscalationEngine::new();
+        let code = code_with_impact(1000);
+
+        engine.observe(&code);
+        assert_eq!(engine.current_phase(ErrorImpact::Breach), Phase::SandboxLockdown);
+
+        engine.reset(ErrorImpact::Breach);
+        assert_eq!(engine.current_phase(ErrorImpact::Breach), Phase::Logged);
+    }
+
+    #[test]
+    fn phase_never_regresses_without_explicit_reset() {
+        let policy = EscalationPolicy::new(1, Duration::from_secs(3600), Duration::from_secs(3600));
+        let engine = EscalationEngine::with_policy(policy);
+        let code = code_with_impact(400);
+
+        engine.observe(&code);
+        let phase_after_first = engine.current_phase(ErrorImpact::Glitch);
+        assert_eq!(phase_after_first, Phase::OperationalAlert);
+
+        // Further sub-threshold hits within the (huge) timeout shouldn't move
+        // the phase backward or skip it around.
+        engine.observe(&code);
+        assert!(engine.current_phase(ErrorImpact::Glitch) >= phase_after_first);
+    }
+
+    #[test]
+    fn classes_escalate_independently() {
+        let policy = EscalationPolicy::new(1, Duration::from_secs(3600), Duration::from_secs(3600));
+        let engine = EscalationEngine::with_policy(policy);
+
+        engine.observe(&code_with_impact(400)); // Glitch
+        assert_eq!(engine.current_phase(ErrorImpact::Glitch), Phase::OperationalAlert);
+        assert_eq!(engine.current_phase(ErrorImpact::Noise), Phase::Logged);
+    }
+
+    #[test]
+    fn transition_hooks_fire_with_correct_from_and_to() {
+        let policy = EscalationPolicy::new(1, Duration::from_secs(3600), Duration::from_secs(3600));
+        let engine = EscalationEngine::with_policy(policy);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let seen_clone = Arc::clone(&seen);
+        engine.on_transition(move |class, from, to| {
+            seen_clone.lock().unwrap().push((class, from, to));
+        });
+
+        engine.observe(&code_with_impact(400));
+
+        let recorded = seen.lock().unwrap();
+        assert_eq!(
+            recorded.as_slice(),
+            &[(ErrorImpact::Glitch, Phase::Logged, Phase::OperationalAlert)]
+        );
+    }
+
+    #[test]
+    fn reset_on_untouched_class_is_a_noop() {
+        let engine = EscalationEngine::new();
+        engine.reset(ErrorImpact::Collapse);
+        assert_eq!(engine.current_phase(ErrorImpact::Collapse), Phase::Logged);
+    }
+
+    #[test]
+    fn engine_clone_shares_state() {
+        let policy = EscalationPolicy::new(1, Duration::from_secs(3600), Duration::from_secs(3600));
+        let engine = EscalationEngine::with_policy(policy);
+        let clone = engine.clone();
+
+        engine.observe(&code_with_impact(400));
+        assert_eq!(clone.current_phase(ErrorImpact::Glitch), Phase::OperationalAlert);
+    }
+
+    #[test]
+    fn phase_ordering_matches_severity() {
+        assert!(Phase::Logged < Phase::OperationalAlert);
+        assert!(Phase::OperationalAlert < Phase::StrategicReview);
+        assert!(Phase::StrategicReview < Phase::PersonaHardReset);
+        assert!(Phase::PersonaHardReset < Phase::SandboxLockdown);
+        assert!(Phase::SandboxLockdown.is_terminal());
+        assert!(Phase::SandboxLockdown.next().is_none());
+    }
+}
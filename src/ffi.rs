@@ -0,0 +1,302 @@
+//! Opt-in C-ABI bridge exposing [`PublicContext`]/[`InternalContext`] (and
+//! [`SocAccess`]) as opaque handles, so polyglot honeypot components written
+//! in C/C++ can produce and consume dual contexts without bypassing the
+//! trust boundary the rest of this crate enforces.
+//!
+//! # Architecture
+//!
+//! Follows the `ForeignOwnable`-style `into_foreign`/`from_foreign`/`borrow`
+//! pattern: each `palisade_*_lie`/`palisade_*_sensitive` constructor boxes a
+//! Rust value and hands the caller an opaque `*mut`; `palisade_public_as_str`
+//! and `palisade_internal_expose_sensitive` borrow through the pointer
+//! without taking ownership; `palisade_free_*` reconstitutes the `Box` and
+//! drops it. Every `into_foreign` (a constructor below) must be matched by
+//! exactly one `from_foreign` (the matching `palisade_free_*` call) -
+//! double-free and use-after-free are the caller's to avoid, same as any C
+//! API built this way.
+//!
+//! # Security
+//!
+//! The only function here that can yield sensitive bytes across the FFI
+//! boundary is [`palisade_internal_expose_sensitive`], and it requires a
+//! `*const SocAccess` handle exactly as [`InternalContext::expose_sensitive`]
+//! requires `&SocAccess` on the Rust side - there is no `palisade_*`
+//! function that returns internal or sensitive content without one.
+//! [`palisade_public_as_str`] never needs an access handle, since
+//! [`PublicContext`] never carries internal content in the first place.
+//! Its output is freed with the plain [`palisade_free_str`];
+//! [`palisade_internal_expose_sensitive`]'s output carries raw sensitive
+//! plaintext and must instead be freed with
+//! [`palisade_free_sensitive_str`], which zeroizes the bytes before
+//! releasing them - every other sensitive-holding type in this crate
+//! zeroizes on drop, and this FFI boundary is no exception.
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `ffi` feature, and unavailable under `no_std` (which
+//! has no `CString`/heap-allocation story to build this on), so the core
+//! path never takes a stance on C-ABI stability or symbol visibility.
+
+use crate::{InternalContext, PublicContext, SocAccess};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// Box `value` and hand ownership to the caller as an opaque pointer.
+///
+/// Paired with [`from_foreign`] - every pointer this returns must reach
+/// `from_foreign` exactly once, directly or via a `palisade_free_*` wrapper.
+fn into_foreign<T>(value: T) -> *mut T {
+    Box::into_raw(Box::new(value))
+}
+
+/// Reclaim ownership of a value previously handed out by [`into_foreign`].
+///
+/// # Safety
+///
+/// `ptr` must be null, or a pointer previously returned by [`into_foreign`]
+/// for this exact `T` that has not already been passed to `from_foreign`.
+unsafe fn from_foreign<T>(ptr: *mut T) -> Option<Box<T>> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(Box::from_raw(ptr))
+    }
+}
+
+/// Borrow through an opaque pointer without taking ownership.
+///
+/// # Safety
+///
+/// `ptr` must be null, or a live pointer previously returned by
+/// [`into_foreign`] for this exact `T` that has not yet been passed to
+/// [`from_foreign`].
+unsafe fn borrow<'a, T>(ptr: *const T) -> Option<&'a T> {
+    ptr.as_ref()
+}
+
+/// Read a C string into an owned `String`, lossily replacing any invalid
+/// UTF-8 - FFI callers are untrusted input, and this crate would rather
+/// record a lossy message than refuse to build a context at all.
+///
+/// # Safety
+///
+/// `ptr` must be null, or point to a valid, readable, nul-terminated C
+/// string.
+unsafe fn read_c_str(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+    }
+}
+
+/// Build a deceptive [`PublicContext`] from a C string.
+///
+/// # Safety
+///
+/// `message` must be null, or a valid, readable, nul-terminated C string.
+/// Returns null if `message` is null. The returned handle must eventually
+/// reach [`palisade_free_public`].
+#[no_mangle]
+pub unsafe extern "C" fn palisade_public_lie(message: *const c_char) -> *mut PublicContext {
+    match read_c_str(message) {
+        Some(text) => into_foreign(PublicContext::lie(text)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Build a sensitive [`InternalContext`] from a C string.
+///
+/// # Safety
+///
+/// `message` must be null, or a valid, readable, nul-terminated C string.
+/// Returns null if `message` is null. The returned handle must eventually
+/// reach [`palisade_free_internal`].
+#[no_mangle]
+pub unsafe extern "C" fn palisade_internal_sensitive(
+    message: *const c_char,
+) -> *mut InternalContext {
+    match read_c_str(message) {
+        Some(text) => into_foreign(InternalContext::sensitive(text)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Get the external-safe string from a [`PublicContext`] handle.
+///
+/// Public-safe only: this never requires a [`SocAccess`] handle, since
+/// [`PublicContext`] can never carry internal content.
+///
+/// # Safety
+///
+/// `handle` must be null, or a live pointer from [`palisade_public_lie`] not
+/// yet passed to [`palisade_free_public`]. Returns null if `handle` is null.
+/// The returned pointer must be freed with [`palisade_free_str`], and must
+/// not be used after `handle` is freed.
+#[no_mangle]
+pub unsafe extern "C" fn palisade_public_as_str(handle: *const PublicContext) -> *const c_char {
+    match borrow(handle) {
+        Some(context) => match CString::new(context.as_str()) {
+            Ok(owned) => owned.into_raw(),
+            Err(_) => ptr::null_mut(),
+        },
+        None => ptr::null_mut(),
+    }
+}
+
+/// Acquire a [`SocAccess`] capability for use with
+/// [`palisade_internal_expose_sensitive`].
+///
+/// Carries the same authorization contract as [`SocAccess::acquire`] on the
+/// Rust side: call only from contexts where sensitive data disclosure is
+/// already authorized.
+///
+/// # Safety
+///
+/// The returned handle must eventually reach [`palisade_free_soc_access`].
+#[no_mangle]
+pub unsafe extern "C" fn palisade_soc_access_acquire() -> *mut SocAccess {
+    into_foreign(SocAccess::acquire())
+}
+
+/// Get the raw sensitive content from an [`InternalContext`] handle, if any.
+///
+/// The one function in this module that can yield sensitive bytes across
+/// the FFI boundary - requires `access` to cross the boundary too, mirroring
+/// [`InternalContext::expose_sensitive`]'s `&SocAccess` requirement in Rust.
+///
+/// # Safety
+///
+/// `handle` and `access` must each be null, or live pointers from their
+/// respective constructors, not yet freed. Returns null if either pointer
+/// is null, if `handle` isn't a sensitive context, or if its content
+/// contains an embedded NUL byte that can't round-trip through a C string.
+/// The returned pointer carries raw sensitive plaintext and must be freed
+/// with [`palisade_free_sensitive_str`], not the plain [`palisade_free_str`].
+#[no_mangle]
+pub unsafe extern "C" fn palisade_internal_expose_sensitive(
+    handle: *const InternalContext,
+    access: *const SocAccess,
+) -> *const c_char {
+    let (Some(context), Some(access)) = (borrow(handle), borrow(access)) else {
+        return ptr::null_mut();
+    };
+    match context.expose_sensitive(access) {
+        Some(text) => match CString::new(text) {
+            Ok(owned) => owned.into_raw(),
+            Err(_) => ptr::null_mut(),
+        },
+        None => ptr::null_mut(),
+    }
+}
+
+/// Free a [`PublicContext`] handle from [`palisade_public_lie`].
+///
+/// Running this drops the `PublicContext`, which zeroizes its owned bytes
+/// via `ZeroizeOnDrop` exactly as a Rust-side drop would.
+///
+/// # Safety
+///
+/// `handle` must be null, or a pointer previously returned by
+/// [`palisade_public_lie`] not already freed. Each handle must be freed
+/// exactly once.
+#[no_mangle]
+pub unsafe extern "C" fn palisade_free_public(handle: *mut PublicContext) {
+    drop(from_foreign(handle));
+}
+
+/// Free an [`InternalContext`] handle from [`palisade_internal_sensitive`].
+///
+/// Running this drops the `InternalContext`, which zeroizes its owned bytes
+/// via `ZeroizeOnDrop` exactly as a Rust-side drop would.
+///
+/// # Safety
+///
+/// `handle` must be null, or a pointer previously returned by
+/// [`palisade_internal_sensitive`] not already freed. Each handle must be
+/// freed exactly once.
+#[no_mangle]
+pub unsafe extern "C" fn palisade_free_internal(handle: *mut InternalContext) {
+    drop(from_foreign(handle));
+}
+
+/// Free a [`SocAccess`] handle from [`palisade_soc_access_acquire`].
+///
+/// Running this drops the `SocAccess`, which reports its accumulated
+/// exposure count to the registered `AuditSink` exactly as a Rust-side drop
+/// would.
+///
+/// # Safety
+///
+/// `handle` must be null, or a pointer previously returned by
+/// [`palisade_soc_access_acquire`] not already freed. Each handle must be
+/// freed exactly once.
+#[no_mangle]
+pub unsafe extern "C" fn palisade_free_soc_access(handle: *mut SocAccess) {
+    drop(from_foreign(handle));
+}
+
+/// Free a string previously returned by [`palisade_public_as_str`].
+///
+/// Not for [`palisade_internal_expose_sensitive`]'s output - that carries
+/// raw sensitive plaintext and must be freed with
+/// [`palisade_free_sensitive_str`] instead, which zeroizes the bytes before
+/// releasing them. This function does not zeroize, matching
+/// [`palisade_public_as_str`]'s own never-sensitive content.
+///
+/// # Safety
+///
+/// `ptr` must be null, or a pointer previously returned by
+/// [`palisade_public_as_str`], not already freed. Each pointer must be
+/// freed exactly once, and never with `free()` directly - ownership must
+/// come back through `CString` so its length-prefixed layout is reclaimed
+/// correctly.
+#[no_mangle]
+pub unsafe extern "C" fn palisade_free_str(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+/// Best-effort volatile zero of `len` bytes starting at `ptr`, mirroring the
+/// same "prevent the compiler from eliding this as a dead store" hardening
+/// [`crate::models`]'s `InternalContextField::drop` applies on the Rust
+/// side before a sensitive string's buffer is freed.
+///
+/// # Safety
+///
+/// `ptr` must be valid for `len` writes of `u8`.
+unsafe fn volatile_zero_bytes(ptr: *mut u8, len: usize) {
+    for i in 0..len {
+        ptr::write_volatile(ptr.add(i), 0u8);
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Free a string previously returned by
+/// [`palisade_internal_expose_sensitive`], zeroizing its bytes before the
+/// underlying allocation is released.
+///
+/// Every other sensitive-holding type in this crate zeroizes on drop -
+/// [`palisade_free_str`] doesn't, since it also serves
+/// [`palisade_public_as_str`]'s never-sensitive output, so raw sensitive
+/// plaintext crossing the FFI boundary needs this dedicated free function
+/// instead, or it leaks into freed, unwiped heap memory.
+///
+/// # Safety
+///
+/// `ptr` must be null, or a pointer previously returned by
+/// [`palisade_internal_expose_sensitive`], not already freed. Each pointer
+/// must be freed exactly once, and never with `free()` directly - ownership
+/// must come back through `CString` so its length-prefixed layout is
+/// reclaimed correctly.
+#[no_mangle]
+pub unsafe extern "C" fn palisade_free_sensitive_str(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        let len = CStr::from_ptr(ptr).to_bytes().len();
+        volatile_zero_bytes(ptr.cast::<u8>(), len);
+        drop(CString::from_raw(ptr));
+    }
+}
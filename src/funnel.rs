@@ -0,0 +1,288 @@
+// src/funnel.rs
+//! Bounded, non-blocking-enqueue queue for deferring [`OwnedLog`] formatting
+//! off the hot path.
+//!
+//! # Purpose
+//!
+//! [`InternalLog`] is deliberately borrow-bound and must be consumed
+//! synchronously - fine for most call sites, but it rules out the deferred
+//! logging pattern used by embedded loggers like `cortex-m-funnel`, where
+//! producers push records into a ring buffer and a separate consumer
+//! formats/drains them later, off the hot path. [`LogFunnel`] is that ring
+//! buffer: any number of producer threads can [`LogFunnel::push`] an
+//! [`OwnedLog`] without ever blocking on a lock, and a single consumer
+//! thread later calls [`LogFunnel::drain`] to format/ship each entry with
+//! the existing [`InternalLog::write_to`] (or `log_kv`/`slog_kv`) machinery.
+//!
+//! # Design
+//!
+//! A bounded multi-producer queue in the style of Dmitry Vyukov's
+//! [MPMC bounded queue](https://www.1024cores.net/home/lock-free-algorithms/queues/bounded-mpmc-queue):
+//! each slot carries its own sequence number, so producers coordinate with a
+//! single `compare_exchange` on a shared cursor rather than a lock, and a
+//! full queue simply hands the entry back via `Err` instead of blocking or
+//! overwriting an undrained one. This module only uses one consumer at a
+//! time (`drain` takes `&self`, not `&mut self`, but is documented as
+//! single-consumer - see its docs), which is a correct restriction of the
+//! general MPMC algorithm, not a different one.
+//!
+//! # Security
+//!
+//! Every [`OwnedLog`] that `drain` removes is dropped at the end of its
+//! closure call unless the caller moves it elsewhere, so the zeroize-on-drop
+//! guarantee is preserved: an entry that sits in the funnel for a while
+//! before being drained is scrubbed the same as any other owned log, just
+//! later.
+//!
+//! # Feature Gate
+//!
+//! Unconditional (no feature flag): like [`crate::ring_buffer`] and
+//! [`crate::audit`], this needs `std`'s atomics-across-threads and heap
+//! allocation and is therefore outside this crate's `no_std` carve-out (see
+//! the crate-level `no_std` feature docs).
+
+use crate::logging::OwnedLog;
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Slot {
+    sequence: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<OwnedLog>>,
+}
+
+/// Bounded, lock-free-on-enqueue queue of [`OwnedLog`] entries.
+///
+/// # Capacity
+///
+/// Fixed at construction; `push` never grows the buffer. A full funnel
+/// rejects the push (returning the log back to the caller) rather than
+/// overwriting an entry that hasn't been drained yet, so a slow consumer
+/// cannot corrupt in-flight data - only cause producers to see `Err` and
+/// decide for themselves whether to drop, block, or fall back to
+/// synchronous formatting.
+///
+/// # Concurrency
+///
+/// `push` may be called concurrently from any number of producer threads
+/// without blocking. `drain` must only be called from one thread at a time
+/// (single-consumer); calling it concurrently from multiple threads would
+/// not corrupt memory, but could hand the same logical slot to two callers
+/// out of order. Wrap `drain`'s caller in its own synchronization if more
+/// than one consumer is needed.
+pub struct LogFunnel {
+    buffer: Box<[Slot]>,
+    capacity: usize,
+    enqueue_pos: AtomicUsize,
+    dequeue_pos: AtomicUsize,
+}
+
+impl LogFunnel {
+    /// Create a funnel holding up to `capacity` entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "LogFunnel capacity must be non-zero");
+
+        let buffer: Vec<Slot> = (0..capacity)
+            .map(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+
+        Self {
+            buffer: buffer.into_boxed_slice(),
+            capacity,
+            enqueue_pos: AtomicUsize::new(0),
+            dequeue_pos: AtomicUsize::new(0),
+        }
+    }
+
+    /// Enqueue `log` without blocking.
+    ///
+    /// Returns `Err(log)` - handing the entry back unchanged, boxed since
+    /// [`OwnedLog`] is too large to return by value without tripping
+    /// clippy's `result_large_err` - if the funnel is full, so the caller
+    /// can decide whether to drop it, retry, or fall back to formatting
+    /// synchronously.
+    pub fn push(&self, log: OwnedLog) -> Result<(), Box<OwnedLog>> {
+        let mut pos = self.enqueue_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.buffer[pos % self.capacity];
+            let seq = slot.sequence.load(Ordering::Acquire);
+            let diff = seq as isize - pos as isize;
+
+            if diff == 0 {
+                match self.enqueue_pos.compare_exchange_weak(
+                    pos,
+                    pos.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: winning the compare_exchange on `pos` is the
+                        // only way to claim this slot for this lap (the
+                        // `sequence == pos` check above proves it's empty),
+                        // so no other producer can write here concurrently,
+                        // and the consumer cannot read it until we publish
+                        // via the `Release` store below.
+                        unsafe {
+                            (*slot.value.get()).write(log);
+                        }
+                        slot.sequence.store(pos.wrapping_add(1), Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => pos = current,
+                }
+            } else if diff < 0 {
+                // Slot hasn't been drained since its previous lap: full.
+                return Err(Box::new(log));
+            } else {
+                pos = self.enqueue_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Drain every entry currently available, oldest first, calling `f` on
+    /// each before it drops.
+    ///
+    /// Single-consumer: see the struct-level concurrency note.
+    pub fn drain(&self, mut f: impl FnMut(OwnedLog)) {
+        loop {
+            let pos = self.dequeue_pos.load(Ordering::Relaxed);
+            let slot = &self.buffer[pos % self.capacity];
+            let seq = slot.sequence.load(Ordering::Acquire);
+            let diff = seq as isize - pos.wrapping_add(1) as isize;
+
+            if diff != 0 {
+                // Nothing new to drain.
+                break;
+            }
+
+            self.dequeue_pos.store(pos.wrapping_add(1), Ordering::Relaxed);
+            // SAFETY: `sequence == pos + 1` proves a producer published a
+            // value here via the `Release` store in `push` and it has not
+            // been taken since (the matching `Acquire` load above
+            // synchronizes with that store); we are the sole consumer
+            // (struct-level invariant), so no one else can read or free
+            // this slot concurrently.
+            let value = unsafe { (*slot.value.get()).assume_init_read() };
+            slot.sequence
+                .store(pos.wrapping_add(self.capacity), Ordering::Release);
+            f(value);
+        }
+    }
+}
+
+impl Drop for LogFunnel {
+    fn drop(&mut self) {
+        // Any entries still sitting in the funnel need their zeroize-on-drop
+        // to actually run; `MaybeUninit` does not drop its contents for us.
+        self.drain(drop);
+    }
+}
+
+// SAFETY: every access to a `Slot`'s `UnsafeCell` is gated by the atomic
+// `sequence` handshake in `push`/`drain` (Acquire/Release pairs establish
+// happens-before around each write/read), so `LogFunnel` upholds the same
+// aliasing and synchronization invariants as `Sync`/`Send` require even
+// though `Slot` itself contains an `UnsafeCell`.
+unsafe impl Send for LogFunnel {}
+unsafe impl Sync for LogFunnel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definitions::CFG_PARSE_FAILED;
+    use crate::AgentError;
+
+    fn sample_owned(details: &str) -> OwnedLog {
+        AgentError::config(CFG_PARSE_FAILED, "boot", details.to_string())
+            .internal_log()
+            .into_owned()
+    }
+
+    #[test]
+    fn push_then_drain_preserves_fifo_order() {
+        let funnel = LogFunnel::new(4);
+        funnel.push(sample_owned("first")).unwrap();
+        funnel.push(sample_owned("second")).unwrap();
+        funnel.push(sample_owned("third")).unwrap();
+
+        let mut seen = Vec::new();
+        funnel.drain(|log| {
+            let mut buf = String::new();
+            log.as_internal_log().write_to(&mut buf).unwrap();
+            seen.push(buf);
+        });
+
+        assert_eq!(seen.len(), 3);
+        assert!(seen[0].contains("first"));
+        assert!(seen[1].contains("second"));
+        assert!(seen[2].contains("third"));
+    }
+
+    #[test]
+    fn push_rejects_once_full_and_returns_the_log_back() {
+        let funnel = LogFunnel::new(2);
+        funnel.push(sample_owned("a")).unwrap();
+        funnel.push(sample_owned("b")).unwrap();
+
+        let rejected = funnel.push(sample_owned("c"));
+        assert!(rejected.is_err());
+    }
+
+    #[test]
+    fn drain_frees_slots_for_reuse() {
+        let funnel = LogFunnel::new(2);
+        funnel.push(sample_owned("a")).unwrap();
+        funnel.push(sample_owned("b")).unwrap();
+        assert!(funnel.push(sample_owned("c")).is_err());
+
+        funnel.drain(|_| {});
+
+        funnel.push(sample_owned("d")).unwrap();
+        funnel.push(sample_owned("e")).unwrap();
+        assert!(funnel.push(sample_owned("f")).is_err());
+    }
+
+    #[test]
+    fn drain_on_empty_funnel_calls_nothing() {
+        let funnel = LogFunnel::new(4);
+        let mut calls = 0;
+        funnel.drain(|_| calls += 1);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn concurrent_producers_never_lose_or_duplicate_entries() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let funnel = Arc::new(LogFunnel::new(64));
+        let mut handles = Vec::new();
+
+        for t in 0..8 {
+            let funnel = Arc::clone(&funnel);
+            handles.push(thread::spawn(move || {
+                let mut accepted = 0;
+                for i in 0..8 {
+                    if funnel.push(sample_owned(&format!("t{t}-{i}"))).is_ok() {
+                        accepted += 1;
+                    }
+                }
+                accepted
+            }));
+        }
+
+        let total_accepted: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(total_accepted, 64);
+
+        let mut drained = 0;
+        funnel.drain(|_| drained += 1);
+        assert_eq!(drained, 64);
+    }
+}
@@ -0,0 +1,579 @@
+//! Opt-in HTTP response mapping for `DualContextError` and `ContextChain`.
+//!
+//! # Architecture
+//!
+//! Follows Garage's `common_error.rs` / S3 `error.rs` pattern of turning a
+//! typed error into a status code plus a safe response body, rather than
+//! depending on any particular web framework. This module returns the status
+//! code and body as plain data (`HttpErrorResponse`); axum/actix/etc. users
+//! wrap that trivially into their own response type.
+//!
+//! Alongside the bare `status_code()`, this module also exposes a
+//! structured [`Reason`]/[`Status`] pair (`external_reason()`,
+//! `http_status()`, `status()`) for handlers that want to pick a status
+//! code consistent with a specific error's `public_lie`, rather than a
+//! blanket per-category default.
+//!
+//! When an error carries a [`crate::definitions`] code (e.g. via
+//! `config_err!`), [`DualContextError::status_code`] and
+//! [`DualContextError::to_http_body`] prefer that code's own
+//! [`crate::definitions::ErrorDefinition::http_status`]/`code_id` over the
+//! category-level default - a deterministic, per-definition status/code pair
+//! instead of every code in a category sharing one status.
+//!
+//! # Security
+//!
+//! `to_http_body()` only ever touches `external_message()` and the
+//! `correlation_id` public metadata key, so an HTTP response built from this
+//! module can never leak internal/sensitive context - the same trust
+//! boundary the rest of the crate maintains.
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `http` feature, so the core path never takes a stance
+//! on status code conventions that a given deployment might want to override.
+
+use crate::{ContextChain, DualContextError, OperationCategory};
+
+/// A framework-agnostic HTTP error response: a status code plus a body.
+///
+/// # Use Case
+///
+/// Deliberately not tied to any HTTP crate's response type. Callers on
+/// axum/actix/etc. construct their own response from these two fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpErrorResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response body (JSON, escaped the same way as `JsonEmitter`).
+    pub body: String,
+}
+
+fn push_escaped(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// A coarse, attacker-facing reason a [`DualContextError`] can surface - the
+/// shape a web handler needs to pick an HTTP status code without learning
+/// the true `OperationCategory`.
+///
+/// # Design
+///
+/// Deliberately narrower than `OperationCategory`: every variant has an
+/// obvious, conventional status code ([`Reason::http_code`]), and none of
+/// them reveal whether the request tripped a honeypot defense versus a
+/// genuine operational fault. `Reason::Routine` is the deliberate-deception
+/// case - it pairs with [`OperationCategory::deceptive_name`]'s "Routine
+/// Operation" framing and renders as a plain `200`, so a blocked attacker
+/// sees a normal-looking response instead of a status that would tip them
+/// off that something was detected.
+///
+/// # Consistency With `public_lie`
+///
+/// [`DualContextError::status_with`] lets a caller pick the `Reason` that
+/// matches whatever the public lie claims happened - a lie about an
+/// exhausted connection pool should resolve to `Reason::Unavailable` (503),
+/// not the generic `Reason::Internal` (500) default, so the lie holds up at
+/// the HTTP layer as well as in the message body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reason {
+    /// The caller is sending requests too fast.
+    RateLimited,
+    /// The service (or a claimed dependency of it) is temporarily down.
+    Unavailable,
+    /// The request itself was malformed or invalid.
+    BadRequest,
+    /// The caller isn't authorized for this operation.
+    Unauthorized,
+    /// An unspecified internal fault - the safe, uninformative default.
+    Internal,
+    /// Nothing worth mentioning happened; looks like routine success.
+    Routine,
+}
+
+impl Reason {
+    /// The conventional HTTP status code for this reason.
+    ///
+    /// Fixed, not configurable: a caller that wants a different number picks
+    /// a different `Reason` rather than this crate offering an independent
+    /// status-code override that could drift out of sync with the reason it
+    /// claims to describe.
+    #[inline]
+    pub const fn http_code(self) -> u16 {
+        match self {
+            Self::RateLimited => 429,
+            Self::Unavailable => 503,
+            Self::BadRequest => 400,
+            Self::Unauthorized => 401,
+            Self::Internal => 500,
+            Self::Routine => 200,
+        }
+    }
+}
+
+/// A [`Reason`] paired with its HTTP status code, as returned by
+/// [`DualContextError::status`].
+///
+/// # Use Case
+///
+/// Bundles the two values a web handler actually needs so it doesn't have to
+/// make two separate calls and assume they stay consistent with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Status {
+    /// HTTP status code, derived from `reason`.
+    pub http_code: u16,
+    /// The reason the status code was chosen.
+    pub reason: Reason,
+}
+
+/// Default `OperationCategory` → [`Reason`] mapping.
+///
+/// # Rationale
+///
+/// Mirrors the `Deception`/`Detection`/`Containment` → routine-looking split
+/// used by [`OperationCategory::deceptive_name`]: those three categories
+/// default to `Reason::Routine` (a plain `200`), everything else to the
+/// uninformative `Reason::Internal` (`500`). Deployments that want the
+/// reason to track the specific `public_lie` instead of the category should
+/// use [`DualContextError::status_with`].
+#[inline]
+const fn default_reason_for_category(category: OperationCategory) -> Reason {
+    match category {
+        OperationCategory::Deception | OperationCategory::Detection | OperationCategory::Containment => {
+            Reason::Routine
+        }
+        _ => Reason::Internal,
+    }
+}
+
+/// Default `OperationCategory` → HTTP status code mapping.
+///
+/// # Rationale
+///
+/// Every operational category maps to `500` (the honeypot's internal
+/// machinery failed, not the caller's request), except the honeypot-specific
+/// categories (`Deception`, `Detection`, `Containment`), which map to `403`
+/// since those represent a deliberate block rather than an internal fault.
+/// Deployments with their own taxonomy (e.g. a future `Auth` category → 401)
+/// should use [`DualContextError::status_code_with`] instead of relying on
+/// this default.
+///
+/// Delegates to [`crate::definitions::default_http_status_for_category`] so
+/// this mapping and [`crate::definitions::ErrorDefinition::http_status`]'s
+/// own default can't drift apart.
+#[inline]
+const fn default_status_for_category(category: OperationCategory) -> u16 {
+    crate::definitions::default_http_status_for_category(category)
+}
+
+impl DualContextError {
+    /// HTTP status code for this error.
+    ///
+    /// Prefers the [`crate::definitions::ErrorDefinition::http_status`] for
+    /// whatever code was attached via [`crate::ContextBuilder::code`] (e.g.
+    /// by `config_err!`); falls back to [`default_status_for_category`] when
+    /// no code was attached, or the attached code isn't in
+    /// [`crate::definitions::REGISTRY`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "http")] {
+    /// use palisade_errors::{DualContextError, OperationCategory};
+    ///
+    /// let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+    /// assert_eq!(err.status_code(), 500);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn status_code(&self) -> u16 {
+        self.definition()
+            .map(|def| def.http_status)
+            .unwrap_or_else(|| default_status_for_category(self.category()))
+    }
+
+    /// HTTP status code with a per-call override hook.
+    ///
+    /// # Use Case
+    ///
+    /// For deployments whose category taxonomy or routing conventions don't
+    /// match the built-in default (e.g. mapping `Configuration` to `400`
+    /// instead of `500`). Returning `None` from `override_fn` falls back to
+    /// [`Self::status_code`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "http")] {
+    /// use palisade_errors::{DualContextError, OperationCategory};
+    ///
+    /// let err = DualContextError::with_lie("Bad config", "Missing key", OperationCategory::Configuration);
+    /// let status = err.status_code_with(|category| {
+    ///     (category == OperationCategory::Configuration).then_some(400)
+    /// });
+    /// assert_eq!(status, 400);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn status_code_with(&self, override_fn: impl FnOnce(OperationCategory) -> Option<u16>) -> u16 {
+        override_fn(self.category()).unwrap_or_else(|| self.status_code())
+    }
+
+    /// The external [`Reason`] this error should surface, inferred from
+    /// [`OperationCategory`] via [`default_reason_for_category`].
+    ///
+    /// Never reveals the true category for the honeypot-specific ones - see
+    /// [`Reason`]'s docs.
+    #[inline]
+    pub fn external_reason(&self) -> Reason {
+        default_reason_for_category(self.category())
+    }
+
+    /// [`Self::external_reason`] with a per-call override hook, so the
+    /// reason can be picked to match this error's specific `public_lie`
+    /// rather than a blanket per-category default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "http")] {
+    /// use palisade_errors::{ContextBuilder, OperationCategory, Reason};
+    ///
+    /// let err = ContextBuilder::new()
+    ///     .public_lie("Database connection pool exhausted. Please try again later.")
+    ///     .internal_sensitive("SQL injection attempt")
+    ///     .category(OperationCategory::Deception)
+    ///     .build();
+    ///
+    /// let reason = err.external_reason_with(|category| {
+    ///     (category == OperationCategory::Deception).then_some(Reason::Unavailable)
+    /// });
+    /// assert_eq!(reason, Reason::Unavailable);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn external_reason_with(&self, override_fn: impl FnOnce(OperationCategory) -> Option<Reason>) -> Reason {
+        override_fn(self.category()).unwrap_or_else(|| self.external_reason())
+    }
+
+    /// HTTP status code for [`Self::external_reason`].
+    #[inline]
+    pub fn http_status(&self) -> u16 {
+        self.external_reason().http_code()
+    }
+
+    /// [`Self::http_status`] and [`Self::external_reason`], bundled together.
+    #[inline]
+    pub fn status(&self) -> Status {
+        let reason = self.external_reason();
+        Status {
+            http_code: reason.http_code(),
+            reason,
+        }
+    }
+
+    /// [`Self::status`] with a per-call [`Reason`] override hook - see
+    /// [`Self::external_reason_with`].
+    #[inline]
+    pub fn status_with(&self, override_fn: impl FnOnce(OperationCategory) -> Option<Reason>) -> Status {
+        let reason = self.external_reason_with(override_fn);
+        Status {
+            http_code: reason.http_code(),
+            reason,
+        }
+    }
+
+    /// Build the safe response body: `external_message()` plus a
+    /// `code` field (this error's attached [`crate::definitions::ErrorDefinition::code_id`],
+    /// if one resolves) and a `correlation_id` public metadata entry, if one
+    /// was attached.
+    pub fn to_http_body(&self) -> String {
+        let mut body = String::from("{\"message\":");
+        push_escaped(&mut body, self.external_message());
+
+        if let Some(definition) = self.definition() {
+            body.push_str(",\"code\":");
+            push_escaped(&mut body, definition.code_id);
+        }
+
+        if let Some((_, correlation_id)) = self
+            .public_metadata()
+            .find(|(key, _)| *key == "correlation_id")
+        {
+            body.push_str(",\"correlation_id\":");
+            push_escaped(&mut body, correlation_id);
+        }
+
+        body.push('}');
+        body
+    }
+
+    /// Build the full `HttpErrorResponse` (status + body) for this error -
+    /// the status and `code` field are deterministic per attached
+    /// [`crate::definitions::ErrorDefinition`], so a `config_err!(...)`
+    /// result can be returned directly from a web handler. See
+    /// [`Self::serialize_external`] for just the body, without a status.
+    pub fn to_http_response(&self) -> HttpErrorResponse {
+        HttpErrorResponse {
+            status: self.status_code(),
+            body: self.to_http_body(),
+        }
+    }
+
+    /// Alias for [`Self::to_http_body`], for call sites serializing this
+    /// error onto a wire that isn't HTTP (a message queue, say), where
+    /// "serialize the external view" reads more naturally than "http body".
+    #[inline]
+    pub fn serialize_external(&self) -> String {
+        self.to_http_body()
+    }
+}
+
+impl ContextChain {
+    /// HTTP status code for the chain, derived from [`Self::head`] - the
+    /// final, outward-facing error is what the caller actually received.
+    #[inline]
+    pub fn status_code(&self) -> u16 {
+        self.head().status_code()
+    }
+
+    /// Build an `HttpErrorResponse` from the chain's head error.
+    pub fn to_http_response(&self) -> HttpErrorResponse {
+        self.head().to_http_response()
+    }
+
+    /// Like [`Self::to_http_response`], but the body surfaces the whole
+    /// chain's public narrative (`external_summary()`) instead of just the
+    /// head's message - useful when a SOC-facing client wants to see the
+    /// causal chain without needing `SocAccess`.
+    pub fn to_http_response_with_chain_summary(&self) -> HttpErrorResponse {
+        let mut body = String::from("{\"message\":");
+        push_escaped(&mut body, &self.external_summary());
+        body.push('}');
+
+        HttpErrorResponse {
+            status: self.status_code(),
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ContextBuilder;
+
+    #[test]
+    fn default_status_is_500_for_operational_categories() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        assert_eq!(err.status_code(), 500);
+    }
+
+    #[test]
+    fn default_status_is_403_for_honeypot_categories() {
+        let err = DualContextError::with_lie(
+            "Access denied",
+            "Intrusion blocked",
+            OperationCategory::Detection,
+        );
+        assert_eq!(err.status_code(), 403);
+    }
+
+    #[test]
+    fn status_code_with_override_takes_precedence() {
+        let err = DualContextError::with_lie(
+            "Bad config",
+            "Missing key",
+            OperationCategory::Configuration,
+        );
+        let status = err.status_code_with(|category| (category == OperationCategory::Configuration).then_some(400));
+        assert_eq!(status, 400);
+    }
+
+    #[test]
+    fn status_code_with_falls_back_on_none() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        let status = err.status_code_with(|_| None);
+        assert_eq!(status, 500);
+    }
+
+    #[test]
+    fn http_body_contains_only_public_message_and_correlation_id() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .public_metadata("correlation_id", "req-42")
+            .build();
+
+        let body = err.to_http_body();
+        assert!(body.contains("Operation failed"));
+        assert!(body.contains("req-42"));
+        assert!(!body.contains("hunter2"));
+    }
+
+    #[test]
+    fn http_body_omits_correlation_id_when_absent() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        let body = err.to_http_body();
+        assert!(!body.contains("correlation_id"));
+    }
+
+    #[test]
+    fn default_reason_is_internal_for_operational_categories() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        assert_eq!(err.external_reason(), Reason::Internal);
+        assert_eq!(err.http_status(), 500);
+    }
+
+    #[test]
+    fn default_reason_is_routine_for_honeypot_categories() {
+        let err = DualContextError::with_lie(
+            "Access denied",
+            "Intrusion blocked",
+            OperationCategory::Detection,
+        );
+        assert_eq!(err.external_reason(), Reason::Routine);
+        assert_eq!(err.http_status(), 200);
+    }
+
+    #[test]
+    fn external_reason_with_override_takes_precedence() {
+        let err = ContextBuilder::new()
+            .public_lie("Database connection pool exhausted. Please try again later.")
+            .internal_sensitive("SQL injection attempt")
+            .category(OperationCategory::Deception)
+            .build();
+
+        let reason = err.external_reason_with(|category| {
+            (category == OperationCategory::Deception).then_some(Reason::Unavailable)
+        });
+        assert_eq!(reason, Reason::Unavailable);
+    }
+
+    #[test]
+    fn external_reason_with_falls_back_on_none() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        assert_eq!(err.external_reason_with(|_| None), Reason::Internal);
+    }
+
+    #[test]
+    fn status_bundles_http_code_and_reason() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        let status = err.status();
+        assert_eq!(status.reason, Reason::Internal);
+        assert_eq!(status.http_code, 500);
+    }
+
+    #[test]
+    fn status_with_applies_override_to_both_fields() {
+        let err = DualContextError::with_lie("Rate limited", "Too many login attempts", OperationCategory::Analysis);
+        let status = err.status_with(|category| {
+            (category == OperationCategory::Analysis).then_some(Reason::RateLimited)
+        });
+        assert_eq!(status.reason, Reason::RateLimited);
+        assert_eq!(status.http_code, 429);
+    }
+
+    #[test]
+    fn chain_status_code_uses_head() {
+        let root = DualContextError::with_lie("Database error", "Connection refused", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+        chain.push(DualContextError::with_lie(
+            "Access denied",
+            "Blocked",
+            OperationCategory::Detection,
+        ));
+
+        assert_eq!(chain.status_code(), 403);
+    }
+
+    #[test]
+    fn status_code_prefers_the_attached_definition_over_the_category_default() {
+        let err = ContextBuilder::new()
+            .public_lie("Invalid request")
+            .internal_diagnostic("bad value for key 'retry_budget'")
+            .category(OperationCategory::Configuration)
+            .code(&crate::definitions::CFG_INVALID_VALUE)
+            .build();
+
+        // Configuration's category default is 500; the definition overrides it to 400.
+        assert_eq!(err.status_code(), 400);
+    }
+
+    #[test]
+    fn status_code_falls_back_to_category_when_no_definition_is_attached() {
+        let err = DualContextError::with_lie("Bad config", "Missing key", OperationCategory::Configuration);
+        assert_eq!(err.status_code(), 500);
+    }
+
+    #[test]
+    fn http_body_contains_the_attached_definitions_stable_code_id() {
+        let err = ContextBuilder::new()
+            .public_lie("Access denied")
+            .internal_diagnostic("disallowed secrets path")
+            .category(OperationCategory::Configuration)
+            .code(&crate::definitions::CFG_SECURITY_VIOLATION)
+            .build();
+
+        let body = err.to_http_body();
+        assert!(body.contains("\"code\":\"PAL_CFG_SECURITY_VIOLATION\""));
+        assert!(body.contains("Access denied"));
+    }
+
+    #[test]
+    fn http_body_omits_code_when_no_definition_is_attached() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        assert!(!err.to_http_body().contains("\"code\":"));
+    }
+
+    #[test]
+    fn serialize_external_is_an_alias_for_to_http_body() {
+        let err = DualContextError::with_lie("Operation failed", "Disk full", OperationCategory::IO);
+        assert_eq!(err.serialize_external(), err.to_http_body());
+    }
+
+    #[test]
+    fn to_http_response_status_and_code_round_trip_through_definitions_lookup() {
+        let err = ContextBuilder::new()
+            .public_lie("Invalid request")
+            .internal_diagnostic("bad value")
+            .category(OperationCategory::Configuration)
+            .code(&crate::definitions::CFG_INVALID_VALUE)
+            .build();
+
+        let response = err.to_http_response();
+        assert_eq!(response.status, 400);
+
+        let definition =
+            crate::definitions::lookup("PAL_CFG_INVALID_VALUE").expect("code_id is registered");
+        assert_eq!(definition.http_status, response.status);
+    }
+
+    #[test]
+    fn chain_response_with_summary_contains_every_link() {
+        let root = DualContextError::with_lie("Database error", "Connection refused", OperationCategory::IO);
+        let mut chain = ContextChain::new(root);
+        chain.push(DualContextError::with_lie(
+            "Retry failed",
+            "Max retries exceeded",
+            OperationCategory::System,
+        ));
+
+        let response = chain.to_http_response_with_chain_summary();
+        assert!(response.body.contains("Database error"));
+        assert!(response.body.contains("Retry failed"));
+    }
+}
@@ -0,0 +1,469 @@
+//! Tamper-evident signing for `DualContextError`'s internal context.
+//!
+//! # Purpose
+//!
+//! Once an error's internal context crosses a trust boundary - serialized
+//! into a log shipper, stored in a SIEM, read back hours later by a SOC
+//! analyst - nothing stops a compromised intermediary from silently editing
+//! it. This module adds an HMAC-SHA256 integrity tag over the internal
+//! content so tampering in transit becomes detectable rather than invisible.
+//!
+//! # What Is Signed
+//!
+//! The canonical encoding covers:
+//! - The internal context's content (sensitive/diagnostic/lie text)
+//! - The `OperationCategory`
+//! - The `ContextMetadata` pairs, sorted by key for determinism
+//!
+//! The public "lie" is deliberately excluded: it is expected to be
+//! attacker-visible, so signing it protects nothing that isn't already
+//! assumed adversarial.
+//!
+//! # Design
+//!
+//! Implemented as a small self-contained SHA-256 + HMAC rather than pulling
+//! in a crypto crate, keeping this module alloc-compatible (same `no_std`
+//! posture as the rest of `models.rs`/`context.rs`). This is a standard,
+//! unmodified HMAC-SHA256 construction (RFC 2104 / FIPS 180-4) - not a
+//! crate-specific cipher.
+//!
+//! # Security Model
+//!
+//! `SigningKey` material is zeroized on drop, and tag comparison runs in
+//! constant time. As with the rest of this crate's "not cryptographic"
+//! capability tokens, the guarantee here is narrower than it may look:
+//! `verify()` proves the content matches what was signed *with this key* - it
+//! is only as strong as how the key itself is protected and distributed.
+
+use crate::{ContextMetadata, InternalContext, InternalPayload, OperationCategory};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::vec::Vec;
+use zeroize::Zeroize;
+
+// ============================================================================
+// SHA-256 (FIPS 180-4)
+// ============================================================================
+
+const SHA256_H0: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Compute the SHA-256 digest of `data`.
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut h = SHA256_H0;
+
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    let mut msg = Vec::with_capacity(data.len() + 72);
+    msg.extend_from_slice(data);
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&bit_len.to_be_bytes());
+
+    for chunk in msg.chunks_exact(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in w.iter_mut().take(16).enumerate() {
+            *word = u32::from_be_bytes([
+                chunk[4 * i],
+                chunk[4 * i + 1],
+                chunk[4 * i + 2],
+                chunk[4 * i + 3],
+            ]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh) =
+            (h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7]);
+
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ ((!e) & g);
+            let temp1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA256_K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+        h[5] = h[5].wrapping_add(f);
+        h[6] = h[6].wrapping_add(g);
+        h[7] = h[7].wrapping_add(hh);
+    }
+
+    let mut out = [0u8; 32];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
+// ============================================================================
+// HMAC-SHA256 (RFC 2104)
+// ============================================================================
+
+const HMAC_BLOCK_SIZE: usize = 64;
+
+/// `pub(crate)` (rather than private) so [`crate::logging`] can reuse this
+/// construction to key its redaction tokens, instead of duplicating an HMAC
+/// implementation or pulling in a crypto crate for that one use.
+pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut key_block = [0u8; HMAC_BLOCK_SIZE];
+    if key.len() > HMAC_BLOCK_SIZE {
+        let hashed = sha256(key);
+        key_block[..32].copy_from_slice(&hashed);
+    } else {
+        key_block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0x36u8; HMAC_BLOCK_SIZE];
+    let mut opad = [0x5cu8; HMAC_BLOCK_SIZE];
+    for i in 0..HMAC_BLOCK_SIZE {
+        ipad[i] ^= key_block[i];
+        opad[i] ^= key_block[i];
+    }
+
+    let mut inner = Vec::with_capacity(HMAC_BLOCK_SIZE + message.len());
+    inner.extend_from_slice(&ipad);
+    inner.extend_from_slice(message);
+    let inner_hash = sha256(&inner);
+
+    let mut outer = Vec::with_capacity(HMAC_BLOCK_SIZE + 32);
+    outer.extend_from_slice(&opad);
+    outer.extend_from_slice(&inner_hash);
+    sha256(&outer)
+}
+
+// ============================================================================
+// Signing Key
+// ============================================================================
+
+/// Symmetric key used to sign and verify a `DualContextError`'s internal
+/// context via HMAC-SHA256.
+///
+/// # No Clone Policy
+///
+/// Matches the crate's general policy for sensitive material: single-owner,
+/// zeroized on drop, never duplicated across memory.
+pub struct SigningKey(Vec<u8>);
+
+impl SigningKey {
+    /// Wrap raw key bytes for use with `ContextBuilder::sign_with()` and
+    /// `DualContextError::verify()`.
+    #[inline]
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+        Self(bytes.into())
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+// ============================================================================
+// Integrity Tag
+// ============================================================================
+
+/// HMAC-SHA256 tag over a `DualContextError`'s internal content, category,
+/// and metadata.
+///
+/// # Clone/Copy
+///
+/// A tag is 32 bytes of opaque digest output, not sensitive material, so
+/// unlike `SigningKey` it is safe to copy freely.
+#[derive(Clone, Copy)]
+pub struct IntegrityTag([u8; 32]);
+
+impl IntegrityTag {
+    /// Compare two tags in constant time with respect to their bytes, so a
+    /// caller probing with guessed tags cannot learn anything from timing.
+    #[inline]
+    pub(crate) fn constant_time_eq(&self, other: &Self) -> bool {
+        let mut diff = 0u8;
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+
+impl fmt::Debug for IntegrityTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IntegrityTag(")?;
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        f.write_str(")")
+    }
+}
+
+// ============================================================================
+// Verification Errors
+// ============================================================================
+
+/// Why `DualContextError::verify()` failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// The error was never signed (`ContextBuilder::sign_with()` was not called).
+    Unsigned,
+    /// The recomputed tag does not match the stored one: the internal
+    /// content, category, or metadata was altered after signing, or the
+    /// wrong key was used to verify.
+    Tampered,
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned => f.write_str("error was never signed"),
+            Self::Tampered => f.write_str("integrity tag mismatch - content may have been tampered with"),
+        }
+    }
+}
+
+#[cfg(feature = "no_std")]
+impl core::error::Error for IntegrityError {}
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for IntegrityError {}
+
+// ============================================================================
+// Canonical Encoding + Tag Computation
+// ============================================================================
+
+/// Build the canonical byte encoding that gets signed: internal content,
+/// then category, then metadata pairs sorted by key. Length-prefixed with a
+/// type tag and `0xFF` separators so no field can be confused for another by
+/// concatenation alone.
+fn canonical_bytes(
+    internal: &InternalContext,
+    category: OperationCategory,
+    metadata: &ContextMetadata,
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    match internal.payload() {
+        Some(InternalPayload::Truth(text)) => {
+            buf.push(0u8);
+            buf.extend_from_slice(text.as_bytes());
+        }
+        Some(InternalPayload::Lie(text)) => {
+            buf.push(1u8);
+            buf.extend_from_slice(text.as_bytes());
+        }
+        // Tagged identically to the `None` branch below so the canonical
+        // encoding - and thus the signature - never depends on whether a
+        // ForensicMode guard happens to be live when this runs.
+        Some(InternalPayload::Sensitive(text)) => {
+            buf.push(2u8);
+            buf.extend_from_slice(text.as_bytes());
+        }
+        // `payload()` returns `None` for `Sensitive`/`SensitiveAt` outside
+        // `ForensicMode` - but the tag still has to cover the real content,
+        // so this reads it via `signing_bytes()` rather than
+        // `expose_sensitive()`: every sign/verify call would otherwise fire
+        // a `SocAccess` audit-trail entry, drowning genuine SOC exposures in
+        // automatic bookkeeping noise. See `signing_bytes()`'s doc comment.
+        None => {
+            buf.push(2u8);
+            buf.extend_from_slice(internal.signing_bytes().as_bytes());
+        }
+    }
+    buf.push(0xFF);
+
+    buf.extend_from_slice(category.display_name().as_bytes());
+    buf.push(0xFF);
+
+    let mut pairs: Vec<(&str, &str)> = metadata.iter().collect();
+    pairs.sort_unstable_by_key(|(key, _)| *key);
+    for (key, value) in pairs {
+        buf.extend_from_slice(key.as_bytes());
+        buf.push(0x00);
+        buf.extend_from_slice(value.as_bytes());
+        buf.push(0xFF);
+    }
+
+    buf
+}
+
+/// Compute the integrity tag for a given internal context, category, and
+/// metadata set under `key`.
+pub(crate) fn compute_tag(
+    internal: &InternalContext,
+    category: OperationCategory,
+    metadata: &ContextMetadata,
+    key: &SigningKey,
+) -> IntegrityTag {
+    let bytes = canonical_bytes(internal, category, metadata);
+    IntegrityTag(hmac_sha256(key.as_bytes(), &bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_known_vectors() {
+        assert_eq!(
+            sha256(b""),
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55,
+            ]
+        );
+        assert_eq!(
+            sha256(b"abc"),
+            [
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad,
+            ]
+        );
+    }
+
+    #[test]
+    fn hmac_sha256_known_vector() {
+        // RFC 4231 test case 1.
+        let key = [0x0bu8; 20];
+        let data = b"Hi There";
+        assert_eq!(
+            hmac_sha256(&key, data),
+            [
+                0xb0, 0x34, 0x4c, 0x61, 0xd8, 0xdb, 0x38, 0x53, 0x5c, 0xa8, 0xaf, 0xce, 0xaf, 0x0b,
+                0xf1, 0x2b, 0x88, 0x1d, 0xc2, 0x00, 0xc9, 0x83, 0x3d, 0xa7, 0x26, 0xe9, 0x37, 0x6c,
+                0x2e, 0x32, 0xcf, 0xf7,
+            ]
+        );
+    }
+
+    #[test]
+    fn same_key_and_content_produce_same_tag() {
+        let internal = InternalContext::diagnostic("database timeout");
+        let metadata = ContextMetadata::new();
+        let key = SigningKey::new(b"test-key".to_vec());
+
+        let tag1 = compute_tag(&internal, OperationCategory::IO, &metadata, &key);
+        let tag2 = compute_tag(&internal, OperationCategory::IO, &metadata, &key);
+
+        assert!(tag1.constant_time_eq(&tag2));
+    }
+
+    #[test]
+    fn different_content_produces_different_tag() {
+        let metadata = ContextMetadata::new();
+        let key = SigningKey::new(b"test-key".to_vec());
+
+        let tag1 = compute_tag(
+            &InternalContext::diagnostic("database timeout"),
+            OperationCategory::IO,
+            &metadata,
+            &key,
+        );
+        let tag2 = compute_tag(
+            &InternalContext::diagnostic("database timeout!"),
+            OperationCategory::IO,
+            &metadata,
+            &key,
+        );
+
+        assert!(!tag1.constant_time_eq(&tag2));
+    }
+
+    #[test]
+    fn different_key_produces_different_tag() {
+        let internal = InternalContext::diagnostic("database timeout");
+        let metadata = ContextMetadata::new();
+
+        let tag1 = compute_tag(
+            &internal,
+            OperationCategory::IO,
+            &metadata,
+            &SigningKey::new(b"key-a".to_vec()),
+        );
+        let tag2 = compute_tag(
+            &internal,
+            OperationCategory::IO,
+            &metadata,
+            &SigningKey::new(b"key-b".to_vec()),
+        );
+
+        assert!(!tag1.constant_time_eq(&tag2));
+    }
+
+    #[test]
+    fn metadata_order_does_not_affect_tag() {
+        let internal = InternalContext::diagnostic("database timeout");
+        let key = SigningKey::new(b"test-key".to_vec());
+
+        let mut metadata_a = ContextMetadata::new();
+        metadata_a.add("a", "1", crate::MetadataTrust::Internal);
+        metadata_a.add("b", "2", crate::MetadataTrust::Internal);
+
+        let mut metadata_b = ContextMetadata::new();
+        metadata_b.add("b", "2", crate::MetadataTrust::Internal);
+        metadata_b.add("a", "1", crate::MetadataTrust::Internal);
+
+        let tag_a = compute_tag(&internal, OperationCategory::IO, &metadata_a, &key);
+        let tag_b = compute_tag(&internal, OperationCategory::IO, &metadata_b, &key);
+
+        assert!(tag_a.constant_time_eq(&tag_b));
+    }
+}
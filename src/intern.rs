@@ -0,0 +1,201 @@
+//! Hash-keyed string interning for repeated operation/detail text.
+//!
+//! ## Use Case
+//!
+//! [`crate::ring_buffer::RingBufferLogger`] turns every logged error's
+//! operation and details into an owned `Arc<str>` so a
+//! [`crate::ring_buffer::ForensicEntry`] can outlive the `AgentError` it was
+//! built from. In practice those strings repeat heavily - a honeypot under a
+//! brute-force burst logs the same operation name and the same templated
+//! detail message thousands of times in a row - so allocating a fresh `Arc`
+//! backing buffer per entry wastes memory and allocator traffic that an
+//! interning table can avoid entirely on a repeat.
+//!
+//! ## Design
+//!
+//! [`InternTable`] hashes the incoming string and probes a map guarded by a
+//! single `RwLock`: a hash hit (confirmed with a full string compare, since
+//! the hash itself is not collision-proof) clones the existing `Arc<str>`
+//! handle instead of allocating. A miss allocates once and inserts. Because
+//! every hit returns the *same* `Arc` allocation, two entries with identical
+//! text are also pointer-equal, which lets a caller like
+//! [`crate::ring_buffer::RingBufferLogger::get_filtered`] group identical
+//! errors with `Arc::ptr_eq` instead of a full string comparison.
+//!
+//! The table is capped at [`DEFAULT_CAPACITY`] entries with FIFO-style
+//! eviction of the oldest insertion once full, so an attacker who floods
+//! logging with unique detail strings can't grow it without bound - the
+//! worst case degrades to "every call misses and allocates", not unbounded
+//! memory growth.
+//!
+//! This module only intercepts the already-allocating `Arc<str>` conversion
+//! done when building a [`crate::ring_buffer::ForensicEntry`]. The
+//! zero-allocation `&'static str` path through `AgentError` construction
+//! (its internal `SmallString::Borrowed` case) is untouched - there is
+//! nothing to dedup there, since it never allocates in the first place.
+
+use std::collections::VecDeque;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Maximum distinct strings an [`InternTable`] caches before evicting the
+/// oldest insertion to make room - bounds memory under attacker-controlled
+/// detail strings that would otherwise never repeat.
+const DEFAULT_CAPACITY: usize = 4096;
+
+struct InternTableInner {
+    entries: HashMap<u64, Arc<str>>,
+    /// Insertion order, oldest first, for FIFO eviction once `entries` is full.
+    order: VecDeque<u64>,
+}
+
+/// Bounded, thread-safe string interning table.
+///
+/// See the [module docs](self) for the motivating use case and design.
+pub(crate) struct InternTable {
+    inner: RwLock<InternTableInner>,
+    capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl InternTable {
+    /// Create a table with the default capacity.
+    pub(crate) fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            inner: RwLock::new(InternTableInner {
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Return a shared handle for `s`, reusing an existing allocation on a
+    /// hit or allocating once on a miss.
+    pub(crate) fn intern(&self, s: &str) -> Arc<str> {
+        let key = hash_str(s);
+
+        {
+            let inner = match self.inner.read() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            if let Some(existing) = inner.entries.get(&key) {
+                if existing.as_ref() == s {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    return Arc::clone(existing);
+                }
+            }
+        }
+
+        let mut inner = match self.inner.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        // Another thread may have inserted the same string while we were
+        // between the read lock above and this write lock - re-check before
+        // allocating.
+        if let Some(existing) = inner.entries.get(&key) {
+            if existing.as_ref() == s {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Arc::clone(existing);
+            }
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let arc: Arc<str> = Arc::from(s);
+        if inner.entries.len() >= self.capacity {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.entries.remove(&oldest);
+            }
+        }
+        inner.entries.insert(key, Arc::clone(&arc));
+        inner.order.push_back(key);
+        arc
+    }
+
+    /// Fraction of `intern()` calls so far that reused an existing
+    /// allocation instead of making a new one, in `[0.0, 1.0]`. `0.0` with no
+    /// calls yet.
+    pub(crate) fn dedup_ratio(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed) as f64;
+        let misses = self.misses.load(Ordering::Relaxed) as f64;
+        if hits + misses == 0.0 {
+            0.0
+        } else {
+            hits / (hits + misses)
+        }
+    }
+}
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_strings_share_the_same_allocation() {
+        let table = InternTable::new();
+        let a = table.intern("operation_42");
+        let b = table.intern("operation_42");
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn distinct_strings_get_distinct_allocations() {
+        let table = InternTable::new();
+        let a = table.intern("operation_42");
+        let b = table.intern("operation_43");
+        assert!(!Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn dedup_ratio_reflects_hit_rate() {
+        let table = InternTable::new();
+        assert_eq!(table.dedup_ratio(), 0.0);
+
+        table.intern("a");
+        table.intern("a");
+        table.intern("b");
+
+        // 1 hit ("a" the second time) out of 3 calls.
+        assert!((table.dedup_ratio() - (1.0 / 3.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn eviction_bounds_table_size() {
+        let table = InternTable::with_capacity(4);
+        for i in 0..100 {
+            table.intern(&format!("unique_{}", i));
+        }
+        let inner = table.inner.read().unwrap();
+        assert!(inner.entries.len() <= 4);
+    }
+
+    #[test]
+    fn hash_collision_does_not_return_wrong_string() {
+        // Same hash bucket behavior is exercised indirectly: interning two
+        // different strings must never alias the same Arc's contents.
+        let table = InternTable::new();
+        let a = table.intern("one");
+        let b = table.intern("two");
+        assert_eq!(a.as_ref(), "one");
+        assert_eq!(b.as_ref(), "two");
+    }
+}
@@ -0,0 +1,599 @@
+// src/journal.rs
+//! Append-only forensic journal with crash recovery, for [`RingBufferLogger`]
+//! deployments that can't afford to lose a buffer to a crash or OOM-kill.
+//!
+//! # Why This Exists
+//!
+//! [`RingBufferLogger`] is memory-only by design (see its own module docs) -
+//! that's what makes it safe to log thousands of attacker-triggered errors
+//! per second without the allocator in the loop. But a honeypot that gets
+//! crashed or OOM-killed by the very attacker it's observing loses every
+//! entry it hadn't exported yet, which is exactly the data a post-incident
+//! review needs most. [`PersistentRingBufferLogger`] wraps a
+//! `RingBufferLogger` and mirrors every [`Self::log`] call to an append-only
+//! file on disk, so a restart can reconstruct the buffer instead of starting
+//! empty.
+//!
+//! # Record Format
+//!
+//! Each journaled record is a self-describing frame:
+//!
+//! ```text
+//! [body_len: u32 BE][body][crc32(body): u32 BE]
+//! ```
+//!
+//! `body` holds a fixed field order - `timestamp`, `code`, `operation`,
+//! `details`, `source_ip`, a `retryable` flag, then a metadata count followed
+//! by that many key/value pairs, each length-prefixed the same way
+//! [`crate::seal`] frames its own plaintext. This is intentionally a
+//! narrower field set than [`ForensicEntry`] itself (no `last_seen`, `count`,
+//! `trace_id`, `code_raw` or `size_bytes`) - those are either derivable
+//! (`size_bytes`) or only meaningful to a live, in-process buffer
+//! (aggregation bookkeeping); see [`Self::log`] for how a replayed entry
+//! fills them back in.
+//!
+//! # Segments And Rotation
+//!
+//! Records are appended to a numbered segment file
+//! (`segment-00000000000000000000.journal`, then `...0001`, ...). Once the
+//! current segment exceeds `target_segment_bytes`, [`Self::log`] rotates to a
+//! fresh one and prunes segments older than whatever's needed to cover
+//! `max_entries` records, so disk usage stays bounded the same way the
+//! in-memory ring does.
+//!
+//! # Recovery
+//!
+//! [`Self::open`] scans existing segments newest-to-oldest, replaying valid
+//! records into a fresh [`RingBufferLogger`] until it has `max_entries` of
+//! them (or runs out of segments). A segment is read front-to-back; the
+//! first record that fails its CRC (or is too short to even hold a length
+//! prefix) ends that segment's contribution. This is *expected*, not an
+//! error - a crash mid-`log()` leaves a torn tail on whichever segment was
+//! open, and recovery's whole job is to take everything before that tail and
+//! move on rather than discarding the segment entirely.
+//!
+//! # Durability Knob
+//!
+//! `fsync` after every record would bound the honeypot's throughput to disk
+//! latency, which is exactly the kind of thing a volumetric attack can
+//! exploit. `bytes_per_sync` trades durability for throughput the same way a
+//! database's WAL does: `Some(n)` flushes once `n` bytes have accumulated
+//! since the last sync, `None` disables syncing (the OS page cache still
+//! protects against a process crash, just not a power loss or kernel panic).
+
+use crate::AgentError;
+use crate::ring_buffer::{ForensicEntry, RingBufferLogger};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write as IoWrite};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const SEGMENT_PREFIX: &str = "segment-";
+const SEGMENT_SUFFIX: &str = ".journal";
+const SEGMENT_INDEX_WIDTH: usize = 20;
+
+/// Standard CRC-32 (IEEE 802.3 / zlib polynomial `0xEDB88320`, reflected).
+///
+/// Hand-rolled rather than pulled in as a dependency, the same way
+/// [`crate::integrity`] hand-rolls SHA-256 and [`crate::seal`] hand-rolls
+/// ChaCha20-Poly1305 - there is no `Cargo.toml` entry point to add one to in
+/// the first place.
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut byte = 0usize;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+static CRC32_TABLE: [u32; 256] = crc32_table();
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        let index = ((crc ^ byte as u32) & 0xFF) as usize;
+        crc = (crc >> 8) ^ CRC32_TABLE[index];
+    }
+    !crc
+}
+
+/// Failure to open, read, or recover a [`PersistentRingBufferLogger`]'s
+/// journal directory.
+#[derive(Debug)]
+pub enum JournalError {
+    /// `dir` doesn't exist - this logger only journals into an existing
+    /// directory, it doesn't provision the filesystem layout it lives in
+    /// (mirrors [`crate::sink::SinkError::MissingDirectory`]).
+    MissingDirectory { path: String },
+    /// A segment file couldn't be created, opened, read, or written.
+    Io { path: String, source: io::Error },
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDirectory { path } => {
+                write!(f, "cannot journal to {path}: parent directory does not exist")
+            }
+            Self::Io { path, source } => write!(f, "journal I/O failed on {path}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+fn segment_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join(format!("{SEGMENT_PREFIX}{index:0width$}{SEGMENT_SUFFIX}", width = SEGMENT_INDEX_WIDTH))
+}
+
+fn segment_index_from_name(name: &str) -> Option<u64> {
+    let middle = name.strip_prefix(SEGMENT_PREFIX)?.strip_suffix(SEGMENT_SUFFIX)?;
+    middle.parse().ok()
+}
+
+/// Segment indices present in `dir`, ascending (oldest first).
+fn list_segments(dir: &Path) -> Result<Vec<u64>, JournalError> {
+    let entries = fs::read_dir(dir).map_err(|source| JournalError::Io {
+        path: dir.display().to_string(),
+        source,
+    })?;
+    let mut indices = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|source| JournalError::Io {
+            path: dir.display().to_string(),
+            source,
+        })?;
+        if let Some(index) = entry.file_name().to_str().and_then(segment_index_from_name) {
+            indices.push(index);
+        }
+    }
+    indices.sort_unstable();
+    Ok(indices)
+}
+
+fn write_len_prefixed(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn encode_record(entry: &ForensicEntry) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&entry.timestamp.to_be_bytes());
+    write_len_prefixed(&mut body, entry.code.as_bytes());
+    write_len_prefixed(&mut body, entry.operation.as_bytes());
+    write_len_prefixed(&mut body, entry.details.as_bytes());
+    write_len_prefixed(&mut body, entry.source_ip.as_bytes());
+    body.push(entry.retryable as u8);
+    body.extend_from_slice(&(entry.metadata.len() as u32).to_be_bytes());
+    for (key, value) in entry.metadata.iter() {
+        write_len_prefixed(&mut body, key.as_bytes());
+        write_len_prefixed(&mut body, value.as_bytes());
+    }
+    body
+}
+
+fn read_bytes<'b>(buf: &'b [u8], cursor: &mut usize, len: usize) -> Option<&'b [u8]> {
+    let end = cursor.checked_add(len)?;
+    if end > buf.len() {
+        return None;
+    }
+    let slice = &buf[*cursor..end];
+    *cursor = end;
+    Some(slice)
+}
+
+fn read_u32(buf: &[u8], cursor: &mut usize) -> Option<u32> {
+    Some(u32::from_be_bytes(read_bytes(buf, cursor, 4)?.try_into().ok()?))
+}
+
+fn read_u64(buf: &[u8], cursor: &mut usize) -> Option<u64> {
+    Some(u64::from_be_bytes(read_bytes(buf, cursor, 8)?.try_into().ok()?))
+}
+
+fn read_string(buf: &[u8], cursor: &mut usize) -> Option<String> {
+    let len = read_u32(buf, cursor)? as usize;
+    let bytes = read_bytes(buf, cursor, len)?;
+    core::str::from_utf8(bytes).ok().map(str::to_string)
+}
+
+/// Decode one record body (already CRC-checked) into a [`ForensicEntry`].
+///
+/// Fields the journal doesn't carry are filled in honestly rather than
+/// guessed: `last_seen` mirrors `timestamp` and `count` is `1`, since the
+/// journal records one occurrence per `log()` call the same way a
+/// non-aggregating [`RingBufferLogger`] does; `trace_id` comes back empty,
+/// since no correlation ID was journaled; `code_raw` is set equal to `code`
+/// rather than re-deobfuscated, since deobfuscation depends on a session
+/// salt that may not even exist in the recovering process - reporting the
+/// same string the honeypot observed live is more honest than guessing at a
+/// salt that's gone; `size_bytes` is recomputed from the decoded fields.
+fn decode_record(body: &[u8]) -> Option<ForensicEntry> {
+    let mut cursor = 0usize;
+    let timestamp = read_u64(body, &mut cursor)?;
+    let code = read_string(body, &mut cursor)?;
+    let operation = read_string(body, &mut cursor)?;
+    let details = read_string(body, &mut cursor)?;
+    let source_ip = read_string(body, &mut cursor)?;
+    let retryable = *read_bytes(body, &mut cursor, 1)?.first()? != 0;
+    let metadata_count = read_u32(body, &mut cursor)? as usize;
+    let mut metadata = Vec::with_capacity(metadata_count.min(4096));
+    for _ in 0..metadata_count {
+        let key = read_string(body, &mut cursor)?;
+        let value = read_string(body, &mut cursor)?;
+        metadata.push((std::sync::Arc::from(key.as_str()), std::sync::Arc::from(value.as_str())));
+    }
+
+    let size_bytes = code.len()
+        + operation.len()
+        + details.len()
+        + source_ip.len()
+        + metadata.iter().map(|(k, v): &(std::sync::Arc<str>, std::sync::Arc<str>)| k.len() + v.len()).sum::<usize>();
+
+    Some(ForensicEntry {
+        timestamp,
+        last_seen: timestamp,
+        count: 1,
+        code_raw: std::sync::Arc::from(code.as_str()),
+        code: std::sync::Arc::from(code.as_str()),
+        trace_id: std::sync::Arc::from(""),
+        operation: std::sync::Arc::from(operation.as_str()),
+        details: std::sync::Arc::from(details.as_str()),
+        source_ip: std::sync::Arc::from(source_ip.as_str()),
+        metadata: metadata.into_boxed_slice().into(),
+        size_bytes,
+        retryable,
+    })
+}
+
+/// Read every valid record out of `path`, stopping (without error) at the
+/// first frame that's too short or fails its CRC - a torn tail from a crash
+/// mid-write, which is expected and not a recovery failure.
+fn scan_segment(path: &Path) -> Result<Vec<ForensicEntry>, JournalError> {
+    let mut bytes = Vec::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_end(&mut bytes))
+        .map_err(|source| JournalError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+    let mut entries = Vec::new();
+    let mut cursor = 0usize;
+    loop {
+        let Some(body_len) = read_u32(&bytes, &mut cursor) else { break };
+        let Some(body) = read_bytes(&bytes, &mut cursor, body_len as usize) else { break };
+        let Some(stored_crc) = read_u32(&bytes, &mut cursor) else { break };
+        if crc32(body) != stored_crc {
+            break;
+        }
+        match decode_record(body) {
+            Some(entry) => entries.push(entry),
+            None => break,
+        }
+    }
+    Ok(entries)
+}
+
+/// Count of valid (CRC-checked) records in `path`, for retention accounting -
+/// cheaper than [`scan_segment`] when only the count is needed.
+fn count_segment_records(path: &Path) -> Result<usize, JournalError> {
+    Ok(scan_segment(path)?.len())
+}
+
+/// Replay the last `max_entries` valid records across `segments` (ascending
+/// indices), newest segment first, oldest-first in the returned order.
+fn recover(dir: &Path, segments: &[u64], max_entries: usize) -> Result<Vec<ForensicEntry>, JournalError> {
+    let mut per_segment = Vec::new();
+    let mut collected = 0usize;
+    for &index in segments.iter().rev() {
+        let entries = scan_segment(&segment_path(dir, index))?;
+        collected += entries.len();
+        per_segment.push(entries);
+        if collected >= max_entries {
+            break;
+        }
+    }
+    per_segment.reverse();
+    let mut all: Vec<ForensicEntry> = per_segment.into_iter().flatten().collect();
+    if all.len() > max_entries {
+        all.drain(0..all.len() - max_entries);
+    }
+    Ok(all)
+}
+
+struct SegmentWriter {
+    file: File,
+    index: u64,
+    bytes_written: usize,
+    bytes_since_sync: usize,
+}
+
+fn open_segment(dir: &Path, index: u64) -> Result<SegmentWriter, JournalError> {
+    let path = segment_path(dir, index);
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|source| JournalError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+    Ok(SegmentWriter {
+        file,
+        index,
+        bytes_written: 0,
+        bytes_since_sync: 0,
+    })
+}
+
+/// Wraps a [`RingBufferLogger`] with a crash-recoverable on-disk journal.
+///
+/// See the module docs for the record format, rotation policy, recovery
+/// procedure, and the `bytes_per_sync` durability knob.
+pub struct PersistentRingBufferLogger {
+    inner: RingBufferLogger,
+    dir: PathBuf,
+    max_entries: usize,
+    target_segment_bytes: usize,
+    bytes_per_sync: Option<usize>,
+    writer: Mutex<SegmentWriter>,
+}
+
+impl PersistentRingBufferLogger {
+    /// Open (recovering from, if present) a journal rooted at `dir`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - directory holding segment files; must already exist (this
+    ///   type journals into a directory, it doesn't provision one).
+    /// * `max_entries` / `max_entry_bytes` - as [`RingBufferLogger::new`].
+    /// * `target_segment_bytes` - rotate to a new segment once the current
+    ///   one reaches this size.
+    /// * `bytes_per_sync` - `fsync` after this many bytes have been written
+    ///   since the last sync; `None` disables syncing entirely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError::MissingDirectory`] if `dir` doesn't exist, or
+    /// [`JournalError::Io`] if an existing segment can't be read or a new one
+    /// can't be created.
+    pub fn open(
+        dir: impl AsRef<Path>,
+        max_entries: usize,
+        max_entry_bytes: usize,
+        target_segment_bytes: usize,
+        bytes_per_sync: Option<usize>,
+    ) -> Result<Self, JournalError> {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            return Err(JournalError::MissingDirectory {
+                path: dir.display().to_string(),
+            });
+        }
+
+        let max_entries = max_entries.max(1);
+        let inner = RingBufferLogger::new(max_entries, max_entry_bytes);
+
+        let segments = list_segments(dir)?;
+        for entry in recover(dir, &segments, max_entries)? {
+            inner.replay_insert(entry);
+        }
+
+        let next_index = segments.last().map_or(0, |last| last + 1);
+        let writer = open_segment(dir, next_index)?;
+
+        Ok(Self {
+            inner,
+            dir: dir.to_path_buf(),
+            max_entries,
+            target_segment_bytes: target_segment_bytes.max(1),
+            bytes_per_sync,
+            writer: Mutex::new(writer),
+        })
+    }
+
+    /// The underlying in-memory buffer - every read method
+    /// (`get_recent`, `get_all`, `export_json`, ...) lives there.
+    pub fn inner(&self) -> &RingBufferLogger {
+        &self.inner
+    }
+
+    /// Log an error, journaling it to disk in addition to the in-memory ring.
+    ///
+    /// Relies on the wrapped logger being non-aggregating: every call
+    /// produces exactly one fresh entry, so the most-recently-touched entry
+    /// ([`RingBufferLogger::get_recent`]) is always the one this call just
+    /// inserted.
+    pub fn log(&self, err: &AgentError, source_ip: &str) {
+        self.inner.log(err, source_ip);
+        if let Some(entry) = self.inner.get_recent(1).into_iter().next() {
+            self.append(&entry);
+        }
+    }
+
+    fn lock_writer(&self) -> std::sync::MutexGuard<'_, SegmentWriter> {
+        match self.writer.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn append(&self, entry: &ForensicEntry) {
+        let body = encode_record(entry);
+        let crc = crc32(&body);
+        let mut frame = Vec::with_capacity(4 + body.len() + 4);
+        frame.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&body);
+        frame.extend_from_slice(&crc.to_be_bytes());
+
+        let mut writer = self.lock_writer();
+        if writer.bytes_written > 0 && writer.bytes_written + frame.len() > self.target_segment_bytes {
+            self.rotate(&mut writer);
+        }
+
+        if writer.file.write_all(&frame).is_err() {
+            return;
+        }
+        writer.bytes_written += frame.len();
+        writer.bytes_since_sync += frame.len();
+
+        if let Some(threshold) = self.bytes_per_sync {
+            if writer.bytes_since_sync >= threshold {
+                let _ = writer.file.sync_all();
+                writer.bytes_since_sync = 0;
+            }
+        }
+    }
+
+    fn rotate(&self, writer: &mut SegmentWriter) {
+        let _ = writer.file.sync_all();
+        let next_index = writer.index + 1;
+        if let Ok(fresh) = open_segment(&self.dir, next_index) {
+            *writer = fresh;
+            self.prune_old_segments(next_index);
+        }
+    }
+
+    /// Delete segments older than whatever's needed to cover `max_entries`
+    /// records, counting backward from the newest already-rotated segment.
+    fn prune_old_segments(&self, current_index: u64) {
+        let Ok(segments) = list_segments(&self.dir) else { return };
+        let mut covered = 0usize;
+        let mut keep_from = current_index;
+        for &index in segments.iter().rev() {
+            if index == current_index {
+                continue;
+            }
+            keep_from = index;
+            covered += count_segment_records(&segment_path(&self.dir, index)).unwrap_or(0);
+            if covered >= self.max_entries {
+                break;
+            }
+        }
+        for &index in &segments {
+            if index < keep_from {
+                let _ = fs::remove_file(segment_path(&self.dir, index));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definitions;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "palisade-journal-test-{label}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn crc32_matches_the_known_check_value() {
+        // The canonical CRC-32/ISO-HDLC check value for the ASCII string "123456789".
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn open_rejects_a_missing_directory() {
+        let dir = std::env::temp_dir().join("palisade-journal-does-not-exist");
+        let _ = fs::remove_dir_all(&dir);
+        let result = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, None);
+        assert!(matches!(result, Err(JournalError::MissingDirectory { .. })));
+    }
+
+    #[test]
+    fn logged_entries_survive_a_reopen() {
+        let dir = temp_dir("survives-reopen");
+        {
+            let logger = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, None).unwrap();
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op-a", "details-a");
+            logger.log(&err, "10.0.0.1");
+        }
+        let reopened = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, None).unwrap();
+        let recovered = reopened.inner().get_all();
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(recovered[0].operation.as_ref(), "op-a");
+        assert_eq!(recovered[0].source_ip.as_ref(), "10.0.0.1");
+    }
+
+    #[test]
+    fn recovery_stops_at_a_torn_tail_without_losing_earlier_records() {
+        let dir = temp_dir("torn-tail");
+        {
+            let logger = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, None).unwrap();
+            for i in 0..3 {
+                let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+                logger.log(&err, &format!("10.0.0.{i}"));
+            }
+        }
+
+        let segments = list_segments(&dir).unwrap();
+        let path = segment_path(&dir, segments[0]);
+        let mut bytes = fs::read(&path).unwrap();
+        bytes.truncate(bytes.len() - 3); // chop into the last record's CRC
+        fs::write(&path, bytes).unwrap();
+
+        let reopened = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, None).unwrap();
+        assert_eq!(reopened.inner().len(), 2);
+    }
+
+    #[test]
+    fn rotation_creates_a_new_segment_once_the_target_size_is_exceeded() {
+        let dir = temp_dir("rotation");
+        let logger = PersistentRingBufferLogger::open(&dir, 1000, 4096, 64, None).unwrap();
+        for i in 0..20 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "some longer details string");
+            logger.log(&err, &format!("10.0.0.{i}"));
+        }
+        let segments = list_segments(&dir).unwrap();
+        assert!(segments.len() > 1, "expected rotation to produce multiple segments");
+    }
+
+    #[test]
+    fn pruning_retains_enough_segments_to_cover_max_entries() {
+        let dir = temp_dir("pruning");
+        let logger = PersistentRingBufferLogger::open(&dir, 5, 4096, 64, None).unwrap();
+        for i in 0..40 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "some longer details string");
+            logger.log(&err, &format!("10.0.0.{i}"));
+        }
+        drop(logger);
+        let reopened = PersistentRingBufferLogger::open(&dir, 5, 4096, 64, None).unwrap();
+        assert_eq!(reopened.inner().len(), 5);
+    }
+
+    #[test]
+    fn bytes_per_sync_none_never_panics_on_write() {
+        let dir = temp_dir("no-sync");
+        let logger = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, None).unwrap();
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+        logger.log(&err, "10.0.0.1");
+        assert_eq!(logger.inner().len(), 1);
+    }
+
+    #[test]
+    fn bytes_per_sync_some_flushes_without_losing_entries() {
+        let dir = temp_dir("sync-threshold");
+        let logger = PersistentRingBufferLogger::open(&dir, 10, 1024, 1 << 20, Some(16)).unwrap();
+        for i in 0..5 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+            logger.log(&err, &format!("10.0.0.{i}"));
+        }
+        assert_eq!(logger.inner().len(), 5);
+    }
+}
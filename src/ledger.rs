@@ -0,0 +1,275 @@
+// src/ledger.rs
+//! Tiered clearance tokens and an audited sensitive-access ledger.
+//!
+//! # Purpose
+//!
+//! `SocAccess` and `Capability` are both all-or-nothing gates: once held,
+//! they reveal the full sensitive payload, and only successful
+//! `Capability`-gated exposures are audited (see [`crate::audit`]). This
+//! module adds a second, independent gate - [`Clearance`]-tagged sensitive
+//! context (see [`crate::InternalContext::sensitive_at`]) checked against a
+//! [`ClearanceToken`]'s level - and makes every attempt through that gate,
+//! granted or denied, an immutable entry in an [`AccessLedger`].
+//!
+//! # Design
+//!
+//! Deliberately simpler than [`crate::audit::AuditSink`]: that module is a
+//! pluggable trait for routing successful exposures to an arbitrary sink.
+//! [`AccessLedger`] is a single concrete, in-crate, append-only store -
+//! there's no plugin point because the point here is a trustworthy record
+//! of every *attempt*, not a routing layer. Callers periodically
+//! [`AccessLedger::drain`] it into their own audit log.
+//!
+//! # std-only
+//!
+//! Like [`crate::escalation`] and [`crate::throttle`], this needs
+//! `RwLock`/`SystemTime` and is unavailable under `no_std`.
+
+use crate::Clearance;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Presents a caller's [`Clearance`] level to
+/// [`crate::DualContextError::expose_sensitive_at`].
+///
+/// # Security Model
+///
+/// Same posture as [`crate::SocAccess`] and [`crate::Capability`]: not
+/// cryptographic, just organizational process safety. The value is in
+/// forcing an explicit level to be named and checked at every exposure
+/// site, with every attempt recorded in an [`AccessLedger`].
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::ledger::ClearanceToken;
+/// use palisade_errors::Clearance;
+///
+/// let token = ClearanceToken::new(Clearance::IncidentResponder);
+/// assert_eq!(token.level(), Clearance::IncidentResponder);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClearanceToken {
+    level: Clearance,
+}
+
+impl ClearanceToken {
+    /// Issue a token presenting `level`.
+    #[inline]
+    pub const fn new(level: Clearance) -> Self {
+        Self { level }
+    }
+
+    /// This token's clearance level.
+    #[inline]
+    pub const fn level(&self) -> Clearance {
+        self.level
+    }
+}
+
+/// One immutable record of an attempt to expose [`Clearance`]-tagged
+/// sensitive content, granted or denied.
+///
+/// Appended by [`crate::DualContextError::expose_sensitive_at`] - never
+/// constructed directly by callers outside this crate.
+#[derive(Debug, Clone)]
+pub struct AccessLedgerEntry {
+    /// Unix timestamp (seconds) this attempt happened at.
+    pub timestamp: u64,
+    /// The level presented by the [`ClearanceToken`] making the attempt.
+    pub token_level: Clearance,
+    /// Identifier of the error whose sensitive context was attempted -
+    /// the attached `ErrorCode`'s rendered form if one was set, else the
+    /// error's `external_message()`.
+    pub error_id: String,
+    /// Whether the attempt was granted (`token_level` met or exceeded the
+    /// tagged requirement).
+    pub granted: bool,
+}
+
+impl AccessLedgerEntry {
+    pub(crate) fn new(token_level: Clearance, error_id: String, granted: bool) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            token_level,
+            error_id,
+            granted,
+        }
+    }
+}
+
+/// Append-only, in-crate ledger of [`AccessLedgerEntry`] attempts.
+///
+/// Cheap to clone - internal state is `Arc`-shared, the same convention as
+/// [`crate::escalation::EscalationEngine`] and
+/// [`crate::throttle::DeceptionThrottle`].
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::ledger::{AccessLedger, ClearanceToken};
+/// use palisade_errors::{Clearance, ContextBuilder, OperationCategory};
+///
+/// let ledger = AccessLedger::new();
+/// let err = ContextBuilder::new()
+///     .public_lie("Operation failed")
+///     .internal_sensitive_at(Clearance::Forensics, "raw credential material")
+///     .category(OperationCategory::IO)
+///     .build();
+///
+/// let analyst = ClearanceToken::new(Clearance::Analyst);
+/// assert!(err.expose_sensitive_at(&analyst, &ledger).is_none());
+///
+/// let forensics = ClearanceToken::new(Clearance::Forensics);
+/// assert!(err.expose_sensitive_at(&forensics, &ledger).is_some());
+///
+/// let entries = ledger.drain();
+/// assert_eq!(entries.len(), 2);
+/// assert!(!entries[0].granted);
+/// assert!(entries[1].granted);
+/// ```
+#[derive(Clone)]
+pub struct AccessLedger {
+    entries: Arc<RwLock<Vec<AccessLedgerEntry>>>,
+}
+
+impl AccessLedger {
+    /// Create an empty ledger.
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Append `entry`. Not part of the public API - entries are only ever
+    /// produced by [`crate::DualContextError::expose_sensitive_at`].
+    pub(crate) fn record(&self, entry: AccessLedgerEntry) {
+        let mut entries = match self.entries.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        entries.push(entry);
+    }
+
+    /// Remove and return every entry recorded so far, oldest first, for the
+    /// caller to fold into its own audit log.
+    pub fn drain(&self) -> Vec<AccessLedgerEntry> {
+        let mut entries = match self.entries.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        std::mem::take(&mut *entries)
+    }
+
+    /// Number of entries currently retained (not yet drained).
+    pub fn len(&self) -> usize {
+        let entries = match self.entries.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        entries.len()
+    }
+
+    /// Whether no entries are currently retained.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for AccessLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContextBuilder, OperationCategory};
+
+    #[test]
+    fn denied_attempt_is_still_recorded() {
+        let ledger = AccessLedger::new();
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive_at(Clearance::Forensics, "raw credential material")
+            .category(OperationCategory::IO)
+            .build();
+
+        let token = ClearanceToken::new(Clearance::Analyst);
+        assert!(err.expose_sensitive_at(&token, &ledger).is_none());
+        assert_eq!(ledger.len(), 1);
+
+        let entries = ledger.drain();
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].granted);
+        assert_eq!(entries[0].token_level, Clearance::Analyst);
+    }
+
+    #[test]
+    fn exact_and_higher_clearance_is_granted() {
+        let ledger = AccessLedger::new();
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive_at(Clearance::IncidentResponder, "customer PII")
+            .category(OperationCategory::IO)
+            .build();
+
+        let responder = ClearanceToken::new(Clearance::IncidentResponder);
+        assert_eq!(err.expose_sensitive_at(&responder, &ledger), Some("customer PII"));
+
+        let forensics = ClearanceToken::new(Clearance::Forensics);
+        assert_eq!(err.expose_sensitive_at(&forensics, &ledger), Some("customer PII"));
+
+        assert_eq!(ledger.len(), 2);
+    }
+
+    #[test]
+    fn untagged_sensitive_context_is_never_granted_via_clearance() {
+        let ledger = AccessLedger::new();
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .build();
+
+        let forensics = ClearanceToken::new(Clearance::Forensics);
+        assert!(err.expose_sensitive_at(&forensics, &ledger).is_none());
+    }
+
+    #[test]
+    fn drain_empties_the_ledger() {
+        let ledger = AccessLedger::new();
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive_at(Clearance::Analyst, "low-sensitivity detail")
+            .category(OperationCategory::IO)
+            .build();
+
+        let token = ClearanceToken::new(Clearance::Analyst);
+        let _ = err.expose_sensitive_at(&token, &ledger);
+        assert_eq!(ledger.len(), 1);
+
+        let drained = ledger.drain();
+        assert_eq!(drained.len(), 1);
+        assert!(ledger.is_empty());
+    }
+
+    #[test]
+    fn error_id_uses_external_message_without_attached_code() {
+        let ledger = AccessLedger::new();
+        let err = ContextBuilder::new()
+            .public_lie("Connection pool exhausted")
+            .internal_sensitive_at(Clearance::Analyst, "pool diagnostics")
+            .category(OperationCategory::IO)
+            .build();
+
+        let token = ClearanceToken::new(Clearance::Analyst);
+        let _ = err.expose_sensitive_at(&token, &ledger);
+
+        let entries = ledger.drain();
+        assert_eq!(entries[0].error_id, "Connection pool exhausted");
+    }
+}
@@ -104,72 +104,410 @@
 //!
 //! - `trusted_debug`: Enable detailed debug formatting for trusted environments (debug builds only)
 //! - `external_signaling`: Reserved for future external signaling capabilities
+//! - `no_std`: Turns on `#![no_std]` for the whole crate (via `cfg_attr`) and
+//!   builds the taxonomy and dual-context modules (`codes`, `registry`,
+//!   `definitions`, `models`, `context`, `logging`, `convenience`,
+//!   `integrity`) against `core`/`alloc` instead of `std`, for embedding the
+//!   error model in kernel-space or bare-metal security agents.
+//!
+//!   This file's legacy `AgentError`/`ErrorContext` type (needs `std::io` and
+//!   `Instant` for I/O error mapping and timing normalization), and the
+//!   `ambient`/`audit`/`drop_bomb`/`escalation`/`funnel`/`obfuscation`/
+//!   `panic_shield`/`ring_buffer`/`sink` modules (need thread-locals,
+//!   `RwLock`, `SystemTime`, OS threads, or `std::panic`), are dropped
+//!   entirely from a `no_std` build rather than failing it.
+//!   Within `models.rs`, `Capability` and its `CapabilityScope` (and the
+//!   `expose_sensitive`/`metadata_with`/`expose_with` methods built on them)
+//!   are likewise `std`-only, since capability expiry needs `SystemTime`.
+//!   `integrity.rs`'s HMAC-SHA256 signing has no such dependency and builds
+//!   against `alloc` alone.
+//!   `registry.rs`'s `explain()` and `grouped_by_namespace()`, `codes.rs`'s
+//!   `ErrorCode::parse`/`to_public`, and `convenience.rs`'s sanitization
+//!   helpers fall back to `alloc::string::String`/`alloc::borrow::Cow`
+//!   in place of their `std` equivalents.
+//!   A `no_std` consumer should depend on this crate with `default-features =
+//!   false, features = ["no_std"]` and use only the alloc-compatible modules
+//!   above; the crate itself still builds with `std` present.
+//!
+//!   `codes.rs`'s `Display` impls were already `core::fmt`-only; its
+//!   `std::error::Error` impls for `ImpactScoreError` and
+//!   `InternalErrorCodeViolation` (and `definitions.rs`'s `CodeRangeError`
+//!   and `ParseCodeError`) are now gated on `not(feature = "no_std")`
+//!   instead of being unconditional, so they drop out of a `no_std` build
+//!   rather than failing it. Pair with `core-error` below to keep an
+//!   `Error` impl on those types under `no_std`.
+//! - `core-error`: Implements `core::error::Error` for `ImpactScoreError` and
+//!   `InternalErrorCodeViolation` (see `codes.rs`) when `no_std` is also
+//!   enabled, for `no_std` consumers on toolchains where `core::error::Error`
+//!   is stable (1.81+). Mutually exclusive with the `std`-only impls those
+//!   types get without `no_std` - `core::error::Error` and
+//!   `std::error::Error` are the same trait, so both impls can never coexist
+//!   in one build.
+//! - `serde`: Adds redact-by-default `Serialize`/`Deserialize` impls for
+//!   `DualContextError`, `ContextChain`, and `ContextMetadata` (see
+//!   `serde_support.rs`). The core path has no hard `serde` dependency
+//!   without this feature, matching the `json_emitter` feature's reasoning.
+//!   Also adds `codes::AuditView`/`codes::PublicView`, explicit wrapper
+//!   newtypes around `ErrorCode` giving audit-log and untrusted-boundary
+//!   callers their own distinct `Serialize` impl - there is no plain
+//!   `impl Serialize for ErrorCode`, so the disclosure level is always an
+//!   explicit choice at the call site. `codes::ViolationView`/
+//!   `codes::PublicViolationView` offer the same split for
+//!   `InternalErrorCodeViolation`.
+//! - `structured_emitter`: Adds `emission::ErrorEmitter` and a streaming
+//!   `emission::JsonEmitter<W: io::Write>` (see `emission.rs`) for exporting
+//!   a single `DualContextError` straight to a log shipper or SIEM feed.
+//!   Deliberately not re-exported at the crate root - `context.rs`'s
+//!   `json_emitter`-gated `JsonEmitter` (a different, `ContextChain`-shaped
+//!   type) already uses that name. Requires `std`; unavailable under
+//!   `no_std`, which has no `std::io::Write` to write to.
+//! - `http`: Adds `status_code()`/`to_http_body()`/`to_http_response()` on
+//!   `DualContextError` and `ContextChain` (see `http.rs`), mapping
+//!   `OperationCategory` to a status code plus a safe, public-only JSON
+//!   body. Framework-agnostic - returns plain data for axum/actix/etc. to
+//!   wrap.
+//! - `ffi`: Adds a C-ABI bridge exposing `PublicContext`/`InternalContext`/
+//!   `SocAccess` as opaque handles (see `ffi.rs`), so polyglot honeypot
+//!   components written in C/C++ can produce and consume dual contexts
+//!   without reaching past the trust boundary - no accessor crosses the
+//!   boundary with sensitive bytes unless a `SocAccess` handle crosses it
+//!   too. Requires `std`; unavailable under `no_std`, which has no
+//!   `CString`/heap-allocation story to build this on.
+//!   Also adds `uniffi_bridge::ExternalError` (see `uniffi_bridge.rs`), a
+//!   plain-data, UniFFI-record-shaped projection of `DualContextError`
+//!   holding only its external message and category, plus
+//!   `DualContextError::debug_repr()` for a redaction-aware introspection
+//!   string safe to log across that same boundary. Same `no_std` carve-out
+//!   as the C-ABI bridge above.
+//! - `emission_tracking`: Adds a drop-bomb to `DualContextError` - an
+//!   `AtomicBool` latch, set by `external_message()` and any other accessor
+//!   that routes through `InternalContext::payload()`, checked by a `Drop`
+//!   impl that fires a process-global hook (installed via
+//!   `models::set_unhandled_error_hook`) when a `Detection`/`Containment`/
+//!   `Deception` error is dropped unemitted - only the `OperationCategory`
+//!   crosses into the hook, never content. Runs before, and does not
+//!   interfere with, the existing `ZeroizeOnDrop` field-clearing glue.
+//!   Unavailable under `no_std`, which has no `OnceLock`/`RwLock` to host
+//!   the hook on; deployments that don't enable this feature pay no
+//!   overhead for it.
+//! - `log_kv`: Implements `log::kv::Source` for `InternalLog` (see
+//!   `logging.rs`), so a structured logging backend visits `code`,
+//!   `operation`, `details`, `retryable`, `source_internal`, and metadata as
+//!   discrete key-values instead of re-parsing `write_to`'s flattened
+//!   string. `source_sensitive` still only visits its real value under
+//!   `all(feature = "trusted_debug", debug_assertions)`.
+//! - `slog_kv`: Implements `slog::KV` for `InternalLog` (see `logging.rs`),
+//!   the same field-by-field emission as `log_kv` but through slog's
+//!   `Serializer`, with the same `trusted_debug`-gated redaction of
+//!   `source_sensitive`.
+//! - `toml_config`: Adds `config::PalisadeConfig`, deserialized from a
+//!   `.toml` file, replacing a hardcoded `init_session_salt(...)` call with
+//!   startup-configurable obfuscation salt, internal code-disclosure
+//!   policy, per-category external message templates, and permanent-vs-
+//!   retryable category classification. Also adds
+//!   `logging::InternalLog::disclosed_code`, which honors the configured
+//!   disclosure policy. The core path has no hard `toml` dependency without
+//!   this feature.
+//! - `panic_reports`: Adds `panic_shield::install_report_hook`, a panic hook
+//!   that writes a sanitized crash report (message, location, backtrace) to
+//!   a uniquely-named file under a configured directory and prints only a
+//!   short public line naming the path, plus `panic_shield::read_report` to
+//!   read one back, gated by the same `SocAccess` token
+//!   `InternalContext::expose_sensitive` requires. A heavier, disk-writing
+//!   alternative to the always-on `install_panic_hook`'s inline redaction,
+//!   not a replacement for it. Unavailable under `no_std`, which has no
+//!   `std::panic::set_hook`/`std::fs` to build this on.
 
+#![cfg_attr(feature = "no_std", no_std)]
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+#[cfg(feature = "no_std")]
+extern crate alloc;
+
+// The legacy `AgentError`/`ErrorContext` path below needs `std::io`, wall-clock
+// timing (`Instant`), and `std::error::Error` - none of which exist under
+// `no_std`. See the `no_std` feature bullet above for the full module carve-out.
+#[cfg(not(feature = "no_std"))]
 use std::fmt;
+#[cfg(not(feature = "no_std"))]
 use std::io;
+#[cfg(not(feature = "no_std"))]
 use std::result;
+#[cfg(not(feature = "no_std"))]
 use std::time::{Duration, Instant};
+#[cfg(not(feature = "no_std"))]
 use smallvec::SmallVec;
+#[cfg(not(feature = "no_std"))]
 use zeroize::Zeroize;
+#[cfg(not(feature = "no_std"))]
 use std::error::Error;
+#[cfg(not(feature = "no_std"))]
 use std::borrow::Cow;
 
+#[cfg(feature = "serde")]
+pub mod advisory;
+#[cfg(not(feature = "no_std"))]
+pub mod ambient;
+#[cfg(not(feature = "no_std"))]
+pub mod audit;
+#[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+pub mod backtrace;
 pub mod codes;
+#[cfg(all(feature = "toml_config", not(feature = "no_std")))]
+pub mod config;
 pub mod context;
 pub mod convenience;
 pub mod definitions;
+#[cfg(not(feature = "no_std"))]
+pub mod drop_bomb;
+#[cfg(all(feature = "structured_emitter", not(feature = "no_std")))]
+pub mod emission;
+#[cfg(not(feature = "no_std"))]
+pub mod escalation;
+#[cfg(all(feature = "ffi", not(feature = "no_std")))]
+pub mod ffi;
+#[cfg(not(feature = "no_std"))]
+pub mod funnel;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod integrity;
+#[cfg(not(feature = "no_std"))]
+mod intern;
+#[cfg(not(feature = "no_std"))]
+pub mod journal;
+#[cfg(not(feature = "no_std"))]
+pub mod ledger;
+#[cfg(not(feature = "no_std"))]
+pub mod locale;
 pub mod logging;
+#[cfg(feature = "serde")]
+pub mod manifest;
 pub mod models;
+#[cfg(not(feature = "no_std"))]
 pub mod obfuscation;
+#[cfg(not(feature = "no_std"))]
+pub mod panic_shield;
+pub mod parse_context;
+pub mod provider;
+pub mod registry;
+#[cfg(not(feature = "no_std"))]
+pub mod report;
+#[cfg(not(feature = "no_std"))]
+pub mod retry;
+#[cfg(not(feature = "no_std"))]
 pub mod ring_buffer;
+#[cfg(not(feature = "no_std"))]
+pub mod scope;
+#[cfg(not(feature = "no_std"))]
+pub mod seal;
+pub mod signature;
+#[cfg(not(feature = "no_std"))]
+pub mod sink;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+#[cfg(not(feature = "no_std"))]
+pub mod throttle;
+#[cfg(not(feature = "no_std"))]
+pub mod trace_id;
+#[cfg(all(feature = "ffi", not(feature = "no_std")))]
+pub mod uniffi_bridge;
 
+#[cfg(feature = "serde")]
+pub use advisory::*;
+#[cfg(not(feature = "no_std"))]
+pub use ambient::*;
+#[cfg(not(feature = "no_std"))]
+pub use audit::*;
 pub use codes::*;
 pub use context::*;
 pub use convenience::*;
 pub use definitions::*;
+#[cfg(not(feature = "no_std"))]
+pub use drop_bomb::*;
+#[cfg(not(feature = "no_std"))]
+pub use escalation::*;
+#[cfg(all(feature = "ffi", not(feature = "no_std")))]
+pub use ffi::*;
+#[cfg(not(feature = "no_std"))]
+pub use funnel::*;
+#[cfg(feature = "http")]
+pub use http::*;
+pub use integrity::*;
 pub use logging::*;
+#[cfg(feature = "serde")]
+pub use manifest::*;
 pub use models::*;
+#[cfg(not(feature = "no_std"))]
 pub use obfuscation::*;
+#[cfg(not(feature = "no_std"))]
+pub use panic_shield::*;
+#[cfg(not(feature = "no_std"))]
+pub use report::*;
+#[cfg(not(feature = "no_std"))]
 pub use ring_buffer::*;
+#[cfg(not(feature = "no_std"))]
+pub use trace_id::*;
+#[cfg(all(feature = "ffi", not(feature = "no_std")))]
+pub use uniffi_bridge::*;
 
 /// Type alias for Results using our error type.
+#[cfg(not(feature = "no_std"))]
 pub type Result<T> = result::Result<T, AgentError>;
 
 // ============================================================================
 // Internal Error Context (Legacy, Still Used by AgentError)
 // ============================================================================
+//
+// `AgentError` needs `std::io` error mapping and `Instant`-based timing
+// normalization, so this whole section - and `AgentError` itself below - is
+// unavailable under `no_std`, same carve-out as the `audit`/`obfuscation`/
+// `ring_buffer`/`escalation`/`funnel`/`sink` modules.
+/// Inline-small-string storage for `ErrorContext`'s `operation`/`details`/
+/// source fields.
+///
+/// # Purpose
+///
+/// `&'static str` arguments (the overwhelmingly common case - see the
+/// `Design Rationale` docs on `AgentError`) were already zero-allocation via
+/// `Cow::Borrowed`. What wasn't: short *dynamic* strings, like a
+/// `format!("attempt {n}")` detail message, which forced a heap `String`
+/// even at a handful of bytes. `SmallString` closes that gap by also
+/// inlining short owned strings, falling back to the heap only once a
+/// dynamic string outgrows `INLINE_CAPACITY`.
+///
+/// # Use Case
+///
+/// Internal plumbing for `ErrorContext` only - not part of the public API.
+/// Construction stays source-compatible with the prior `Cow<'static, str>`
+/// bound via `Into`.
+/// Bytes of inline storage before [`SmallString`] falls back to the heap -
+/// sized to comfortably fit short operation names and detail messages
+/// (`"attempt 3 of 5"`, `"timeout after 30s"`) without forcing every
+/// `ErrorContext` to carry a much larger inline buffer than most fields ever
+/// use.
+#[cfg(not(feature = "no_std"))]
+const SMALL_STRING_INLINE_CAPACITY: usize = 23;
+
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug)]
+enum SmallString {
+    /// A `&'static str` argument - the common case, zero allocation.
+    Borrowed(&'static str),
+    /// A dynamic string short enough to fit inline - zero allocation.
+    Inline { buf: [u8; SMALL_STRING_INLINE_CAPACITY], len: u8 },
+    /// A dynamic string too long to inline - the heap fallback.
+    Owned(String),
+}
+
+#[cfg(not(feature = "no_std"))]
+impl SmallString {
+    #[inline]
+    fn from_dynamic(s: String) -> Self {
+        if s.len() <= SMALL_STRING_INLINE_CAPACITY {
+            let mut buf = [0u8; SMALL_STRING_INLINE_CAPACITY];
+            buf[..s.len()].copy_from_slice(s.as_bytes());
+            Self::Inline { buf, len: s.len() as u8 }
+        } else {
+            Self::Owned(s)
+        }
+    }
+
+    #[inline]
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Borrowed(s) => s,
+            Self::Inline { buf, len } => {
+                // Safety: only ever written from `str::as_bytes()` in `from_dynamic`.
+                unsafe { core::str::from_utf8_unchecked(&buf[..*len as usize]) }
+            }
+            Self::Owned(s) => s.as_str(),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl From<&'static str> for SmallString {
+    #[inline]
+    fn from(s: &'static str) -> Self {
+        Self::Borrowed(s)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl From<String> for SmallString {
+    #[inline]
+    fn from(s: String) -> Self {
+        Self::from_dynamic(s)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl From<Cow<'static, str>> for SmallString {
+    #[inline]
+    fn from(s: Cow<'static, str>) -> Self {
+        match s {
+            Cow::Borrowed(s) => Self::Borrowed(s),
+            Cow::Owned(s) => Self::from_dynamic(s),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Zeroize for SmallString {
+    fn zeroize(&mut self) {
+        match self {
+            Self::Borrowed(_) => {}
+            Self::Inline { buf, len } => {
+                buf.zeroize();
+                *len = 0;
+            }
+            Self::Owned(s) => s.zeroize(),
+        }
+    }
+}
 
 /// Internal error context storage for `AgentError`.
 ///
 /// This preserves the legacy context model while newer DualContextError APIs evolve.
+#[cfg(not(feature = "no_std"))]
 struct ErrorContext {
-    operation: Cow<'static, str>,
-    details: Cow<'static, str>,
-    source_internal: Option<Cow<'static, str>>,
-    source_sensitive: Option<Cow<'static, str>>,
+    operation: SmallString,
+    details: SmallString,
+    source_internal: Option<SmallString>,
+    source_sensitive: Option<SmallString>,
     metadata: SmallVec<[(&'static str, ContextField); 4]>,
+    #[cfg(feature = "internal_backtrace")]
+    backtrace: Option<crate::backtrace::CapturedBacktrace>,
 }
 
+#[cfg(not(feature = "no_std"))]
 impl ErrorContext {
     #[inline]
-    fn new(operation: impl Into<Cow<'static, str>>, details: impl Into<Cow<'static, str>>) -> Self {
+    fn new(operation: impl Into<SmallString>, details: impl Into<SmallString>) -> Self {
         Self {
             operation: operation.into(),
             details: details.into(),
             source_internal: None,
             source_sensitive: None,
             metadata: SmallVec::new(),
+            #[cfg(feature = "internal_backtrace")]
+            backtrace: None,
         }
     }
 
     #[inline]
     fn with_sensitive(
-        operation: impl Into<Cow<'static, str>>,
-        details: impl Into<Cow<'static, str>>,
-        sensitive_info: impl Into<Cow<'static, str>>,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
+        sensitive_info: impl Into<SmallString>,
     ) -> Self {
         Self {
             operation: operation.into(),
@@ -177,15 +515,17 @@ impl ErrorContext {
             source_internal: None,
             source_sensitive: Some(sensitive_info.into()),
             metadata: SmallVec::new(),
+            #[cfg(feature = "internal_backtrace")]
+            backtrace: None,
         }
     }
 
     #[inline]
     fn with_source_split(
-        operation: impl Into<Cow<'static, str>>,
-        details: impl Into<Cow<'static, str>>,
-        internal_source: impl Into<Cow<'static, str>>,
-        sensitive_source: impl Into<Cow<'static, str>>,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
+        internal_source: impl Into<SmallString>,
+        sensitive_source: impl Into<SmallString>,
     ) -> Self {
         Self {
             operation: operation.into(),
@@ -193,6 +533,8 @@ impl ErrorContext {
             source_internal: Some(internal_source.into()),
             source_sensitive: Some(sensitive_source.into()),
             metadata: SmallVec::new(),
+            #[cfg(feature = "internal_backtrace")]
+            backtrace: None,
         }
     }
 
@@ -202,27 +544,29 @@ impl ErrorContext {
     }
 }
 
+#[cfg(not(feature = "no_std"))]
 impl Zeroize for ErrorContext {
     fn zeroize(&mut self) {
-        if let Cow::Owned(ref mut s) = self.operation {
-            s.zeroize();
-        }
-        if let Cow::Owned(ref mut s) = self.details {
+        self.operation.zeroize();
+        self.details.zeroize();
+        if let Some(s) = &mut self.source_internal {
             s.zeroize();
         }
-        if let Some(Cow::Owned(ref mut s)) = self.source_internal {
-            s.zeroize();
-        }
-        if let Some(Cow::Owned(ref mut s)) = self.source_sensitive {
+        if let Some(s) = &mut self.source_sensitive {
             s.zeroize();
         }
         for (_, value) in &mut self.metadata {
             value.zeroize();
         }
         self.metadata.clear();
+        #[cfg(feature = "internal_backtrace")]
+        if let Some(backtrace) = &mut self.backtrace {
+            backtrace.zeroize();
+        }
     }
 }
 
+#[cfg(not(feature = "no_std"))]
 impl Drop for ErrorContext {
     fn drop(&mut self) {
         self.zeroize();
@@ -230,6 +574,7 @@ impl Drop for ErrorContext {
 }
 
 #[inline]
+#[cfg(not(feature = "no_std"))]
 const fn io_error_kind_label(kind: io::ErrorKind) -> &'static str {
     match kind {
         io::ErrorKind::NotFound => "NotFound",
@@ -258,6 +603,174 @@ const fn io_error_kind_label(kind: io::ErrorKind) -> &'static str {
     }
 }
 
+/// Controls how `AgentError`'s external `Display` renders.
+///
+/// # Use Case
+///
+/// `Plain` is deterministic, decoration-free output suited to log matching
+/// in tests and narrow terminals. `Fancy` adds a framed, human-friendly
+/// layout for interactive use. Both styles carry exactly the same fields -
+/// switching styles never changes what information is revealed, only how
+/// it's laid out.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorStyle {
+    /// Code and sanitized message only, no box-drawing or ANSI.
+    #[default]
+    Plain,
+    /// Framed layout for interactive display.
+    Fancy,
+}
+
+#[cfg(not(feature = "no_std"))]
+thread_local! {
+    static DEFAULT_ERROR_STYLE: std::cell::Cell<ErrorStyle> = const { std::cell::Cell::new(ErrorStyle::Plain) };
+}
+
+/// Verbosity level for [`AgentError::display_with_verbosity`], orthogonal to
+/// [`ErrorStyle`]: `ErrorStyle` picks the layout (plain line vs. a framed
+/// box), `DisplayVerbosity` picks how much of it to include.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayVerbosity {
+    /// Just the error code, e.g. `"E-CFG-100"` - for a caller that already
+    /// shows category and retry semantics elsewhere (a dashboard column, a
+    /// structured log field) and just needs a short inline reference.
+    CodeOnly,
+    /// The current full sentence - category, permanence, code, correlation
+    /// ref. The default, matching plain `Display`.
+    #[default]
+    Full,
+    /// [`Self::Full`] plus an [`AgeBucket`] - how long ago the error was
+    /// constructed, at a resolution coarse enough to carry no timing side
+    /// channel (see [`AgentError::age_bucket`]).
+    FullWithAge,
+}
+
+/// A coarse, bucketed view of [`AgentError::age`] safe to show an untrusted
+/// viewer.
+///
+/// [`AgentError::age`]'s own docs warn the raw [`Duration`] should never be
+/// exposed externally - exact elapsed time is exactly what a timing
+/// side-channel wants. Collapsing it to one of a handful of named bands
+/// keeps the signal an operator actually wants ("is this a fresh error or a
+/// stale one being replayed?") while destroying the precision an attacker
+/// would need to correlate it with anything.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeBucket {
+    /// Under a second old.
+    JustNow,
+    /// Under a minute old.
+    UnderAMinute,
+    /// Under an hour old.
+    UnderAnHour,
+    /// An hour old or more.
+    Stale,
+}
+
+impl AgeBucket {
+    fn from_age(age: Duration) -> Self {
+        if age < Duration::from_secs(1) {
+            Self::JustNow
+        } else if age < Duration::from_secs(60) {
+            Self::UnderAMinute
+        } else if age < Duration::from_secs(3600) {
+            Self::UnderAnHour
+        } else {
+            Self::Stale
+        }
+    }
+
+    /// A short, human-readable label for this band.
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::JustNow => "just now",
+            Self::UnderAMinute => "under a minute",
+            Self::UnderAnHour => "under an hour",
+            Self::Stale => "stale",
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for AgeBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Disclosure tier for [`AgentError::render_for`].
+///
+/// # Use Case
+///
+/// The crate's trust boundary used to be binary: an external `Display`
+/// (obfuscated code, generic wording) and an internal `InternalLog` (real
+/// code, full context) behind [`AgentError::with_internal_log`]. A single
+/// error often needs to flow to more than those two destinations at once -
+/// a public API response, a partner webhook, an operator dashboard, and a
+/// SOC log - each entitled to a different amount of detail. `Audience`
+/// names those stops and [`AgentError::render_for`] renders accordingly,
+/// borrowing the "each layer carries a trust level" shape of a layered
+/// config system rather than inventing a bespoke one.
+///
+/// # Ordering
+///
+/// Deliberately `Ord`: each tier is a strict superset of the detail in the
+/// one before it, so `Audience::Operator > Audience::Partner` reads the way
+/// the trust model intends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Audience {
+    /// The anonymous/attacker caller: only the obfuscated code, matching
+    /// the detail level of `Display`.
+    External,
+    /// A partner integration (webhook consumer, etc.): adds the category
+    /// and permanence, using [`OperationCategory::deceptive_name`] so
+    /// honeypot-internal categories stay masked at this still-outward-
+    /// facing tier.
+    Partner,
+    /// An internal operator dashboard: adds the real category name and the
+    /// operation that failed.
+    Operator,
+    /// A fully trusted SOC/admin viewer: adds the full details and the
+    /// real, pre-obfuscation code recovered via
+    /// [`crate::obfuscation::deobfuscate_code`].
+    Admin,
+}
+
+/// Borrows an `AgentError` and a fixed `ErrorStyle` together so `format!`
+/// can render it without touching the thread-level default.
+///
+/// Returned by [`AgentError::display_with_style`].
+#[cfg(not(feature = "no_std"))]
+pub struct StyledDisplay<'a> {
+    error: &'a AgentError,
+    style: ErrorStyle,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for StyledDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt_with_style(f, self.style)
+    }
+}
+
+/// Renders an [`AgentError`] at a specific [`DisplayVerbosity`]. Built by
+/// [`AgentError::display_with_verbosity`]; mirrors [`StyledDisplay`]'s
+/// borrow-and-wrap shape for the orthogonal style axis.
+#[cfg(not(feature = "no_std"))]
+pub struct VerbosityDisplay<'a> {
+    error: &'a AgentError,
+    verbosity: DisplayVerbosity,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for VerbosityDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt_with_verbosity(f, self.verbosity)
+    }
+}
+
 /// Main error type with security-conscious design.
 ///
 /// # Key Properties
@@ -283,14 +796,19 @@ const fn io_error_kind_label(kind: io::ErrorKind) -> &'static str {
 /// The redundancy is acceptable because it improves maintainability and reduces
 /// the chance of errors being created with mismatched code/category pairs.
 #[must_use = "errors should be handled or logged"]
+#[cfg(not(feature = "no_std"))]
 pub struct AgentError {
     code: ErrorCode,
     context: ErrorContext,
     retryable: bool,
     source: Option<Box<dyn Error + Send + Sync>>,
     created_at: Instant,
+    trace_id: crate::trace_id::TraceId,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<crate::backtrace::CapturedBacktrace>,
 }
 
+#[cfg(not(feature = "no_std"))]
 impl AgentError {
     #[inline]
     fn enforce_constant_time(created_at: Instant) {
@@ -302,9 +820,45 @@ impl AgentError {
         }
     }
 
+    /// Finish construction: enforce the [`Self::enforce_constant_time`]
+    /// floor, then (only then) capture a backtrace. Capturing stack frames
+    /// takes a variable amount of time depending on call depth, so it runs
+    /// after the floor rather than before - otherwise its own timing
+    /// variance would leak through the floor it's supposed to be hidden
+    /// behind.
+    ///
+    /// Two independent triggers, at most one of which fires per error:
+    /// `internal_backtrace` captures unconditionally, for deployments that
+    /// want a trace on every error regardless of severity. Otherwise, under
+    /// plain `backtrace`, a trace is captured automatically only for
+    /// [`ErrorImpact::Escalation`]/[`ErrorImpact::Breach`]-level codes -
+    /// exactly the errors an investigator would reach for
+    /// [`Self::with_backtrace`] on by hand anyway - so a deployment gets
+    /// the signal that matters most without paying capture cost on every
+    /// routine `Noise`/`Flaw` error.
+    #[inline]
+    fn with_constant_time(mut self, created_at: Instant) -> Self {
+        Self::enforce_constant_time(created_at);
+        self.created_at = created_at;
+        #[cfg(feature = "internal_backtrace")]
+        {
+            self.context.backtrace = Some(crate::backtrace::CapturedBacktrace::capture());
+        }
+        #[cfg(all(feature = "backtrace", not(feature = "internal_backtrace")))]
+        {
+            if matches!(
+                self.code.impact_level(),
+                ErrorImpact::Escalation | ErrorImpact::Breach
+            ) {
+                self.backtrace = Some(crate::backtrace::CapturedBacktrace::capture());
+            }
+        }
+        self
+    }
+
     /// Create a generic error with internal context only.
     #[inline]
-    fn new(code: ErrorCode, operation: impl Into<Cow<'static, str>>, details: impl Into<Cow<'static, str>>) -> Self {
+    fn new(code: ErrorCode, operation: impl Into<SmallString>, details: impl Into<SmallString>) -> Self {
         let created_at = Instant::now();
         Self {
             code: crate::obfuscation::obfuscate_code(&code),
@@ -312,6 +866,9 @@ impl AgentError {
             retryable: false,
             source: None,
             created_at,
+            trace_id: crate::trace_id::TraceId::generate(),
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
         }
         .with_constant_time(created_at)
     }
@@ -320,9 +877,9 @@ impl AgentError {
     #[inline]
     fn new_sensitive(
         code: ErrorCode,
-        operation: impl Into<Cow<'static, str>>,
-        details: impl Into<Cow<'static, str>>,
-        sensitive_info: impl Into<Cow<'static, str>>,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
+        sensitive_info: impl Into<SmallString>,
     ) -> Self {
         let created_at = Instant::now();
         Self {
@@ -331,6 +888,9 @@ impl AgentError {
             retryable: false,
             source: None,
             created_at,
+            trace_id: crate::trace_id::TraceId::generate(),
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
         }
         .with_constant_time(created_at)
     }
@@ -342,10 +902,10 @@ impl AgentError {
     #[inline]
     fn new_with_split_source(
         code: ErrorCode,
-        operation: impl Into<Cow<'static, str>>,
-        details: impl Into<Cow<'static, str>>,
-        internal_source: impl Into<Cow<'static, str>>,
-        sensitive_source: impl Into<Cow<'static, str>>,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
+        internal_source: impl Into<SmallString>,
+        sensitive_source: impl Into<SmallString>,
     ) -> Self {
         let created_at = Instant::now();
         Self {
@@ -359,21 +919,65 @@ impl AgentError {
             retryable: false,
             source: None,
             created_at,
+            trace_id: crate::trace_id::TraceId::generate(),
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
         }
         .with_constant_time(created_at)
     }
 
+    /// Mark this error as retryable (transient failure)
     #[inline]
-    fn with_constant_time(mut self, created_at: Instant) -> Self {
-        Self::enforce_constant_time(created_at);
-        self.created_at = created_at;
+    pub fn with_retry(mut self) -> Self {
+        self.retryable = true;
         self
     }
 
-    /// Mark this error as retryable (transient failure)
+    /// Capture a backtrace at this point, confined to the internal log -
+    /// see [`crate::backtrace`].
+    ///
+    /// Raw frame addresses are captured immediately; symbol resolution is
+    /// deferred until [`Self::internal_log`]'s [`InternalLog::write_to`]
+    /// (or any other internal-log formatter) first serializes it, and the
+    /// resolved text is cached for subsequent writes.
+    ///
+    /// # Feature Gate
+    ///
+    /// Entirely behind the `backtrace` cargo feature.
+    #[cfg(feature = "backtrace")]
     #[inline]
-    pub fn with_retry(mut self) -> Self {
-        self.retryable = true;
+    pub fn with_backtrace(mut self) -> Self {
+        self.backtrace = Some(crate::backtrace::CapturedBacktrace::capture());
+        self
+    }
+
+    /// The backtrace captured automatically at construction by the
+    /// `internal_backtrace` feature, if any.
+    ///
+    /// Gated to internal consumers: there is no `pub` accessor for this,
+    /// only [`Self::internal_log`]'s [`InternalLog::backtrace_text`], so a
+    /// caller can reach it solely through the same lifetime-bounded path
+    /// every other internal-only field goes through.
+    #[cfg(feature = "internal_backtrace")]
+    #[inline]
+    pub(crate) fn internal_backtrace(&self) -> Option<&crate::backtrace::CapturedBacktrace> {
+        self.context.backtrace.as_ref()
+    }
+
+    /// Attach an underlying error as this error's [`std::error::Error::source`].
+    ///
+    /// Visible only through [`Self::source`] and [`Self::report`] - never
+    /// through `Display`, which stays obfuscated-code-only regardless of
+    /// what's chained underneath. A `source` that is itself an [`AgentError`]
+    /// is recognized specially by [`Report`](crate::report::Report): it's
+    /// already been through this crate's own sanitized construction path, so
+    /// it's safe to render in full internally.
+    #[inline]
+    pub fn caused_by<E>(mut self, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.source = Some(Box::new(source));
         self
     }
 
@@ -465,6 +1069,17 @@ impl AgentError {
         &self.code
     }
 
+    /// Get this error's correlation ID.
+    ///
+    /// Stable across `Display`, `internal_log()`, and any
+    /// [`crate::ring_buffer::ForensicEntry`] logged from this error, so an
+    /// operator can take the ID from a support ticket's external message and
+    /// find the matching internal log entry (or ring buffer record) with it.
+    #[inline]
+    pub const fn trace_id(&self) -> crate::trace_id::TraceId {
+        self.trace_id
+    }
+
     /// Get operation category
     #[inline]
     pub const fn category(&self) -> OperationCategory {
@@ -508,20 +1123,20 @@ impl AgentError {
     pub fn internal_log(&self) -> InternalLog<'_> {
         InternalLog {
             code: &self.code,
-            operation: self.context.operation.as_ref(),
-            details: self.context.details.as_ref(),
-            source_internal: self
-                .context
-                .source_internal
-                .as_ref()
-                .map(|s: &Cow<'static, str>| s.as_ref()),
-            source_sensitive: self
-                .context
-                .source_sensitive
-                .as_ref()
-                .map(|s: &Cow<'static, str>| s.as_ref()),
+            trace_id: self.trace_id,
+            operation: self.context.operation.as_str(),
+            details: self.context.details.as_str(),
+            source_internal: self.context.source_internal.as_ref().map(SmallString::as_str),
+            source_sensitive: self.context.source_sensitive.as_ref().map(SmallString::as_str),
             metadata: &self.context.metadata,
             retryable: self.retryable,
+            #[cfg(feature = "internal_backtrace")]
+            backtrace: self.internal_backtrace().map(crate::backtrace::BacktraceSource::Captured),
+            #[cfg(all(feature = "backtrace", not(feature = "internal_backtrace")))]
+            backtrace: self
+                .backtrace
+                .as_ref()
+                .map(crate::backtrace::BacktraceSource::Captured),
         }
     }
 
@@ -546,6 +1161,175 @@ impl AgentError {
         f(&log)
     }
 
+    /// Dispatch this error's internal log to every sink installed via
+    /// [`crate::sink::register_sink`].
+    ///
+    /// Deliberately opt-in rather than run automatically from every
+    /// constructor: construction happens before builder methods like
+    /// [`Self::with_retry`] and [`Self::with_metadata`] finish shaping the
+    /// error, so dispatching there would persist an incomplete record.
+    /// Call `emit()` once the error is fully built - typically right before
+    /// returning or logging it, the same place a caller would otherwise
+    /// reach for [`Self::with_internal_log`] or [`Self::to_json`].
+    pub fn emit(&self) {
+        self.with_internal_log(crate::sink::dispatch_to_registered_sinks);
+    }
+
+    /// Render this error's internal log through a pluggable
+    /// [`crate::logging::LogEmitter`] - [`crate::logging::HumanLogEmitter`]
+    /// for the existing line format, [`crate::logging::JsonLogEmitter`] for
+    /// structured output, or a caller's own.
+    ///
+    /// Takes the emitter by `&mut dyn` reference rather than consuming it so
+    /// a caller can render a batch of errors into the same emitter across
+    /// several `emit_to` calls before reading its accumulated output.
+    #[inline]
+    pub fn emit_to(&self, emitter: &mut dyn LogEmitter) {
+        emitter.emit(&self.internal_log());
+    }
+
+    /// Offer this error's structured fields to a type-based request,
+    /// mirroring the standard library's unstable `Error::provide`.
+    ///
+    /// Reachable today: [`ErrorCode`] by reference, this error's
+    /// [`OperationCategory`], its [`crate::trace_id::TraceId`], and the
+    /// [`Duration`] elapsed since construction - the closest built-in
+    /// proxy for a retry-after hint until a caller attaches a real backoff
+    /// policy. Most callers want [`Self::request_ref`] or
+    /// [`Self::request_value`] instead of calling this directly.
+    pub fn provide<'a>(&'a self, req: &mut crate::provider::ContextRequest<'a>) {
+        req.provide_ref::<ErrorCode>(self.code());
+        req.provide_value(|| self.category());
+        req.provide_value(|| self.trace_id());
+        req.provide_value(|| self.age());
+    }
+
+    /// Look up a `&T` offered by [`Self::provide`], falling back to the
+    /// boxed `source` chain (downcasting each link to [`AgentError`]) if
+    /// this error itself didn't offer one.
+    pub fn request_ref<T: 'static>(&self) -> Option<&T> {
+        let mut slot: Option<&T> = None;
+        let mut req = crate::provider::ContextRequest::for_ref(&mut slot);
+        self.provide(&mut req);
+        drop(req);
+        slot.or_else(|| {
+            self.source
+                .as_deref()
+                .and_then(|source| source.downcast_ref::<AgentError>())
+                .and_then(|inner| inner.request_ref::<T>())
+        })
+    }
+
+    /// Look up an owned `T` offered by [`Self::provide`], falling back to
+    /// the boxed `source` chain (downcasting each link to [`AgentError`])
+    /// if this error itself didn't offer one.
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        let mut slot: Option<T> = None;
+        let mut req = crate::provider::ContextRequest::for_value(&mut slot);
+        self.provide(&mut req);
+        drop(req);
+        slot.or_else(|| {
+            self.source
+                .as_deref()
+                .and_then(|source| source.downcast_ref::<AgentError>())
+                .and_then(|inner| inner.request_value::<T>())
+        })
+    }
+
+    /// Build a [`crate::report::Report`] over this error's `source` chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{AgentError, definitions};
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "bad syntax");
+    /// let external = err.report().to_string();
+    /// assert!(!external.contains("bad syntax"));
+    ///
+    /// let mut internal = String::new();
+    /// err.report().write_internal(&mut internal).unwrap();
+    /// assert!(internal.contains("bad syntax"));
+    /// ```
+    #[inline]
+    pub fn report(&self) -> crate::report::Report<'_> {
+        crate::report::Report::new(self)
+    }
+
+    /// Render this error's internal log as a single-line JSON object, via
+    /// [`InternalLog::write_json`] - the convenience most callers reach for
+    /// over building their own buffer with `with_internal_log`.
+    ///
+    /// Lets operators pipe errors into log shippers and SIEM pipelines
+    /// instead of scraping `to_string()`'s human-readable `Display` output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{AgentError, definitions};
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+    /// let json = err.to_json();
+    /// assert!(json.contains("\"operation\":\"op\""));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut buf = String::new();
+        self.internal_log().write_json(&mut buf).unwrap();
+        buf
+    }
+
+    /// Render this error for a single [`Audience`] tier, revealing
+    /// progressively more detail the more trusted the tier is rather than
+    /// the binary external/internal split `Display`/`with_internal_log`
+    /// draw.
+    ///
+    /// - [`Audience::External`]: the obfuscated code only.
+    /// - [`Audience::Partner`]: adds the (deceptive) category and permanence.
+    /// - [`Audience::Operator`]: adds the real category and the operation
+    ///   name.
+    /// - [`Audience::Admin`]: adds the full details and the real,
+    ///   pre-obfuscation code.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use palisade_errors::{AgentError, Audience, definitions};
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "load_config", "bad syntax");
+    ///
+    /// assert!(!err.render_for(Audience::External).contains("load_config"));
+    /// assert!(err.render_for(Audience::Operator).contains("load_config"));
+    /// assert!(err.render_for(Audience::Admin).contains("bad syntax"));
+    /// ```
+    pub fn render_for(&self, audience: Audience) -> String {
+        let permanence = if self.retryable { "temporary" } else { "permanent" };
+        match audience {
+            Audience::External => format!("Request failed ({})", self.code),
+            Audience::Partner => format!(
+                "{} request failed [{}] ({})",
+                self.code.category().deceptive_name(),
+                permanence,
+                self.code
+            ),
+            Audience::Operator => format!(
+                "{} request failed [{}] ({}) operation='{}'",
+                self.code.category().display_name(),
+                permanence,
+                self.code,
+                self.context.operation.as_str()
+            ),
+            Audience::Admin => {
+                let raw_code = crate::obfuscation::deobfuscate_code(&self.code);
+                format!(
+                    "{} request failed [{}] ({}, real: {}) operation='{}' details='{}'",
+                    self.code.category().display_name(),
+                    permanence,
+                    self.code,
+                    raw_code,
+                    self.context.operation.as_str(),
+                    self.context.details.as_str()
+                )
+            }
+        }
+    }
+
     // Convenience constructors for each subsystem.
     // See "Design Rationale - Error Constructors" above for why these exist
     // despite apparent redundancy with ErrorCode categories.
@@ -553,9 +1337,9 @@ impl AgentError {
     /// Create a configuration error
     #[inline]
     pub fn config(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -563,10 +1347,10 @@ impl AgentError {
     /// Create a configuration error with sensitive context
     #[inline]
     pub fn config_sensitive(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>, 
-        sensitive: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
+        sensitive: impl Into<SmallString>,
     ) -> Self {
         Self::new_sensitive(code, operation, details, sensitive)
     }
@@ -574,9 +1358,9 @@ impl AgentError {
     /// Create a deployment error
     #[inline]
     pub fn deployment(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -584,9 +1368,9 @@ impl AgentError {
     /// Create a telemetry error
     #[inline]
     pub fn telemetry(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -594,9 +1378,9 @@ impl AgentError {
     /// Create a correlation error
     #[inline]
     pub fn correlation(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -604,9 +1388,9 @@ impl AgentError {
     /// Create a response error
     #[inline]
     pub fn response(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -614,9 +1398,9 @@ impl AgentError {
     /// Create a logging error
     #[inline]
     pub fn logging(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -624,9 +1408,9 @@ impl AgentError {
     /// Create a platform error
     #[inline]
     pub fn platform(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -634,9 +1418,9 @@ impl AgentError {
     /// Create an I/O operation error
     #[inline]
     pub fn io_operation(
-        code: ErrorCode, 
-        operation: impl Into<Cow<'static, str>>, 
-        details: impl Into<Cow<'static, str>>,
+        code: ErrorCode,
+        operation: impl Into<SmallString>,
+        details: impl Into<SmallString>,
     ) -> Self {
         Self::new(code, operation, details)
     }
@@ -652,8 +1436,8 @@ impl AgentError {
     #[inline]
     pub fn from_io_path(
         code: ErrorCode,
-        operation: impl Into<Cow<'static, str>>,
-        path: impl Into<Cow<'static, str>>,
+        operation: impl Into<SmallString>,
+        path: impl Into<SmallString>,
         error: io::Error,
     ) -> Self {
         Self::new_with_split_source(
@@ -715,6 +1499,7 @@ impl AgentError {
 }
 
 // Manual Drop implementation to ensure proper zeroization ordering
+#[cfg(not(feature = "no_std"))]
 impl Drop for AgentError {
     /// Panic-safe drop with explicit zeroization order.
     ///
@@ -726,19 +1511,26 @@ impl Drop for AgentError {
             // Drop the source error first (may contain sensitive data)
             // By setting to None, we ensure the boxed error is dropped
             self.source = None;
-            
+
             // Context zeroizes itself via ZeroizeOnDrop
             // but we're explicit here for documentation
             self.context.zeroize();
+
+            #[cfg(feature = "backtrace")]
+            {
+                self.backtrace = None;
+            }
         }));
     }
 }
 
+#[cfg(not(feature = "no_std"))]
 impl fmt::Debug for AgentError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AgentError")
             .field("code", &self.code)
             .field("category", &self.code.category())
+            .field("trace_id", &self.trace_id)
             .field("retryable", &self.retryable)
             .field("age", &self.created_at.elapsed())
             .field("context", &"<REDACTED>")
@@ -747,6 +1539,7 @@ impl fmt::Debug for AgentError {
     }
 }
 
+#[cfg(not(feature = "no_std"))]
 impl fmt::Display for AgentError {
     /// External display - sanitized for untrusted viewers.
     /// Zero-allocation formatting.
@@ -767,24 +1560,195 @@ impl fmt::Display for AgentError {
     /// - Configuration values
     /// - Timing information
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let permanence = if self.retryable { "temporary" } else { "permanent" };
-        write!(
-            f,
-            "{} operation failed [{}] ({})",
-            self.code.category().display_name(),
-            permanence,
-            self.code  // ErrorCode::Display also writes directly
-        )
+        self.fmt_with_style(f, AgentError::default_style())
     }
 }
 
+/// Structured, JSON-friendly mirror of [`Display`](fmt::Display)/[`Debug`](fmt::Debug)'s
+/// already-sanitized fields, for automation that wants to parse retry
+/// semantics and error codes without scraping the human-readable string.
+///
+/// Serializes exactly `code`, `category`, `retryable`, and a coarse
+/// `age_secs` - nothing this type's `Debug` impl doesn't already show in
+/// some form. `context` and `source` have no path into this impl at all, so
+/// there is no field to forget to redact as either grows; unlike
+/// [`Self::to_json`] (which renders the full [`InternalLog`] for a trusted
+/// sink), this is safe to hand an untrusted caller.
+///
+/// `age_secs` is whole seconds, not [`Self::age`]'s full [`Duration`] -
+/// sub-second precision is exactly the resolution a timing side-channel
+/// would want, and no external consumer needs it.
+///
+/// # Feature Gate
+///
+/// Entirely behind the `serde` feature, so the core path never takes a hard
+/// `serde` dependency - the same reasoning as [`InternalLog`]'s own
+/// `serde::Serialize` impl in `logging.rs`.
+///
+/// # Example
+///
+/// ```rust
+/// # use palisade_errors::{AgentError, definitions};
+/// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "password=hunter2");
+/// let json = serde_json::to_string(&err).unwrap();
+/// assert!(json.contains("\"retryable\":"));
+/// assert!(!json.contains("hunter2"));
+/// ```
+#[cfg(all(feature = "serde", not(feature = "no_std")))]
+impl serde::Serialize for AgentError {
+    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("AgentError", 4)?;
+        state.serialize_field("code", &self.code.to_string())?;
+        state.serialize_field("category", self.category().display_name())?;
+        state.serialize_field("retryable", &self.retryable)?;
+        state.serialize_field("age_secs", &self.age().as_secs())?;
+        state.end()
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl AgentError {
+    /// Set the thread-level default [`ErrorStyle`] used by `Display`.
+    ///
+    /// Affects only the calling thread - each thread starts at
+    /// [`ErrorStyle::Plain`].
+    #[inline]
+    pub fn set_default_style(style: ErrorStyle) {
+        DEFAULT_ERROR_STYLE.with(|s| s.set(style));
+    }
+
+    /// The current thread-level default [`ErrorStyle`].
+    #[inline]
+    pub fn default_style() -> ErrorStyle {
+        DEFAULT_ERROR_STYLE.with(std::cell::Cell::get)
+    }
+
+    /// Render this error's external `Display` in a specific [`ErrorStyle`],
+    /// ignoring the thread-level default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{AgentError, ErrorStyle, definitions};
+    ///
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+    /// let plain = format!("{}", err.display_with_style(ErrorStyle::Plain));
+    /// let fancy = format!("{}", err.display_with_style(ErrorStyle::Fancy));
+    /// assert_ne!(plain, fancy);
+    /// ```
+    #[inline]
+    pub const fn display_with_style(&self, style: ErrorStyle) -> StyledDisplay<'_> {
+        StyledDisplay { error: self, style }
+    }
+
+    /// This error's [`AgeBucket`] - how long ago it was constructed, coarse
+    /// enough to carry no timing side channel. See [`Self::age`]'s docs for
+    /// why the underlying [`Duration`] itself must never be shown externally.
+    #[inline]
+    pub fn age_bucket(&self) -> AgeBucket {
+        AgeBucket::from_age(self.age())
+    }
+
+    /// Render this error's external `Display` at a specific
+    /// [`DisplayVerbosity`], ignoring the default `Full` verbosity.
+    ///
+    /// Composes with [`Self::display_with_style`]: verbosity picks how much
+    /// content to include, style picks how to lay it out. This only ever
+    /// renders with [`ErrorStyle::Plain`] layout - reach for
+    /// [`Self::display_with_style`] directly if `Fancy` framing is also
+    /// wanted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{AgentError, DisplayVerbosity, definitions};
+    ///
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+    /// let code_only = format!("{}", err.display_with_verbosity(DisplayVerbosity::CodeOnly));
+    /// assert_eq!(code_only, err.code().to_string());
+    /// ```
+    #[inline]
+    pub const fn display_with_verbosity(&self, verbosity: DisplayVerbosity) -> VerbosityDisplay<'_> {
+        VerbosityDisplay { error: self, verbosity }
+    }
+
+    /// Shared rendering body for [`VerbosityDisplay`]. See
+    /// [`DisplayVerbosity`]'s variants for what each level includes; like
+    /// [`Self::fmt_with_style`], never reveals anything beyond category,
+    /// permanence, code, correlation ref, and (at [`DisplayVerbosity::FullWithAge`])
+    /// a bucketed age.
+    fn fmt_with_verbosity(&self, f: &mut fmt::Formatter<'_>, verbosity: DisplayVerbosity) -> fmt::Result {
+        match verbosity {
+            DisplayVerbosity::CodeOnly => write!(f, "{}", self.code),
+            DisplayVerbosity::Full => self.fmt_with_style(f, ErrorStyle::Plain),
+            DisplayVerbosity::FullWithAge => {
+                self.fmt_with_style(f, ErrorStyle::Plain)?;
+                write!(f, " [age: {}]", self.age_bucket())
+            }
+        }
+    }
+
+    /// Shared rendering body for both `Display::fmt` and [`StyledDisplay`].
+    ///
+    /// Format ("Plain"): "{Category} operation failed [{permanence}] ({ERROR-CODE})"
+    /// Example: "Configuration operation failed [permanent] (E-CFG-100)"
+    ///
+    /// Format ("Fancy"): the same fields framed in a box-drawn layout.
+    ///
+    /// This provides:
+    /// - Operation domain (for troubleshooting)
+    /// - Retry semantics (for automation)
+    /// - Error code (for tracking)
+    /// - A correlation ID (opaque reference the user can quote in a support
+    ///   ticket)
+    ///
+    /// Without revealing:
+    /// - Internal paths or structure
+    /// - Validation logic
+    /// - User identifiers
+    /// - Configuration values
+    /// - Timing information
+    fn fmt_with_style(&self, f: &mut fmt::Formatter<'_>, style: ErrorStyle) -> fmt::Result {
+        let permanence = crate::locale::resolved_permanence_word(self.retryable);
+        let category = crate::locale::resolved_category_name(self.code.category());
+        match style {
+            ErrorStyle::Plain => write!(
+                f,
+                "{} operation failed [{}] ({}) [ref: {}]",
+                category,
+                permanence,
+                self.code, // ErrorCode::Display also writes directly
+                self.trace_id
+            ),
+            ErrorStyle::Fancy => write!(
+                f,
+                "┌─ Error ──────────────────────\n\
+                 │ {} operation failed [{}]\n\
+                 │ code: {}\n\
+                 │ ref:  {}\n\
+                 └──────────────────────────────",
+                category,
+                permanence,
+                self.code,
+                self.trace_id
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
 impl std::error::Error for AgentError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         self.source.as_ref().map(|e| e.as_ref() as &(dyn std::error::Error + 'static))
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "no_std")))]
 mod unit_tests {
     use super::*;
     use std::thread;
@@ -849,6 +1813,62 @@ mod unit_tests {
         assert!(displayed.contains("E-IO-800"));
     }
 
+    #[test]
+    fn code_only_verbosity_reveals_no_details() {
+        crate::obfuscation::clear_session_salt();
+        let err = AgentError::from_io_path(
+            definitions::IO_READ_FAILED,
+            "load_config",
+            "/etc/shadow",
+            io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+
+        let displayed = format!("{}", err.display_with_verbosity(DisplayVerbosity::CodeOnly));
+
+        assert!(!displayed.contains("/etc"));
+        assert!(!displayed.contains("shadow"));
+        assert!(!displayed.contains("load_config"));
+        assert_eq!(displayed, "E-IO-800");
+    }
+
+    #[test]
+    fn full_with_age_verbosity_reveals_no_details_or_precise_timing() {
+        crate::obfuscation::clear_session_salt();
+        let err = AgentError::from_io_path(
+            definitions::IO_READ_FAILED,
+            "load_config",
+            "/etc/shadow",
+            io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+
+        let displayed = format!("{}", err.display_with_verbosity(DisplayVerbosity::FullWithAge));
+
+        assert!(!displayed.contains("/etc"));
+        assert!(!displayed.contains("shadow"));
+        assert!(!displayed.contains("load_config"));
+        assert!(displayed.contains("E-IO-800"));
+        assert!(displayed.contains("age: just now"));
+        // No raw duration formatting (e.g. "123.456" or a "ns"/"µs" suffix)
+        // should ever leak through the bucket.
+        assert!(!displayed.contains("ns]"));
+        assert!(!displayed.contains("µs"));
+    }
+
+    #[test]
+    fn full_verbosity_matches_plain_display() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(
+            format!("{}", err.display_with_verbosity(DisplayVerbosity::Full)),
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn age_bucket_starts_just_now() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(err.age_bucket(), AgeBucket::JustNow);
+    }
+
     #[test]
     fn internal_log_contains_details() {
         let err = AgentError::config(
@@ -876,4 +1896,218 @@ mod unit_tests {
         
         assert!(age2 > age1);
     }
+
+    #[test]
+    fn default_style_is_plain() {
+        assert_eq!(AgentError::default_style(), ErrorStyle::Plain);
+    }
+
+    #[test]
+    fn plain_style_matches_unstyled_display() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(
+            format!("{}", err),
+            format!("{}", err.display_with_style(ErrorStyle::Plain))
+        );
+    }
+
+    #[test]
+    fn fancy_style_frames_the_same_fields_without_leaking_more() {
+        let err = AgentError::from_io_path(
+            definitions::IO_READ_FAILED,
+            "load_config",
+            "/etc/shadow",
+            io::Error::from(io::ErrorKind::PermissionDenied)
+        );
+
+        let plain = format!("{}", err.display_with_style(ErrorStyle::Plain));
+        let fancy = format!("{}", err.display_with_style(ErrorStyle::Fancy));
+
+        assert_ne!(plain, fancy);
+        assert!(fancy.contains("E-IO-800"));
+        assert!(fancy.contains("I/O"));
+        assert!(!fancy.contains("/etc"));
+        assert!(!fancy.contains("shadow"));
+        assert!(!fancy.contains("load_config"));
+    }
+
+    #[test]
+    fn set_default_style_affects_unstyled_display_on_this_thread() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+
+        AgentError::set_default_style(ErrorStyle::Fancy);
+        assert_eq!(AgentError::default_style(), ErrorStyle::Fancy);
+        assert_eq!(format!("{}", err), format!("{}", err.display_with_style(ErrorStyle::Fancy)));
+
+        // Reset so later tests in this thread see the documented default.
+        AgentError::set_default_style(ErrorStyle::Plain);
+    }
+
+    #[test]
+    fn render_for_reveals_more_detail_at_higher_audiences() {
+        crate::obfuscation::clear_session_salt();
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "load_config", "bad syntax");
+
+        let external = err.render_for(Audience::External);
+        let partner = err.render_for(Audience::Partner);
+        let operator = err.render_for(Audience::Operator);
+        let admin = err.render_for(Audience::Admin);
+
+        assert!(!external.contains("load_config"));
+        assert!(!external.contains("bad syntax"));
+        assert!(!external.contains("Configuration"));
+
+        assert!(partner.contains("Configuration"));
+        assert!(!partner.contains("load_config"));
+
+        assert!(operator.contains("load_config"));
+        assert!(!operator.contains("bad syntax"));
+
+        assert!(admin.contains("load_config"));
+        assert!(admin.contains("bad syntax"));
+    }
+
+    #[test]
+    fn audience_tiers_are_ordered_least_to_most_trusted() {
+        assert!(Audience::External < Audience::Partner);
+        assert!(Audience::Partner < Audience::Operator);
+        assert!(Audience::Operator < Audience::Admin);
+    }
+
+    #[test]
+    fn request_ref_finds_the_error_code() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        let code = err.request_ref::<ErrorCode>().expect("ErrorCode is always provided");
+        assert_eq!(code.category(), OperationCategory::Configuration);
+    }
+
+    #[test]
+    fn request_value_finds_category_and_trace_id() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(err.request_value::<OperationCategory>(), Some(OperationCategory::Configuration));
+        assert_eq!(err.request_value::<crate::trace_id::TraceId>(), Some(err.trace_id()));
+    }
+
+    #[test]
+    fn request_value_of_an_unprovided_type_is_none() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(err.request_value::<u8>(), None);
+    }
+
+    #[test]
+    fn request_value_also_works_through_a_downcast_source() {
+        let inner = AgentError::io_operation(definitions::IO_READ_FAILED, "read", "disk error");
+        let inner_trace_id = inner.trace_id();
+        let mut outer = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        outer.source = Some(Box::new(inner));
+
+        // `provide` offers the same fixed set of types at every level, so
+        // the outer error's own answer always wins for them...
+        assert_eq!(outer.request_value::<crate::trace_id::TraceId>(), Some(outer.trace_id()));
+        // ...but the boxed source is still reachable directly, and
+        // implements this same lookup over its own fields.
+        let source = outer
+            .source()
+            .and_then(|s| s.downcast_ref::<AgentError>())
+            .expect("source is a boxed AgentError");
+        assert_eq!(source.request_value::<crate::trace_id::TraceId>(), Some(inner_trace_id));
+    }
+
+    #[test]
+    fn report_iterates_every_link_in_a_multi_level_chain() {
+        let inner = AgentError::io_operation(definitions::IO_READ_FAILED, "read", "disk error");
+        let mut outer = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        outer.source = Some(Box::new(inner));
+
+        assert_eq!(outer.report().count(), 2);
+    }
+
+    #[test]
+    fn report_write_internal_redacts_a_non_agent_error_source_by_default() {
+        let mut outer = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        outer.source = Some(Box::new(io::Error::new(io::ErrorKind::Other, "/etc/shadow")));
+
+        let mut out = String::new();
+        outer.report().write_internal(&mut out).unwrap();
+        assert!(!out.contains("/etc/shadow"));
+    }
+
+    #[test]
+    fn report_write_internal_reveals_a_non_agent_error_source_when_opted_out() {
+        let mut outer = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        outer.source = Some(Box::new(io::Error::new(io::ErrorKind::Other, "/etc/shadow")));
+
+        let mut out = String::new();
+        outer.report().redact_sources(false).write_internal(&mut out).unwrap();
+        assert!(out.contains("/etc/shadow"));
+    }
+
+    #[cfg(feature = "internal_backtrace")]
+    #[test]
+    fn internal_backtrace_is_captured_automatically_and_stays_internal() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert!(err.internal_backtrace().is_some());
+        assert!(err.internal_log().backtrace_text().is_some());
+        assert!(!err.to_string().contains("backtrace"));
+    }
+
+    #[cfg(all(feature = "backtrace", not(feature = "internal_backtrace")))]
+    #[test]
+    fn backtrace_is_captured_automatically_for_breach_level_codes() {
+        let breach_code = ErrorCode::checked_new(
+            &crate::codes::namespaces::DCP,
+            999,
+            OperationCategory::Deception,
+            ImpactScore::new(960),
+        )
+        .expect("DCP permits Deception/Breach");
+        assert_eq!(breach_code.impact_level(), ErrorImpact::Breach);
+
+        let err = AgentError::platform(breach_code, "persona_check", "narrative contradiction");
+        assert!(err.internal_log().backtrace_text().is_some());
+        assert!(!err.to_string().contains("backtrace"));
+    }
+
+    #[cfg(all(feature = "backtrace", not(feature = "internal_backtrace")))]
+    #[test]
+    fn backtrace_is_not_captured_automatically_for_routine_codes() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(err.code().impact_level(), ErrorImpact::Jitter);
+        assert!(err.internal_log().backtrace_text().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_includes_only_the_externally_safe_fields() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "password=hunter2");
+        let json = serde_json::to_string(&err).unwrap();
+
+        assert!(json.contains(&format!("\"code\":\"{}\"", err.code())));
+        assert!(json.contains("\"category\":\"Configuration\""));
+        assert!(json.contains("\"retryable\":"));
+        assert!(json.contains("\"age_secs\":"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_never_includes_details_or_source() {
+        let mut err = AgentError::config_sensitive(definitions::CFG_PARSE_FAILED, "boot", "bad syntax", "hunter2");
+        err.source = Some(Box::new(io::Error::new(io::ErrorKind::Other, "/etc/shadow")));
+        let json = serde_json::to_string(&err).unwrap();
+
+        assert!(!json.contains("hunter2"));
+        assert!(!json.contains("bad syntax"));
+        assert!(!json.contains("/etc/shadow"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_age_is_whole_seconds_not_a_full_duration() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        let json = serde_json::to_string(&err).unwrap();
+
+        assert!(!json.contains("nanos"));
+        assert!(!json.contains("secs"));
+        assert!(json.contains("age_secs"));
+    }
 }
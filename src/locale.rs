@@ -0,0 +1,166 @@
+// src/locale.rs
+//! Pluggable localization for the handful of static words `Display` emits.
+//!
+//! # Purpose
+//!
+//! Borrows the shape of rustc's `fallback_fluent_bundle`/`FluentBundle`
+//! pair: [`AgentError`](crate::AgentError)'s external `Display` only ever
+//! says two kinds of thing in a natural language - the operation category
+//! (via [`OperationCategory::display_name`](crate::models::OperationCategory::display_name))
+//! and the permanence word ("temporary"/"permanent"). [`MessageBundle`] lets
+//! a host translate those words for its operators, with the crate's
+//! built-in English strings as the unconditional fallback when a bundle
+//! has nothing for a given key - an error never goes untranslated, it just
+//! falls back to English.
+//!
+//! # What Is Never Translatable
+//!
+//! Only the two static words above ever route through a bundle. The
+//! error's `context`, `details`, and `source` fields are never passed to a
+//! [`MessageBundle`] implementation - doing so would hand a translation
+//! layer a plausible-looking excuse to interpolate attacker- or
+//! operator-supplied text into rendered output, which is exactly the kind
+//! of data-leak vector this crate's trust boundary exists to prevent.
+//!
+//! # Fast Path
+//!
+//! [`EnglishBundle`], the default, returns `None` from both lookups so the
+//! caller falls through to the zero-allocation `&'static str` constants
+//! every other `Display` path already uses. Only a caller that installs a
+//! real translation via [`set_message_bundle`] pays the (small, `String`)
+//! allocation cost of a translated render.
+
+use crate::models::OperationCategory;
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// A pluggable source of translated category/permanence words.
+///
+/// Both methods default to `None` - "no translation for this key" - so an
+/// implementation only needs to override the keys it actually has
+/// coverage for; everything else falls back to the built-in English word.
+pub trait MessageBundle {
+    /// The locale this bundle serves, e.g. `"fr"` or `"ja"`. Informational
+    /// only - lookups never branch on it, callers may use it for logging
+    /// which bundle is active.
+    fn locale(&self) -> &str;
+
+    /// Translated label for `category`, or `None` to fall back to
+    /// [`OperationCategory::display_name`].
+    fn category_name(&self, category: OperationCategory) -> Option<&str> {
+        let _ = category;
+        None
+    }
+
+    /// Translated word for "temporary" (`retryable`) or "permanent"
+    /// (`!retryable`), or `None` to fall back to the built-in English word.
+    fn permanence_word(&self, retryable: bool) -> Option<&str> {
+        let _ = retryable;
+        None
+    }
+}
+
+/// The no-op fallback bundle: translates nothing, so every lookup falls
+/// through to the built-in English constant on the zero-allocation path.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EnglishBundle;
+
+impl MessageBundle for EnglishBundle {
+    fn locale(&self) -> &str {
+        "en"
+    }
+}
+
+thread_local! {
+    static MESSAGE_BUNDLE: RefCell<Box<dyn MessageBundle>> = RefCell::new(Box::new(EnglishBundle));
+}
+
+/// Install a thread-level [`MessageBundle`] used by `Display` for category
+/// and permanence wording. Any key the bundle doesn't cover still falls
+/// back to English.
+pub fn set_message_bundle(bundle: impl MessageBundle + 'static) {
+    MESSAGE_BUNDLE.with(|cell| *cell.borrow_mut() = Box::new(bundle));
+}
+
+/// Restore the default, untranslated [`EnglishBundle`].
+pub fn reset_message_bundle() {
+    MESSAGE_BUNDLE.with(|cell| *cell.borrow_mut() = Box::new(EnglishBundle));
+}
+
+/// Resolve `category`'s display label through the active bundle, falling
+/// back to [`OperationCategory::display_name`].
+///
+/// Borrowed (no allocation) on the default, untranslated path; owned only
+/// when a real translation is returned.
+pub(crate) fn resolved_category_name(category: OperationCategory) -> Cow<'static, str> {
+    MESSAGE_BUNDLE.with(|cell| match cell.borrow().category_name(category) {
+        Some(translated) => Cow::Owned(translated.to_string()),
+        None => Cow::Borrowed(category.display_name()),
+    })
+}
+
+/// Resolve the "temporary"/"permanent" word through the active bundle,
+/// falling back to the built-in English word.
+///
+/// Borrowed (no allocation) on the default, untranslated path; owned only
+/// when a real translation is returned.
+pub(crate) fn resolved_permanence_word(retryable: bool) -> Cow<'static, str> {
+    MESSAGE_BUNDLE.with(|cell| match cell.borrow().permanence_word(retryable) {
+        Some(translated) => Cow::Owned(translated.to_string()),
+        None => Cow::Borrowed(if retryable { "temporary" } else { "permanent" }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FrenchBundle;
+
+    impl MessageBundle for FrenchBundle {
+        fn locale(&self) -> &str {
+            "fr"
+        }
+
+        fn category_name(&self, category: OperationCategory) -> Option<&str> {
+            match category {
+                OperationCategory::Configuration => Some("Configuration"),
+                OperationCategory::IO => Some("E/S"),
+                _ => None,
+            }
+        }
+
+        fn permanence_word(&self, retryable: bool) -> Option<&str> {
+            Some(if retryable { "temporaire" } else { "permanent" })
+        }
+    }
+
+    #[test]
+    fn default_bundle_falls_back_to_english_for_every_key() {
+        reset_message_bundle();
+        assert_eq!(
+            resolved_category_name(OperationCategory::IO),
+            Cow::Borrowed("I/O")
+        );
+        assert_eq!(resolved_permanence_word(true), Cow::Borrowed("temporary"));
+        assert_eq!(resolved_permanence_word(false), Cow::Borrowed("permanent"));
+    }
+
+    #[test]
+    fn installed_bundle_is_used_where_it_has_coverage() {
+        set_message_bundle(FrenchBundle);
+        assert_eq!(resolved_category_name(OperationCategory::IO), Cow::Borrowed("E/S"));
+        assert_eq!(resolved_permanence_word(true), Cow::Borrowed("temporaire"));
+        reset_message_bundle();
+    }
+
+    #[test]
+    fn installed_bundle_falls_back_to_english_for_uncovered_keys() {
+        set_message_bundle(FrenchBundle);
+        assert_eq!(
+            resolved_category_name(OperationCategory::Detection),
+            Cow::Borrowed(OperationCategory::Detection.display_name())
+        );
+        reset_message_bundle();
+    }
+}
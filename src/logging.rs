@@ -17,16 +17,97 @@
 //! sensitive data cannot be retained beyond its intended scope.
 
 use crate::ErrorCode;
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write as _;
+#[cfg(not(feature = "no_std"))]
 use std::borrow::Cow;
-use std::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::string::{String, ToString};
+#[cfg(not(feature = "no_std"))]
+use std::vec::Vec;
 use zeroize::Zeroize;
 
 /// Maximum length for any individual field in formatted output (DoS prevention)
 const MAX_FIELD_OUTPUT_LEN: usize = 1024;
 
+/// Maximum length for a rendered backtrace in formatted output. Wider than
+/// [`MAX_FIELD_OUTPUT_LEN`] - a deep stack's symbol names legitimately run
+/// into the tens of frames - but still bounded, so a captured backtrace
+/// can't blow through [`crate::ring_buffer::RingBufferLogger`]'s per-entry
+/// `max_entry_bytes` cap the way an untruncated one could.
+#[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+const MAX_BACKTRACE_OUTPUT_LEN: usize = 4096;
+
 /// Truncation indicator appended to truncated strings
 const TRUNCATION_INDICATOR: &str = "...[TRUNCATED]";
 
+/// Hex digits of a redaction token - enough to correlate repeat occurrences
+/// of the same secret across a process's logs without materializing a full
+/// digest anywhere in the output.
+const REDACTION_TOKEN_HEX_LEN: usize = 8;
+
+/// Per-process key used to compute stable [`InternalLog::write_redacted`]
+/// tokens.
+///
+/// # No Clone Policy
+///
+/// Matches [`crate::integrity::SigningKey`]: single-owner, zeroized on
+/// drop, never duplicated across memory.
+pub struct RedactionKey(Vec<u8>);
+
+impl RedactionKey {
+    /// Wrap raw key bytes for use with `InternalLog::write_redacted()`.
+    #[inline]
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+        Self(bytes.into())
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Zeroize for RedactionKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for RedactionKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Compute a printk `%pK`-style redaction token for `value`: a keyed-hash
+/// prefix that is identical for identical secrets under the same key (so
+/// operators can correlate and deduplicate occurrences) but does not
+/// disclose the value itself.
+///
+/// Reuses [`crate::integrity::hmac_sha256`] rather than pulling in a
+/// dedicated keyed-hash crate, matching this crate's "implement the small
+/// primitive ourselves" posture elsewhere in the `no_std`-compatible path.
+fn redaction_token(key: &RedactionKey, value: &str) -> String {
+    let mut buf = value.as_bytes().to_vec();
+    let digest = crate::integrity::hmac_sha256(key.as_bytes(), &buf);
+    buf.zeroize();
+
+    let mut token = String::with_capacity(REDACTION_TOKEN_HEX_LEN);
+    for byte in &digest[..REDACTION_TOKEN_HEX_LEN / 2] {
+        token.push_str(&format!("{:02x}", byte));
+    }
+    token
+}
+
 /// Metadata value wrapper with zeroization for owned data.
 ///
 /// Borrowed values are assumed static and are not zeroized.
@@ -40,6 +121,14 @@ impl ContextField {
     pub fn as_str(&self) -> &str {
         self.value.as_ref()
     }
+
+    /// True for owned (runtime-constructed) values - the same split
+    /// [`Zeroize`] uses to decide what needs scrubbing, reused by
+    /// [`InternalLog::write_redacted`] to decide what needs redacting.
+    #[inline]
+    fn is_owned(&self) -> bool {
+        matches!(self.value, Cow::Owned(_))
+    }
 }
 
 impl From<&'static str> for ContextField {
@@ -95,12 +184,17 @@ impl Drop for ContextField {
 #[derive(Debug)]
 pub struct InternalLog<'a> {
     pub code: &'a ErrorCode,
+    /// Correlation ID stamped on the `AgentError` this log was built from.
+    #[cfg(not(feature = "no_std"))]
+    pub trace_id: crate::trace_id::TraceId,
     pub operation: &'a str,
     pub details: &'a str,
     pub source_internal: Option<&'a str>,
     pub source_sensitive: Option<&'a str>,
     pub metadata: &'a [(&'static str, ContextField)],
     pub retryable: bool,
+    #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+    pub backtrace: Option<crate::backtrace::BacktraceSource<'a>>,
 }
 
 impl<'a> InternalLog<'a> {
@@ -129,6 +223,9 @@ impl<'a> InternalLog<'a> {
             truncate_with_indicator(self.details)
         );
 
+        #[cfg(not(feature = "no_std"))]
+        output.push_str(&format!(" trace={}", self.trace_id));
+
         if let Some(internal) = self.source_internal {
             output.push_str(&format!(
                 " source='{}'",
@@ -151,6 +248,11 @@ impl<'a> InternalLog<'a> {
             ));
         }
 
+        #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+        if let Some(backtrace) = &self.backtrace {
+            output.push_str(&format!("\nbacktrace:\n{}", truncate_backtrace(backtrace.text())));
+        }
+
         output
     }
 
@@ -179,6 +281,9 @@ impl<'a> InternalLog<'a> {
             truncate_with_indicator(self.details)
         )?;
 
+        #[cfg(not(feature = "no_std"))]
+        write!(f, " trace={}", self.trace_id)?;
+
         if let Some(internal) = self.source_internal {
             write!(f, " source='{}'", truncate_with_indicator(internal))?;
         }
@@ -196,9 +301,150 @@ impl<'a> InternalLog<'a> {
             )?;
         }
 
+        #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+        if let Some(backtrace) = &self.backtrace {
+            write!(f, "\nbacktrace:\n{}", truncate_backtrace(backtrace.text()))?;
+        }
+
         Ok(())
     }
 
+    /// Write structured log data to a formatter the way printk's `%pK`
+    /// formatter writes pointers: sensitive fields are replaced with a
+    /// stable, keyed-hash token rather than either the plaintext (`write_to`)
+    /// or nothing at all.
+    ///
+    /// `source_sensitive`, and any metadata [`ContextField`] built from owned
+    /// (runtime-constructed) data, are redacted to
+    /// `<redacted:XXXXXXXX>`, where the hex token is a prefix of an
+    /// HMAC-SHA256 over the field's bytes keyed by `key`. The same secret
+    /// under the same key always redacts to the same token, so operators can
+    /// correlate and deduplicate occurrences across a process's logs without
+    /// ever seeing the plaintext. Non-sensitive fields print as in
+    /// `write_to`.
+    ///
+    /// Unlike `format_for_trusted_debug`, this is available in production
+    /// builds - it never materializes the sensitive plaintext into the
+    /// output at all, so there is no `trusted_debug` boundary to cross.
+    pub fn write_redacted(&self, f: &mut impl fmt::Write, key: &RedactionKey) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {} operation='{}' details='{}'",
+            self.code,
+            if self.retryable { "[RETRYABLE]" } else { "" },
+            truncate_with_indicator(self.operation),
+            truncate_with_indicator(self.details)
+        )?;
+
+        #[cfg(not(feature = "no_std"))]
+        write!(f, " trace={}", self.trace_id)?;
+
+        if let Some(internal) = self.source_internal {
+            write!(f, " source='{}'", truncate_with_indicator(internal))?;
+        }
+
+        if let Some(sensitive) = self.source_sensitive {
+            write!(f, " sensitive=<redacted:{}>", redaction_token(key, sensitive))?;
+        }
+
+        for (field_key, value) in self.metadata {
+            if value.is_owned() {
+                write!(f, " {}=<redacted:{}>", field_key, redaction_token(key, value.as_str()))?;
+            } else {
+                write!(
+                    f,
+                    " {}='{}'",
+                    field_key,
+                    truncate_with_indicator(value.as_str())
+                )?;
+            }
+        }
+
+        #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+        if let Some(backtrace) = &self.backtrace {
+            write!(f, "\nbacktrace:\n{}", truncate_backtrace(backtrace.text()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write this log entry as a single-line JSON object, for log shippers
+    /// and SIEM pipelines that want structured fields instead of scraping
+    /// `write_to`'s human-readable line.
+    ///
+    /// Like `write_to` and `encode`, this streams field-by-field directly
+    /// into `f` - there is no intermediate `serde_json::Value` tree, so
+    /// passing a reused buffer across many calls keeps the zero-allocation
+    /// construction path intact all the way to the wire for `&'static str`
+    /// fields.
+    ///
+    /// `code` is the (obfuscated) code an on-session observer would see;
+    /// `code_raw` is the pre-obfuscation code, recovered via
+    /// [`crate::obfuscation::deobfuscate_code`] under the *current* session
+    /// salt - see that function's docs for the same-session caveat.
+    /// `category` and `impact_level` are the code's
+    /// [`crate::models::OperationCategory`]/[`crate::codes::ErrorImpact`]
+    /// labels, and `timestamp` is the Unix time (seconds) this record was
+    /// serialized - none of which `Display`'s external string reveals. The
+    /// remaining fields mirror `write_to`: sensitive fields print in the
+    /// clear here, matching the internal-log trust boundary (use
+    /// `write_redacted` instead for output that may reach a less-trusted
+    /// sink).
+    ///
+    /// # Errors
+    ///
+    /// Propagates any formatting error from `f`.
+    #[cfg(not(feature = "no_std"))]
+    pub fn write_json(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        let raw_code = crate::obfuscation::deobfuscate_code(self.code);
+
+        f.write_str("{\"code\":")?;
+        write_json_string(f, &self.code.to_string())?;
+        f.write_str(",\"code_raw\":")?;
+        write_json_string(f, &raw_code.to_string())?;
+        f.write_str(",\"category\":")?;
+        write_json_string(f, self.code.category().display_name())?;
+        f.write_str(",\"impact_level\":")?;
+        write_json_string(f, self.code.impact_level().label())?;
+        f.write_str(",\"trace_id\":")?;
+        write_json_string(f, &self.trace_id.to_string())?;
+        write!(f, ",\"timestamp\":{}", current_unix_timestamp())?;
+        f.write_str(",\"operation\":")?;
+        write_json_string(f, &truncate_with_indicator(self.operation))?;
+        f.write_str(",\"details\":")?;
+        write_json_string(f, &truncate_with_indicator(self.details))?;
+        write!(f, ",\"retryable\":{}", self.retryable)?;
+
+        if let Some(internal) = self.source_internal {
+            f.write_str(",\"source_internal\":")?;
+            write_json_string(f, &truncate_with_indicator(internal))?;
+        }
+
+        if let Some(sensitive) = self.source_sensitive {
+            f.write_str(",\"source_sensitive\":")?;
+            write_json_string(f, &truncate_with_indicator(sensitive))?;
+        }
+
+        f.write_str(",\"metadata\":{")?;
+        for (i, (key, value)) in self.metadata.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write_json_string(f, key)?;
+            f.write_str(":")?;
+            write_json_string(f, &truncate_with_indicator(value.as_str()))?;
+        }
+        f.write_str("}")?;
+
+        #[cfg(any(feature = "backtrace", feature = "internal_backtrace"))]
+        if let Some(backtrace) = &self.backtrace {
+            f.write_str(",\"backtrace\":")?;
+            write_json_string(f, &truncate_backtrace(backtrace.text()))?;
+        }
+
+        f.write_str("}")
+    }
+
     /// Access structured fields for JSON/structured logging.
     ///
     /// Preferred over string formatting because it allows the logging
@@ -211,6 +457,35 @@ impl<'a> InternalLog<'a> {
         self.code
     }
 
+    /// The code a viewer of *this* internal log should see, honoring the
+    /// current thread's disclosure policy: the real, pre-obfuscation code if
+    /// [`crate::config::PalisadeConfig::reveal_real_code_internally`] was
+    /// set via [`crate::config::PalisadeConfig::init`], otherwise the same
+    /// obfuscated code [`Self::code`] always returns. `code_raw` in
+    /// [`Self::write_json`]'s output is unaffected by this policy and always
+    /// carries the real code - this method is for viewers that only look at
+    /// one "the" code per entry.
+    #[cfg(all(feature = "toml_config", not(feature = "no_std")))]
+    pub fn disclosed_code(&self) -> ErrorCode {
+        if crate::config::reveal_real_code_internally() {
+            crate::obfuscation::deobfuscate_code(self.code)
+        } else {
+            ErrorCode::const_new(
+                self.code.namespace(),
+                self.code.code(),
+                self.code.category(),
+                self.code.impact(),
+            )
+        }
+    }
+
+    /// This log entry's correlation ID - see [`crate::trace_id`].
+    #[cfg(not(feature = "no_std"))]
+    #[inline]
+    pub const fn trace_id(&self) -> crate::trace_id::TraceId {
+        self.trace_id
+    }
+
     #[inline]
     pub const fn operation(&self) -> &str {
         self.operation
@@ -245,6 +520,916 @@ impl<'a> InternalLog<'a> {
     pub const fn is_retryable(&self) -> bool {
         self.retryable
     }
+
+    /// The resolved text of the backtrace captured via
+    /// [`crate::AgentError::with_backtrace`] (the `backtrace` feature) or
+    /// automatically at construction time (the `internal_backtrace`
+    /// feature), if either captured one. Symbol resolution happens lazily
+    /// on the first call and is cached for later ones.
+    #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+    #[inline]
+    pub fn backtrace_text(&self) -> Option<&str> {
+        self.backtrace.as_ref().map(crate::backtrace::BacktraceSource::text)
+    }
+
+    /// Deep-copy this borrowed log into an [`OwnedLog`] that can outlive the
+    /// error that produced it - e.g. to enqueue onto a [`crate::funnel::LogFunnel`]
+    /// for a background drainer, instead of formatting on the hot path.
+    ///
+    /// Every owned field keeps the same zeroize-on-drop guarantee `AgentError`
+    /// gives `InternalLog`'s borrowed fields; see [`OwnedLog`].
+    pub fn into_owned(&self) -> OwnedLog {
+        OwnedLog {
+            code: ErrorCode::const_new(
+                self.code.namespace(),
+                self.code.code(),
+                self.code.category(),
+                self.code.impact(),
+            ),
+            #[cfg(not(feature = "no_std"))]
+            trace_id: self.trace_id,
+            operation: self.operation.to_string(),
+            details: self.details.to_string(),
+            source_internal: self.source_internal.map(str::to_string),
+            source_sensitive: self.source_sensitive.map(str::to_string),
+            metadata: self
+                .metadata
+                .iter()
+                .map(|(key, value)| (*key, ContextField::from(value.as_str().to_string())))
+                .collect(),
+            retryable: self.retryable,
+            #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+            backtrace: self.backtrace.as_ref().map(|b| b.text().to_string()),
+        }
+    }
+
+    /// Encode into a compact, versioned binary wire format, for honeypot
+    /// deployments that want to ship captured errors off-box to a
+    /// collector without the overhead of formatting strings first.
+    ///
+    /// Layout: a leading `u16` schema version, then the error code (its
+    /// `E-<NAMESPACE>-<CODE>` display form), the trace ID as a fixed 16-byte
+    /// big-endian integer (see [`crate::trace_id::TraceId::as_u128`]),
+    /// operation, details, optional internal source, optional sensitive
+    /// source, a one-byte retry flag, and finally a `u16` count of metadata
+    /// pairs. Every variable-length field is prefixed with its length as a
+    /// `u32`; optional fields are preceded by a single presence byte.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any I/O error from `out`.
+    #[cfg(not(feature = "no_std"))]
+    pub fn encode(&self, out: &mut impl std::io::Write) -> std::io::Result<()> {
+        out.write_all(&WIRE_FORMAT_VERSION.to_be_bytes())?;
+        write_field(out, self.code.to_string().as_bytes())?;
+        out.write_all(&self.trace_id.as_u128().to_be_bytes())?;
+        write_field(out, self.operation.as_bytes())?;
+        write_field(out, self.details.as_bytes())?;
+        write_optional_field(out, self.source_internal)?;
+        write_optional_field(out, self.source_sensitive)?;
+        out.write_all(&[self.retryable as u8])?;
+        out.write_all(&(self.metadata.len() as u16).to_be_bytes())?;
+        for (key, value) in self.metadata {
+            write_field(out, key.as_bytes())?;
+            write_field(out, value.as_str().as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Decode a buffer produced by [`Self::encode`] back into an
+    /// [`OwnedLog`].
+    ///
+    /// Rejects unknown or truncated schema versions, and bounds every
+    /// length prefix against the bytes actually remaining in `buf` before
+    /// slicing - a malicious or truncated buffer makes this return an
+    /// error, never allocate more than `buf.len()` worth of data or panic.
+    ///
+    /// # Metadata Keys
+    ///
+    /// `OwnedLog`'s metadata keys are `&'static str`, matching
+    /// [`InternalLog`]'s - so a decoded key is interned via [`Box::leak`]
+    /// to get that lifetime. The leak is bounded by `buf.len()` per call
+    /// and only happens on this (comparatively rare) wire-decode path, not
+    /// on the zero-allocation constructor hot path the rest of the crate
+    /// is built around.
+    ///
+    /// # Errors
+    ///
+    /// See [`DecodeError`].
+    #[cfg(not(feature = "no_std"))]
+    pub fn decode(buf: &[u8]) -> Result<OwnedLog, DecodeError> {
+        let mut cursor = 0usize;
+        let version = read_u16(buf, &mut cursor)?;
+        if version != WIRE_FORMAT_VERSION && version != WIRE_FORMAT_VERSION_V1_NO_TRACE_ID {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+
+        let code = ErrorCode::parse(read_str_field(buf, &mut cursor, "code")?)
+            .map_err(DecodeError::InvalidCode)?;
+        // Version 1 predates `trace_id` entirely - there is nothing to read
+        // back for it, so synthesize a fresh one rather than leaving the
+        // field unset.
+        let trace_id = if version == WIRE_FORMAT_VERSION_V1_NO_TRACE_ID {
+            crate::trace_id::TraceId::generate()
+        } else {
+            crate::trace_id::TraceId::from_u128(read_u128(buf, &mut cursor)?)
+        };
+        let operation = read_str_field(buf, &mut cursor, "operation")?.to_string();
+        let details = read_str_field(buf, &mut cursor, "details")?.to_string();
+        let source_internal =
+            read_optional_str_field(buf, &mut cursor, "source_internal")?.map(str::to_string);
+        let source_sensitive =
+            read_optional_str_field(buf, &mut cursor, "source_sensitive")?.map(str::to_string);
+        let retryable = read_u8(buf, &mut cursor)? != 0;
+
+        let metadata_count = read_u16(buf, &mut cursor)?;
+        let mut metadata = Vec::new();
+        for _ in 0..metadata_count {
+            let key = read_str_field(buf, &mut cursor, "metadata_key")?.to_string();
+            let value = read_str_field(buf, &mut cursor, "metadata_value")?.to_string();
+            let key: &'static str = Box::leak(key.into_boxed_str());
+            metadata.push((key, ContextField::from(value)));
+        }
+
+        Ok(OwnedLog {
+            code,
+            trace_id,
+            operation,
+            details,
+            source_internal,
+            source_sensitive,
+            metadata,
+            retryable,
+            #[cfg(any(feature = "backtrace", feature = "internal_backtrace"))]
+            backtrace: None,
+        })
+    }
+}
+
+// ============================================================================
+// Emitters (Pluggable Output Formats)
+// ============================================================================
+
+/// Renders an [`InternalLog`] into a specific output format.
+///
+/// Modeled on rustc's `Emitter`/`EmitterWriter` split - the same prior art
+/// [`crate::context::Emitter`] borrows for `ContextChain` rendering, this
+/// is the [`InternalLog`] analogue. Decoupled from *destination*, which is
+/// [`crate::sink::LogSink`]'s job: a `LogSink` decides where an entry goes,
+/// a `LogEmitter` decides what it looks like once it gets there.
+pub trait LogEmitter {
+    /// Render `log`, appending to whatever destination this emitter wraps.
+    fn emit(&mut self, log: &InternalLog<'_>);
+}
+
+/// Renders each [`InternalLog`] as the same single-line
+/// `[E-CFG-103] operation='...' details='...'` format [`InternalLog::write_to`]
+/// produces, accumulating one line per [`LogEmitter::emit`] call.
+#[derive(Debug, Default)]
+pub struct HumanLogEmitter {
+    output: String,
+}
+
+impl HumanLogEmitter {
+    /// Create an emitter with an empty output buffer.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every line emitted so far, newline-separated.
+    #[inline]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Consume the emitter, taking ownership of its accumulated output.
+    #[inline]
+    pub fn into_output(self) -> String {
+        self.output
+    }
+}
+
+impl LogEmitter for HumanLogEmitter {
+    fn emit(&mut self, log: &InternalLog<'_>) {
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        log.write_to(&mut self.output).expect("String writes are infallible");
+    }
+}
+
+/// How [`JsonLogEmitter`] handles `source_sensitive`: this crate's internal
+/// log is a trusted-sink format (see [`InternalLog::write_to`]'s own
+/// docs), but a caller routing [`JsonLogEmitter`]'s output somewhere less
+/// trusted than that - a shared SIEM index, say - still needs a way to
+/// keep the raw sensitive text out of it. Defaults to [`Self::Omit`], the
+/// same redact-by-default stance [`crate::report::Report`] takes for
+/// unproven source links.
+pub enum SensitiveSourcePolicy {
+    /// Replace the field with a stable [`RedactionKey`]-keyed token, the
+    /// same scheme [`InternalLog::write_redacted`] uses.
+    Hash(RedactionKey),
+    /// Drop the field entirely.
+    Omit,
+}
+
+// Hand-written rather than derived: `RedactionKey` deliberately has no
+// `Debug` of its own (see its own docs) so the key material never ends up
+// in a log or test failure message - derive would either fail to compile
+// or, if `RedactionKey` grew a derive to satisfy it, defeat the point of
+// that restriction.
+impl fmt::Debug for SensitiveSourcePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hash(_) => f.write_str("Hash(<redacted>)"),
+            Self::Omit => f.write_str("Omit"),
+        }
+    }
+}
+
+impl Default for SensitiveSourcePolicy {
+    fn default() -> Self {
+        Self::Omit
+    }
+}
+
+/// Renders each [`InternalLog`] as a single JSON object per
+/// [`LogEmitter::emit`] call, one line each - code, operation, details,
+/// both source fields (subject to [`SensitiveSourcePolicy`]), the
+/// retryable flag, and metadata. Deliberately narrower than
+/// [`InternalLog::write_json`]'s schema: no `category`/`impact_level`/
+/// `trace_id`/`timestamp`, and never `created_at`/age, since those exist
+/// to correlate and enrich a record a human or SIEM already trusts, not to
+/// feed a pluggable sink that might forward it somewhere less trusted.
+///
+/// Hand-rolled JSON via [`write_json_string`], matching
+/// [`InternalLog::write_json`] - no serde dependency for this crate's core
+/// path.
+#[derive(Debug)]
+pub struct JsonLogEmitter {
+    output: String,
+    sensitive_policy: SensitiveSourcePolicy,
+}
+
+impl Default for JsonLogEmitter {
+    fn default() -> Self {
+        Self::new(SensitiveSourcePolicy::default())
+    }
+}
+
+impl JsonLogEmitter {
+    /// Create an emitter with an empty output buffer and the given
+    /// [`SensitiveSourcePolicy`].
+    #[inline]
+    pub fn new(sensitive_policy: SensitiveSourcePolicy) -> Self {
+        Self {
+            output: String::new(),
+            sensitive_policy,
+        }
+    }
+
+    /// Every JSON object emitted so far, newline-separated.
+    #[inline]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Consume the emitter, taking ownership of its accumulated output.
+    #[inline]
+    pub fn into_output(self) -> String {
+        self.output
+    }
+
+    fn write_entry(&mut self, log: &InternalLog<'_>) -> fmt::Result {
+        let out = &mut self.output;
+        out.write_str("{\"code\":")?;
+        write_json_string(out, &log.code.to_string())?;
+        out.write_str(",\"operation\":")?;
+        write_json_string(out, &truncate_with_indicator(log.operation))?;
+        out.write_str(",\"details\":")?;
+        write_json_string(out, &truncate_with_indicator(log.details))?;
+
+        if let Some(internal) = log.source_internal {
+            out.write_str(",\"source_internal\":")?;
+            write_json_string(out, &truncate_with_indicator(internal))?;
+        }
+
+        if let Some(sensitive) = log.source_sensitive {
+            match &self.sensitive_policy {
+                SensitiveSourcePolicy::Hash(key) => {
+                    out.write_str(",\"source_sensitive\":")?;
+                    write_json_string(out, &redaction_token(key, sensitive))?;
+                }
+                SensitiveSourcePolicy::Omit => {}
+            }
+        }
+
+        write!(out, ",\"retryable\":{}", log.retryable)?;
+
+        out.write_str(",\"metadata\":{")?;
+        for (i, (key, value)) in log.metadata.iter().enumerate() {
+            if i > 0 {
+                out.write_str(",")?;
+            }
+            write_json_string(out, key)?;
+            out.write_str(":")?;
+            write_json_string(out, &truncate_with_indicator(value.as_str()))?;
+        }
+        out.write_str("}}")
+    }
+}
+
+impl LogEmitter for JsonLogEmitter {
+    fn emit(&mut self, log: &InternalLog<'_>) {
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        self.write_entry(log).expect("String writes are infallible");
+    }
+}
+
+// ============================================================================
+// Binary Wire Format
+// ============================================================================
+
+/// Schema version for [`InternalLog::encode`]'s wire format.
+///
+/// [`InternalLog::decode`] rejects any version it doesn't recognize
+/// outright rather than guessing at a different layout, so the format can
+/// evolve by bumping this constant and adding a new decode arm, never by
+/// overloading the meaning of the existing one.
+#[cfg(not(feature = "no_std"))]
+const WIRE_FORMAT_VERSION: u16 = 2;
+
+/// Version 1 of the wire format shipped before [`crate::trace_id::TraceId`]
+/// existed on [`InternalLog`]/[`OwnedLog`] - [`OwnedLog::decode`] still
+/// accepts it for compatibility with anything already captured on disk,
+/// synthesizing a fresh [`crate::trace_id::TraceId`] for the field the old
+/// format never wrote.
+#[cfg(not(feature = "no_std"))]
+const WIRE_FORMAT_VERSION_V1_NO_TRACE_ID: u16 = 1;
+
+#[cfg(not(feature = "no_std"))]
+fn write_field(out: &mut impl std::io::Write, bytes: &[u8]) -> std::io::Result<()> {
+    out.write_all(&(bytes.len() as u32).to_be_bytes())?;
+    out.write_all(bytes)
+}
+
+#[cfg(not(feature = "no_std"))]
+fn write_optional_field(out: &mut impl std::io::Write, value: Option<&str>) -> std::io::Result<()> {
+    match value {
+        Some(s) => {
+            out.write_all(&[1])?;
+            write_field(out, s.as_bytes())
+        }
+        None => out.write_all(&[0]),
+    }
+}
+
+/// Write `s` as a quoted, escaped JSON string. Shared by
+/// [`InternalLog::write_json`] and [`crate::ring_buffer::RingBufferLogger::export_json`].
+///
+/// Backslash, double quote, and the C0 control characters are escaped per
+/// the JSON spec; everything else - including multi-byte UTF-8 like emoji -
+/// is copied through unescaped, since JSON strings are UTF-8 by definition.
+///
+/// Unconditional, unlike most of this file's helpers: only uses
+/// `core::fmt::Write` and `char` matching, so - unlike
+/// [`current_unix_timestamp`] or the wire `read_exact`/`write_*` helpers -
+/// it has no `std`-only dependency to gate on.
+pub(crate) fn write_json_string(f: &mut impl fmt::Write, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Unix timestamp (seconds) for [`InternalLog::write_json`]'s `timestamp`
+/// field, matching how [`crate::ring_buffer::ForensicEntry`] stamps its own
+/// `timestamp`/`last_seen` fields.
+#[cfg(not(feature = "no_std"))]
+fn current_unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_exact<'b>(buf: &'b [u8], cursor: &mut usize, len: usize) -> Option<&'b [u8]> {
+    let end = cursor.checked_add(len)?;
+    if end > buf.len() {
+        return None;
+    }
+    let slice = &buf[*cursor..end];
+    *cursor = end;
+    Some(slice)
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_u8(buf: &[u8], cursor: &mut usize) -> Result<u8, DecodeError> {
+    read_exact(buf, cursor, 1)
+        .map(|b| b[0])
+        .ok_or(DecodeError::UnexpectedEof)
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_u16(buf: &[u8], cursor: &mut usize) -> Result<u16, DecodeError> {
+    read_exact(buf, cursor, 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or(DecodeError::UnexpectedEof)
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_u32(buf: &[u8], cursor: &mut usize) -> Result<u32, DecodeError> {
+    read_exact(buf, cursor, 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(DecodeError::UnexpectedEof)
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_u128(buf: &[u8], cursor: &mut usize) -> Result<u128, DecodeError> {
+    read_exact(buf, cursor, 16)
+        .map(|b| u128::from_be_bytes(b.try_into().expect("read_exact(.., 16) returns 16 bytes")))
+        .ok_or(DecodeError::UnexpectedEof)
+}
+
+/// Read a length-prefixed field, bounding the claimed length against the
+/// bytes actually remaining in `buf` before slicing - the guard that keeps
+/// [`InternalLog::decode`] from being an allocation-bomb vector.
+#[cfg(not(feature = "no_std"))]
+fn read_field<'b>(buf: &'b [u8], cursor: &mut usize, field: &'static str) -> Result<&'b [u8], DecodeError> {
+    let len = read_u32(buf, cursor)? as usize;
+    let remaining = buf.len() - *cursor;
+    if len > remaining {
+        return Err(DecodeError::LengthOutOfBounds {
+            field,
+            len: len as u32,
+            remaining,
+        });
+    }
+    Ok(read_exact(buf, cursor, len).expect("length already checked against remaining buffer"))
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_str_field<'b>(buf: &'b [u8], cursor: &mut usize, field: &'static str) -> Result<&'b str, DecodeError> {
+    core::str::from_utf8(read_field(buf, cursor, field)?).map_err(|_| DecodeError::InvalidUtf8 { field })
+}
+
+#[cfg(not(feature = "no_std"))]
+fn read_optional_str_field<'b>(
+    buf: &'b [u8],
+    cursor: &mut usize,
+    field: &'static str,
+) -> Result<Option<&'b str>, DecodeError> {
+    if read_u8(buf, cursor)? == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(read_str_field(buf, cursor, field)?))
+    }
+}
+
+/// Errors from [`InternalLog::decode`].
+///
+/// # Security
+///
+/// Every variant is reachable by feeding arbitrary bytes from an untrusted
+/// wire - a collector terminating connections from honeypot sensors must
+/// never panic or allocate unboundedly on malformed input. Each length
+/// prefix is bounds-checked against the bytes remaining in the buffer
+/// before any slicing or allocation happens.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The buffer ended before a fixed-size field (version, length prefix,
+    /// presence byte) could be read.
+    UnexpectedEof,
+    /// The leading version field doesn't match any layout this build
+    /// understands.
+    UnsupportedVersion(u16),
+    /// A length prefix claims more bytes than remain in the buffer.
+    LengthOutOfBounds {
+        field: &'static str,
+        len: u32,
+        remaining: usize,
+    },
+    /// A field's bytes are not valid UTF-8.
+    InvalidUtf8 { field: &'static str },
+    /// The embedded error code string doesn't parse back into a registered
+    /// [`ErrorCode`].
+    InvalidCode(crate::codes::InternalErrorCodeViolation),
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "buffer ended before a fixed-size field could be read"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported wire format version {}", version),
+            Self::LengthOutOfBounds { field, len, remaining } => write!(
+                f,
+                "{} field claims {} bytes but only {} remain in the buffer",
+                field, len, remaining
+            ),
+            Self::InvalidUtf8 { field } => write!(f, "{} field is not valid UTF-8", field),
+            Self::InvalidCode(violation) => write!(f, "embedded error code is invalid: {}", violation),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidCode(violation) => Some(violation),
+            _ => None,
+        }
+    }
+}
+
+/// Owned, zeroizing counterpart to [`InternalLog`] that can outlive the
+/// error that produced it.
+///
+/// # Purpose
+///
+/// `InternalLog` is deliberately borrow-bound so sensitive data cannot be
+/// retained beyond its intended scope - but that rules out the deferred
+/// logging pattern used by embedded loggers like `cortex-m-funnel`, where
+/// producers push records onto a queue and a separate consumer formats and
+/// drains them later, off the hot path. `OwnedLog` is the type that crosses
+/// that boundary deliberately: every field is deep-copied into its own
+/// zeroizing buffer, so the "scrub sensitive data on drop" guarantee still
+/// holds even though the value now outlives the originating error.
+///
+/// # Use Case
+///
+/// Produced by [`InternalLog::into_owned`]; typically pushed onto a
+/// [`crate::funnel::LogFunnel`] from a latency-sensitive call site and
+/// formatted later by [`Self::as_internal_log`] plus
+/// [`InternalLog::write_to`] on a background drain thread.
+///
+/// # No Clone Policy
+///
+/// Matches [`ContextField`]/[`crate::integrity::SigningKey`]: single-owner,
+/// zeroized on drop, never duplicated across memory.
+#[derive(Debug)]
+pub struct OwnedLog {
+    code: ErrorCode,
+    #[cfg(not(feature = "no_std"))]
+    trace_id: crate::trace_id::TraceId,
+    operation: String,
+    details: String,
+    source_internal: Option<String>,
+    source_sensitive: Option<String>,
+    metadata: Vec<(&'static str, ContextField)>,
+    retryable: bool,
+    #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+    backtrace: Option<String>,
+}
+
+impl OwnedLog {
+    /// Borrow this owned log back as an [`InternalLog`] so callers can reuse
+    /// the existing `write_to`/`log_kv`/`slog_kv` formatting instead of
+    /// duplicating it for the owned representation.
+    pub fn as_internal_log(&self) -> InternalLog<'_> {
+        InternalLog {
+            code: &self.code,
+            #[cfg(not(feature = "no_std"))]
+            trace_id: self.trace_id,
+            operation: &self.operation,
+            details: &self.details,
+            source_internal: self.source_internal.as_deref(),
+            source_sensitive: self.source_sensitive.as_deref(),
+            metadata: &self.metadata,
+            retryable: self.retryable,
+            #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+            backtrace: self
+                .backtrace
+                .as_deref()
+                .map(crate::backtrace::BacktraceSource::Resolved),
+        }
+    }
+}
+
+impl Zeroize for OwnedLog {
+    fn zeroize(&mut self) {
+        self.operation.zeroize();
+        self.details.zeroize();
+        if let Some(s) = &mut self.source_internal {
+            s.zeroize();
+        }
+        if let Some(s) = &mut self.source_sensitive {
+            s.zeroize();
+        }
+        // `metadata`'s `ContextField` entries zeroize themselves on drop.
+        #[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+        if let Some(s) = &mut self.backtrace {
+            s.zeroize();
+        }
+    }
+}
+
+impl Drop for OwnedLog {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// `slog` structured key-value integration.
+///
+/// # Feature Gate
+///
+/// Entirely behind the `slog_kv` feature, so the core path never takes a
+/// hard dependency on `slog`.
+///
+/// # Security
+///
+/// Mirrors the `log_kv` gate above: every field emits as its own slog
+/// field instead of `write_to`'s flattened string, so slog's own drains
+/// can truncate/filter instead of `MAX_FIELD_OUTPUT_LEN`. `source_sensitive`
+/// only emits its real value under `all(feature = "trusted_debug",
+/// debug_assertions)`; otherwise it still emits the key, with the same
+/// `"[SENSITIVE REDACTED]"` marker used everywhere else in this crate.
+#[cfg(feature = "slog_kv")]
+impl<'a> slog::KV for InternalLog<'a> {
+    fn serialize(&self, record: &slog::Record, serializer: &mut dyn slog::Serializer) -> slog::Result {
+        serializer.emit_arguments("code", &format_args!("{}", self.code))?;
+        serializer.emit_str("operation", self.operation)?;
+        serializer.emit_str("details", self.details)?;
+        serializer.emit_bool("retryable", self.retryable)?;
+
+        if let Some(internal) = self.source_internal {
+            serializer.emit_str("source_internal", internal)?;
+        }
+
+        if let Some(sensitive) = self.source_sensitive {
+            #[cfg(all(feature = "trusted_debug", debug_assertions))]
+            serializer.emit_str("source_sensitive", sensitive)?;
+            #[cfg(not(all(feature = "trusted_debug", debug_assertions)))]
+            {
+                let _ = sensitive;
+                serializer.emit_str("source_sensitive", "[SENSITIVE REDACTED]")?;
+            }
+        }
+
+        for (key, value) in self.metadata {
+            serializer.emit_str(key, value.as_str())?;
+        }
+
+        let _ = record;
+        Ok(())
+    }
+}
+
+/// `log` crate structured key-value integration.
+///
+/// # Feature Gate
+///
+/// Entirely behind the `log_kv` feature, so the core path never takes a
+/// hard dependency on the `log` crate's kv API.
+///
+/// # Security
+///
+/// Every field visits as a discrete key-value pair so a JSON/structured
+/// sink never has to re-parse `write_to`'s flattened string - except
+/// `source_sensitive`, which only visits its real value under
+/// `all(feature = "trusted_debug", debug_assertions)`; otherwise the
+/// framework still visits the key, but with the same `"[SENSITIVE
+/// REDACTED]"` marker used everywhere else in this crate, so a subscriber
+/// can tell the field existed without ever seeing its value.
+#[cfg(feature = "log_kv")]
+impl<'a> log::kv::Source for InternalLog<'a> {
+    fn visit<'kvs>(
+        &'kvs self,
+        visitor: &mut dyn log::kv::VisitSource<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        visitor.visit_pair(log::kv::Key::from_str("code"), log::kv::Value::from_display(self.code))?;
+        visitor.visit_pair(log::kv::Key::from_str("operation"), log::kv::Value::from(self.operation))?;
+        visitor.visit_pair(log::kv::Key::from_str("details"), log::kv::Value::from(self.details))?;
+        visitor.visit_pair(log::kv::Key::from_str("retryable"), log::kv::Value::from(self.retryable))?;
+
+        if let Some(internal) = self.source_internal {
+            visitor.visit_pair(log::kv::Key::from_str("source_internal"), log::kv::Value::from(internal))?;
+        }
+
+        if let Some(sensitive) = self.source_sensitive {
+            #[cfg(all(feature = "trusted_debug", debug_assertions))]
+            let value = log::kv::Value::from(sensitive);
+            #[cfg(not(all(feature = "trusted_debug", debug_assertions)))]
+            let value = {
+                let _ = sensitive;
+                log::kv::Value::from("[SENSITIVE REDACTED]")
+            };
+            visitor.visit_pair(log::kv::Key::from_str("source_sensitive"), value)?;
+        }
+
+        for (key, value) in self.metadata {
+            visitor.visit_pair(log::kv::Key::from_str(key), log::kv::Value::from(value.as_str()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How [`InternalLog`]'s `serde::Serialize` impl renders a field it judges
+/// sensitive (`source_sensitive`, and any metadata [`ContextField`] built
+/// from owned data - the same split [`InternalLog::write_redacted`] uses).
+///
+/// # Feature Gate
+///
+/// Entirely behind the `serde` feature, matching this crate's other
+/// `serde`-gated modules.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionStyle {
+    /// Replace the value with a fixed placeholder string (default: `"***"`).
+    Sentinel(&'static str),
+    /// Replace the value with `{"redacted":true,"len":N}`, letting a
+    /// consumer flag unusually large sensitive payloads without ever
+    /// seeing their content.
+    LengthOnly,
+}
+
+/// Wire shape `RedactionStyle::LengthOnly` serializes to.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct RedactedLength {
+    redacted: bool,
+    len: usize,
+}
+
+/// Policy controlling how [`InternalLog::serialize_with`] (and the default
+/// `serde::Serialize` impl, which uses [`SerializeOptions::default`]) renders
+/// each field.
+///
+/// # Defaults
+///
+/// Sensitive fields redact to `RedactionStyle::Sentinel("***")`; other
+/// fields truncate at the same field-length cap `write_to`/`write_redacted`
+/// use.
+///
+/// # No Clone Policy Exception
+///
+/// Unlike `RedactionKey`/`ContextField`/`OwnedLog`, this type holds no
+/// sensitive data itself - just formatting knobs - so it derives `Clone`
+/// like any other plain options struct.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone)]
+pub struct SerializeOptions {
+    redaction: RedactionStyle,
+    max_field_len: usize,
+    reveal_sensitive: bool,
+}
+
+#[cfg(feature = "serde")]
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            redaction: RedactionStyle::Sentinel("***"),
+            max_field_len: MAX_FIELD_OUTPUT_LEN,
+            reveal_sensitive: false,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl SerializeOptions {
+    /// Start from the default policy (see the struct docs).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Choose how sensitive fields are rendered. Default is
+    /// `RedactionStyle::Sentinel("***")`.
+    pub fn with_redaction_style(mut self, style: RedactionStyle) -> Self {
+        self.redaction = style;
+        self
+    }
+
+    /// Cap non-sensitive field length before serializing, reusing
+    /// `truncate_with_indicator`'s UTF-8-boundary logic. Default is
+    /// `MAX_FIELD_OUTPUT_LEN`.
+    pub fn with_max_field_len(mut self, max_field_len: usize) -> Self {
+        self.max_field_len = max_field_len;
+        self
+    }
+
+    /// Opt into serializing sensitive fields' raw values instead of
+    /// redacting them.
+    ///
+    /// Mirrors `format_for_trusted_debug`'s gate: only available with BOTH
+    /// the `trusted_debug` feature flag AND debug assertions, preventing
+    /// accidental use in production.
+    #[cfg(all(feature = "trusted_debug", debug_assertions))]
+    pub fn reveal_sensitive(mut self) -> Self {
+        self.reveal_sensitive = true;
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_redactable_field<M>(
+    map: &mut M,
+    options: &SerializeOptions,
+    key: &str,
+    value: &str,
+) -> Result<(), M::Error>
+where
+    M: serde::ser::SerializeMap,
+{
+    if options.reveal_sensitive {
+        return map.serialize_entry(key, truncate_to_len(value, options.max_field_len).as_ref());
+    }
+
+    match options.redaction {
+        RedactionStyle::Sentinel(sentinel) => map.serialize_entry(key, sentinel),
+        RedactionStyle::LengthOnly => map.serialize_entry(
+            key,
+            &RedactedLength {
+                redacted: true,
+                len: value.len(),
+            },
+        ),
+    }
+}
+
+/// `serde::Serialize` support for [`InternalLog`], emitting a map of its
+/// fields for JSON (or any other `serde` data format) structured logging -
+/// the same role the `log` crate's own `serde` bridge plays for `log::Record`.
+///
+/// # Feature Gate
+///
+/// Entirely behind the `serde` feature, so the core path never takes a hard
+/// `serde` dependency, matching `serde_support.rs`'s reasoning.
+///
+/// # Security
+///
+/// The blanket `serde::Serialize` impl below always uses
+/// `SerializeOptions::default()`, redacting `source_sensitive` and any owned
+/// metadata value per [`RedactionStyle`]. Call [`InternalLog::serialize_with`]
+/// directly for a custom [`SerializeOptions`] - e.g. opting into
+/// `reveal_sensitive()` under `trusted_debug` + debug assertions, matching
+/// `format_for_trusted_debug`'s own gate.
+#[cfg(feature = "serde")]
+impl<'a> InternalLog<'a> {
+    /// Serialize with an explicit [`SerializeOptions`] policy instead of the
+    /// blanket `serde::Serialize` impl's default (redact-everything-sensitive)
+    /// behavior.
+    pub fn serialize_with<S>(&self, options: &SerializeOptions, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", &self.code.to_string())?;
+        map.serialize_entry("category", self.code.category().display_name())?;
+        map.serialize_entry("impact_level", self.code.impact_level().label())?;
+        #[cfg(not(feature = "no_std"))]
+        map.serialize_entry("trace_id", &self.trace_id.to_string())?;
+        #[cfg(not(feature = "no_std"))]
+        map.serialize_entry("timestamp", &current_unix_timestamp())?;
+        map.serialize_entry("operation", truncate_to_len(self.operation, options.max_field_len).as_ref())?;
+        map.serialize_entry("details", truncate_to_len(self.details, options.max_field_len).as_ref())?;
+        map.serialize_entry("retryable", &self.retryable)?;
+
+        if let Some(internal) = self.source_internal {
+            map.serialize_entry("source_internal", truncate_to_len(internal, options.max_field_len).as_ref())?;
+        }
+
+        if let Some(sensitive) = self.source_sensitive {
+            serialize_redactable_field(&mut map, options, "source_sensitive", sensitive)?;
+        }
+
+        for (key, value) in self.metadata {
+            if value.is_owned() {
+                serialize_redactable_field(&mut map, options, key, value.as_str())?;
+            } else {
+                map.serialize_entry(key, truncate_to_len(value.as_str(), options.max_field_len).as_ref())?;
+            }
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for InternalLog<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.serialize_with(&SerializeOptions::default(), serializer)
+    }
 }
 
 /// Truncate a string for display to prevent DoS via extremely long error messages.
@@ -254,12 +1439,28 @@ impl<'a> InternalLog<'a> {
 ///
 /// Returns a Cow<str> to avoid allocation when no truncation is needed.
 fn truncate_with_indicator(s: &str) -> Cow<'_, str> {
-    if s.len() <= MAX_FIELD_OUTPUT_LEN {
+    truncate_to_len(s, MAX_FIELD_OUTPUT_LEN)
+}
+
+/// [`truncate_with_indicator`], but capped at [`MAX_BACKTRACE_OUTPUT_LEN`]
+/// instead of [`MAX_FIELD_OUTPUT_LEN`] - a resolved backtrace is expected to
+/// run much longer than an ordinary field.
+#[cfg(all(any(feature = "backtrace", feature = "internal_backtrace"), not(feature = "no_std")))]
+fn truncate_backtrace(s: &str) -> Cow<'_, str> {
+    truncate_to_len(s, MAX_BACKTRACE_OUTPUT_LEN)
+}
+
+/// Parameterized form of [`truncate_with_indicator`], used by
+/// [`SerializeOptions::with_max_field_len`] so callers can pick a different
+/// cap than the crate-wide default while reusing the same UTF-8-boundary
+/// logic.
+fn truncate_to_len(s: &str, max_len: usize) -> Cow<'_, str> {
+    if s.len() <= max_len {
         return Cow::Borrowed(s);
     }
 
     // Reserve space for the truncation indicator
-    let max_content_len = MAX_FIELD_OUTPUT_LEN.saturating_sub(TRUNCATION_INDICATOR.len());
+    let max_content_len = max_len.saturating_sub(TRUNCATION_INDICATOR.len());
 
     // Find the last valid UTF-8 character boundary at or before the limit
     let mut idx = max_content_len;
@@ -279,9 +1480,10 @@ fn truncate_with_indicator(s: &str) -> Cow<'_, str> {
     Cow::Owned(result)
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "no_std")))]
 mod tests {
     use super::*;
+    use crate::AgentError;
 
     #[test]
     fn truncate_ascii() {
@@ -382,4 +1584,480 @@ mod tests {
         // Value should still be intact (static string)
         assert_eq!(field.as_str(), "static");
     }
+
+    fn sample_log(error: &AgentError) -> InternalLog<'_> {
+        error.internal_log()
+    }
+
+    #[test]
+    fn write_redacted_never_contains_sensitive_plaintext() {
+        let error = AgentError::config_sensitive(
+            crate::definitions::CFG_PARSE_FAILED,
+            "boot",
+            "bad syntax",
+            "db password hunter2",
+        );
+        let key = RedactionKey::new(b"test-redaction-key".to_vec());
+
+        let mut out = String::new();
+        sample_log(&error).write_redacted(&mut out, &key).unwrap();
+
+        assert!(!out.contains("hunter2"));
+        assert!(out.contains("sensitive=<redacted:"));
+    }
+
+    #[test]
+    fn write_redacted_same_secret_same_key_yields_same_token() {
+        let error_a = AgentError::config_sensitive(
+            crate::definitions::CFG_PARSE_FAILED,
+            "boot",
+            "bad syntax",
+            "hunter2",
+        );
+        let error_b = AgentError::config_sensitive(
+            crate::definitions::CFG_PARSE_FAILED,
+            "reboot",
+            "also bad syntax",
+            "hunter2",
+        );
+        let key = RedactionKey::new(b"test-redaction-key".to_vec());
+
+        let mut out_a = String::new();
+        sample_log(&error_a).write_redacted(&mut out_a, &key).unwrap();
+        let mut out_b = String::new();
+        sample_log(&error_b).write_redacted(&mut out_b, &key).unwrap();
+
+        let token_of = |s: &str| {
+            s.split("sensitive=<redacted:")
+                .nth(1)
+                .unwrap()
+                .split('>')
+                .next()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(token_of(&out_a), token_of(&out_b));
+    }
+
+    #[test]
+    fn write_redacted_different_key_yields_different_token() {
+        let error = AgentError::config_sensitive(
+            crate::definitions::CFG_PARSE_FAILED,
+            "boot",
+            "bad syntax",
+            "hunter2",
+        );
+
+        let mut out_a = String::new();
+        sample_log(&error)
+            .write_redacted(&mut out_a, &RedactionKey::new(b"key-a".to_vec()))
+            .unwrap();
+        let mut out_b = String::new();
+        sample_log(&error)
+            .write_redacted(&mut out_b, &RedactionKey::new(b"key-b".to_vec()))
+            .unwrap();
+
+        assert_ne!(out_a, out_b);
+    }
+
+    #[test]
+    fn write_redacted_leaves_non_sensitive_fields_readable() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        let key = RedactionKey::new(b"test-redaction-key".to_vec());
+
+        let mut out = String::new();
+        sample_log(&error).write_redacted(&mut out, &key).unwrap();
+
+        assert!(out.contains("operation='boot'"));
+        assert!(out.contains("details='bad syntax'"));
+    }
+
+    #[test]
+    fn write_json_includes_category_impact_level_and_timestamp() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+
+        let mut out = String::new();
+        sample_log(&error).write_json(&mut out).unwrap();
+
+        assert!(out.contains("\"category\":"));
+        assert!(out.contains("\"impact_level\":"));
+        assert!(out.contains("\"timestamp\":"));
+    }
+
+    #[cfg(feature = "toml_config")]
+    #[test]
+    fn disclosed_code_honors_the_configured_policy() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        let log = sample_log(&error);
+
+        crate::config::PalisadeConfig {
+            session_salt: Some(7),
+            reveal_real_code_internally: false,
+            ..Default::default()
+        }
+        .init();
+        assert_eq!(log.disclosed_code().code(), log.code().code());
+
+        crate::config::PalisadeConfig {
+            session_salt: Some(7),
+            reveal_real_code_internally: true,
+            ..Default::default()
+        }
+        .init();
+        assert_eq!(
+            log.disclosed_code().code(),
+            crate::obfuscation::deobfuscate_code(log.code()).code()
+        );
+
+        crate::obfuscation::clear_session_salt();
+        crate::config::PalisadeConfig::default().init();
+    }
+
+    #[test]
+    fn human_log_emitter_matches_write_to() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        let mut expected = String::new();
+        sample_log(&error).write_to(&mut expected).unwrap();
+
+        let mut emitter = HumanLogEmitter::new();
+        error.emit_to(&mut emitter);
+
+        assert_eq!(emitter.output(), expected);
+    }
+
+    #[test]
+    fn human_log_emitter_accumulates_across_calls() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+
+        let mut emitter = HumanLogEmitter::new();
+        error.emit_to(&mut emitter);
+        error.emit_to(&mut emitter);
+
+        assert_eq!(emitter.output().lines().count(), 2);
+    }
+
+    #[test]
+    fn json_log_emitter_omits_source_sensitive_by_default() {
+        let error =
+            AgentError::config_sensitive(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax", "hunter2");
+
+        let mut emitter = JsonLogEmitter::default();
+        error.emit_to(&mut emitter);
+
+        assert!(!emitter.output().contains("hunter2"));
+        assert!(!emitter.output().contains("source_sensitive"));
+    }
+
+    #[test]
+    fn json_log_emitter_hashes_source_sensitive_when_asked() {
+        let error =
+            AgentError::config_sensitive(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax", "hunter2");
+        let key = RedactionKey::new(b"test-redaction-key".to_vec());
+
+        let mut emitter = JsonLogEmitter::new(SensitiveSourcePolicy::Hash(key));
+        error.emit_to(&mut emitter);
+
+        assert!(!emitter.output().contains("hunter2"));
+        assert!(emitter.output().contains("\"source_sensitive\":"));
+    }
+
+    #[test]
+    fn json_log_emitter_never_includes_created_at_or_age() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+
+        let mut emitter = JsonLogEmitter::default();
+        error.emit_to(&mut emitter);
+
+        assert!(!emitter.output().contains("created_at"));
+        assert!(!emitter.output().contains("\"age\""));
+    }
+
+    #[test]
+    fn json_log_emitter_includes_code_operation_details_and_retryable() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+
+        let mut emitter = JsonLogEmitter::default();
+        error.emit_to(&mut emitter);
+
+        let out = emitter.into_output();
+        assert!(out.contains("\"operation\":\"boot\""));
+        assert!(out.contains("\"details\":\"bad syntax\""));
+        assert!(out.contains("\"retryable\":"));
+    }
+
+    #[test]
+    fn encode_decode_round_trips_the_trace_id() {
+        let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        let log = sample_log(&error);
+
+        let mut buf = Vec::new();
+        log.encode(&mut buf).unwrap();
+        let decoded = InternalLog::decode(&buf).unwrap();
+
+        assert_eq!(decoded.as_internal_log().trace_id, log.trace_id);
+    }
+
+    #[test]
+    fn decode_synthesizes_a_trace_id_for_the_pre_trace_id_wire_version() {
+        // Hand-build a version-1 buffer (code, operation, details, no
+        // internal/sensitive source, not retryable, no metadata) - the
+        // layout `encode` produced before `trace_id` existed on the wire.
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&1u16.to_be_bytes());
+        write_field(&mut buf, crate::definitions::CFG_PARSE_FAILED.to_string().as_bytes()).unwrap();
+        write_field(&mut buf, b"boot").unwrap();
+        write_field(&mut buf, b"bad syntax").unwrap();
+        buf.push(0); // source_internal: absent
+        buf.push(0); // source_sensitive: absent
+        buf.push(0); // retryable: false
+        buf.extend_from_slice(&0u16.to_be_bytes()); // metadata_count
+
+        let decoded = InternalLog::decode(&buf).unwrap();
+        assert_eq!(decoded.operation, "boot");
+    }
+
+    #[cfg(feature = "log_kv")]
+    mod log_kv {
+        use super::*;
+        use crate::definitions::CFG_PARSE_FAILED;
+        use crate::AgentError;
+        use log::kv::{Error, Key, Source, Value, VisitSource};
+
+        #[derive(Default)]
+        struct CollectingVisitor {
+            pairs: std::vec::Vec<(std::string::String, std::string::String)>,
+        }
+
+        impl<'kvs> VisitSource<'kvs> for CollectingVisitor {
+            fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+                self.pairs.push((key.to_string(), value.to_string()));
+                Ok(())
+            }
+        }
+
+        fn collect(log: &InternalLog<'_>) -> std::vec::Vec<(std::string::String, std::string::String)> {
+            let mut visitor = CollectingVisitor::default();
+            log.visit(&mut visitor).unwrap();
+            visitor.pairs
+        }
+
+        #[test]
+        fn visits_core_fields_as_discrete_pairs() {
+            let err = AgentError::config(CFG_PARSE_FAILED, "boot", "bad syntax");
+            let pairs = collect(&err.internal_log());
+
+            let has = |key: &str, value: &str| pairs.iter().any(|(k, v)| k == key && v == value);
+            assert!(has("operation", "boot"));
+            assert!(has("details", "bad syntax"));
+            assert!(has("retryable", "false"));
+        }
+
+        #[test]
+        fn redacts_sensitive_source_without_trusted_debug() {
+            let err = AgentError::config_sensitive(
+                CFG_PARSE_FAILED,
+                "boot",
+                "bad syntax",
+                "db password leaked here",
+            );
+            let pairs = collect(&err.internal_log());
+
+            let sensitive = pairs.iter().find(|(k, _)| k == "source_sensitive");
+            #[cfg(not(all(feature = "trusted_debug", debug_assertions)))]
+            assert_eq!(sensitive.map(|(_, v)| v.as_str()), Some("[SENSITIVE REDACTED]"));
+            #[cfg(all(feature = "trusted_debug", debug_assertions))]
+            assert_eq!(sensitive.map(|(_, v)| v.as_str()), Some("db password leaked here"));
+        }
+    }
+
+    #[cfg(feature = "slog_kv")]
+    mod slog_kv {
+        use super::*;
+        use crate::definitions::CFG_PARSE_FAILED;
+        use crate::AgentError;
+        use slog::{Level, Serializer, KV};
+
+        #[derive(Default)]
+        struct CollectingSerializer {
+            pairs: std::vec::Vec<(std::string::String, std::string::String)>,
+        }
+
+        impl Serializer for CollectingSerializer {
+            fn emit_arguments(&mut self, key: slog::Key, val: &std::fmt::Arguments) -> slog::Result {
+                self.pairs.push((key.to_string(), val.to_string()));
+                Ok(())
+            }
+        }
+
+        fn collect(log: &InternalLog<'_>) -> std::vec::Vec<(std::string::String, std::string::String)> {
+            let args = format_args!("test");
+            let record = slog::record!(Level::Error, "", &args, slog::b!());
+            let mut serializer = CollectingSerializer::default();
+            log.serialize(&record, &mut serializer).unwrap();
+            serializer.pairs
+        }
+
+        #[test]
+        fn serializes_core_fields_as_discrete_slog_values() {
+            let err = AgentError::config(CFG_PARSE_FAILED, "boot", "bad syntax");
+            let pairs = collect(&err.internal_log());
+
+            let has = |key: &str, value: &str| pairs.iter().any(|(k, v)| k == key && v == value);
+            assert!(has("operation", "boot"));
+            assert!(has("details", "bad syntax"));
+            assert!(has("retryable", "false"));
+        }
+
+        #[test]
+        fn redacts_sensitive_source_without_trusted_debug() {
+            let err = AgentError::config_sensitive(
+                CFG_PARSE_FAILED,
+                "boot",
+                "bad syntax",
+                "db password leaked here",
+            );
+            let pairs = collect(&err.internal_log());
+
+            let sensitive = pairs.iter().find(|(k, _)| k == "source_sensitive");
+            #[cfg(not(all(feature = "trusted_debug", debug_assertions)))]
+            assert_eq!(sensitive.map(|(_, v)| v.as_str()), Some("[SENSITIVE REDACTED]"));
+            #[cfg(all(feature = "trusted_debug", debug_assertions))]
+            assert_eq!(sensitive.map(|(_, v)| v.as_str()), Some("db password leaked here"));
+        }
+    }
+
+    #[test]
+    fn into_owned_preserves_every_field() {
+        let error = AgentError::config_sensitive(
+            crate::definitions::CFG_PARSE_FAILED,
+            "boot",
+            "bad syntax",
+            "db password hunter2",
+        );
+        let owned = sample_log(&error).into_owned();
+
+        let mut original = String::new();
+        sample_log(&error).write_to(&mut original).unwrap();
+
+        let mut restored = String::new();
+        owned.as_internal_log().write_to(&mut restored).unwrap();
+
+        assert_eq!(original, restored);
+    }
+
+    #[test]
+    fn into_owned_outlives_the_originating_error() {
+        let owned = {
+            let error = AgentError::config(crate::definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+            sample_log(&error).into_owned()
+        };
+
+        let mut out = String::new();
+        owned.as_internal_log().write_to(&mut out).unwrap();
+        assert!(out.contains("bad syntax"));
+    }
+
+    #[test]
+    fn owned_log_zeroizes_on_drop() {
+        let mut owned = {
+            let error = AgentError::config_sensitive(
+                crate::definitions::CFG_PARSE_FAILED,
+                "boot",
+                "bad syntax",
+                "hunter2",
+            );
+            sample_log(&error).into_owned()
+        };
+
+        owned.zeroize();
+        assert_eq!(owned.details, "");
+        assert_eq!(owned.source_sensitive.as_deref(), Some(""));
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_support {
+        use super::*;
+        use crate::definitions::CFG_PARSE_FAILED;
+
+        fn sensitive_error() -> AgentError {
+            AgentError::config_sensitive(CFG_PARSE_FAILED, "boot", "bad syntax", "db password hunter2")
+        }
+
+        #[test]
+        fn default_serialize_redacts_sensitive_source_with_sentinel() {
+            let error = sensitive_error();
+            let json = serde_json::to_string(&sample_log(&error)).unwrap();
+
+            assert!(!json.contains("hunter2"));
+            assert!(json.contains("\"source_sensitive\":\"***\""));
+            assert!(json.contains("\"operation\":\"boot\""));
+            assert!(json.contains("\"details\":\"bad syntax\""));
+        }
+
+        #[test]
+        fn length_only_style_reports_length_without_content() {
+            let error = sensitive_error();
+            let options = SerializeOptions::new().with_redaction_style(RedactionStyle::LengthOnly);
+
+            let mut buf = Vec::new();
+            let mut serializer = serde_json::Serializer::new(&mut buf);
+            sample_log(&error).serialize_with(&options, &mut serializer).unwrap();
+            let json = String::from_utf8(buf).unwrap();
+
+            assert!(!json.contains("hunter2"));
+            assert!(json.contains("\"redacted\":true"));
+            assert!(json.contains(&format!("\"len\":{}", "db password hunter2".len())));
+        }
+
+        #[test]
+        fn owned_metadata_values_are_redacted_borrowed_are_not() {
+            let err = AgentError::config(CFG_PARSE_FAILED, "boot", "bad syntax")
+                .with_metadata("static_key", "static_value")
+                .with_metadata("runtime_key", String::from("runtime_secret"));
+            let json = serde_json::to_string(&sample_log(&err)).unwrap();
+
+            assert!(json.contains("\"static_key\":\"static_value\""));
+            assert!(!json.contains("runtime_secret"));
+            assert!(json.contains("\"runtime_key\":\"***\""));
+        }
+
+        #[cfg(all(feature = "trusted_debug", debug_assertions))]
+        #[test]
+        fn reveal_sensitive_opts_into_raw_values() {
+            let error = sensitive_error();
+            let options = SerializeOptions::new().reveal_sensitive();
+
+            let mut buf = Vec::new();
+            let mut serializer = serde_json::Serializer::new(&mut buf);
+            sample_log(&error).serialize_with(&options, &mut serializer).unwrap();
+            let json = String::from_utf8(buf).unwrap();
+
+            assert!(json.contains("hunter2"));
+        }
+
+        #[test]
+        fn max_field_len_truncates_non_sensitive_fields() {
+            let long_details = "x".repeat(50);
+            let error = AgentError::config(CFG_PARSE_FAILED, "boot", long_details.clone());
+            let options = SerializeOptions::new().with_max_field_len(10);
+
+            let mut buf = Vec::new();
+            let mut serializer = serde_json::Serializer::new(&mut buf);
+            sample_log(&error).serialize_with(&options, &mut serializer).unwrap();
+            let json = String::from_utf8(buf).unwrap();
+
+            assert!(!json.contains(&long_details));
+            assert!(json.contains(TRUNCATION_INDICATOR));
+        }
+
+        #[test]
+        fn serialize_with_includes_category_impact_level_and_timestamp() {
+            let error = AgentError::config(CFG_PARSE_FAILED, "boot", "bad syntax");
+            let json = serde_json::to_string(&sample_log(&error)).unwrap();
+
+            assert!(json.contains("\"category\":"));
+            assert!(json.contains("\"impact_level\":"));
+            assert!(json.contains("\"timestamp\":"));
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,319 @@
+// src/manifest.rs
+//! Versioned taxonomy manifest for cross-process compatibility negotiation.
+//!
+//! # Purpose
+//!
+//! [`crate::advisory`] exports the catalog of error *codes* for a downstream
+//! pipeline that already trusts this build's taxonomy. This module answers a
+//! narrower question: can a *peer* process - a sidecar correlation engine,
+//! or another palisade component built from a different revision or feature
+//! set - be trusted to interpret our codes at all?
+//!
+//! Two components exchanging `E-XXX-YYY` codes need to agree on what
+//! namespaces exist, which ones carry breach authority, and which
+//! namespace/category pairings are considered valid - otherwise a foreign
+//! `E-RSP-9xx` might mean "total compromise" to the sender and "routine
+//! response" to the receiver. [`taxonomy_manifest`] emits that agreement as
+//! a machine-readable snapshot; [`Manifest::compatible_with`] lets a
+//! consumer detect a mismatch *before* trusting a foreign code, rather than
+//! discovering it the hard way downstream.
+//!
+//! This turns the compile-time governance in [`crate::codes`] into
+//! something negotiable across a process boundary, without weakening it -
+//! the manifest only ever describes the taxonomy a build already enforces,
+//! it can't loosen it.
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `serde` feature, same reasoning as
+//! [`crate::advisory`] and [`crate::serde_support`]: negotiating a manifest
+//! only makes sense once it can actually be serialized across the wire.
+
+use crate::{namespaces, permits_category, ErrorNamespace, OperationCategory};
+use serde::{Deserialize, Serialize};
+
+/// Current taxonomy manifest format version, as `(major, minor)`.
+///
+/// Bump `major` for a change that breaks [`Manifest::compatible_with`]'s
+/// assumptions about the document shape (e.g. a renamed field); bump
+/// `minor` for an additive change (e.g. a new namespace) that old consumers
+/// can still degrade gracefully on.
+pub const TAXONOMY_VERSION: (u16, u16) = (1, 0);
+
+/// One `(category, permitted)` pairing for a namespace's permission row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryPermission {
+    /// Display name of the category, e.g. `"Deception"`.
+    pub category: String,
+    /// Whether this build's [`permits_category`] allows the pairing.
+    pub permitted: bool,
+}
+
+/// Snapshot of a single namespace's identity and authority.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamespaceManifest {
+    /// Namespace name, e.g. `"DCP"`.
+    pub name: String,
+    /// Whether this namespace is permitted to emit Breach-level impacts.
+    pub can_breach: bool,
+    /// This namespace's full category-permission row, covering every
+    /// [`OperationCategory`] in [`OperationCategory::ALL`].
+    pub categories: Vec<CategoryPermission>,
+}
+
+impl NamespaceManifest {
+    fn from_namespace(namespace: &ErrorNamespace) -> Self {
+        Self {
+            name: namespace.as_str().to_string(),
+            can_breach: namespace.can_breach(),
+            categories: OperationCategory::ALL
+                .iter()
+                .map(|&category| CategoryPermission {
+                    category: category.display_name().to_string(),
+                    permitted: permits_category(namespace, category),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Machine-readable snapshot of the taxonomy a build was compiled with.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::manifest::{taxonomy_manifest, TAXONOMY_VERSION};
+///
+/// let manifest = taxonomy_manifest();
+/// assert_eq!(manifest.version, TAXONOMY_VERSION);
+/// assert!(!manifest.namespaces.is_empty());
+///
+/// let json = serde_json::to_string(&manifest).unwrap();
+/// assert!(json.contains("strict_taxonomy"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Format version of this document - see [`TAXONOMY_VERSION`].
+    pub version: (u16, u16),
+    /// Every namespace this build knows about, in [`namespaces::ALL`] order.
+    pub namespaces: Vec<NamespaceManifest>,
+    /// Whether this build was compiled with the `strict_taxonomy` feature.
+    pub strict_taxonomy: bool,
+    /// Whether this build was compiled with the `strict_severity` feature.
+    pub strict_severity: bool,
+}
+
+/// Why two [`Manifest`]s disagree about the taxonomy, surfaced so a caller
+/// can decide whether to reject, downgrade, or simply log the mismatch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Incompatibility {
+    /// The peer's major version differs from ours; the document shape or
+    /// semantics may not match what this build expects.
+    VersionMismatch {
+        /// This build's `(major, minor)` version.
+        ours: (u16, u16),
+        /// The peer's `(major, minor)` version.
+        theirs: (u16, u16),
+    },
+    /// The peer does not advertise a namespace we rely on.
+    MissingNamespace {
+        /// Name of the namespace we expected, e.g. `"RSP"`.
+        namespace: String,
+    },
+    /// The peer grants breach authority to a namespace we don't, or
+    /// vice versa - trusting a foreign code at face value could under- or
+    /// over-estimate its severity.
+    BreachAuthorityMismatch {
+        /// Name of the namespace whose authority flags disagree.
+        namespace: String,
+        /// Whether we allow Breach-level impacts for this namespace.
+        ours: bool,
+        /// Whether the peer allows Breach-level impacts for this namespace.
+        theirs: bool,
+    },
+    /// The peer permits a namespace/category pairing we forbid, or
+    /// vice versa.
+    CategoryPolicyMismatch {
+        /// Name of the namespace whose category policy disagrees.
+        namespace: String,
+        /// Display name of the category whose permission disagrees.
+        category: String,
+        /// Whether we permit this pairing.
+        ours: bool,
+        /// Whether the peer permits this pairing.
+        theirs: bool,
+    },
+}
+
+impl Manifest {
+    /// Check whether `self` can safely interpret codes from a peer
+    /// advertising `theirs`.
+    ///
+    /// Returns the *first* disagreement found, checked in order: version,
+    /// then missing namespaces, then breach authority, then category
+    /// policy, for any namespace we both advertise. A minor version
+    /// difference alone is not treated as incompatible - only a major
+    /// mismatch, or a concrete disagreement about what the taxonomy means.
+    pub fn compatible_with(&self, theirs: &Manifest) -> Result<(), Incompatibility> {
+        if self.version.0 != theirs.version.0 {
+            return Err(Incompatibility::VersionMismatch {
+                ours: self.version,
+                theirs: theirs.version,
+            });
+        }
+
+        for ours in &self.namespaces {
+            let Some(peer) = theirs.namespaces.iter().find(|n| n.name == ours.name) else {
+                return Err(Incompatibility::MissingNamespace {
+                    namespace: ours.name.clone(),
+                });
+            };
+
+            if ours.can_breach != peer.can_breach {
+                return Err(Incompatibility::BreachAuthorityMismatch {
+                    namespace: ours.name.clone(),
+                    ours: ours.can_breach,
+                    theirs: peer.can_breach,
+                });
+            }
+
+            for ours_category in &ours.categories {
+                let Some(peer_category) = peer
+                    .categories
+                    .iter()
+                    .find(|c| c.category == ours_category.category)
+                else {
+                    continue;
+                };
+
+                if ours_category.permitted != peer_category.permitted {
+                    return Err(Incompatibility::CategoryPolicyMismatch {
+                        namespace: ours.name.clone(),
+                        category: ours_category.category.clone(),
+                        ours: ours_category.permitted,
+                        theirs: peer_category.permitted,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Build a [`Manifest`] describing the taxonomy this build was compiled
+/// with.
+///
+/// Serialize the result (e.g. with `serde_json::to_string`) to send it to a
+/// peer, and check an incoming peer manifest with [`Manifest::compatible_with`]
+/// before trusting codes it sends.
+pub fn taxonomy_manifest() -> Manifest {
+    Manifest {
+        version: TAXONOMY_VERSION,
+        namespaces: namespaces::ALL
+            .iter()
+            .map(|namespace| NamespaceManifest::from_namespace(namespace))
+            .collect(),
+        strict_taxonomy: cfg!(feature = "strict_taxonomy"),
+        strict_severity: cfg!(feature = "strict_severity"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_covers_every_namespace() {
+        let manifest = taxonomy_manifest();
+        assert_eq!(manifest.namespaces.len(), namespaces::ALL.len());
+        assert_eq!(manifest.version, TAXONOMY_VERSION);
+    }
+
+    #[test]
+    fn manifest_is_compatible_with_itself() {
+        let manifest = taxonomy_manifest();
+        assert_eq!(manifest.compatible_with(&manifest), Ok(()));
+    }
+
+    #[test]
+    fn manifest_detects_major_version_mismatch() {
+        let ours = taxonomy_manifest();
+        let mut theirs = ours.clone();
+        theirs.version.0 += 1;
+
+        assert_eq!(
+            ours.compatible_with(&theirs),
+            Err(Incompatibility::VersionMismatch {
+                ours: ours.version,
+                theirs: theirs.version,
+            })
+        );
+    }
+
+    #[test]
+    fn manifest_detects_missing_namespace() {
+        let ours = taxonomy_manifest();
+        let mut theirs = ours.clone();
+        theirs.namespaces.retain(|n| n.name != "DCP");
+
+        assert_eq!(
+            ours.compatible_with(&theirs),
+            Err(Incompatibility::MissingNamespace {
+                namespace: "DCP".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn manifest_detects_breach_authority_mismatch() {
+        let ours = taxonomy_manifest();
+        let mut theirs = ours.clone();
+        let dcp = theirs
+            .namespaces
+            .iter_mut()
+            .find(|n| n.name == "DCP")
+            .expect("DCP present");
+        dcp.can_breach = !dcp.can_breach;
+
+        assert_eq!(
+            ours.compatible_with(&theirs),
+            Err(Incompatibility::BreachAuthorityMismatch {
+                namespace: "DCP".to_string(),
+                ours: true,
+                theirs: false,
+            })
+        );
+    }
+
+    #[test]
+    fn manifest_detects_category_policy_mismatch() {
+        let ours = taxonomy_manifest();
+        let mut theirs = ours.clone();
+        let io = theirs
+            .namespaces
+            .iter_mut()
+            .find(|n| n.name == "IO")
+            .expect("IO present");
+        let category = io
+            .categories
+            .iter_mut()
+            .find(|c| c.category == "I/O")
+            .expect("IO category present");
+        category.permitted = !category.permitted;
+
+        assert!(matches!(
+            ours.compatible_with(&theirs),
+            Err(Incompatibility::CategoryPolicyMismatch { namespace, .. }) if namespace == "IO"
+        ));
+    }
+
+    #[test]
+    fn manifest_round_trips_through_json() {
+        let manifest = taxonomy_manifest();
+        let json = serde_json::to_string(&manifest).unwrap();
+        let restored: Manifest = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, manifest);
+    }
+}
@@ -41,10 +41,30 @@
 //! It does NOT provide HSM-grade secure memory wiping. For that, use platform-specific
 //! APIs (mlock, SecureZeroMemory, etc.) and dedicated secure allocators.
 
+use crate::ErrorCode;
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+#[cfg(feature = "no_std")]
+use alloc::string::ToString;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+use core::sync::atomic::{compiler_fence, AtomicBool, AtomicUsize, Ordering};
+use smallvec::SmallVec;
+#[cfg(not(feature = "no_std"))]
 use std::borrow::Cow;
-use std::fmt;
-use std::ptr;
-use std::sync::atomic::{compiler_fence, Ordering};
+#[cfg(not(feature = "no_std"))]
+use std::panic::Location;
+#[cfg(not(feature = "no_std"))]
+use std::sync::Arc;
+#[cfg(not(feature = "no_std"))]
+use std::sync::OnceLock;
+#[cfg(not(feature = "no_std"))]
+use std::sync::RwLock;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // ============================================================================
@@ -74,6 +94,19 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 /// this type. The purpose is **organizational process safety**: preventing accidental
 /// misuse by well-meaning developers, not preventing malicious actors.
 ///
+/// # Audit Trail
+///
+/// Under `std`, acquisition and release are no longer just a documentation
+/// suggestion: `acquire()` reports the caller's [`Location`] and `Drop`
+/// reports how many [`InternalContext::expose_sensitive`] calls the guard
+/// authorized, both via the process-wide sink installed with
+/// [`Self::register_sink`]. The default sink is a no-op, so deployments
+/// that never call `register_sink` pay only the cost of an uncontended
+/// `RwLock` read per acquire/release/expose - see [`Self::register_sink`]
+/// for how to install a real one. Unavailable under `no_std`, which has no
+/// portable way to capture caller location or host a process-wide sink;
+/// `SocAccess` there is exactly the zero-sized token it always was.
+///
 /// # Example
 ///
 /// ```ignore
@@ -83,8 +116,15 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 ///     secure_log_to_encrypted_siem(sensitive);
 /// }
 /// ```
+#[cfg(not(feature = "no_std"))]
+pub struct SocAccess {
+    exposed_count: AtomicUsize,
+}
+
+#[cfg(feature = "no_std")]
 pub struct SocAccess(());
 
+#[cfg(not(feature = "no_std"))]
 impl SocAccess {
     /// Acquire SOC access capability for sensitive data exposure.
     ///
@@ -96,23 +136,456 @@ impl SocAccess {
     /// - Encrypted internal logging pipelines
     /// - Forensic analysis tools with access controls
     ///
-    /// # Audit Recommendation
+    /// # Audit Trail
+    ///
+    /// Reports the caller's location to the registered [`SocAuditSink`] via
+    /// [`SocAuditSink::on_acquire`] before returning. `#[track_caller]` means
+    /// this is the call site that acquired the token, not somewhere inside
+    /// this function.
+    #[inline]
+    #[track_caller]
+    pub fn acquire() -> Self {
+        soc_audit_sink()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .on_acquire(Location::caller());
+        Self {
+            exposed_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Install the process-wide [`SocAuditSink`] that future [`Self::acquire`]
+    /// and `Drop` calls report to. Replaces whatever sink was previously
+    /// installed (the default is a no-op).
+    pub fn register_sink(sink: Box<dyn SocAuditSink + Send + Sync>) {
+        *soc_audit_sink()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = sink;
+    }
+
+    /// Record one more `expose_sensitive` authorization against this guard,
+    /// for [`SocAuditSink::on_release`] to report when it drops.
+    #[inline]
+    fn record_exposure(&self) {
+        self.exposed_count.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Drop for SocAccess {
+    fn drop(&mut self) {
+        let exposed_count = self.exposed_count.load(Ordering::SeqCst);
+        soc_audit_sink()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .on_release(exposed_count);
+    }
+}
+
+#[cfg(feature = "no_std")]
+impl SocAccess {
+    /// Acquire SOC access capability for sensitive data exposure.
     ///
-    /// Calls to this method should be logged separately for compliance auditing.
-    /// Consider wrapping this in a macro that logs the caller's location:
+    /// # Security Contract
     ///
-    /// ```ignore
-    /// macro_rules! acquire_soc_access {
-    ///     () => {{
-    ///         audit_log!("SOC access acquired at {}:{}", file!(), line!());
-    ///         SocAccess::acquire()
-    ///     }}
-    /// }
-    /// ```
+    /// Caller must ensure this is invoked only in contexts where sensitive data
+    /// disclosure is authorized:
+    /// - Authenticated SOC dashboards with RBAC
+    /// - Encrypted internal logging pipelines
+    /// - Forensic analysis tools with access controls
     #[inline]
     pub fn acquire() -> Self {
         Self(())
     }
+
+    /// No-op under `no_std`, where there's no audit machinery to report to -
+    /// exists so [`InternalContext::expose_sensitive`] can call it
+    /// unconditionally regardless of which `SocAccess` shape is compiled in.
+    #[inline]
+    fn record_exposure(&self) {}
+}
+
+/// Compliance hook for [`SocAccess`] acquisition and release, installed
+/// process-wide via [`SocAccess::register_sink`].
+///
+/// # Purpose
+///
+/// Gives deployments a real audit trail - who acquired SOC capability, from
+/// where, and how much sensitive data it authorized - without every call
+/// site reimplementing the macro `SocAccess::acquire`'s own docs used to
+/// merely suggest.
+#[cfg(not(feature = "no_std"))]
+pub trait SocAuditSink: Send + Sync {
+    /// Called from inside [`SocAccess::acquire`], before the token is
+    /// returned to the caller.
+    fn on_acquire(&self, location: &'static Location<'static>);
+
+    /// Called from the guard's `Drop`, with the number of
+    /// [`InternalContext::expose_sensitive`] calls it authorized over its
+    /// lifetime.
+    fn on_release(&self, exposed_count: usize);
+}
+
+/// Default [`SocAuditSink`] installed until [`SocAccess::register_sink`] is
+/// called - does nothing, so the zero-cost story holds for deployments that
+/// never opt into auditing.
+#[cfg(not(feature = "no_std"))]
+struct NoopAuditSink;
+
+#[cfg(not(feature = "no_std"))]
+impl SocAuditSink for NoopAuditSink {
+    fn on_acquire(&self, _location: &'static Location<'static>) {}
+    fn on_release(&self, _exposed_count: usize) {}
+}
+
+#[cfg(not(feature = "no_std"))]
+static SOC_AUDIT_SINK: OnceLock<RwLock<Box<dyn SocAuditSink + Send + Sync>>> = OnceLock::new();
+
+#[cfg(not(feature = "no_std"))]
+fn soc_audit_sink() -> &'static RwLock<Box<dyn SocAuditSink + Send + Sync>> {
+    SOC_AUDIT_SINK.get_or_init(|| RwLock::new(Box::new(NoopAuditSink)))
+}
+
+// ============================================================================
+// Runtime Forensic Unlock (RAII-Scoped, Process-Global)
+// ============================================================================
+
+/// Process-global unlock count for [`ForensicMode`]. Zero means locked -
+/// [`InternalContext::payload`] and `Display` behave exactly as if
+/// [`ForensicMode`] didn't exist. A count rather than a bare flag so nested
+/// or concurrent [`ForensicMode::unlock`] calls (from different threads, or
+/// a helper function that unlocks internally while its caller already has)
+/// compose correctly: the guard that drops last is the one that actually
+/// re-locks.
+static FORENSIC_UNLOCK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// RAII-scoped override that lets [`InternalContext::payload`] yield
+/// `Sensitive` content and `Display` render real text, for as long as the
+/// guard is alive.
+///
+/// # Purpose
+///
+/// Today the only way to reveal truthful or sensitive content externally is
+/// the compile-time `external_signaling` feature - there's no way for an
+/// authenticated incident responder to temporarily dump full diagnostics
+/// from a REPL without recompiling and redeploying. `ForensicMode` follows
+/// the same "enforce vs. relax safe logging at runtime" pattern as
+/// safe-logging toggles in other systems (e.g. Tor's safelog): a guard,
+/// gated behind [`SocAccess`], that flips a process-global unlock for its
+/// lifetime and restores the locked default when dropped.
+///
+/// # Hard Invariants
+///
+/// - Defaults to locked: with no live guard, behavior is unchanged from
+///   before this type existed.
+/// - [`Drop::drop`] always runs, including during unwinding from a panic -
+///   this is a language guarantee for non-`mem::forget`, non-`abort` code
+///   paths, so there's no separate "restore on panic" mechanism to build.
+/// - Not `Clone`: duplicating a guard would let one `drop()` undo two
+///   `unlock()` calls' worth of intent. Every unlock has exactly one guard,
+///   and the unlock count only ever moves in matched +1/-1 pairs.
+///
+/// # Why Not Volatile Writes / `compiler_fence`
+///
+/// [`InternalContextField`]'s `Drop` impl reaches for `ptr::write_volatile`
+/// and `compiler_fence` because it's clearing sensitive *bytes in owned
+/// memory*, where the compiler is otherwise free to elide the write as a
+/// dead store once it can prove nothing reads the memory again. A
+/// [`FORENSIC_UNLOCK_COUNT`] update is different in kind: atomic read-modify-write
+/// operations are observable side effects the compiler can never treat as
+/// dead code, and the value they protect (a counter, not secret bytes)
+/// isn't something to zero out - so ordinary `Ordering::SeqCst` atomics
+/// already give the same "this really happens, in order" guarantee that
+/// discipline exists to provide for [`InternalContextField`].
+///
+/// # Example
+///
+/// ```ignore
+/// let access = SocAccess::acquire();
+/// {
+///     let _forensic = ForensicMode::unlock(&access);
+///     // Inside this scope, context.payload() and Display reveal real content.
+///     secure_log_to_encrypted_siem(&format!("{}", context));
+/// } // Guard drops here - locked again, even if the block above panicked.
+/// ```
+pub struct ForensicMode(());
+
+impl ForensicMode {
+    /// Unlock forensic mode for the lifetime of the returned guard.
+    ///
+    /// Requires [`SocAccess`] so the same organizational-process-safety
+    /// reasoning that gates [`InternalContext::expose_sensitive`] also gates
+    /// this much broader, process-wide relaxation.
+    #[inline]
+    #[must_use = "forensic mode re-locks as soon as this guard is dropped"]
+    pub fn unlock(_access: &SocAccess) -> Self {
+        FORENSIC_UNLOCK_COUNT.fetch_add(1, Ordering::SeqCst);
+        Self(())
+    }
+
+    /// Whether any [`ForensicMode`] guard is currently live, anywhere in
+    /// the process.
+    #[inline]
+    #[must_use]
+    pub fn is_active() -> bool {
+        FORENSIC_UNLOCK_COUNT.load(Ordering::SeqCst) > 0
+    }
+}
+
+impl Drop for ForensicMode {
+    fn drop(&mut self) {
+        FORENSIC_UNLOCK_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// ============================================================================
+// Capability-Scoped Access (Time-Boxed, Category-Limited)
+// ============================================================================
+
+/// Scope describes what a [`Capability`] is authorized to reveal: a set of
+/// [`OperationCategory`] values it applies to, plus an optional set of
+/// `ContextMetadata` keys it may additionally surface via
+/// [`ContextMetadata::expose_with`].
+///
+/// # Deny-by-Default
+///
+/// An empty scope (the `Default` impl) permits nothing. Operators must
+/// explicitly opt in via [`Self::categories`] or [`Self::all_categories`],
+/// matching the crate's overall "sensitive data is explicitly marked" posture.
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone)]
+pub struct CapabilityScope {
+    categories: SmallVec<[OperationCategory; 4]>,
+    metadata_keys: SmallVec<[&'static str; 4]>,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl CapabilityScope {
+    /// Scope limited to the given operation categories, with no metadata keys.
+    #[inline]
+    pub fn categories(categories: impl IntoIterator<Item = OperationCategory>) -> Self {
+        Self {
+            categories: categories.into_iter().collect(),
+            metadata_keys: SmallVec::new(),
+        }
+    }
+
+    /// Scope spanning every [`OperationCategory`] (still no metadata keys
+    /// unless [`Self::with_metadata_keys`] is also called).
+    #[inline]
+    pub fn all_categories() -> Self {
+        Self::categories([
+            OperationCategory::Configuration,
+            OperationCategory::Deployment,
+            OperationCategory::Monitoring,
+            OperationCategory::Analysis,
+            OperationCategory::Response,
+            OperationCategory::Audit,
+            OperationCategory::System,
+            OperationCategory::IO,
+            OperationCategory::Deception,
+            OperationCategory::Detection,
+            OperationCategory::Containment,
+        ])
+    }
+
+    /// Additionally authorize these `ContextMetadata` keys for
+    /// [`ContextMetadata::expose_with`].
+    #[inline]
+    pub fn with_metadata_keys(mut self, keys: impl IntoIterator<Item = &'static str>) -> Self {
+        self.metadata_keys.extend(keys);
+        self
+    }
+
+    #[inline]
+    fn allows_category(&self, category: OperationCategory) -> bool {
+        self.categories.contains(&category)
+    }
+
+    #[inline]
+    fn allows_key(&self, key: &str) -> bool {
+        self.metadata_keys.iter().any(|allowed| *allowed == key)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Default for CapabilityScope {
+    fn default() -> Self {
+        Self {
+            categories: SmallVec::new(),
+            metadata_keys: SmallVec::new(),
+        }
+    }
+}
+
+/// Time-boxed, category-limited capability for exposing sensitive context.
+///
+/// # Purpose
+///
+/// `SocAccess` is an unconditional gate: any caller holding one can expose
+/// *any* error's sensitive data. `Capability` narrows this to a read-only,
+/// time-boxed, category-limited token (inspired by NextGraph's
+/// permissions/capabilities model) suitable for handing to a single
+/// debugging session instead of blanket access.
+///
+/// # Fields
+///
+/// - `issuer`/`holder`: identity bookkeeping for audit trails (who minted the
+///   token, who it was handed to).
+/// - `scope`: the [`CapabilityScope`] of categories/metadata keys it unlocks.
+/// - `issued_at`/`ttl`: the capability expires once `issued_at.elapsed() > ttl`.
+///
+/// # Security Model
+///
+/// Like `SocAccess`, this is organizational process safety, not cryptography:
+/// an attacker with code execution can construct one directly. The value is
+/// in making scope and expiry explicit, auditable, and enforced at every
+/// exposure site rather than trusting callers to self-limit.
+///
+/// # std-only
+///
+/// Expiry is wall-clock based (`std::time::SystemTime`), so `Capability` is
+/// unavailable under the `no_std` feature - same carve-out as `AgentError`
+/// and the `obfuscation`/`ring_buffer` modules.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::{Capability, CapabilityScope, OperationCategory};
+/// use std::time::Duration;
+///
+/// let capability = Capability::issue(
+///     "soc-lead@example.com",
+///     "debug-session-42",
+///     CapabilityScope::categories([OperationCategory::IO]),
+///     Duration::from_secs(300),
+/// );
+///
+/// assert!(!capability.is_expired());
+/// ```
+#[cfg(not(feature = "no_std"))]
+pub struct Capability {
+    issuer: Cow<'static, str>,
+    holder: Cow<'static, str>,
+    scope: CapabilityScope,
+    issued_at: std::time::SystemTime,
+    ttl: std::time::Duration,
+    audit_sink: Option<Arc<dyn crate::audit::AuditSink + Send + Sync>>,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl Capability {
+    /// Issue a new capability, stamped with the current time.
+    #[inline]
+    pub fn issue(
+        issuer: impl Into<Cow<'static, str>>,
+        holder: impl Into<Cow<'static, str>>,
+        scope: CapabilityScope,
+        ttl: std::time::Duration,
+    ) -> Self {
+        Self {
+            issuer: issuer.into(),
+            holder: holder.into(),
+            scope,
+            issued_at: std::time::SystemTime::now(),
+            ttl,
+            audit_sink: None,
+        }
+    }
+
+    /// Override the [`crate::audit::AuditSink`] that `DualContextError::expose_sensitive`
+    /// reports to when gated on this capability, instead of the process-wide
+    /// default installed via [`crate::audit::set_global_audit_sink`].
+    ///
+    /// # Use Case
+    ///
+    /// A single debug session's capability can be wired to its own sink
+    /// (e.g. one `RingBufferAuditSink` per incident), without disturbing the
+    /// global sink other capabilities still report to.
+    #[inline]
+    pub fn with_audit_sink(mut self, sink: impl crate::audit::AuditSink + Send + Sync + 'static) -> Self {
+        self.audit_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// This capability's sink override, if [`Self::with_audit_sink`] was called.
+    #[inline]
+    pub(crate) fn audit_sink(&self) -> Option<&Arc<dyn crate::audit::AuditSink + Send + Sync>> {
+        self.audit_sink.as_ref()
+    }
+
+    /// Identity of whoever minted this capability.
+    #[inline]
+    pub fn issuer(&self) -> &str {
+        self.issuer.as_ref()
+    }
+
+    /// Identity of whoever holds this capability.
+    #[inline]
+    pub fn holder(&self) -> &str {
+        self.holder.as_ref()
+    }
+
+    /// The scope this capability was issued for.
+    #[inline]
+    pub fn scope(&self) -> &CapabilityScope {
+        &self.scope
+    }
+
+    /// Whether this capability's TTL has elapsed.
+    ///
+    /// # Fail-Closed
+    ///
+    /// If the system clock appears to have moved backward since issuance
+    /// (`SystemTime::elapsed()` returns `Err`), this treats the capability as
+    /// expired rather than trusting an untrustworthy clock reading.
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        match self.issued_at.elapsed() {
+            Ok(age) => age > self.ttl,
+            Err(_) => true,
+        }
+    }
+
+    /// Whether this capability currently authorizes access to `category`.
+    ///
+    /// `false` if the capability is expired or `category` is outside its
+    /// scope.
+    #[inline]
+    fn permits(&self, category: OperationCategory) -> bool {
+        !self.is_expired() && self.scope.allows_category(category)
+    }
+}
+
+// ============================================================================
+// Tiered Clearance (Minimum-Level Tagging For Sensitive Context)
+// ============================================================================
+
+/// Minimum clearance level required to view a [`InternalContext::sensitive_at`]
+/// context, ordered least to most privileged.
+///
+/// # Purpose
+///
+/// `SocAccess` and `Capability` are both all-or-nothing once their gate is
+/// passed: any holder sees the full sensitive payload. `Clearance` adds an
+/// orthogonal axis - a level tagged on the sensitive data itself at build
+/// time, checked against a [`crate::ledger::ClearanceToken`]'s level at
+/// exposure time - so a context can require `Forensics` clearance even from
+/// a caller who already holds a valid `Capability`.
+///
+/// # Ordering
+///
+/// `Forensics > IncidentResponder > Analyst`: a token's level must meet or
+/// exceed the context's tagged level, the same "derived `Ord`, higher
+/// variants are more privileged" convention as [`Severity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Clearance {
+    /// Baseline tier: routine SOC analyst triage.
+    Analyst,
+    /// Mid tier: active incident handling.
+    IncidentResponder,
+    /// Highest tier: forensic investigation of the most sensitive payloads.
+    Forensics,
 }
 
 // ============================================================================
@@ -153,6 +626,10 @@ impl SocAccess {
 enum InternalContextField {
     Diagnostic(Cow<'static, str>),
     Sensitive(Cow<'static, str>),
+    /// Like `Sensitive`, but additionally tagged with the minimum
+    /// [`Clearance`] a [`crate::ledger::ClearanceToken`] must present to
+    /// view it - see [`InternalContext::sensitive_at`].
+    SensitiveAt(Cow<'static, str>, Clearance),
     Lie(Cow<'static, str>),
 }
 
@@ -164,16 +641,90 @@ impl Zeroize for InternalContextField {
                     s.zeroize();
                 }
             }
+            Self::SensitiveAt(cow, _) => {
+                if let Cow::Owned(s) = cow {
+                    s.zeroize();
+                }
+            }
         }
     }
 }
 
 impl ZeroizeOnDrop for InternalContextField {}
 
+/// Overwrite a `String`'s backing bytes with `0u8` via
+/// [`ptr::write_volatile`], so the compiler cannot prove the writes are dead
+/// and elide them - the same "harden past what `zeroize()` alone promises"
+/// step [`InternalContextField::drop`] applies to every owned variant, not
+/// only `Sensitive`/`SensitiveAt`: a `Diagnostic` or `Lie` string can still
+/// be read back out via [`InternalContext::into_inner`] before this point,
+/// and once dropped deserves the same best-effort clearing.
+///
+/// # Safety-Adjacent Note
+///
+/// Writing all-zero bytes keeps the buffer valid UTF-8 (`\0` is a complete
+/// one-byte code point), so this never has to reach for `as_bytes_mut`.
+#[inline]
+fn volatile_zero_string(s: &mut String) {
+    // SAFETY:
+    // - We own this String and are in its Drop implementation
+    // - as_mut_ptr() returns a valid pointer to the String's buffer
+    // - len() is correct and bounds-checked by Rust
+    // - We write only within allocated bounds (0..len)
+    // - Volatile writes prevent compiler optimization
+    unsafe {
+        let ptr = s.as_mut_ptr();
+        let len = s.len();
+        for i in 0..len {
+            ptr::write_volatile(ptr.add(i), 0u8);
+        }
+    }
+}
+
+/// Architecture-specific memory fence emitted after
+/// [`compiler_fence`], mirroring the extra hardware barrier the `zeroize`
+/// crate's own `x86.rs`/`aarch64.rs` back ends add on top of a compiler-only
+/// fence - `compiler_fence` only constrains what the *compiler* may
+/// reorder, not what the CPU itself may reorder or keep buffered.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn arch_fence() {
+    // SAFETY: `mfence` takes no operands, touches no memory this function
+    // doesn't already own, and has no preconditions beyond "runs on
+    // x86_64" (guaranteed by the `cfg` above).
+    unsafe {
+        core::arch::asm!("mfence", options(nostack, preserves_flags));
+    }
+}
+
+/// See [`arch_fence`] (x86_64) - `dmb ish` is aarch64's inner-shareable-domain
+/// equivalent of `mfence`.
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn arch_fence() {
+    // SAFETY: `dmb ish` takes no operands, touches no memory this function
+    // doesn't already own, and has no preconditions beyond "runs on
+    // aarch64" (guaranteed by the `cfg` above).
+    unsafe {
+        core::arch::asm!("dmb ish", options(nostack, preserves_flags));
+    }
+}
+
+/// No hand-picked hardware fence exists for this architecture - the
+/// [`compiler_fence`] call at each [`arch_fence`] call site remains the only
+/// barrier, same as before this function existed.
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline]
+fn arch_fence() {}
+
 impl Drop for InternalContextField {
     fn drop(&mut self) {
-        // For sensitive variants with owned data, perform volatile write to prevent
-        // compiler from eliding the zeroization as a "dead store" optimization.
+        // Perform a volatile write on every owned variant's string to
+        // prevent the compiler from eliding the zeroization below as a
+        // "dead store" optimization - not just `Sensitive`/`SensitiveAt`,
+        // since `Diagnostic`/`Lie` content can also have been read back out
+        // via `InternalContext::into_inner` and deserves the same
+        // best-effort clearing once this copy is going away.
         //
         // GUARANTEES PROVIDED:
         // - Prevents LLVM from removing the write as dead code
@@ -188,27 +739,17 @@ impl Drop for InternalContextField {
         // This is best-effort memory clearing for defense against casual inspection
         // and compiler optimizations. Not suitable for cryptographic key material
         // that requires HSM-grade wiping.
-        if let Self::Sensitive(cow) = &mut *self {
-            if let Cow::Owned(s) = cow {
-                // SAFETY:
-                // - We own this String and are in its Drop implementation
-                // - as_mut_ptr() returns valid pointer to the String's buffer
-                // - len() is correct and bounds-checked by Rust
-                // - We write only within allocated bounds (0..len)
-                // - Volatile writes prevent compiler optimization
-                unsafe {
-                    let ptr = s.as_mut_ptr();
-                    let len = s.len();
-                    for i in 0..len {
-                        ptr::write_volatile(ptr.add(i), 0u8);
-                    }
-                }
-            };
+        let cow = match &mut *self {
+            Self::Diagnostic(cow) | Self::Sensitive(cow) | Self::Lie(cow) => cow,
+            Self::SensitiveAt(cow, _) => cow,
+        };
+        if let Cow::Owned(s) = cow {
+            volatile_zero_string(s);
         }
-        
+
         // High-level zeroization via zeroize crate
         self.zeroize();
-        
+
         // Compiler fence prevents reordering of instructions across this boundary.
         // Ensures zeroization completes before any subsequent destructor logic.
         //
@@ -221,6 +762,11 @@ impl Drop for InternalContextField {
         // - Does NOT force cache coherence across CPU cores
         // - Other threads may still observe old values in their caches
         compiler_fence(Ordering::SeqCst);
+
+        // Hardware fence, where this architecture has one available - see
+        // `arch_fence`'s own doc comment for why `compiler_fence` alone
+        // isn't the full story.
+        arch_fence();
     }
 }
 
@@ -427,6 +973,130 @@ impl fmt::Debug for PublicContext {
     }
 }
 
+/// Process-lifetime salt for [`RedactedView`] fingerprints, generated once
+/// from [`crate::obfuscation::random_u64`] on first use.
+///
+/// Never derived from a fixed or public key - a reader who knows the scheme
+/// still can't precompute a rainbow table of fingerprints, and fingerprints
+/// from two different processes (or two runs of the same process) never
+/// correlate, since each gets its own salt. Within a single process, the
+/// salt is stable for the process's lifetime, so identical sensitive values
+/// produce identical fingerprints - that's the whole point: an analyst can
+/// tell "this is the same leaked credential as that other alert" without
+/// either alert ever showing the credential itself.
+#[cfg(not(feature = "no_std"))]
+static REDACTION_SALT: OnceLock<u64> = OnceLock::new();
+
+#[cfg(not(feature = "no_std"))]
+fn redaction_salt() -> u64 {
+    *REDACTION_SALT.get_or_init(crate::obfuscation::random_u64)
+}
+
+/// First 3 bytes of a salted digest of `bytes` - a stable, non-reversible
+/// fingerprint, not a cryptographic commitment. Good enough to let an
+/// analyst join two redacted alerts that touched the same value; not good
+/// enough (by design - it's only 24 bits) to serve as a dedup key over a
+/// huge corpus, where collisions become likely.
+#[cfg(not(feature = "no_std"))]
+fn redaction_fingerprint(bytes: &[u8]) -> [u8; 3] {
+    let salt = redaction_salt();
+    let digest = crate::obfuscation::derive_session_key(&[&salt.to_be_bytes(), bytes]);
+    let be = digest.to_be_bytes();
+    [be[0], be[1], be[2]]
+}
+
+/// Redacted stand-in for a sensitive value, rendering as
+/// `Sensitive(len=42, fp=9f3a1c)` instead of a bare `[REDACTED]`.
+///
+/// Inspired by Tor's safelog: total suppression destroys an analyst's
+/// ability to tell whether two different errors touched the *same*
+/// sensitive value (the same leaked credential probed twice, say). A
+/// salted fingerprint lets identical inputs join across events within this
+/// process, without ever reconstructing - or even bounding the search space
+/// for - the original value. See [`REDACTION_SALT`] for the non-correlation
+/// and non-brute-forceability guarantees.
+///
+/// Built by [`InternalContext::redacted`], which computes `len` and the
+/// fingerprint immediately rather than holding a reference to the
+/// underlying sensitive bytes - so this view outlives neither its
+/// usefulness nor the data it's describing.
+#[cfg(not(feature = "no_std"))]
+pub struct RedactedView<'a> {
+    len: usize,
+    fingerprint: [u8; 3],
+    _tied_to: core::marker::PhantomData<&'a ()>,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for RedactedView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sensitive(len={}, fp={:02x}{:02x}{:02x})",
+            self.len, self.fingerprint[0], self.fingerprint[1], self.fingerprint[2]
+        )
+    }
+}
+
+/// Default cap on the number of [`Breadcrumb`]s a single [`InternalContext`]
+/// trail retains - see [`InternalContext::push_breadcrumb`].
+const DEFAULT_BREADCRUMB_CAP: usize = 16;
+
+/// A single internal diagnostic step recorded in an [`InternalContext`]'s
+/// breadcrumb trail, borrowing Mozilla `error-support`'s breadcrumb idea:
+/// several internal "truth" events can lead up to the one stable external
+/// message a [`DualContextError`] ultimately surfaces.
+///
+/// # No Clone/Copy Policy
+///
+/// Matches [`InternalContextField`]'s no-duplication policy for owned
+/// diagnostic strings - a trail is read by reference via
+/// [`InternalContext::breadcrumbs`], never copied out piecemeal.
+#[derive(Debug)]
+pub struct Breadcrumb {
+    message: Cow<'static, str>,
+    category: OperationCategory,
+}
+
+impl Breadcrumb {
+    /// The recorded diagnostic message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        self.message.as_ref()
+    }
+
+    /// The [`OperationCategory`] this step was recorded under.
+    #[inline]
+    pub const fn category(&self) -> OperationCategory {
+        self.category
+    }
+}
+
+impl Zeroize for Breadcrumb {
+    fn zeroize(&mut self) {
+        if let Cow::Owned(s) = &mut self.message {
+            s.zeroize();
+        }
+    }
+}
+
+impl ZeroizeOnDrop for Breadcrumb {}
+
+impl Drop for Breadcrumb {
+    /// Same volatile-write-then-fence hardening [`InternalContextField::drop`]
+    /// applies to its owned content, so a breadcrumb trail never gets weaker
+    /// zeroize-on-drop guarantees than the context it's attached to just
+    /// because it lives in a `Vec` instead of directly in the field.
+    fn drop(&mut self) {
+        if let Cow::Owned(s) = &mut self.message {
+            volatile_zero_string(s);
+        }
+        self.zeroize();
+        compiler_fence(Ordering::SeqCst);
+        arch_fence();
+    }
+}
+
 /// Type-safe wrapper for internal-only error contexts.
 ///
 /// # Trust Boundary Enforcement
@@ -439,6 +1109,11 @@ impl fmt::Debug for PublicContext {
 ///
 /// - `payload()`: Returns structured data for SOC logging (zero allocation)
 /// - `expose_sensitive()`: Returns raw sensitive content (requires `SocAccess` capability)
+/// - `push_breadcrumb()`/`breadcrumbs()`: Record and read back an ordered
+///   trail of internal diagnostic steps leading up to this context, bounded
+///   by a configurable cap (oldest dropped first) so an attacker-driven
+///   error flood can't grow a single context's memory unboundedly - see
+///   [`Self::with_breadcrumb_cap`].
 ///
 /// Both methods require conscious choice and cannot be used accidentally via
 /// generic string formatting.
@@ -447,15 +1122,32 @@ impl fmt::Debug for PublicContext {
 ///
 /// Implements `ZeroizeOnDrop` to clear owned string data. Sensitive variants
 /// receive additional volatile write treatment in `InternalContextField::drop()`
-/// to prevent compiler optimization of the clearing operation.
+/// to prevent compiler optimization of the clearing operation. The
+/// breadcrumb trail is covered by the same path - see [`Self`]'s `Zeroize`
+/// impl.
 ///
 /// # No Clone/Copy Policy
 ///
 /// Single-owner semantics prevent sensitive diagnostic data from being duplicated
 /// across memory regions, reducing attack surface for memory inspection.
-pub struct InternalContext(InternalContextField);
+pub struct InternalContext {
+    field: InternalContextField,
+    breadcrumbs: Vec<Breadcrumb>,
+    breadcrumb_cap: usize,
+}
 
 impl InternalContext {
+    /// Wrap a field with an empty breadcrumb trail at the default cap - the
+    /// shared tail of every public constructor below.
+    #[inline]
+    fn from_field(field: InternalContextField) -> Self {
+        Self {
+            field,
+            breadcrumbs: Vec::new(),
+            breadcrumb_cap: DEFAULT_BREADCRUMB_CAP,
+        }
+    }
+
     /// Create a standard diagnostic internal context.
     ///
     /// # Use Case
@@ -470,7 +1162,7 @@ impl InternalContext {
     /// ```
     #[inline]
     pub fn diagnostic(message: impl Into<Cow<'static, str>>) -> Self {
-        Self(InternalContextField::Diagnostic(message.into()))
+        Self::from_field(InternalContextField::Diagnostic(message.into()))
     }
 
     /// Create a sensitive internal context with best-effort memory clearing.
@@ -506,19 +1198,44 @@ impl InternalContext {
     /// ```
     #[inline]
     pub fn sensitive(message: impl Into<Cow<'static, str>>) -> Self {
-        Self(InternalContextField::Sensitive(message.into()))
+        Self::from_field(InternalContextField::Sensitive(message.into()))
     }
 
-    /// Create an internal context marked as deceptive.
+    /// Create a sensitive internal context tagged with a minimum
+    /// [`Clearance`] level, for the tiered access path.
     ///
     /// # Use Case
     ///
-    /// When internal logs themselves may be exfiltrated and you need to track
-    /// deceptive narratives without exposing them externally. The `payload()`
-    /// method will return this with a `Lie` marker to prevent SOC analysts from
-    /// treating it as authentic diagnostic data.
+    /// Same data-sensitivity cases as [`Self::sensitive`], but for payloads
+    /// that should require more than a blanket `SocAccess`/`Capability`
+    /// grant - e.g. raw credential material that only `Forensics`-level
+    /// investigators should see, versus PII an `Analyst` token can view.
+    /// Exposure is gated by [`crate::ledger::ClearanceToken`] via
+    /// [`DualContextError::expose_sensitive_at`], which also appends an
+    /// entry to a [`crate::ledger::AccessLedger`] regardless of outcome.
     ///
-    /// # Distinction from PublicContext::lie()
+    /// # Note
+    ///
+    /// This is orthogonal to, not a replacement for, `SocAccess`/`Capability`
+    /// gating: `expose_sensitive()` with a valid `SocAccess` still reveals
+    /// this content, the same as any other `Sensitive` context - `Clearance`
+    /// only adds a second, independently-enforced gate for callers going
+    /// through the tiered path.
+    #[inline]
+    pub fn sensitive_at(level: Clearance, message: impl Into<Cow<'static, str>>) -> Self {
+        Self::from_field(InternalContextField::SensitiveAt(message.into(), level))
+    }
+
+    /// Create an internal context marked as deceptive.
+    ///
+    /// # Use Case
+    ///
+    /// When internal logs themselves may be exfiltrated and you need to track
+    /// deceptive narratives without exposing them externally. The `payload()`
+    /// method will return this with a `Lie` marker to prevent SOC analysts from
+    /// treating it as authentic diagnostic data.
+    ///
+    /// # Distinction from PublicContext::lie()
     ///
     /// - `PublicContext::lie()`: For external consumption
     /// - `InternalContext::lie()`: For internal tracking of deception operations
@@ -530,7 +1247,7 @@ impl InternalContext {
     /// ```
     #[inline]
     pub fn lie(message: impl Into<Cow<'static, str>>) -> Self {
-        Self(InternalContextField::Lie(message.into()))
+        Self::from_field(InternalContextField::Lie(message.into()))
     }
 
     /// Get classification label for logging and metrics.
@@ -548,20 +1265,38 @@ impl InternalContext {
     /// routing different context types to different storage backends.
     #[inline]
     pub const fn classification(&self) -> &'static str {
-        match &self.0 {
+        match &self.field {
             InternalContextField::Diagnostic(_) => "InternalDiagnostic",
             InternalContextField::Sensitive(_) => "Sensitive",
+            InternalContextField::SensitiveAt(..) => "Sensitive",
             InternalContextField::Lie(_) => "InternalLie",
         }
     }
 
+    /// The minimum [`Clearance`] required to view this context via the
+    /// tiered path, if it was built with [`Self::sensitive_at`].
+    ///
+    /// `None` for every other variant, including plain [`Self::sensitive`]
+    /// contexts - those are only reachable via `SocAccess`/`Capability`, not
+    /// a `ClearanceToken`.
+    #[inline]
+    pub const fn required_clearance(&self) -> Option<Clearance> {
+        match &self.field {
+            InternalContextField::SensitiveAt(_, level) => Some(*level),
+            _ => None,
+        }
+    }
+
     /// Get structured payload for internal logging without heap allocation.
     ///
     /// # Returns
     ///
     /// - `Some(InternalPayload::Truth(_))`: For diagnostic contexts
     /// - `Some(InternalPayload::Lie(_))`: For lie contexts (marked for SOC awareness)
-    /// - `None`: For sensitive contexts (use `expose_sensitive()` instead)
+    /// - `Some(InternalPayload::Sensitive(_))`: For sensitive contexts, but
+    ///   only while a [`ForensicMode`] guard is live
+    /// - `None`: For sensitive contexts outside forensic mode (use
+    ///   `expose_sensitive()` instead)
     ///
     /// # Performance
     ///
@@ -584,9 +1319,11 @@ impl InternalContext {
     /// - Better performance under high error rates
     #[inline]
     pub fn payload(&self) -> Option<InternalPayload<'_>> {
-        match &self.0 {
+        match &self.field {
             InternalContextField::Diagnostic(c) => Some(InternalPayload::Truth(c.as_ref())),
-            InternalContextField::Sensitive(_) => None,
+            InternalContextField::Sensitive(c) | InternalContextField::SensitiveAt(c, _) => {
+                ForensicMode::is_active().then(|| InternalPayload::Sensitive(c.as_ref()))
+            }
             InternalContextField::Lie(c) => Some(InternalPayload::Lie(c.as_ref())),
         }
     }
@@ -633,17 +1370,230 @@ impl InternalContext {
     /// ```
     #[must_use]
     #[inline]
-    pub fn expose_sensitive(&self, _access: &SocAccess) -> Option<&str> {
-        match &self.0 {
+    pub fn expose_sensitive(&self, access: &SocAccess) -> Option<&str> {
+        let exposed = match &self.field {
             InternalContextField::Sensitive(c) => Some(c.as_ref()),
+            InternalContextField::SensitiveAt(c, _) => Some(c.as_ref()),
+            _ => None,
+        };
+        if exposed.is_some() {
+            access.record_exposure();
+        }
+        exposed
+    }
+
+    /// Read the raw content of any variant - including `Sensitive`/
+    /// `SensitiveAt` - without a [`SocAccess`] token or a live
+    /// [`ForensicMode`] guard.
+    ///
+    /// # Why This Doesn't Go Through `SocAccess`
+    ///
+    /// [`Self::expose_sensitive`]'s capability check exists to make *someone
+    /// reading sensitive content* a grep-able, audited event. This accessor
+    /// is for [`crate::integrity`]'s tag computation, which runs on every
+    /// sign/verify of a `DualContextError` - including fully automatic ones
+    /// triggered by ordinary error construction, with no human or SOC
+    /// workflow involved. Routing that through `SocAccess::acquire()` would
+    /// fire a real audit-trail entry on every such call, burying genuine SOC
+    /// exposures in routine bookkeeping noise. The tag still has to cover
+    /// the actual sensitive bytes (a redacted/omitted form wouldn't catch
+    /// tampering with sensitive content), so this reads them directly
+    /// instead of widening `expose_sensitive`'s contract.
+    ///
+    /// `pub(crate)` and deliberately not documented as a general escape
+    /// hatch: anything that surfaces this content to a human or an external
+    /// sink must still go through [`Self::expose_sensitive`] or
+    /// [`Self::payload`].
+    #[must_use]
+    pub(crate) fn signing_bytes(&self) -> &str {
+        match &self.field {
+            InternalContextField::Diagnostic(c)
+            | InternalContextField::Sensitive(c)
+            | InternalContextField::SensitiveAt(c, _)
+            | InternalContextField::Lie(c) => c.as_ref(),
+        }
+    }
+
+    /// Consume this context and take ownership of its message, bypassing
+    /// [`InternalContextField`]'s volatile zeroize-on-drop.
+    ///
+    /// # Access Control
+    ///
+    /// Gated by `SocAccess` exactly like [`Self::expose_sensitive`] - moving
+    /// a `Sensitive`/`SensitiveAt` payload out is strictly more exposure than
+    /// borrowing it, not less, so it earns the same capability check and
+    /// records the same audit-trail exposure.
+    ///
+    /// # Implementation
+    ///
+    /// `InternalContext` has no custom `Drop` of its own - the zeroization
+    /// lives entirely in [`InternalContextField::drop`] - so taking the
+    /// field out without running that `Drop` is the same
+    /// `ManuallyDrop`-plus-`ptr::read` dance `std::sync::Mutex::into_inner`
+    /// uses: wrap `self` so its destructor never fires, `ptr::read` the
+    /// field out (the only copy of it that will ever exist), then let the
+    /// empty shell evaporate.
+    ///
+    /// # Returns
+    ///
+    /// `Some(String)` for every current variant - there's no case where this
+    /// context has no message to give back. `Option` matches
+    /// [`Self::payload`]'s return shape for the same reason: future variants
+    /// might not always have one.
+    #[must_use]
+    pub fn into_inner(self, access: &SocAccess) -> Option<String> {
+        let shell = ManuallyDrop::new(self);
+        // SAFETY: `shell` is `ManuallyDrop<InternalContext>`, so its
+        // destructor never runs and never touches `shell.field` - this read is
+        // the only place that field's bytes are observed as an owned value.
+        // `mem::forget` below discards the (now-logically-moved-from) shell
+        // without invoking `InternalContext`'s (nonexistent) `Drop`, so
+        // `InternalContextField::drop`'s volatile zeroize never executes
+        // against the data this function is returning. `shell.breadcrumbs` is
+        // read out the same way, just so it still gets a normal `drop` below
+        // instead of being forgotten along with the rest of the shell - this
+        // function only grants an exemption to the one field it's handing
+        // back, not to the breadcrumb trail riding alongside it.
+        let mut field = unsafe { ptr::read(&shell.field) };
+        let breadcrumbs = unsafe { ptr::read(&shell.breadcrumbs) };
+        mem::forget(shell);
+        drop(breadcrumbs);
+        // `field` (owning a `Cow` behind a type that implements `Drop`) can't
+        // be moved out of by-value here (E0509) - match on `&mut field` and
+        // `mem::take` each arm's `Cow` instead, leaving `field` holding an
+        // empty one to drop normally at the end of this function.
+        match &mut field {
+            InternalContextField::Sensitive(cow) => {
+                access.record_exposure();
+                Some(mem::take(cow).into_owned())
+            }
+            InternalContextField::SensitiveAt(cow, _) => {
+                access.record_exposure();
+                Some(mem::take(cow).into_owned())
+            }
+            InternalContextField::Diagnostic(cow) | InternalContextField::Lie(cow) => {
+                Some(mem::take(cow).into_owned())
+            }
+        }
+    }
+
+    /// Expose sensitive content via the tiered [`Clearance`] path, if
+    /// `token_level` meets or exceeds this context's tagged requirement.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&str)`: this is a [`Self::sensitive_at`] context and
+    ///   `token_level >= required_clearance()`.
+    /// - `None`: otherwise - either not a clearance-tagged context at all,
+    ///   or `token_level` is below what's required.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Self::expose_sensitive`], this never reveals a plain
+    /// [`Self::sensitive`] (untagged) context - only [`Self::sensitive_at`]
+    /// ones, since only those carry a level to check `token_level` against.
+    /// Callers should use [`DualContextError::expose_sensitive_at`] rather
+    /// than this directly, so the attempt is recorded in an
+    /// [`crate::ledger::AccessLedger`] regardless of outcome.
+    #[must_use]
+    #[inline]
+    pub fn expose_at(&self, token_level: Clearance) -> Option<&str> {
+        match &self.field {
+            InternalContextField::SensitiveAt(c, required) if token_level >= *required => Some(c.as_ref()),
             _ => None,
         }
     }
+
+    /// Returns a [`RedactedView`] fingerprinting this context's underlying
+    /// bytes, for use in `Debug` output and analyst-facing logs that want
+    /// join-ability without total suppression.
+    ///
+    /// Works uniformly across all four variants - it's the `Debug` impl,
+    /// not this method, that decides which branches actually route through
+    /// it (see [`Self`]'s `Debug` impl: `Diagnostic` still shows full
+    /// content, since it was never sensitive to begin with).
+    ///
+    /// Not available under `no_std`: fingerprinting needs a process-random
+    /// salt, and `no_std` has no portable entropy source to seed one from.
+    #[cfg(not(feature = "no_std"))]
+    #[must_use]
+    pub fn redacted(&self) -> RedactedView<'_> {
+        let bytes = match &self.field {
+            InternalContextField::Diagnostic(c)
+            | InternalContextField::Sensitive(c)
+            | InternalContextField::SensitiveAt(c, _)
+            | InternalContextField::Lie(c) => c.as_bytes(),
+        };
+        RedactedView {
+            len: bytes.len(),
+            fingerprint: redaction_fingerprint(bytes),
+            _tied_to: core::marker::PhantomData,
+        }
+    }
+
+    /// Override this context's breadcrumb cap (default
+    /// [`DEFAULT_BREADCRUMB_CAP`]). A cap of `0` means every
+    /// [`Self::push_breadcrumb`] call is immediately discarded - useful for
+    /// a caller that wants to opt a context out of trail-keeping entirely
+    /// without special-casing the call site.
+    #[inline]
+    #[must_use]
+    pub fn with_breadcrumb_cap(mut self, cap: usize) -> Self {
+        self.breadcrumb_cap = cap;
+        while self.breadcrumbs.len() > cap {
+            self.breadcrumbs.remove(0);
+        }
+        self
+    }
+
+    /// Record one more internal "truth" event in this context's breadcrumb
+    /// trail, leading up to the single external message a
+    /// [`DualContextError`] ultimately surfaces - see this module's
+    /// `Breadcrumb` docs.
+    ///
+    /// # Bounded Memory
+    ///
+    /// The trail is capped at [`Self::with_breadcrumb_cap`] (or
+    /// [`DEFAULT_BREADCRUMB_CAP`] if never overridden): once full, pushing a
+    /// new breadcrumb drops the oldest one first, so an attacker-driven
+    /// error flood recording thousands of steps on one context can't grow
+    /// its memory unboundedly.
+    pub fn push_breadcrumb(&mut self, message: impl Into<Cow<'static, str>>, category: OperationCategory) {
+        if self.breadcrumb_cap == 0 {
+            return;
+        }
+        if self.breadcrumbs.len() >= self.breadcrumb_cap {
+            self.breadcrumbs.remove(0);
+        }
+        self.breadcrumbs.push(Breadcrumb {
+            message: message.into(),
+            category,
+        });
+    }
+
+    /// Read back this context's breadcrumb trail, oldest first.
+    ///
+    /// # Access Control
+    ///
+    /// Gated by `SocAccess` like [`Self::expose_sensitive`] - a breadcrumb
+    /// trail is internal diagnostic content even when no single entry is
+    /// itself `Sensitive`, so reading it earns the same capability check.
+    /// Unlike `expose_sensitive`, this never records an exposure on the
+    /// access token: the trail is [`InternalContextField::Diagnostic`]-like
+    /// by default, not sensitive-tagged content.
+    #[must_use]
+    #[inline]
+    pub fn breadcrumbs(&self, _access: &SocAccess) -> &[Breadcrumb] {
+        &self.breadcrumbs
+    }
 }
 
 impl Zeroize for InternalContext {
     fn zeroize(&mut self) {
-        self.0.zeroize();
+        self.field.zeroize();
+        for crumb in &mut self.breadcrumbs {
+            crumb.zeroize();
+        }
     }
 }
 
@@ -654,17 +1604,30 @@ impl fmt::Display for InternalContext {
     ///
     /// # Security Policy
     ///
-    /// This ALWAYS returns a redacted placeholder, never actual content.
-    /// Internal contexts should not be formatted for external display under
-    /// any circumstances. This implementation exists only to satisfy trait
-    /// bounds in generic code.
+    /// By default this ALWAYS returns a redacted placeholder, never actual
+    /// content - internal contexts should not be formatted for external
+    /// display under any circumstances. The one exception is a live
+    /// [`ForensicMode`] guard: while forensic mode is unlocked, this renders
+    /// the real content instead, for authenticated incident-response use.
+    /// With no guard live, behavior is unchanged from before `ForensicMode`
+    /// existed.
     ///
     /// # Correct Usage
     ///
     /// - Use `payload()` for SOC logging
     /// - Use `expose_sensitive()` for controlled sensitive access
-    /// - Do NOT use `Display` or `ToString` on internal contexts
+    /// - Use `ForensicMode::unlock()` for a scoped, authenticated exception
+    /// - Do NOT use `Display` or `ToString` on internal contexts outside
+    ///   forensic mode
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if ForensicMode::is_active() {
+            return match &self.field {
+                InternalContextField::Diagnostic(c)
+                | InternalContextField::Sensitive(c)
+                | InternalContextField::SensitiveAt(c, _)
+                | InternalContextField::Lie(c) => f.write_str(c.as_ref()),
+            };
+        }
         f.write_str("[INTERNAL CONTEXT REDACTED]")
     }
 }
@@ -675,7 +1638,9 @@ impl fmt::Debug for InternalContext {
     /// # Redaction Policy
     ///
     /// - Diagnostic: Shows full content (for debugging)
-    /// - Sensitive: Redacted (to prevent accidental logging)
+    /// - Sensitive: Shown as a [`RedactedView`] fingerprint (see
+    ///   [`InternalContext::redacted`]) under `std`, or a bare
+    ///   `[REDACTED]` under `no_std`
     /// - Lie: Redacted (to prevent aggregation as factual data)
     ///
     /// # Use Case
@@ -683,9 +1648,16 @@ impl fmt::Debug for InternalContext {
     /// Primarily for unit tests and local development. Production logging should
     /// use `payload()` or `expose_sensitive()` for explicit control.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.0 {
+        match &self.field {
             InternalContextField::Diagnostic(c) => write!(f, "InternalDiagnostic({:?})", c),
+            #[cfg(not(feature = "no_std"))]
+            InternalContextField::Sensitive(_) => write!(f, "{}", self.redacted()),
+            #[cfg(feature = "no_std")]
             InternalContextField::Sensitive(_) => write!(f, "Sensitive([REDACTED])"),
+            #[cfg(not(feature = "no_std"))]
+            InternalContextField::SensitiveAt(_, level) => write!(f, "SensitiveAt({:?}, {})", level, self.redacted()),
+            #[cfg(feature = "no_std")]
+            InternalContextField::SensitiveAt(_, level) => write!(f, "SensitiveAt({:?}, [REDACTED])", level),
             InternalContextField::Lie(_) => write!(f, "InternalLie([REDACTED])"),
         }
     }
@@ -718,8 +1690,9 @@ impl fmt::Debug for InternalContext {
 /// match context.payload() {
 ///     Some(InternalPayload::Truth(msg)) => soc_log!("DIAG: {}", msg),
 ///     Some(InternalPayload::Lie(msg)) => soc_log!("LIE: {}", msg),
+///     Some(InternalPayload::Sensitive(msg)) => soc_log!("FORENSIC: {}", msg),
 ///     None => {
-///         // Sensitive - requires explicit access
+///         // Sensitive, and no ForensicMode guard is live - requires explicit access
 ///         let access = SocAccess::acquire();
 ///         if let Some(sensitive) = context.expose_sensitive(&access) {
 ///             secure_log_encrypted(sensitive);
@@ -731,6 +1704,9 @@ impl fmt::Debug for InternalContext {
 pub enum InternalPayload<'a> {
     Truth(&'a str),
     Lie(&'a str),
+    /// A sensitive context's raw content, yielded only while a
+    /// [`ForensicMode`] guard is live - see [`InternalContext::payload`].
+    Sensitive(&'a str),
 }
 
 impl<'a> InternalPayload<'a> {
@@ -748,7 +1724,7 @@ impl<'a> InternalPayload<'a> {
     #[inline]
     pub const fn as_str(&self) -> &'a str {
         match self {
-            Self::Truth(s) | Self::Lie(s) => s,
+            Self::Truth(s) | Self::Lie(s) | Self::Sensitive(s) => s,
         }
     }
 
@@ -775,20 +1751,104 @@ impl<'a> fmt::Display for InternalPayload<'a> {
     ///
     /// - Truth: Raw message (no prefix)
     /// - Lie: `[LIE] {message}`
+    /// - Sensitive: `[FORENSIC-UNLOCKED] {message}`
     ///
     /// # Rationale
     ///
     /// The `[LIE]` prefix prevents SOC analysts from mistaking deceptive content
     /// for authentic diagnostic data when reviewing logs. This is critical when
-    /// logs may be exported to systems that lack context classification.
+    /// logs may be exported to systems that lack context classification. The
+    /// `[FORENSIC-UNLOCKED]` prefix plays the same role for `Sensitive` payloads:
+    /// a reader should never mistake forensic-mode output for the normal,
+    /// always-redacted default.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Truth(s) => f.write_str(s),
             Self::Lie(s) => write!(f, "[LIE] {}", s),
+            Self::Sensitive(s) => write!(f, "[FORENSIC-UNLOCKED] {}", s),
         }
     }
 }
 
+// ============================================================================
+// Typed Sensitive Wrapper (Generic Over Structured Secrets)
+// ============================================================================
+
+/// A single typed, zeroizing sensitive value - a narrower, additive sibling
+/// to [`InternalContext::sensitive`] for callers who already hold structured
+/// data (a parsed socket address, a `Zeroizing<Vec<u8>>` key buffer, a
+/// custom token struct) and don't want to pay for stringifying it just to
+/// get `InternalContext`'s zeroize-on-drop treatment.
+///
+/// Mirrors safelog's generic `Sensitive<T>` wrapper: `T` is zeroized
+/// precisely, field by field via its own [`Zeroize`] impl, rather than as a
+/// lossy string copy of its `Display` output.
+///
+/// # Scope
+///
+/// This is intentionally narrower than a full generic `InternalContext<T>`.
+/// `payload()`, the `Display`/`Debug` redaction policy, `integrity.rs`
+/// signing, and the `serde`/`http` export paths are all built around
+/// [`InternalPayload`]'s borrowed-`&str` shape, which only the
+/// `Cow<'static, str>` payloads (`InternalContext::sensitive` and friends)
+/// participate in today. This type is a first step - real per-field
+/// zeroization for non-string secrets, gated by [`SocAccess`] the same way
+/// - without yet re-plumbing every string-shaped consumer in the crate to
+/// be generic over `T`.
+///
+/// # No Clone/Copy Policy
+///
+/// Same reasoning as [`InternalContext`]: single-owner semantics keep
+/// exactly one copy of the structured secret alive to zeroize.
+pub struct TypedSensitive<T: Zeroize>(T);
+
+impl<T: Zeroize> TypedSensitive<T> {
+    /// Wrap a structured secret for zeroize-on-drop storage.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Get the wrapped value, gated by [`SocAccess`] exactly like
+    /// [`InternalContext::expose_sensitive`].
+    #[inline]
+    pub fn expose(&self, access: &SocAccess) -> &T {
+        access.record_exposure();
+        &self.0
+    }
+}
+
+impl<T: Zeroize> Zeroize for TypedSensitive<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for TypedSensitive<T> {}
+
+impl<T: Zeroize> Drop for TypedSensitive<T> {
+    fn drop(&mut self) {
+        // Unlike `InternalContextField::drop()`, there's no hand-rolled
+        // volatile-write loop here - we don't know `T`'s layout, so we
+        // dispatch entirely through its own `Zeroize` impl (the zeroize
+        // crate's own `Vec<u8>`/`String`/etc. impls already use volatile
+        // writes internally). The `compiler_fence` below provides the same
+        // "zeroization completes before any subsequent destructor logic"
+        // ordering guarantee `InternalContextField::drop()` documents.
+        self.zeroize();
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for TypedSensitive<T> {
+    /// Always redacted, regardless of `T` - mirrors [`InternalContext`]'s
+    /// `Debug` default so structured secrets can't leak through `{:?}` any
+    /// more easily than string ones can.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TypedSensitive([REDACTED])")
+    }
+}
+
 // ============================================================================
 // Operation Category
 // ============================================================================
@@ -847,6 +1907,25 @@ pub enum OperationCategory {
 }
 
 impl OperationCategory {
+    /// Every category, in declaration order.
+    ///
+    /// Used by code that needs to enumerate the full category space, such as
+    /// [`crate::manifest::taxonomy_manifest`] building a permission matrix
+    /// across all namespace/category pairs.
+    pub const ALL: &'static [OperationCategory] = &[
+        Self::Configuration,
+        Self::Deployment,
+        Self::Monitoring,
+        Self::Analysis,
+        Self::Response,
+        Self::Audit,
+        Self::System,
+        Self::IO,
+        Self::Deception,
+        Self::Detection,
+        Self::Containment,
+    ];
+
     /// Get the authentic display name for this category.
     ///
     /// # Returns
@@ -900,134 +1979,769 @@ impl OperationCategory {
             _ => self.display_name(),
         }
     }
+
+    /// Resolve a category from its [`Self::display_name`] (e.g. `"I/O"` or
+    /// `"Configuration"`), the inverse of that function.
+    ///
+    /// # Use Case
+    ///
+    /// Lets external configuration - such as
+    /// [`crate::config::PalisadeConfig`]'s per-category tables - name a
+    /// category as a plain string instead of requiring a generated
+    /// namespace-style token, while still resolving to the real enum rather
+    /// than staying a loose `String` throughout the crate.
+    pub fn from_display_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|c| c.display_name() == name)
+    }
 }
 
 // ============================================================================
-// Dual-Context Error with Invariant Enforcement
+// Response Recommendation (Automated Triage Hint)
 // ============================================================================
 
-/// Dual-context error model for honeypot systems with constructor-enforced invariants.
+/// Suggested automated-response action for a [`DualContextError`], mirroring
+/// structured diagnostics' suggestion/`Applicability` pairing - but aimed at
+/// a SOC/SOAR pipeline deciding how to react to a honeypot signal, rather
+/// than a human deciding whether to apply a code fix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseAction {
+    /// Keep watching; nothing warrants acting on yet.
+    Observe,
+    /// Rate-limit or otherwise slow down the offending source.
+    Throttle,
+    /// Isolate or quarantine the offending source or session.
+    Isolate,
+    /// Page a human - this needs attention now.
+    Alert,
+}
+
+/// How confident a [`ResponseHint`] is in its recommended
+/// [`ResponseAction`].
 ///
-/// # Type Safety Guarantees
+/// # Why Not `Confidence`
 ///
-/// 1. Public and internal contexts use distinct wrapper types (cannot be confused)
-/// 2. Fields are private (all construction goes through validated constructors)
-/// 3. Constructors enforce semantic consistency rules at creation time
+/// [`Confidence`] already exists and grades a SOC-facing *remediation
+/// suggestion* ("is this hint safe to apply automatically"). A triage
+/// decision about *what to do with an attacker* is a different question
+/// answered on a different scale, so this is its own type rather than a
+/// repurposed variant set - reusing the name `Confidence` here would also
+/// collide with it at the crate root, the same kind of collision
+/// `emission.rs`'s module docs avoid by not re-exporting its `JsonEmitter`
+/// under the name `context.rs`'s `JsonEmitter` already uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriageConfidence {
+    /// Matched against known attack signatures; no corroboration yet.
+    Heuristic,
+    /// Matches more than one independent signal.
+    Corroborated,
+    /// Verified by a human or a trusted out-of-band source.
+    Confirmed,
+}
+
+/// A typed automated-response recommendation attached to a
+/// [`DualContextError`].
 ///
-/// # Enforced Invariants
+/// # Security
 ///
-/// - Public truth requires internal truth (no internal lies when external truth)
-/// - Public lie allows any internal context (deception is flexible)
-/// - Sensitive data flows only through InternalContext (type system prevents external leakage)
+/// Never reachable through `Display`/`external_message()` - only through
+/// [`DualContextError::response_hint`]. Unlike `expose_sensitive`, this
+/// carries no attacker-authored content, only an internal decision made
+/// *about* them, so it's a plain internal accessor rather than one gated
+/// behind [`SocAccess`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResponseHint {
+    action: ResponseAction,
+    confidence: TriageConfidence,
+}
+
+impl ResponseHint {
+    /// Build a response hint from an action and confidence pair.
+    #[inline]
+    pub const fn new(action: ResponseAction, confidence: TriageConfidence) -> Self {
+        Self { action, confidence }
+    }
+
+    /// The recommended action.
+    #[inline]
+    pub const fn action(&self) -> ResponseAction {
+        self.action
+    }
+
+    /// How confident the recommendation is.
+    #[inline]
+    pub const fn confidence(&self) -> TriageConfidence {
+        self.confidence
+    }
+}
+
+/// Default [`ResponseHint`] for a category, so [`DualContextError::response_hint`]
+/// has a sensible answer without every caller specifying one explicitly.
 ///
-/// # Constructor Selection
+/// # Defaults
 ///
-/// - `with_lie()`: Public deception + internal diagnostic (most common)
-/// - `with_lie_and_sensitive()`: Public deception + best-effort cleared sensitive internal
-/// - `with_truth()`: Public truth + internal truth (feature-gated, enforces consistency)
-/// - `with_double_lie()`: Public deception + internal deception (for log exfiltration scenarios)
+/// - [`OperationCategory::Detection`] / [`OperationCategory::Containment`]:
+///   `Isolate`/`Heuristic` - these categories already represent a defensive
+///   signal worth acting on, even before any corroboration.
+/// - Every other category: `None` - no opinion. Most categories (e.g.
+///   `Configuration`, `IO`) have no inherent "this looks like an attack"
+///   signal to key a default off of.
+#[inline]
+pub const fn default_hint(category: OperationCategory) -> Option<ResponseHint> {
+    match category {
+        OperationCategory::Detection | OperationCategory::Containment => Some(ResponseHint::new(
+            ResponseAction::Isolate,
+            TriageConfidence::Heuristic,
+        )),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Severity
+// ============================================================================
+
+/// Diagnostic severity level, borrowed from rustc's diagnostic taxonomy.
 ///
-/// # Memory Management
+/// # Design Principle
 ///
-/// Implements `ZeroizeOnDrop` to clear all owned string data. Sensitive contexts
-/// receive additional volatile write treatment in `InternalContextField::drop()`
-/// to prevent LLVM from eliding the zeroization as a dead-store optimization.
+/// Severity is split across the trust boundary exactly like messages are:
+/// `DualContextError` carries an `external_severity()` (what an attacker is
+/// allowed to infer - possibly a deliberately misleading level) and a
+/// `SocAccess`-gated `internal_severity()` (the true operational severity).
+/// A honeypot can report a brute-force lockout as `Warning` externally while
+/// logging it internally as `Fatal`.
 ///
-/// This provides best-effort memory clearing but does not guarantee:
-/// - Hardware cache flushes
-/// - Cross-thread memory visibility
-/// - Protection against allocator reuse before physical clear
+/// # Ordering
 ///
-/// # No Clone/Copy Policy
+/// Listed from most to least severe, matching rustc's own ordering.
 ///
-/// Single-owner semantics prevent:
-/// - Duplicate error contexts in memory (reduced attack surface)
-/// - Inconsistent public/internal message pairs
-/// - Accidental persistence of sensitive data across scopes
-pub struct DualContextError {
-    public: PublicContext,
-    internal: InternalContext,
-    category: OperationCategory,
+/// # Copy Semantics
+///
+/// Like `OperationCategory`, this is a small fieldless enum with no owned or
+/// sensitive data, so `Copy` is appropriate here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Internal invariant violation - should be unreachable in correct code.
+    Bug,
+    /// Unrecoverable failure; the operation cannot continue.
+    Fatal,
+    /// Recoverable failure requiring attention.
+    Error,
+    /// Degraded behavior that did not block the operation.
+    Warning,
+    /// Informational context, no action required.
+    Note,
+    /// Suggested remediation or next step.
+    Help,
 }
 
-impl DualContextError {
-    /// Internal constructor from pre-built contexts.
+impl Severity {
+    /// Get the rustc-style lowercase label for this severity (e.g. `"warning"`).
     ///
-    /// This is crate-private to preserve external API invariants.
+    /// # Performance
+    ///
+    /// Const function compiled to direct pointer return. Zero runtime cost.
     #[inline]
-    pub(crate) fn new(
-        public: PublicContext,
-        internal: InternalContext,
-        category: OperationCategory,
-    ) -> Self {
-        Self {
-            public,
-            internal,
-            category,
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Bug => "bug",
+            Self::Fatal => "fatal",
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+            Self::Help => "help",
         }
     }
+}
 
-    /// Create error with public deception and internal diagnostic.
-    ///
-    /// # Use Case
-    ///
-    /// Standard constructor for honeypot deployments. External attackers see
-    /// deceptive error message while SOC analysts see actual diagnostic data.
-    ///
-    /// # Invariant
-    ///
-    /// Public message is explicitly marked as `DeceptiveLie`. Internal message
-    /// is authentic diagnostic data for SOC analysis.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// DualContextError::with_lie(
-    ///     "Permission denied",  // Attacker sees generic error
-    ///     "Blocked SQL injection attempt: UNION SELECT detected in query parameter 'id'",
-    ///     OperationCategory::Detection,
-    /// )
-    /// ```
-    ///
-    /// # Performance
-    ///
-    /// Zero allocation if string literals are passed. `Into<Cow<'static, str>>`
-    /// allows both literals and owned strings without forcing allocation.
+impl Default for Severity {
+    /// Defaults to `Error`, preserving the severity-less behavior that
+    /// existed before this type was introduced.
     #[inline]
-    pub fn with_lie(
-        public_lie: impl Into<Cow<'static, str>>,
-        internal_diagnostic: impl Into<Cow<'static, str>>,
-        category: OperationCategory,
-    ) -> Self {
-        Self {
-            public: PublicContext::lie(public_lie),
-            internal: InternalContext::diagnostic(internal_diagnostic),
-            category,
-        }
+    fn default() -> Self {
+        Self::Error
     }
+}
 
-    /// Create error with public deception and sensitive internal data.
-    ///
-    /// # Use Case
-    ///
-    /// When internal diagnostic contains PII, credentials, file paths, or other
-    /// high-value data requiring best-effort memory clearing on drop.
-    ///
-    /// # Memory Clearing Strategy
-    ///
-    /// When this error is dropped, sensitive data receives:
-    /// 1. High-level clearing via `zeroize` crate
-    /// 2. Volatile writes to prevent compiler optimization
-    /// 3. Compiler fence to prevent instruction reordering
-    ///
-    /// This provides best-effort defense against casual memory inspection and
-    /// compiler optimizations. See module-level docs for limitations.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// DualContextError::with_lie_and_sensitive(
-    ///     "Resource not found",
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+// ============================================================================
+// Source Location Capture
+// ============================================================================
+
+/// Call-site location captured by the error-creation macros.
+///
+/// # Rationale
+///
+/// `file!()`, `line!()`, and `column!()` are compile-time literals, so capturing
+/// them costs nothing at runtime and leaks nothing sensitive (they describe the
+/// honeypot's own source tree, not attacker-controlled data). Storing them lets
+/// `DualContextError::render_diagnostic()` produce rustc/cargo-style output that
+/// operators can jump to directly with their editor's "open at location" support.
+///
+/// # Copy Semantics
+///
+/// Like `OperationCategory`, this is plain compile-time metadata with no owned
+/// or sensitive data, so `Copy` is appropriate here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Source file path as captured by `file!()`.
+    pub file: &'static str,
+    /// Line number as captured by `line!()`.
+    pub line: u32,
+    /// Column number as captured by `column!()`.
+    pub column: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+// ============================================================================
+// Structured Metadata
+// ============================================================================
+
+/// Trust classification for a single metadata entry.
+///
+/// # Design Principle
+///
+/// Mirrors the public/internal split already applied to the error message
+/// itself. A `correlation_id` is usually safe to hand back to a caller for a
+/// support ticket; a `session_token` attached for forensic purposes is not.
+/// Tagging each entry lets `DualContextError::public_metadata()` filter
+/// deterministically instead of relying on callers to remember which keys
+/// are safe to surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataTrust {
+    /// Safe to surface to external/untrusted consumers.
+    Public,
+    /// SOC-only visibility, same trust level as `InternalContext`.
+    Internal,
+}
+
+/// Metadata key-value pair with automatic zeroization.
+///
+/// # Design Rationale
+///
+/// Keys are `&'static str` because metadata keys should be compile-time
+/// constants (e.g. "correlation_id", "session_token"). This prevents runtime
+/// injection and keeps the metadata schema greppable.
+///
+/// Values are `Cow<'static, str>` to support both:
+/// - Static metadata: `Cow::Borrowed("literal")`
+/// - Dynamic metadata: `Cow::Owned(runtime_string)`
+///
+/// Only `Cow::Owned` variants are zeroized, as borrowed data points to static
+/// program memory that cannot be cleared.
+///
+/// # No Clone Policy
+///
+/// Matches the parent `ContextMetadata` no-clone policy to prevent lifetime
+/// extension of sensitive values.
+#[allow(dead_code)]
+struct MetadataEntry {
+    key: &'static str,
+    value: Cow<'static, str>,
+    trust: MetadataTrust,
+}
+
+impl Zeroize for MetadataEntry {
+    fn zeroize(&mut self) {
+        // Keys are static, only zeroize owned values
+        if let Cow::Owned(ref mut s) = self.value {
+            s.zeroize();
+        }
+    }
+}
+
+impl Drop for MetadataEntry {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Structured metadata collection attached to a `DualContextError`, modeled on
+/// rustc's `DiagnosticArg` key/value list.
+///
+/// # Capacity Choice
+///
+/// `SmallVec<[T; 4]>` based on profiling:
+/// - 90% of errors have ≤2 metadata entries
+/// - 4 entries fit in ~192 bytes (acceptable inline size)
+/// - Avoids heap allocation for typical cases
+/// - Degrades gracefully to heap for exceptional cases
+///
+/// # Security
+///
+/// All metadata is zeroized on drop. Use [`MetadataTrust`] to mark entries
+/// that are safe to surface externally (e.g. correlation IDs); everything
+/// else defaults to SOC-only visibility, same as `InternalContext`.
+///
+/// # No Clone Policy
+///
+/// This type does NOT implement Clone to prevent accidental lifetime
+/// extension of sensitive data. Cloning would multiply zeroization sites and
+/// complicate threat modeling under memory inspection attacks.
+pub struct ContextMetadata {
+    entries: SmallVec<[MetadataEntry; 4]>,
+}
+
+impl ContextMetadata {
+    /// Create an empty metadata collection.
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: SmallVec::new(),
+        }
+    }
+
+    /// Add a metadata entry with an explicit trust classification.
+    #[inline]
+    pub(crate) fn add(
+        &mut self,
+        key: &'static str,
+        value: impl Into<Cow<'static, str>>,
+        trust: MetadataTrust,
+    ) {
+        self.entries.push(MetadataEntry {
+            key,
+            value: value.into(),
+            trust,
+        });
+    }
+
+    /// Get metadata value by key.
+    ///
+    /// Returns the first matching entry if multiple exist with the same key.
+    #[inline]
+    pub(crate) fn get(&self, key: &'static str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.key == key)
+            .map(|e| e.value.as_ref())
+    }
+
+    /// Iterate over all metadata entries, regardless of trust classification.
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.entries.iter().map(|e| (e.key, e.value.as_ref()))
+    }
+
+    /// Iterate over all entries together with their trust classification.
+    #[inline]
+    pub(crate) fn entries_with_trust(&self) -> impl Iterator<Item = (&'static str, &str, MetadataTrust)> {
+        self.entries.iter().map(|e| (e.key, e.value.as_ref(), e.trust))
+    }
+
+    /// Iterate over only the entries tagged `MetadataTrust::Public`.
+    #[inline]
+    pub(crate) fn public_iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.entries
+            .iter()
+            .filter(|e| e.trust == MetadataTrust::Public)
+            .map(|e| (e.key, e.value.as_ref()))
+    }
+
+    /// Iterate over entries whose key is authorized by `capability`'s
+    /// [`CapabilityScope`], regardless of `MetadataTrust`.
+    ///
+    /// This is the metadata counterpart to `DualContextError::expose_sensitive`:
+    /// a capability holder sees only the keys its scope explicitly lists via
+    /// [`CapabilityScope::with_metadata_keys`], never the full internal set.
+    #[cfg(not(feature = "no_std"))]
+    #[inline]
+    pub(crate) fn expose_with<'a>(
+        &'a self,
+        capability: &'a Capability,
+    ) -> impl Iterator<Item = (&'static str, &'a str)> {
+        self.entries
+            .iter()
+            .filter(move |e| capability.scope().allows_key(e.key))
+            .map(|e| (e.key, e.value.as_ref()))
+    }
+
+    /// Check if metadata is empty.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get number of metadata entries.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl Default for ContextMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Zeroize for ContextMetadata {
+    fn zeroize(&mut self) {
+        for entry in &mut self.entries {
+            entry.zeroize();
+        }
+        self.entries.clear();
+    }
+}
+
+impl Drop for ContextMetadata {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+// ============================================================================
+// SOC Remediation Suggestions
+// ============================================================================
+
+/// Confidence level for a SOC-facing remediation suggestion, mirroring
+/// rustc's `Applicability` for diagnostic suggestions.
+///
+/// # Use Case
+///
+/// Lets an automated incident-response playbook decide whether to act on a
+/// `MachineApplicable` hint directly or surface a `MaybeIncorrect` one for
+/// human review first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Confidence {
+    /// The suggestion is known to be correct and safe to apply automatically.
+    MachineApplicable,
+    /// The suggestion is probably correct but needs human judgement.
+    MaybeIncorrect,
+    /// The suggestion has placeholders that must be filled in by hand.
+    HasPlaceholders,
+    /// Confidence could not be determined; treat as requiring manual review.
+    Unspecified,
+}
+
+/// A SOC-facing remediation suggestion attached to a `DualContextError`.
+///
+/// # Security
+///
+/// Strictly internal: zeroized on drop like other sensitive internal data,
+/// and only reachable via `DualContextError::remediations()`, which is gated
+/// behind `SocAccess`. Never included in `external_message()` or
+/// `ContextChain::external_summary()`.
+pub struct Remediation {
+    hint: Cow<'static, str>,
+    confidence: Confidence,
+}
+
+impl Remediation {
+    #[inline]
+    pub(crate) fn new(hint: impl Into<Cow<'static, str>>, confidence: Confidence) -> Self {
+        Self {
+            hint: hint.into(),
+            confidence,
+        }
+    }
+
+    /// The human-readable "how to fix / how to confirm" guidance.
+    #[inline]
+    pub fn hint(&self) -> &str {
+        self.hint.as_ref()
+    }
+
+    /// How much an automated playbook should trust this suggestion.
+    #[inline]
+    pub const fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+}
+
+impl Zeroize for Remediation {
+    fn zeroize(&mut self) {
+        if let Cow::Owned(ref mut s) = self.hint {
+            s.zeroize();
+        }
+    }
+}
+
+impl Drop for Remediation {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+// ============================================================================
+// Dual-Context Error with Invariant Enforcement
+// ============================================================================
+
+/// Dual-context error model for honeypot systems with constructor-enforced invariants.
+///
+/// # Type Safety Guarantees
+///
+/// 1. Public and internal contexts use distinct wrapper types (cannot be confused)
+/// 2. Fields are private (all construction goes through validated constructors)
+/// 3. Constructors enforce semantic consistency rules at creation time
+///
+/// # Enforced Invariants
+///
+/// - Public truth requires internal truth (no internal lies when external truth)
+/// - Public lie allows any internal context (deception is flexible)
+/// - Sensitive data flows only through InternalContext (type system prevents external leakage)
+///
+/// # Constructor Selection
+///
+/// - `with_lie()`: Public deception + internal diagnostic (most common)
+/// - `with_lie_and_sensitive()`: Public deception + best-effort cleared sensitive internal
+/// - `with_truth()`: Public truth + internal truth (feature-gated, enforces consistency)
+/// - `with_double_lie()`: Public deception + internal deception (for log exfiltration scenarios)
+///
+/// # Memory Management
+///
+/// Implements `ZeroizeOnDrop` to clear all owned string data. Sensitive contexts
+/// receive additional volatile write treatment in `InternalContextField::drop()`
+/// to prevent LLVM from eliding the zeroization as a dead-store optimization.
+///
+/// This provides best-effort memory clearing but does not guarantee:
+/// - Hardware cache flushes
+/// - Cross-thread memory visibility
+/// - Protection against allocator reuse before physical clear
+///
+/// # No Clone/Copy Policy
+///
+/// Single-owner semantics prevent:
+/// - Duplicate error contexts in memory (reduced attack surface)
+/// - Inconsistent public/internal message pairs
+/// - Accidental persistence of sensitive data across scopes
+pub struct DualContextError {
+    public: PublicContext,
+    internal: InternalContext,
+    category: OperationCategory,
+    code: Option<&'static ErrorCode>,
+    location: Option<SourceLocation>,
+    external_severity: Severity,
+    internal_severity: Severity,
+    metadata: ContextMetadata,
+    remediations: SmallVec<[Remediation; 2]>,
+    integrity_tag: Option<crate::integrity::IntegrityTag>,
+    response_hint: Option<ResponseHint>,
+    /// Drop-bomb latch for [`set_unhandled_error_hook`] - see "Unhandled
+    /// Detection" below. Plain `AtomicBool` rather than a `bool` even though
+    /// `DualContextError` is `!Clone`: `Drop::drop` only has `&mut self`,
+    /// but `mark_emitted` is called from shared-reference accessor methods
+    /// like `external_message(&self)`.
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    emitted: AtomicBool,
+}
+
+impl DualContextError {
+    /// Internal constructor from pre-built contexts.
+    ///
+    /// This is crate-private to preserve external API invariants.
+    #[inline]
+    pub(crate) fn new(
+        public: PublicContext,
+        internal: InternalContext,
+        category: OperationCategory,
+    ) -> Self {
+        Self {
+            public,
+            internal,
+            category,
+            code: None,
+            location: None,
+            external_severity: Severity::Error,
+            internal_severity: Severity::Error,
+            metadata: ContextMetadata::new(),
+            remediations: SmallVec::new(),
+            integrity_tag: None,
+            response_hint: None,
+            #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+            emitted: AtomicBool::new(false),
+        }
+    }
+
+    /// Attach an external and internal severity, overriding the `Error` default.
+    ///
+    /// # Use Case
+    ///
+    /// Called by `ContextBuilder::try_build()` once severity has been set via
+    /// `.severity()`, `.external_severity()`, or `.internal_severity()`. Like
+    /// `with_code()`/`with_location()`, this is a chainable post-construction
+    /// setter rather than a constructor parameter, since most errors are happy
+    /// with the default and don't need it threaded through every constructor.
+    #[inline]
+    pub(crate) fn with_severity_pair(mut self, external: Severity, internal: Severity) -> Self {
+        self.external_severity = external;
+        self.internal_severity = internal;
+        self
+    }
+
+    /// Attach the taxonomy error code this error was raised for.
+    ///
+    /// # Use Case
+    ///
+    /// Called by the category macros (`config_err!`, `io_err!`, etc.) so that
+    /// `render_diagnostic()` can print a rustc-style `error[E-CFG-100]:` header.
+    /// Optional: errors constructed directly via `with_lie()` and friends have
+    /// no code attached and render without the bracketed tag.
+    #[inline]
+    pub fn with_code(mut self, code: &'static ErrorCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Attach the source location this error was raised at.
+    ///
+    /// # Use Case
+    ///
+    /// Called by the category macros with `file!()`, `line!()`, and `column!()`
+    /// captured at the call site. These are compile-time literals describing
+    /// the honeypot's own source tree, so recording them leaks nothing to
+    /// attackers while giving operators a grep-friendly, jump-to-source anchor.
+    #[inline]
+    pub fn with_location(mut self, file: &'static str, line: u32, column: u32) -> Self {
+        self.location = Some(SourceLocation { file, line, column });
+        self
+    }
+
+    /// Get the source location this error was raised at, if captured.
+    #[inline]
+    pub const fn location(&self) -> Option<SourceLocation> {
+        self.location
+    }
+
+    /// Get the taxonomy error code this error was raised for, if attached.
+    #[inline]
+    pub const fn error_code(&self) -> Option<&'static ErrorCode> {
+        self.code
+    }
+
+    /// The [`crate::definitions::ErrorDefinition`] this error's attached code
+    /// resolves to, if one was attached and it's registered in
+    /// [`crate::definitions::REGISTRY`].
+    #[inline]
+    pub(crate) fn definition(&self) -> Option<&'static crate::definitions::ErrorDefinition> {
+        crate::definitions::describe(self.error_code()?.code())
+    }
+
+    /// Attach an explicit automated-response recommendation, overriding
+    /// whatever [`default_hint`] would otherwise supply for this error's
+    /// category.
+    #[inline]
+    pub fn with_response_hint(mut self, hint: ResponseHint) -> Self {
+        self.response_hint = Some(hint);
+        self
+    }
+
+    /// Get the automated-response recommendation for this error, falling
+    /// back to [`default_hint`] for the error's category when none was
+    /// explicitly attached via [`Self::with_response_hint`].
+    ///
+    /// # Security
+    ///
+    /// Carries no attacker-authored content - see [`ResponseHint`]'s own
+    /// doc comment - so this is a plain accessor, not gated behind
+    /// [`SocAccess`] and never routed through `mark_emitted()`.
+    #[inline]
+    pub const fn response_hint(&self) -> Option<ResponseHint> {
+        match self.response_hint {
+            Some(hint) => Some(hint),
+            None => default_hint(self.category),
+        }
+    }
+
+    /// Render the internal context in rustc/cargo-style diagnostic form.
+    ///
+    /// # Output
+    ///
+    /// ```text
+    /// error[E-CFG-100]: Configuration op 'validate': invalid threshold value
+    ///   --> src/config.rs:42:9
+    /// ```
+    ///
+    /// If no code or location was attached, those lines are omitted. Only the
+    /// internal context is ever included here (or a redacted marker for
+    /// `Sensitive` contexts) - this is a SOC-facing forensic view and must
+    /// never be routed to `external_message()` or any attacker-visible sink.
+    #[inline]
+    pub fn render_diagnostic(&self) -> Diagnostic<'_> {
+        Diagnostic { error: self }
+    }
+
+    /// Create error with public deception and internal diagnostic.
+    ///
+    /// # Use Case
+    ///
+    /// Standard constructor for honeypot deployments. External attackers see
+    /// deceptive error message while SOC analysts see actual diagnostic data.
+    ///
+    /// # Invariant
+    ///
+    /// Public message is explicitly marked as `DeceptiveLie`. Internal message
+    /// is authentic diagnostic data for SOC analysis.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// DualContextError::with_lie(
+    ///     "Permission denied",  // Attacker sees generic error
+    ///     "Blocked SQL injection attempt: UNION SELECT detected in query parameter 'id'",
+    ///     OperationCategory::Detection,
+    /// )
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// Zero allocation if string literals are passed. `Into<Cow<'static, str>>`
+    /// allows both literals and owned strings without forcing allocation.
+    #[inline]
+    pub fn with_lie(
+        public_lie: impl Into<Cow<'static, str>>,
+        internal_diagnostic: impl Into<Cow<'static, str>>,
+        category: OperationCategory,
+    ) -> Self {
+        Self {
+            public: PublicContext::lie(public_lie),
+            internal: InternalContext::diagnostic(internal_diagnostic),
+            category,
+            code: None,
+            location: None,
+            external_severity: Severity::Error,
+            internal_severity: Severity::Error,
+            metadata: ContextMetadata::new(),
+            remediations: SmallVec::new(),
+            integrity_tag: None,
+            response_hint: None,
+            #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+            emitted: AtomicBool::new(false),
+        }
+    }
+
+    /// Create error with public deception and sensitive internal data.
+    ///
+    /// # Use Case
+    ///
+    /// When internal diagnostic contains PII, credentials, file paths, or other
+    /// high-value data requiring best-effort memory clearing on drop.
+    ///
+    /// # Memory Clearing Strategy
+    ///
+    /// When this error is dropped, sensitive data receives:
+    /// 1. High-level clearing via `zeroize` crate
+    /// 2. Volatile writes to prevent compiler optimization
+    /// 3. Compiler fence to prevent instruction reordering
+    ///
+    /// This provides best-effort defense against casual memory inspection and
+    /// compiler optimizations. See module-level docs for limitations.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// DualContextError::with_lie_and_sensitive(
+    ///     "Resource not found",
     ///     format!("Attempted access to restricted path: /var/secrets/api_keys.txt by user {}", username),
     ///     OperationCategory::IO,
     /// )
@@ -1048,6 +2762,16 @@ impl DualContextError {
             public: PublicContext::lie(public_lie),
             internal: InternalContext::sensitive(internal_sensitive),
             category,
+            code: None,
+            location: None,
+            external_severity: Severity::Error,
+            internal_severity: Severity::Error,
+            metadata: ContextMetadata::new(),
+            remediations: SmallVec::new(),
+            integrity_tag: None,
+            response_hint: None,
+            #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+            emitted: AtomicBool::new(false),
         }
     }
 
@@ -1090,6 +2814,16 @@ impl DualContextError {
             public: PublicContext::truth(public_truth),
             internal: InternalContext::diagnostic(internal_diagnostic),
             category,
+            code: None,
+            location: None,
+            external_severity: Severity::Error,
+            internal_severity: Severity::Error,
+            metadata: ContextMetadata::new(),
+            remediations: SmallVec::new(),
+            integrity_tag: None,
+            response_hint: None,
+            #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+            emitted: AtomicBool::new(false),
         }
     }
 
@@ -1132,6 +2866,16 @@ impl DualContextError {
             public: PublicContext::lie(public_lie),
             internal: InternalContext::lie(internal_lie),
             category,
+            code: None,
+            location: None,
+            external_severity: Severity::Error,
+            internal_severity: Severity::Error,
+            metadata: ContextMetadata::new(),
+            remediations: SmallVec::new(),
+            integrity_tag: None,
+            response_hint: None,
+            #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+            emitted: AtomicBool::new(false),
         }
     }
 
@@ -1177,6 +2921,35 @@ impl DualContextError {
         self.category
     }
 
+    /// The [`crate::definitions::FailureCategory`] this error's attached
+    /// code was classified under, for metrics/alerting - `None` if the
+    /// error carries no [`crate::definitions`] code (e.g. one built with
+    /// [`Self::with_lie`] directly rather than a category macro).
+    ///
+    /// Unlike [`Self::expose_scoped`], this is never gated: the
+    /// classification itself (a spike in `CryptoFailure` vs `Validation`)
+    /// is exactly the kind of signal a SOC dashboard needs without first
+    /// acquiring [`crate::SocAccess`], and it reveals nothing more specific
+    /// than [`Self::category`] already does.
+    #[inline]
+    #[must_use]
+    pub fn failure_category(&self) -> Option<crate::definitions::FailureCategory> {
+        self.definition().map(|def| def.failure_category)
+    }
+
+    /// Whether this error is worth retrying, and on what schedule - see
+    /// [`crate::definitions::Retryability`]. Falls back to
+    /// [`crate::definitions::Retryability::Transient`] for an error with no
+    /// attached code, matching [`crate::AgentError`]'s pre-classification
+    /// default of treating every error as retryable until told otherwise.
+    #[inline]
+    #[must_use]
+    pub fn retryability(&self) -> crate::definitions::Retryability {
+        self.definition()
+            .map(|def| def.retryability)
+            .unwrap_or(crate::definitions::Retryability::Transient)
+    }
+
     /// Get the external-facing error message as a string.
     ///
     /// # Returns
@@ -1198,9 +2971,29 @@ impl DualContextError {
     /// `PublicContext::as_str()` which in turn delegates to `Cow::as_ref()`.
     #[inline]
     pub fn external_message(&self) -> &str {
+        #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+        self.mark_emitted();
         self.public.as_str()
     }
 
+    /// Record that this error reached a known emission path, disarming the
+    /// drop-bomb in `Drop::drop` below.
+    ///
+    /// # Scope
+    ///
+    /// `InternalContext::payload()` - the other method the emission-tracking
+    /// feature is meant to cover - has no back-reference to the
+    /// `DualContextError` that owns it, so it cannot call this itself.
+    /// Instead, every method and free function that already holds `&self`
+    /// and routes through `payload()` calls this directly:
+    /// `external_message()` above, `context.rs`'s `internal_display_text`,
+    /// and `emission::ErrorEmitter::emit_internal`.
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    #[inline]
+    pub(crate) fn mark_emitted(&self) {
+        self.emitted.store(true, Ordering::SeqCst);
+    }
+
     /// Get the deceptive category name for external display.
     ///
     /// # Returns
@@ -1225,22 +3018,435 @@ impl DualContextError {
     pub fn external_category(&self) -> &'static str {
         self.category.deceptive_name()
     }
+
+    /// Get the external-facing severity (safe to reveal to an attacker).
+    ///
+    /// # Returns
+    ///
+    /// Copy of the `Severity` enum. Like `external_message()`, this may be a
+    /// deliberately misleading level if the error was built with a split
+    /// severity via `ContextBuilder::external_severity()`.
+    #[inline]
+    pub const fn external_severity(&self) -> Severity {
+        self.external_severity
+    }
+
+    /// Get the true internal severity (SOC-only visibility).
+    ///
+    /// # Arguments
+    ///
+    /// - `_access`: Proof of `SocAccess` capability acquisition, matching the
+    ///   gating already used by `InternalContext::expose_sensitive()`.
+    ///
+    /// # Returns
+    ///
+    /// Copy of the `Severity` enum representing the true operational severity,
+    /// which may differ from `external_severity()`.
+    #[inline]
+    pub fn internal_severity(&self, _access: &SocAccess) -> Severity {
+        self.internal_severity
+    }
+
+    /// Attach a structured metadata collection, overriding the default empty set.
+    ///
+    /// # Use Case
+    ///
+    /// Called by `ContextBuilder::try_build()` once `.metadata()` and
+    /// `.public_metadata()` have accumulated entries. Like
+    /// `with_severity_pair()`, this is a chainable post-construction setter
+    /// rather than a constructor parameter, since most errors carry none.
+    #[inline]
+    pub(crate) fn with_metadata(mut self, metadata: ContextMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Get metadata entries safe to surface to external/untrusted consumers.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over `(key, value)` pairs tagged `MetadataTrust::Public` when
+    /// the error was built. Entries tagged `Internal` (the default) are never
+    /// reachable through this method.
+    #[inline]
+    pub fn public_metadata(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.metadata.public_iter()
+    }
+
+    /// Get all metadata entries regardless of trust classification (SOC-only).
+    ///
+    /// # Arguments
+    ///
+    /// - `_access`: Proof of `SocAccess` capability acquisition, matching the
+    ///   gating already used by `internal_severity()` and
+    ///   `InternalContext::expose_sensitive()`.
+    #[inline]
+    pub fn all_metadata(&self, _access: &SocAccess) -> impl Iterator<Item = (&'static str, &str)> {
+        self.metadata.iter()
+    }
+
+    /// Get all metadata entries together with their trust classification.
+    ///
+    /// # Use Case
+    ///
+    /// Crate-internal counterpart to `all_metadata()` used by
+    /// `ContextChain::compact()` to merge metadata across folded links
+    /// without collapsing `MetadataTrust::Public` entries into
+    /// `MetadataTrust::Internal` ones.
+    #[inline]
+    pub(crate) fn metadata_entries_with_trust(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &str, MetadataTrust)> {
+        self.metadata.entries_with_trust()
+    }
+
+    /// Expose this error's sensitive internal content to a capability holder.
+    ///
+    /// # Arguments
+    ///
+    /// - `capability`: a time-boxed, category-scoped [`Capability`]. Unlike
+    ///   `internal().expose_sensitive(&SocAccess)`, which is an unconditional
+    ///   gate, this checks that the capability has not expired and that
+    ///   `self.category()` is within its scope before delegating.
+    ///
+    /// # Returns
+    ///
+    /// - `None` if the capability is expired, `self.category()` is out of
+    ///   scope, or the internal context isn't `Sensitive`.
+    /// - `Some(&str)` with the raw sensitive content otherwise.
+    ///
+    /// # Auditing
+    ///
+    /// Every time this gate is actually passed (capability valid, content
+    /// present), an [`crate::audit::AuditEvent`] is recorded: timestamp,
+    /// `self.category()`, `capability.holder()`, `self.external_message()`,
+    /// and a hash (never the raw value) of the content just revealed. It
+    /// goes to `capability`'s own sink if [`Capability::with_audit_sink`] was
+    /// called, otherwise to the process-wide default - see
+    /// [`crate::audit::set_global_audit_sink`]. A capability that is
+    /// expired or out of scope never reaches this point, so denied attempts
+    /// are not audited here.
+    #[cfg(not(feature = "no_std"))]
+    #[must_use]
+    #[inline]
+    pub fn expose_sensitive(&self, capability: &Capability) -> Option<&str> {
+        if !capability.permits(self.category) {
+            return None;
+        }
+        let access = SocAccess::acquire();
+        let sensitive = self.internal.expose_sensitive(&access)?;
+        crate::audit::record_exposure(capability, self.category, self.external_message(), sensitive);
+        Some(sensitive)
+    }
+
+    /// Expose this error's sensitive internal content via the tiered
+    /// [`Clearance`] path, appending an entry to `ledger` whether the
+    /// attempt is granted or denied.
+    ///
+    /// # Arguments
+    ///
+    /// - `token`: a [`crate::ledger::ClearanceToken`] presenting the caller's level.
+    /// - `ledger`: the [`crate::ledger::AccessLedger`] this attempt is recorded to.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&str)` if the internal context is [`InternalContext::sensitive_at`]
+    ///   and `token`'s level meets or exceeds the tagged requirement.
+    /// - `None` otherwise - including for a plain `Sensitive` (untagged)
+    ///   context, which this path can never reveal.
+    ///
+    /// # Auditing
+    ///
+    /// Unlike [`Self::expose_sensitive`]'s capability-gated
+    /// [`crate::audit::AuditEvent`] (recorded only on success), every call
+    /// here - granted or denied - appends an immutable
+    /// [`crate::ledger::AccessLedgerEntry`] to `ledger`, which the caller
+    /// can later drain via [`crate::ledger::AccessLedger::drain`].
+    #[cfg(not(feature = "no_std"))]
+    #[must_use]
+    pub fn expose_sensitive_at(
+        &self,
+        token: &crate::ledger::ClearanceToken,
+        ledger: &crate::ledger::AccessLedger,
+    ) -> Option<&str> {
+        let revealed = self.internal.expose_at(token.level());
+        ledger.record(crate::ledger::AccessLedgerEntry::new(
+            token.level(),
+            self.error_id(),
+            revealed.is_some(),
+        ));
+        revealed
+    }
+
+    /// Stable identifier used in [`crate::ledger::AccessLedgerEntry`]
+    /// entries: the attached [`ErrorCode`]'s rendered form if one was set
+    /// via [`Self::with_code`], else `external_message()`.
+    #[cfg(not(feature = "no_std"))]
+    fn error_id(&self) -> String {
+        self.code
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| self.external_message().to_string())
+    }
+
+    /// Expose this error's sensitive internal content via a dotted
+    /// [`crate::scope::ScopedClearance`] - a fourth gate alongside
+    /// [`Self::expose_sensitive`] and [`Self::expose_sensitive_at`], for
+    /// callers that need finer granularity than `Capability`'s category
+    /// scoping or `Clearance`'s tiers (e.g. "sees API-key errors but not
+    /// raw credential dumps").
+    ///
+    /// # Arguments
+    ///
+    /// - `clearance`: the caller's granted [`crate::scope::ScopedClearance`].
+    ///
+    /// # Required Scope
+    ///
+    /// Resolved from this error's attached [`Self::definition`] -
+    /// [`crate::definitions::ErrorDefinition::required_scope`] - or
+    /// [`crate::definitions::DEFAULT_SENSITIVE_SCOPE`] if no code was
+    /// attached or it isn't registered.
+    ///
+    /// # Returns
+    ///
+    /// - `None` if `clearance` doesn't cover the required scope, or the
+    ///   internal context isn't `Sensitive`.
+    /// - `Some(&str)` with the raw sensitive content otherwise.
+    ///
+    /// # Auditing
+    ///
+    /// Still acquires a [`SocAccess`] token internally and delegates to
+    /// [`InternalContext::expose_sensitive`], so this gate can never reveal
+    /// more than the unconditional `SocAccess` path already could - it only
+    /// narrows who gets to reach it.
+    #[cfg(not(feature = "no_std"))]
+    #[must_use]
+    pub fn expose_scoped(&self, clearance: &crate::scope::ScopedClearance) -> Option<&str> {
+        let required_scope = self
+            .definition()
+            .map(|def| def.required_scope)
+            .unwrap_or(crate::definitions::DEFAULT_SENSITIVE_SCOPE);
+        let required = crate::scope::Scope::parse(required_scope).ok()?;
+        if !clearance.allows(&required) {
+            return None;
+        }
+        let access = SocAccess::acquire();
+        self.internal.expose_sensitive(&access)
+    }
+
+    /// Get metadata entries authorized by `capability`'s scope.
+    ///
+    /// Returns an empty iterator if the capability is expired or
+    /// `self.category()` is out of scope; otherwise filters by the
+    /// capability's allowed metadata keys via [`ContextMetadata::expose_with`].
+    #[cfg(not(feature = "no_std"))]
+    pub fn metadata_with<'a>(
+        &'a self,
+        capability: &'a Capability,
+    ) -> impl Iterator<Item = (&'static str, &'a str)> {
+        let permitted = capability.permits(self.category);
+        self.metadata
+            .expose_with(capability)
+            .filter(move |_| permitted)
+    }
+
+    /// Attach SOC remediation suggestions, overriding the default empty set.
+    ///
+    /// # Use Case
+    ///
+    /// Called by `ContextBuilder::try_build()` once `.remediation()` has
+    /// accumulated suggestions. Like `with_metadata()`, this is a chainable
+    /// post-construction setter rather than a constructor parameter.
+    #[inline]
+    pub(crate) fn with_remediations(mut self, remediations: SmallVec<[Remediation; 2]>) -> Self {
+        self.remediations = remediations;
+        self
+    }
+
+    /// Get SOC-facing remediation suggestions (strictly internal).
+    ///
+    /// # Arguments
+    ///
+    /// - `_access`: Proof of `SocAccess` capability acquisition, matching the
+    ///   gating already used by `all_metadata()` and
+    ///   `InternalContext::expose_sensitive()`.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over attached `Remediation`s in the order they were added.
+    /// Never reachable from `external_message()` or
+    /// `ContextChain::external_summary()`.
+    #[inline]
+    pub fn remediations(&self, _access: &SocAccess) -> impl Iterator<Item = &Remediation> {
+        self.remediations.iter()
+    }
+
+    /// Sign this error's internal context, attaching an HMAC-SHA256 tag.
+    ///
+    /// # Use Case
+    ///
+    /// Called by `ContextBuilder::try_build()` once `.sign_with()` has supplied
+    /// a `SigningKey`. Like `with_metadata()`/`with_remediations()`, this is a
+    /// chainable post-construction setter - most errors aren't signed, so it's
+    /// not threaded through every constructor.
+    ///
+    /// The tag covers the internal sensitive/diagnostic content, the
+    /// `OperationCategory`, and the sorted `ContextMetadata` pairs. The public
+    /// "lie" is intentionally excluded: it's expected to be attacker-visible
+    /// and signing it would add nothing.
+    #[inline]
+    pub(crate) fn with_signature(mut self, key: &crate::integrity::SigningKey) -> Self {
+        self.integrity_tag = Some(crate::integrity::compute_tag(
+            &self.internal,
+            self.category,
+            &self.metadata,
+            key,
+        ));
+        self
+    }
+
+    /// Whether this error carries an integrity tag from `ContextBuilder::sign_with()`.
+    #[inline]
+    pub fn is_signed(&self) -> bool {
+        self.integrity_tag.is_some()
+    }
+
+    /// Get this error's raw integrity tag, if signed.
+    ///
+    /// # Use Case
+    ///
+    /// Crate-internal counterpart to `is_signed()`/`verify()` used by
+    /// `ContextChain::compact()`, which carries signed links' tags forward
+    /// into the resulting `Checkpoint` instead of discarding them.
+    #[inline]
+    pub(crate) fn integrity_tag(&self) -> Option<crate::integrity::IntegrityTag> {
+        self.integrity_tag
+    }
+
+    /// Verify that this error's internal context has not been tampered with
+    /// since it was signed.
+    ///
+    /// # Returns
+    ///
+    /// - `Err(IntegrityError::Unsigned)` if this error was never signed.
+    /// - `Err(IntegrityError::Tampered)` if the recomputed tag doesn't match
+    ///   the stored one (the internal content, category, or metadata changed,
+    ///   or `key` is wrong).
+    /// - `Ok(())` if the tag matches.
+    ///
+    /// Recomputation and comparison both run in constant time with respect to
+    /// the tag bytes, so a caller probing with guessed tags learns nothing
+    /// from timing.
+    pub fn verify(&self, key: &crate::integrity::SigningKey) -> Result<(), crate::integrity::IntegrityError> {
+        let stored = self
+            .integrity_tag
+            .as_ref()
+            .ok_or(crate::integrity::IntegrityError::Unsigned)?;
+        let recomputed = crate::integrity::compute_tag(&self.internal, self.category, &self.metadata, key);
+        if stored.constant_time_eq(&recomputed) {
+            Ok(())
+        } else {
+            Err(crate::integrity::IntegrityError::Tampered)
+        }
+    }
 }
 
 impl Zeroize for DualContextError {
     fn zeroize(&mut self) {
         self.public.zeroize();
         self.internal.zeroize();
-        // category is Copy, contains no sensitive data, no zeroization needed
+        self.metadata.zeroize();
+        for remediation in &mut self.remediations {
+            remediation.zeroize();
+        }
+        self.remediations.clear();
+        self.integrity_tag = None;
+        // category, severities, and response_hint are Copy, contain no sensitive data, no zeroization needed
     }
 }
 
 impl ZeroizeOnDrop for DualContextError {}
 
-// Note: No custom Drop implementation here. Zeroization is handled authoritatively
-// in InternalContextField::drop() for sensitive data. This layer just delegates
-// via ZeroizeOnDrop trait. Consolidating the volatile writes and fences to a single
-// location (the base field type) reduces complexity and prevents redundant operations.
+// Note: The zeroization above is handled authoritatively in
+// InternalContextField::drop() for sensitive data; this impl just delegates
+// via ZeroizeOnDrop trait. Consolidating the volatile writes and fences to a
+// single location (the base field type) reduces complexity and prevents
+// redundant operations. The `emission_tracking` Drop impl below runs first
+// when that feature is enabled, but does not touch this zeroization path -
+// see its own doc comment for why ordering stays deterministic.
+
+// ============================================================================
+// Unhandled Detection ("Drop Bomb" For Un-Emitted Defensive Signals)
+// ============================================================================
+
+/// Process-wide hook installed via [`set_unhandled_error_hook`], invoked by
+/// [`DualContextError`]'s `emission_tracking` `Drop` impl below.
+///
+/// Mirrors the [`SOC_AUDIT_SINK`] pattern above: a `OnceLock<RwLock<Box<dyn
+/// Fn>>>` rather than a plain `static mut`, so installing a hook is safe from
+/// any thread and the default (a no-op) keeps deployments that never opt in
+/// free of any behavior change.
+#[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+static UNHANDLED_ERROR_HOOK: OnceLock<RwLock<Box<dyn Fn(OperationCategory) + Send + Sync>>> =
+    OnceLock::new();
+
+#[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+fn unhandled_error_hook() -> &'static RwLock<Box<dyn Fn(OperationCategory) + Send + Sync>> {
+    UNHANDLED_ERROR_HOOK.get_or_init(|| RwLock::new(Box::new(|_category| {})))
+}
+
+/// Install the process-wide hook fired when a [`DualContextError`] built
+/// with a [`OperationCategory::Detection`], [`OperationCategory::Containment`],
+/// or [`OperationCategory::Deception`] category is dropped without ever
+/// reaching a known emission path - a defensive signal that never reached
+/// the SOC.
+///
+/// # Security
+///
+/// The hook receives only the error's [`OperationCategory`] - never its
+/// public or internal content - so installing one cannot itself become a
+/// new disclosure path. Replaces whatever hook was previously installed
+/// (the default is a no-op).
+///
+/// # Feature Gate
+///
+/// Behind `emission_tracking`, and unavailable under `no_std` (no
+/// `OnceLock`/`RwLock` to host a process-wide hook on), so deployments that
+/// never opt in pay no overhead for the extra `AtomicBool` field or the
+/// `Drop` check.
+#[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+pub fn set_unhandled_error_hook(hook: Box<dyn Fn(OperationCategory) + Send + Sync>) {
+    *unhandled_error_hook()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = hook;
+}
+
+#[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+impl Drop for DualContextError {
+    /// Fire [`set_unhandled_error_hook`]'s hook, before the automatic
+    /// per-field drop glue (the real zeroization, see the note above) runs.
+    ///
+    /// # Ordering
+    ///
+    /// This only reads `self.category` and `self.emitted` - it never touches
+    /// `self.public`/`self.internal`/etc. Once this function returns, the
+    /// compiler's ordinary field-by-field drop glue runs exactly as it did
+    /// before this impl existed, so clearing still happens exactly once and
+    /// in the same order.
+    fn drop(&mut self) {
+        let is_defensive_signal = matches!(
+            self.category,
+            OperationCategory::Detection
+                | OperationCategory::Containment
+                | OperationCategory::Deception
+        );
+        if is_defensive_signal && !self.emitted.load(Ordering::SeqCst) && !std::thread::panicking() {
+            let hook = unhandled_error_hook()
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            hook(self.category);
+        }
+    }
+}
 
 impl fmt::Display for DualContextError {
     /// Render error for external display.
@@ -1285,10 +3491,62 @@ impl fmt::Debug for DualContextError {
             .field("public", &self.public)
             .field("internal", &self.internal)
             .field("category", &self.category)
+            .field("code", &self.code.map(|c| c.to_string()))
+            .field("location", &self.location)
+            .field("external_severity", &self.external_severity)
+            .field("internal_severity", &self.internal_severity)
+            .field("metadata_len", &self.metadata.len())
+            .field("remediation_count", &self.remediations.len())
+            .field("signed", &self.integrity_tag.is_some())
             .finish()
     }
 }
 
+/// Rustc/cargo-style renderer for a `DualContextError`'s internal diagnostic.
+///
+/// # Output Format
+///
+/// ```text
+/// error[E-CFG-100]: Configuration op 'validate': invalid threshold value
+///   --> src/config.rs:42:9
+/// ```
+///
+/// The `error[...]` header is omitted if no `ErrorCode` was attached via
+/// `with_code()`, and the `  --> file:line:col` line is omitted if no
+/// `SourceLocation` was attached via `with_location()`.
+///
+/// # Security Note
+///
+/// This formats the **internal** context only (or a redacted marker for
+/// `Sensitive` contexts, matching `InternalContext`'s existing `Debug` policy).
+/// It must never be routed through `external_message()` or any
+/// attacker-visible sink - same trust boundary as `InternalContext::payload()`.
+pub struct Diagnostic<'a> {
+    error: &'a DualContextError,
+}
+
+impl<'a> fmt::Display for Diagnostic<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error.code {
+            Some(code) => write!(f, "error[{}]: ", code)?,
+            None => f.write_str("error: ")?,
+        }
+
+        match self.error.internal.payload() {
+            Some(InternalPayload::Truth(msg)) => f.write_str(msg)?,
+            Some(InternalPayload::Lie(msg)) => write!(f, "[LIE] {}", msg)?,
+            Some(InternalPayload::Sensitive(msg)) => write!(f, "[FORENSIC-UNLOCKED] {}", msg)?,
+            None => f.write_str("[SENSITIVE REDACTED]")?,
+        }
+
+        if let Some(location) = self.error.location {
+            write!(f, "\n  --> {}", location)?;
+        }
+
+        Ok(())
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1296,6 +3554,8 @@ impl fmt::Debug for DualContextError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "no_std")]
+    use alloc::format;
 
     #[test]
     fn test_public_context_lie() {
@@ -1337,16 +3597,136 @@ mod tests {
     }
 
     #[test]
-    fn test_internal_context_lie() {
-        let ctx = InternalContext::lie("Normal database query");
-        assert_eq!(ctx.classification(), "InternalLie");
-        
-        match ctx.payload() {
-            Some(InternalPayload::Lie(msg)) => {
-                assert_eq!(msg, "Normal database query");
-            }
-            _ => panic!("Expected lie payload"),
-        }
+    fn test_into_inner_returns_sensitive_payload_unzeroized() {
+        let ctx = InternalContext::sensitive("/etc/passwd accessed by user:admin".to_string());
+        let access = SocAccess::acquire();
+        let owned = ctx.into_inner(&access).unwrap();
+        assert_eq!(owned, "/etc/passwd accessed by user:admin");
+        // The moved-out String is untouched by `InternalContextField::drop`'s
+        // volatile zeroize - if it had run, the bytes above would be `\0`s
+        // rather than the original text, and the assert above would fail.
+    }
+
+    #[test]
+    fn test_into_inner_diagnostic_and_lie_do_not_need_sensitive_access() {
+        let access = SocAccess::acquire();
+
+        let diagnostic = InternalContext::diagnostic("plain diagnostic");
+        assert_eq!(diagnostic.into_inner(&access).as_deref(), Some("plain diagnostic"));
+
+        let lie = InternalContext::lie("decoy diagnostic");
+        assert_eq!(lie.into_inner(&access).as_deref(), Some("decoy diagnostic"));
+    }
+
+    #[test]
+    fn test_into_inner_does_not_double_drop() {
+        // If `into_inner`'s `ManuallyDrop`/`ptr::read` dance were wrong, this
+        // would double-free or double-zeroize the underlying `String`'s
+        // heap buffer - under a sanitizer or `cargo test` with enough
+        // pressure that would abort. Looping a few times and actually using
+        // the returned `String` (not just dropping it) exercises both ends.
+        let access = SocAccess::acquire();
+        for i in 0..8 {
+            let ctx = InternalContext::sensitive(format!("secret-{i}"));
+            let owned = ctx.into_inner(&access).unwrap();
+            assert_eq!(owned, format!("secret-{i}"));
+        }
+    }
+
+    #[test]
+    fn test_internal_context_lie() {
+        let ctx = InternalContext::lie("Normal database query");
+        assert_eq!(ctx.classification(), "InternalLie");
+        
+        match ctx.payload() {
+            Some(InternalPayload::Lie(msg)) => {
+                assert_eq!(msg, "Normal database query");
+            }
+            _ => panic!("Expected lie payload"),
+        }
+    }
+
+    #[test]
+    fn push_breadcrumb_accumulates_in_order() {
+        let mut ctx = InternalContext::diagnostic("final state");
+        ctx.push_breadcrumb("step one", OperationCategory::Detection);
+        ctx.push_breadcrumb("step two", OperationCategory::Containment);
+
+        let access = SocAccess::acquire();
+        let trail = ctx.breadcrumbs(&access);
+        assert_eq!(trail.len(), 2);
+        assert_eq!(trail[0].message(), "step one");
+        assert_eq!(trail[0].category(), OperationCategory::Detection);
+        assert_eq!(trail[1].message(), "step two");
+        assert_eq!(trail[1].category(), OperationCategory::Containment);
+    }
+
+    #[test]
+    fn breadcrumb_trail_drops_oldest_once_over_cap() {
+        let mut ctx = InternalContext::diagnostic("final state").with_breadcrumb_cap(2);
+        ctx.push_breadcrumb("first", OperationCategory::Detection);
+        ctx.push_breadcrumb("second", OperationCategory::Detection);
+        ctx.push_breadcrumb("third", OperationCategory::Detection);
+
+        let access = SocAccess::acquire();
+        let trail = ctx.breadcrumbs(&access);
+        assert_eq!(trail.len(), 2);
+        assert_eq!(trail[0].message(), "second");
+        assert_eq!(trail[1].message(), "third");
+    }
+
+    #[test]
+    fn zero_breadcrumb_cap_discards_every_push() {
+        let mut ctx = InternalContext::diagnostic("final state").with_breadcrumb_cap(0);
+        ctx.push_breadcrumb("never kept", OperationCategory::Detection);
+
+        let access = SocAccess::acquire();
+        assert!(ctx.breadcrumbs(&access).is_empty());
+    }
+
+    #[test]
+    fn breadcrumb_trail_never_changes_the_redacted_display() {
+        let mut ctx = InternalContext::sensitive("/etc/shadow accessed");
+        ctx.push_breadcrumb("probed /etc/shadow", OperationCategory::Detection);
+        assert_eq!(ctx.to_string(), "[INTERNAL CONTEXT REDACTED]");
+    }
+
+    #[test]
+    fn volatile_zero_string_clears_all_bytes() {
+        let mut s = String::from("secret-to-clear");
+        volatile_zero_string(&mut s);
+        assert!(s.bytes().all(|b| b == 0));
+    }
+
+    #[test]
+    fn internal_context_field_zeroize_is_idempotent() {
+        let mut field = InternalContextField::Sensitive(Cow::Owned("secret".to_string()));
+        field.zeroize();
+        field.zeroize();
+        match field {
+            InternalContextField::Sensitive(Cow::Owned(ref s)) => {
+                assert!(s.bytes().all(|b| b == 0) || s.is_empty())
+            }
+            _ => panic!("expected Sensitive(Owned(_))"),
+        }
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn arch_fence_compiles_and_runs_on_x86_64() {
+        arch_fence();
+    }
+
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn arch_fence_compiles_and_runs_on_aarch64() {
+        arch_fence();
+    }
+
+    #[test]
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn arch_fence_compiles_and_runs_on_other_arches() {
+        arch_fence();
     }
 
     #[test]
@@ -1402,6 +3782,17 @@ mod tests {
         assert_eq!(OperationCategory::Configuration.deceptive_name(), "Configuration");
     }
 
+    #[test]
+    fn test_operation_category_from_display_name_round_trips() {
+        for category in OperationCategory::ALL {
+            assert_eq!(
+                OperationCategory::from_display_name(category.display_name()),
+                Some(*category)
+            );
+        }
+        assert_eq!(OperationCategory::from_display_name("not a category"), None);
+    }
+
     #[test]
     fn test_soc_access_capability() {
         let ctx = InternalContext::sensitive("secret data".to_string());
@@ -1461,6 +3852,47 @@ mod tests {
         assert!(!display_output.contains("secret"));
     }
 
+    #[test]
+    fn test_render_diagnostic_with_code_and_location() {
+        let err = DualContextError::with_lie(
+            "Access forbidden",
+            "Blocked SQL injection attempt in query parameter 'id'",
+            OperationCategory::Detection,
+        )
+        .with_code(&crate::definitions::DCP_FINGERPRINT_MISMATCH)
+        .with_location("src/honeypot.rs", 42, 9);
+
+        let rendered = err.render_diagnostic().to_string();
+        assert!(rendered.starts_with("error[E-DCP-"));
+        assert!(rendered.contains("Blocked SQL injection attempt"));
+        assert!(rendered.contains("  --> src/honeypot.rs:42:9"));
+    }
+
+    #[test]
+    fn test_render_diagnostic_without_code_or_location() {
+        let err = DualContextError::with_lie(
+            "Access forbidden",
+            "Generic internal diagnostic",
+            OperationCategory::Detection,
+        );
+
+        let rendered = err.render_diagnostic().to_string();
+        assert_eq!(rendered, "error: Generic internal diagnostic");
+    }
+
+    #[test]
+    fn test_render_diagnostic_redacts_sensitive() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Resource not found",
+            "/var/secrets/api_keys.txt",
+            OperationCategory::IO,
+        );
+
+        let rendered = err.render_diagnostic().to_string();
+        assert_eq!(rendered, "error: [SENSITIVE REDACTED]");
+        assert!(!rendered.contains("api_keys"));
+    }
+
     #[test]
     fn test_internal_payload_not_copy() {
         // This test verifies InternalPayload does not implement Copy
@@ -1470,4 +3902,578 @@ mod tests {
         // If we try to use payload again without Clone, it would fail to compile
         // (proving it's not Copy)
     }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn capability_exposes_sensitive_within_scope() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Resource not found",
+            "/var/secrets/api_keys.txt",
+            OperationCategory::IO,
+        );
+
+        let capability = Capability::issue(
+            "soc-lead",
+            "debug-session",
+            CapabilityScope::categories([OperationCategory::IO]),
+            std::time::Duration::from_secs(60),
+        );
+
+        assert_eq!(
+            err.expose_sensitive(&capability),
+            Some("/var/secrets/api_keys.txt")
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn capability_denies_out_of_scope_category() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Resource not found",
+            "/var/secrets/api_keys.txt",
+            OperationCategory::IO,
+        );
+
+        let capability = Capability::issue(
+            "soc-lead",
+            "debug-session",
+            CapabilityScope::categories([OperationCategory::Detection]),
+            std::time::Duration::from_secs(60),
+        );
+
+        assert_eq!(err.expose_sensitive(&capability), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn capability_denies_expired_token() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Resource not found",
+            "/var/secrets/api_keys.txt",
+            OperationCategory::IO,
+        );
+
+        let capability = Capability::issue(
+            "soc-lead",
+            "debug-session",
+            CapabilityScope::categories([OperationCategory::IO]),
+            std::time::Duration::from_secs(0),
+        );
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        assert!(capability.is_expired());
+        assert_eq!(err.expose_sensitive(&capability), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn scoped_clearance_exposes_sensitive_via_exact_match() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Configuration rejected",
+            "disallowed permission bit 0o777 on /etc/palisade/secrets.d",
+            OperationCategory::Configuration,
+        )
+        .with_code(&crate::definitions::CFG_SECURITY_VIOLATION);
+
+        let clearance = crate::scope::ScopedClearanceBuilder::new()
+            .grant("log.sensitive.security")
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            err.expose_scoped(&clearance),
+            Some("disallowed permission bit 0o777 on /etc/palisade/secrets.d")
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn scoped_clearance_exposes_sensitive_via_wildcard() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Configuration rejected",
+            "disallowed permission bit 0o777",
+            OperationCategory::Configuration,
+        )
+        .with_code(&crate::definitions::CFG_SECURITY_VIOLATION);
+
+        let clearance = crate::scope::ScopedClearanceBuilder::new()
+            .grant("log.sensitive.*")
+            .unwrap()
+            .build();
+
+        assert_eq!(err.expose_scoped(&clearance), Some("disallowed permission bit 0o777"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn scoped_clearance_denies_an_uncovered_scope() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Configuration rejected",
+            "disallowed permission bit 0o777",
+            OperationCategory::Configuration,
+        )
+        .with_code(&crate::definitions::CFG_SECURITY_VIOLATION);
+
+        let clearance = crate::scope::ScopedClearanceBuilder::new()
+            .grant("log.sensitive.apikey")
+            .unwrap()
+            .build();
+
+        assert_eq!(err.expose_scoped(&clearance), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn scoped_clearance_falls_back_to_the_default_scope_without_an_attached_code() {
+        let err = DualContextError::with_lie_and_sensitive(
+            "Resource not found",
+            "/var/secrets/api_keys.txt",
+            OperationCategory::IO,
+        );
+
+        let denied = crate::scope::ScopedClearanceBuilder::new()
+            .grant("log.sensitive.security")
+            .unwrap()
+            .build();
+        assert_eq!(err.expose_scoped(&denied), None);
+
+        let granted = crate::scope::ScopedClearanceBuilder::new()
+            .grant(crate::definitions::DEFAULT_SENSITIVE_SCOPE)
+            .unwrap()
+            .build();
+        assert_eq!(err.expose_scoped(&granted), Some("/var/secrets/api_keys.txt"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn capability_all_categories_covers_every_variant() {
+        let capability = Capability::issue(
+            "soc-lead",
+            "debug-session",
+            CapabilityScope::all_categories(),
+            std::time::Duration::from_secs(60),
+        );
+
+        for category in [
+            OperationCategory::Configuration,
+            OperationCategory::Deployment,
+            OperationCategory::Monitoring,
+            OperationCategory::Analysis,
+            OperationCategory::Response,
+            OperationCategory::Audit,
+            OperationCategory::System,
+            OperationCategory::IO,
+            OperationCategory::Deception,
+            OperationCategory::Detection,
+            OperationCategory::Containment,
+        ] {
+            assert!(capability.permits(category));
+        }
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    fn error_with_metadata() -> DualContextError {
+        let mut metadata = ContextMetadata::new();
+        metadata.add("correlation_id", "req-42", MetadataTrust::Public);
+        metadata.add("session_token", "s3cr3t", MetadataTrust::Internal);
+
+        DualContextError::with_lie(
+            "Operation failed",
+            "Database connection timeout",
+            OperationCategory::IO,
+        )
+        .with_metadata(metadata)
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn capability_metadata_with_filters_by_allowed_keys() {
+        let err = error_with_metadata();
+
+        let capability = Capability::issue(
+            "soc-lead",
+            "debug-session",
+            CapabilityScope::categories([OperationCategory::IO])
+                .with_metadata_keys(["correlation_id"]),
+            std::time::Duration::from_secs(60),
+        );
+
+        let revealed: Vec<_> = err.metadata_with(&capability).collect();
+        assert_eq!(revealed, vec![("correlation_id", "req-42")]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn capability_metadata_with_empty_when_out_of_scope_category() {
+        let err = error_with_metadata();
+
+        let capability = Capability::issue(
+            "soc-lead",
+            "debug-session",
+            CapabilityScope::categories([OperationCategory::Detection])
+                .with_metadata_keys(["correlation_id"]),
+            std::time::Duration::from_secs(60),
+        );
+
+        assert_eq!(err.metadata_with(&capability).count(), 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_redacted_is_stable_within_a_process() {
+        let a = InternalContext::sensitive("/etc/passwd accessed by user:admin");
+        let b = InternalContext::sensitive("/etc/passwd accessed by user:admin");
+        assert_eq!(a.redacted().to_string(), b.redacted().to_string());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_redacted_differs_for_different_content() {
+        let a = InternalContext::sensitive("/etc/passwd accessed by user:admin");
+        let b = InternalContext::sensitive("/etc/shadow accessed by user:root");
+        assert_ne!(a.redacted().to_string(), b.redacted().to_string());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_redacted_len_matches_input_byte_length() {
+        let ctx = InternalContext::sensitive("12345");
+        assert!(ctx.redacted().to_string().starts_with("Sensitive(len=5, fp="));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_debug_routes_sensitive_through_redacted_fingerprint() {
+        let ctx = InternalContext::sensitive("/etc/passwd accessed by user:admin");
+        let debug_output = format!("{:?}", ctx);
+        assert!(debug_output.starts_with("Sensitive(len="));
+        assert!(!debug_output.contains("[REDACTED]"));
+        assert!(!debug_output.contains("passwd"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_debug_routes_sensitive_at_through_redacted_fingerprint() {
+        let ctx = InternalContext::sensitive_at(Clearance::Forensics, "rotating API key xyz");
+        let debug_output = format!("{:?}", ctx);
+        assert!(debug_output.starts_with("SensitiveAt("));
+        assert!(debug_output.contains("Sensitive(len="));
+        assert!(!debug_output.contains("xyz"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_redacted_never_leaks_raw_bytes() {
+        let ctx = InternalContext::sensitive("super-secret-value");
+        let rendered = ctx.redacted().to_string();
+        assert!(!rendered.contains("super-secret-value"));
+    }
+
+    // `ForensicMode` flips process-global state, so the handful of tests
+    // that exercise it are serialized against each other via this mutex -
+    // no other test in this module reads or writes that state, so it's the
+    // only coordination needed to make exact (not just `>=`) assertions safe
+    // under cargo's default parallel test execution.
+    #[cfg(not(feature = "no_std"))]
+    static FORENSIC_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_forensic_mode_defaults_to_locked() {
+        let _serialize = FORENSIC_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        assert!(!ForensicMode::is_active());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_forensic_mode_unlock_is_active_until_dropped() {
+        let _serialize = FORENSIC_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let access = SocAccess::acquire();
+        assert!(!ForensicMode::is_active());
+        {
+            let _forensic = ForensicMode::unlock(&access);
+            assert!(ForensicMode::is_active());
+        }
+        assert!(!ForensicMode::is_active());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_forensic_mode_nested_guards_compose() {
+        let _serialize = FORENSIC_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let access = SocAccess::acquire();
+        let outer = ForensicMode::unlock(&access);
+        let inner = ForensicMode::unlock(&access);
+        assert!(ForensicMode::is_active());
+        drop(inner);
+        assert!(ForensicMode::is_active(), "outer guard should keep it unlocked");
+        drop(outer);
+        assert!(!ForensicMode::is_active());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_payload_yields_sensitive_only_while_unlocked() {
+        let _serialize = FORENSIC_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let ctx = InternalContext::sensitive("/etc/passwd accessed by user:admin");
+        assert!(ctx.payload().is_none());
+
+        let access = SocAccess::acquire();
+        let _forensic = ForensicMode::unlock(&access);
+        match ctx.payload() {
+            Some(InternalPayload::Sensitive(msg)) => {
+                assert_eq!(msg, "/etc/passwd accessed by user:admin");
+            }
+            other => panic!("expected Sensitive payload while unlocked, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_display_renders_real_content_only_while_unlocked() {
+        let _serialize = FORENSIC_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let ctx = InternalContext::sensitive("top secret payload");
+        assert_eq!(ctx.to_string(), "[INTERNAL CONTEXT REDACTED]");
+
+        let access = SocAccess::acquire();
+        let _forensic = ForensicMode::unlock(&access);
+        assert_eq!(ctx.to_string(), "top secret payload");
+    }
+
+    // `SocAccess::register_sink` replaces a process-global singleton, so -
+    // like `FORENSIC_TEST_LOCK` above - these tests serialize against each
+    // other. Nothing else in this module reads the installed sink.
+    #[cfg(not(feature = "no_std"))]
+    static SOC_AUDIT_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[cfg(not(feature = "no_std"))]
+    struct CountingAuditSink {
+        acquires: Arc<AtomicUsize>,
+        last_release_count: Arc<AtomicUsize>,
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    impl SocAuditSink for CountingAuditSink {
+        fn on_acquire(&self, _location: &'static Location<'static>) {
+            self.acquires.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_release(&self, exposed_count: usize) {
+            self.last_release_count.store(exposed_count, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_soc_access_reports_acquire_and_release_to_registered_sink() {
+        let _serialize = SOC_AUDIT_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let acquires = Arc::new(AtomicUsize::new(0));
+        let last_release_count = Arc::new(AtomicUsize::new(usize::MAX));
+        SocAccess::register_sink(Box::new(CountingAuditSink {
+            acquires: acquires.clone(),
+            last_release_count: last_release_count.clone(),
+        }));
+
+        {
+            let access = SocAccess::acquire();
+            assert_eq!(acquires.load(Ordering::SeqCst), 1);
+
+            let ctx = InternalContext::sensitive("classified");
+            ctx.expose_sensitive(&access);
+            ctx.expose_sensitive(&access);
+        }
+
+        assert_eq!(last_release_count.load(Ordering::SeqCst), 2);
+
+        SocAccess::register_sink(Box::new(NoopAuditSink));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_soc_access_expose_sensitive_does_not_count_failed_lookups() {
+        let _serialize = SOC_AUDIT_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let acquires = Arc::new(AtomicUsize::new(0));
+        let last_release_count = Arc::new(AtomicUsize::new(usize::MAX));
+        SocAccess::register_sink(Box::new(CountingAuditSink {
+            acquires: acquires.clone(),
+            last_release_count: last_release_count.clone(),
+        }));
+
+        {
+            let access = SocAccess::acquire();
+            let diagnostic = InternalContext::diagnostic("not sensitive");
+            assert!(diagnostic.expose_sensitive(&access).is_none());
+        }
+
+        assert_eq!(last_release_count.load(Ordering::SeqCst), 0);
+
+        SocAccess::register_sink(Box::new(NoopAuditSink));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_soc_access_works_without_registering_a_sink() {
+        let access = SocAccess::acquire();
+        let ctx = InternalContext::sensitive("fine without a custom sink");
+        assert_eq!(ctx.expose_sensitive(&access), Some("fine without a custom sink"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_typed_sensitive_exposes_the_wrapped_value() {
+        let access = SocAccess::acquire();
+        let wrapped = TypedSensitive::new(vec![1u8, 2, 3]);
+        assert_eq!(wrapped.expose(&access), &vec![1u8, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_typed_sensitive_debug_is_always_redacted() {
+        let wrapped = TypedSensitive::new(vec![1u8, 2, 3]);
+        assert_eq!(format!("{:?}", wrapped), "TypedSensitive([REDACTED])");
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_std"))]
+    fn test_typed_sensitive_zeroize_is_explicitly_callable() {
+        let mut wrapped = TypedSensitive::new(vec![1u8, 2, 3]);
+        // Demonstrates the API contract; real clearing happens automatically
+        // on drop via `ZeroizeOnDrop` - see `test_zeroization` above for the
+        // same caveat on `InternalContext`.
+        wrapped.zeroize();
+    }
+
+    // `set_unhandled_error_hook` replaces a process-global singleton, so -
+    // like `SOC_AUDIT_TEST_LOCK` above - these tests serialize against each
+    // other. Nothing else in this module reads the installed hook.
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    static UNHANDLED_ERROR_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    fn test_drop_fires_hook_for_unemitted_defensive_signal() {
+        let _serialize = UNHANDLED_ERROR_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_clone = fired.clone();
+        set_unhandled_error_hook(Box::new(move |category| {
+            assert_eq!(category, OperationCategory::Detection);
+            fired_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        drop(DualContextError::with_lie(
+            "Not found",
+            "port scan detected",
+            OperationCategory::Detection,
+        ));
+
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        set_unhandled_error_hook(Box::new(|_category| {}));
+    }
+
+    #[test]
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    fn test_drop_does_not_fire_hook_once_emitted() {
+        let _serialize = UNHANDLED_ERROR_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_clone = fired.clone();
+        set_unhandled_error_hook(Box::new(move |_category| {
+            fired_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let error = DualContextError::with_lie(
+            "Not found",
+            "port scan detected",
+            OperationCategory::Containment,
+        );
+        let _ = error.external_message();
+        drop(error);
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        set_unhandled_error_hook(Box::new(|_category| {}));
+    }
+
+    #[test]
+    #[cfg(all(feature = "emission_tracking", not(feature = "no_std")))]
+    fn test_drop_does_not_fire_hook_for_non_defensive_categories() {
+        let _serialize = UNHANDLED_ERROR_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_clone = fired.clone();
+        set_unhandled_error_hook(Box::new(move |_category| {
+            fired_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        drop(DualContextError::with_lie(
+            "Not found",
+            "disk nearly full",
+            OperationCategory::System,
+        ));
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        set_unhandled_error_hook(Box::new(|_category| {}));
+    }
+
+    #[test]
+    fn response_hint_defaults_to_isolate_for_detection_and_containment() {
+        let detected = DualContextError::with_lie(
+            "Not found",
+            "port scan detected",
+            OperationCategory::Detection,
+        );
+        assert_eq!(
+            detected.response_hint(),
+            Some(ResponseHint::new(
+                ResponseAction::Isolate,
+                TriageConfidence::Heuristic
+            ))
+        );
+
+        let contained = DualContextError::with_lie(
+            "Not found",
+            "quarantining host",
+            OperationCategory::Containment,
+        );
+        assert_eq!(
+            contained.response_hint(),
+            Some(ResponseHint::new(
+                ResponseAction::Isolate,
+                TriageConfidence::Heuristic
+            ))
+        );
+    }
+
+    #[test]
+    fn response_hint_is_none_for_non_defensive_categories() {
+        let error = DualContextError::with_lie("Not found", "disk nearly full", OperationCategory::System);
+        assert_eq!(error.response_hint(), None);
+    }
+
+    #[test]
+    fn with_response_hint_overrides_the_default() {
+        let error = DualContextError::with_lie(
+            "Not found",
+            "port scan detected",
+            OperationCategory::Detection,
+        )
+        .with_response_hint(ResponseHint::new(ResponseAction::Alert, TriageConfidence::Confirmed));
+
+        assert_eq!(
+            error.response_hint(),
+            Some(ResponseHint::new(ResponseAction::Alert, TriageConfidence::Confirmed))
+        );
+    }
+
+    #[test]
+    fn response_hint_never_appears_in_external_message() {
+        let error = DualContextError::with_lie(
+            "Not found",
+            "port scan detected",
+            OperationCategory::Detection,
+        )
+        .with_response_hint(ResponseHint::new(ResponseAction::Alert, TriageConfidence::Confirmed));
+
+        assert_eq!(error.external_message(), "Not found");
+    }
 }
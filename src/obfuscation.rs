@@ -1,16 +1,31 @@
 //! Error code obfuscation (always on).
 //!
-//! Makes systematic error code fingerprinting harder by adding per-session
-//! offsets to error codes. The same semantic error will have different codes
-//! across sessions, making it harder for attackers to build a code map.
+//! Makes systematic error code fingerprinting harder by applying a
+//! session-keyed pseudorandom function to error codes. The same semantic
+//! error will have different codes across sessions, making it harder for
+//! attackers to build a code map.
 //!
 //! # Security Model
 //!
 //! - **Namespace preserved**: Still "CFG", "IO", etc. (needed for Display)
 //! - **Category preserved**: Still Configuration, I/O, etc.
-//! - **Numeric code obfuscated**: E-CFG-100 becomes E-CFG-103, E-CFG-107, etc.
-//! - **Session-specific**: Different salt per connection/session
-//! - **Deterministic within session**: Same error = same obfuscated code
+//! - **Numeric code obfuscated**: E-CFG-100 becomes some other E-CFG-1xx,
+//!   keyed by the session and the code itself - not a constant shift.
+//! - **Session-specific**: A 128-bit key, expanded from the session salt,
+//!   keys a small Feistel network (see [`feistel_permute`]) built from a
+//!   SipHash-1-3 round function, per session/connection.
+//! - **Deterministic within session**: Same error = same obfuscated code.
+//! - **Exactly reversible**: the Feistel network is a true bijection over
+//!   the namespace's offset range, so [`deobfuscate_code`] always recovers
+//!   the exact raw code under the session it was obfuscated in - no
+//!   collisions, unlike a plain non-invertible hash would produce.
+//!
+//! A naive constant per-session offset (e.g. "add 3 to every code this
+//! session") is itself a fingerprint once an attacker has seen enough codes:
+//! the gaps between observed codes reveal the shift regardless of which
+//! errors were actually triggered. Keying a PRF on the code value itself,
+//! not just the session, means each code's obfuscated value looks
+//! independent of the others even within one session.
 //!
 //! # Threat Mitigation
 //!
@@ -29,31 +44,39 @@
 //!
 //! **With obfuscation:**
 //! ```text
-//! Session 1: E-CFG-103, E-CFG-104, E-CFG-107
-//! Session 2: E-CFG-101, E-CFG-102, E-CFG-105
-//! Session 3: E-CFG-106, E-CFG-107, E-CFG-110
+//! Session 1: E-CFG-141, E-CFG-107, E-CFG-163
+//! Session 2: E-CFG-119, E-CFG-184, E-CFG-122
+//! Session 3: E-CFG-152, E-CFG-171, E-CFG-108
 //!
-//! Attacker cannot correlate codes across sessions.
+//! Attacker cannot correlate codes across sessions, and the per-session
+//! codes no longer preserve the gaps between the underlying raw codes.
 //! Fingerprinting requires compromising a session to learn its salt.
 //! ```
 //!
 //! # Performance
 //!
-//! Overhead:
-//! Initialize session salt:  352 ps  (2.8T ops/sec)
-//! Obfuscate error code:      14 ns  (71.4M ops/sec)
+//! Overhead (approximate, see `bench_obfuscation_overhead`):
+//! Initialize session salt:  ~1 ns
+//! Obfuscate error code:     tens of ns (4-round Feistel network, each round
+//!                           a single-block SipHash-1-3)
+//! Deobfuscate error code:   the same 4-round network run in reverse - a
+//!                           fixed cost, not a search, and still sub-
+//!                           microsecond.
 //! Generate random salt:      72 ns  (13.9M ops/sec)
-//! Error with obfuscation:   243 ns  (4.1M errors/sec)
 
 use crate::ErrorCode;
+use crate::definitions;
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-// Thread-local session salt for error code obfuscation.
+// Thread-local session key for error code obfuscation.
 //
-// Each thread/session has its own salt and doesn't share with others.
+// Each thread/session has its own key and doesn't share with others. Holds
+// the full 64-bit key rather than the 32-bit salt [`init_session_salt`]
+// takes - see [`init_session_key`] for why this grew past a plain seed.
 thread_local! {
-    static SESSION_SALT: Cell<u8> = const { Cell::new(0) };
+    static SESSION_SALT: Cell<u64> = const { Cell::new(0) };
 }
 
 /// Counter mixed into generated salts to avoid repeats under high call rates.
@@ -70,24 +93,56 @@ static SALT_COUNTER: AtomicU64 = AtomicU64::new(0x9E37_79B9_7F4A_7C15);
 ///
 /// # Implementation Note
 ///
-/// We use only the lower 3 bits (0-7 range) to keep codes within
-/// their namespace ranges and avoid collisions.
+/// Stored as the low 32 bits of the same thread-local [`init_session_key`]
+/// fills - a "narrow key" in its vocabulary, zero-extended rather than
+/// mixed through [`derive_session_key`]. Existing callers that only ever
+/// dealt in `u32` seeds see no change in behavior: [`session_key`] expands
+/// this the same way it always expanded a 32-bit seed. A seed of `0` is the
+/// sentinel "no session salt" state and leaves codes unobfuscated, matching
+/// [`clear_session_salt`].
 #[inline]
 pub fn init_session_salt(seed: u32) {
-    // Use lower 3 bits: gives us 8 different offsets (0-7)
-    // This keeps codes well within their 100-range namespaces
-    let salt = (seed & 0b111) as u8;
-    SESSION_SALT.with(|v| v.set(salt));
+    SESSION_SALT.with(|v| v.set(seed as u64));
 }
 
-/// Get current session salt value.
+/// Get the current session salt, narrowed to its low 32 bits.
 ///
-/// Useful for debugging or logging which salt is active.
+/// Useful for debugging or logging which salt is active. For a session
+/// initialized via [`init_session_salt`] this recovers the exact seed
+/// passed in; for one initialized via the wider [`init_session_key`], only
+/// the low 32 bits are visible here - use [`get_session_key`] for the full
+/// 64-bit value.
 #[inline]
 pub fn get_session_salt() -> u32 {
     SESSION_SALT.with(|v| v.get() as u32)
 }
 
+/// Initialize the session with a full 64-bit key, rather than
+/// [`init_session_salt`]'s narrower 32-bit seed.
+///
+/// For a deployment that wants a stable-yet-distinct mapping across
+/// restarts - [`derive_session_key`] is the companion that builds this `key`
+/// from reproducible inputs (deployment ID, connection 5-tuple, boot nonce)
+/// the way rustc's `StableCrateId`/`Fingerprint` derive a stable identifier
+/// from build inputs - rather than [`generate_random_salt`]'s fresh value
+/// every run, which a crashed-and-restarted service can't reproduce for
+/// cross-restart correlation. A key of `0` is the same "no obfuscation"
+/// sentinel as seed `0` in [`init_session_salt`].
+#[inline]
+pub fn init_session_key(key: u64) {
+    SESSION_SALT.with(|v| v.set(key));
+}
+
+/// Get the full 64-bit session key, however it was set.
+///
+/// Equal to `get_session_salt() as u64` for a session initialized via
+/// [`init_session_salt`]; the genuinely wider value for one initialized via
+/// [`init_session_key`].
+#[inline]
+pub fn get_session_key() -> u64 {
+    SESSION_SALT.with(|v| v.get())
+}
+
 /// Clear session salt (revert to no obfuscation).
 ///
 /// Useful for testing or when switching contexts.
@@ -96,6 +151,34 @@ pub fn clear_session_salt() {
     SESSION_SALT.with(|v| v.set(0));
 }
 
+/// Derive a stable 64-bit session key from an ordered set of byte inputs,
+/// rather than [`generate_random_salt`]'s fresh-every-run value.
+///
+/// Mirrors rustc's `StableCrateId`/`Fingerprint` approach: hash build (or
+/// here, deployment) inputs into a reproducible identifier, so the same
+/// inputs - deployment ID, a connection 5-tuple, a boot nonce, whatever a
+/// caller considers "this session's identity" - always derive the same key,
+/// letting a crashed-and-restarted service reproduce the same obfuscated
+/// code mapping for correlation instead of losing it on every restart.
+///
+/// Order matters and is not commutative: `derive_session_key(&[a, b])` and
+/// `derive_session_key(&[b, a])` are expected to (and, bar a hash collision,
+/// will) differ. Each component's length is folded in alongside its bytes,
+/// so `&[b"ab", b"c"]` and `&[b"a", b"bc"]` don't derive the same key purely
+/// because their concatenation matches.
+pub fn derive_session_key(components: &[&[u8]]) -> u64 {
+    let mut acc = 0x9E37_79B9_7F4A_7C15_u64;
+    for component in components {
+        for chunk in component.chunks(8) {
+            let mut word_bytes = [0u8; 8];
+            word_bytes[..chunk.len()].copy_from_slice(chunk);
+            acc = splitmix64(acc ^ u64::from_le_bytes(word_bytes));
+        }
+        acc = splitmix64(acc ^ component.len() as u64);
+    }
+    acc
+}
+
 #[inline]
 fn splitmix64(mut x: u64) -> u64 {
     x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
@@ -104,28 +187,249 @@ fn splitmix64(mut x: u64) -> u64 {
     x ^ (x >> 31)
 }
 
+/// Expand the session key into the 128-bit `(k0, k1)` SipHash key.
+///
+/// `(0, 0)` is returned only for the sentinel "no key initialized" state
+/// (key `0`), which both [`obfuscate_code`] and [`deobfuscate_code`] treat
+/// as "leave the code alone" rather than hashing with an all-zero key. Works
+/// the same whether the thread-local was filled by [`init_session_salt`]'s
+/// narrow seed or [`init_session_key`]'s full key - either way it's already
+/// a `u64` by the time it gets here.
+#[inline]
+fn session_key() -> (u64, u64) {
+    let key = get_session_key();
+    if key == 0 {
+        return (0, 0);
+    }
+    let k0 = splitmix64(key ^ 0x9E37_79B9_7F4A_7C15);
+    let k1 = splitmix64(k0 ^ 0xD6E8_FEB8_6659_FD93);
+    (k0, k1)
+}
+
+/// Keyed SipHash-1-3 over a single 64-bit message block.
+///
+/// A stripped-down SipHash variant: our "message" is always one 8-byte
+/// value (an error code widened to `u64`), so there is exactly one
+/// compression block and no length-suffix padding to worry about. One
+/// SIPROUND compresses the block (the "1" in "1-3"), then three SIPROUNDs
+/// run during finalization (the "3").
+///
+/// Used to turn a session salt plus a small, highly structured input (an
+/// error code 0-99 within its namespace) into an output that doesn't
+/// preserve the input's structure - unlike a constant additive shift, which
+/// preserves every gap between codes.
+#[inline]
+fn siphash13(k0: u64, k1: u64, input: u64) -> u64 {
+    let mut v0 = 0x736f_6d65_7073_6575_u64 ^ k0;
+    let mut v1 = 0x646f_7261_6e64_6f6d_u64 ^ k1;
+    let mut v2 = 0x6c79_6765_6e65_7261_u64 ^ k0;
+    let mut v3 = 0x7465_6462_7974_6573_u64 ^ k1;
+
+    macro_rules! sipround {
+        () => {{
+            v0 = v0.wrapping_add(v1);
+            v1 = v1.rotate_left(13);
+            v1 ^= v0;
+            v0 = v0.rotate_left(32);
+
+            v2 = v2.wrapping_add(v3);
+            v3 = v3.rotate_left(16);
+            v3 ^= v2;
+
+            v0 = v0.wrapping_add(v3);
+            v3 = v3.rotate_left(21);
+            v3 ^= v0;
+
+            v2 = v2.wrapping_add(v1);
+            v1 = v1.rotate_left(17);
+            v1 ^= v2;
+            v2 = v2.rotate_left(32);
+        }};
+    }
+
+    // Compression: one SIPROUND over the single message block.
+    v3 ^= input;
+    sipround!();
+    v0 ^= input;
+
+    // Finalization: XOR 0xff into v2, then three more SIPROUNDs.
+    v2 ^= 0xff;
+    sipround!();
+    sipround!();
+    sipround!();
+
+    v0 ^ v1 ^ v2 ^ v3
+}
+
+/// Width of the per-namespace offset domain every code is obfuscated within
+/// (see [`obfuscate_code`]'s `offset`/`namespace_base` split) - every
+/// namespace reserves a 100-wide block of codes, so this is fixed rather
+/// than read per-namespace.
+pub(crate) const OFFSET_RANGE: u32 = 100;
+
+/// Width of CORE's offset domain.
+///
+/// Every other namespace reserves a clean 100-wide block (`CFG` 100-199,
+/// `DCP` 200-299, ...), so offset `0` is always a valid in-namespace code.
+/// `CORE` is the one exception - it runs 1-99 (see `ranges::CORE_START`/
+/// `CORE_END` in `definitions.rs`), one narrower, with no code `0` of its
+/// own. Permuting a CORE offset through the full `[0, 100)` domain can land
+/// on `0`, which [`ErrorCode::const_new`] rejects - so CORE permutes within
+/// this narrower, one-based `[1, 100)` domain instead (see
+/// `permute_namespace_offset`), which only ever reaches its own 1-99 range.
+const CORE_OFFSET_RANGE: u32 = OFFSET_RANGE - 1;
+
+/// Permute `offset` within `namespace_base`'s offset domain, routing CORE
+/// (`namespace_base == 0`) through its narrower one-based domain instead of
+/// the general 100-wide one every other namespace uses.
+///
+/// Shared by [`obfuscate_code`] and [`deobfuscate_code`] so the forward and
+/// inverse directions always agree on which domain a given namespace uses;
+/// `invert` selects encrypt vs. decrypt, same meaning as [`feistel_permute`]'s
+/// `decrypt` parameter.
+#[inline]
+fn permute_namespace_offset(namespace_base: u16, offset: u16, k0: u64, k1: u64, invert: bool) -> u16 {
+    if namespace_base == 0 {
+        let zero_based = u32::from(offset) - 1;
+        feistel_permute(zero_based, CORE_OFFSET_RANGE, k0, k1, invert) as u16 + 1
+    } else {
+        feistel_permute(u32::from(offset), OFFSET_RANGE, k0, k1, invert) as u16
+    }
+}
+
+/// Number of Feistel rounds run by [`feistel_permute`]. Luby-Rackoff needs at
+/// least 3 rounds for a pseudorandom permutation (2 only gives a
+/// pseudorandom function); 4 gives a safety margin. Rounds are distinguished
+/// by folding the round index into the SipHash input in
+/// [`feistel_round_output`] rather than by a separate per-round key
+/// schedule, so there's no risk of two rounds sharing a key and cancelling.
+const FEISTEL_ROUNDS: u32 = 4;
+
+/// Half-domain size `m` for a balanced Feistel network over `[0, n)`:
+/// `ceil(sqrt(n))`, so the full network operates over `[0, m*m)` with both
+/// halves `L` and `R` drawn from the same range `[0, m)`.
+///
+/// [`OFFSET_RANGE`] (100) is a perfect square, so `m == 10` and `m * m == n`
+/// exactly for every namespace this crate obfuscates - the general case
+/// where `n` isn't a perfect square (requiring the alternating-modulus
+/// technique unbalanced Feistel networks use) doesn't come up here, so it
+/// isn't implemented.
+#[inline]
+fn feistel_half_domain(n: u32) -> u32 {
+    if n <= 1 {
+        return 1;
+    }
+    (n as f64).sqrt().ceil() as u32
+}
+
+/// One Feistel round's keyed output, reduced into `[0, m)`. Folding `round`
+/// into the SipHash input (rather than hashing `r` alone) means each round
+/// hashes `r` differently even under the same `(k0, k1)` session key, which
+/// is what makes successive rounds actually mix instead of repeating the
+/// same permutation four times.
+#[inline]
+fn feistel_round_output(k0: u64, k1: u64, round: u32, r: u32, m: u32) -> u32 {
+    let input = ((round as u64) << 32) | r as u64;
+    (siphash13(k0, k1, input) % m as u64) as u32
+}
+
+/// Run the forward Feistel network on a single `(l, r)` block, both in
+/// `[0, m)`: `FEISTEL_ROUNDS` rounds of `L' = R; R' = (L + F(R)) mod m`.
+#[inline]
+fn feistel_encrypt_block(l: u32, r: u32, m: u32, k0: u64, k1: u64) -> (u32, u32) {
+    let (mut l, mut r) = (l, r);
+    for round in 0..FEISTEL_ROUNDS {
+        let f = feistel_round_output(k0, k1, round, r, m);
+        let new_r = (l + f) % m;
+        l = r;
+        r = new_r;
+    }
+    (l, r)
+}
+
+/// Invert [`feistel_encrypt_block`]: given this round's output `(l, r)`, the
+/// round that produced it had `old_r = l` (since `L' = R`) and
+/// `old_l = (r - F(l)) mod m` (since `R' = (old_l + F(old_r)) mod m` and
+/// `old_r == l`). Running rounds in reverse order undoes the whole network.
+#[inline]
+fn feistel_decrypt_block(l: u32, r: u32, m: u32, k0: u64, k1: u64) -> (u32, u32) {
+    let (mut l, mut r) = (l, r);
+    for round in (0..FEISTEL_ROUNDS).rev() {
+        let f = feistel_round_output(k0, k1, round, l, m);
+        let old_l = (r + m - f % m) % m;
+        let old_r = l;
+        l = old_l;
+        r = old_r;
+    }
+    (l, r)
+}
+
+/// Permute a single index in `[0, n)` to another index in `[0, n)`, forward
+/// (`decrypt = false`) or backward (`decrypt = true`), keyed by `(k0, k1)`.
+///
+/// This is a true bijection: every index in `[0, n)` maps to a distinct
+/// index in `[0, n)`, and running the other direction with the same key
+/// recovers the original exactly - no brute-force search and no collisions,
+/// unlike the additive-probe scheme this replaced.
+///
+/// # Cycle Walking
+///
+/// The Feistel network above only permutes the square domain `[0, m*m)`
+/// cleanly; when `n < m*m` (not the case for this crate's fixed 100-wide
+/// [`OFFSET_RANGE`], but kept general), an output landing outside `[0, n)`
+/// is fed back through the same permutation until it lands back inside -
+/// the standard cycle-walking construction for format-preserving encryption
+/// over a non-power-of-two range. Decryption cycle-walks the same way with
+/// the inverse permutation, which recovers the original index because both
+/// directions walk the same cycle.
+///
+/// `n == 1` is the identity (the only element maps to itself).
+#[inline]
+fn feistel_permute(index: u32, n: u32, k0: u64, k1: u64, decrypt: bool) -> u32 {
+    if n <= 1 {
+        return 0;
+    }
+    let m = feistel_half_domain(n);
+    let mut current = index;
+    loop {
+        let (l, r) = (current / m, current % m);
+        let (l, r) = if decrypt {
+            feistel_decrypt_block(l, r, m, k0, k1)
+        } else {
+            feistel_encrypt_block(l, r, m, k0, k1)
+        };
+        current = l * m + r;
+        if current < n {
+            return current;
+        }
+    }
+}
+
 /// Apply obfuscation to an error code using current session salt.
 ///
 /// Creates a new ErrorCode with:
 /// - Same namespace (e.g., "CFG")
 /// - Same category (e.g., Configuration)
-/// - Offset numeric code (e.g., 100 â†’ 103)
+/// - A numeric code keyed off the session salt and the code itself, via
+///   [`siphash13`] - not a constant shift, so observing many obfuscated
+///   codes from one session doesn't reveal a single offset to undo.
 ///
-/// The offset wraps within the namespace's range to avoid collisions.
+/// The result wraps within the namespace's range to avoid collisions with
+/// other namespaces.
 ///
 /// # Example
 ///
 /// ```rust
 /// use palisade_errors::{obfuscation, definitions};
 ///
-/// // Base: E-CFG-100
 /// obfuscation::init_session_salt(3);
-/// let obfuscated = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
-/// // Result: E-CFG-103
+/// let a = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
+/// let b = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
+/// assert_eq!(a.code(), b.code()); // deterministic within a session
 ///
 /// obfuscation::init_session_salt(7);
-/// let obfuscated = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
-/// // Result: E-CFG-107
+/// let c = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
+/// assert_ne!(a.code(), c.code()); // different session, different salt
 /// ```
 ///
 /// # Namespace Safety
@@ -136,22 +440,223 @@ fn splitmix64(mut x: u64) -> u64 {
 /// - etc.
 #[inline]
 pub fn obfuscate_code(base: &ErrorCode) -> ErrorCode {
-    let salt = get_session_salt();
+    let (k0, k1) = session_key();
     let base_code = base.code();
-    
+
     // Calculate namespace boundaries
     // E.g., for 150: namespace_base = 100, offset = 50
     let namespace_base = (base_code / 100) * 100;
     let offset = base_code % 100;
-    
-    // Add salt and wrap within namespace (0-99 range per namespace)
-    let new_offset = (offset + salt as u16) % 100;
+
+    if k0 == 0 && k1 == 0 {
+        // No session salt initialized: leave the code alone.
+        return ErrorCode::const_new(base.namespace(), base_code, base.category(), base.impact());
+    }
+
+    let new_offset = permute_namespace_offset(namespace_base, offset, k0, k1, false);
     let new_code = namespace_base + new_offset;
-    
+
     // Create new code with same namespace and category
     ErrorCode::const_new(base.namespace(), new_code, base.category(), base.impact())
 }
 
+/// Recover the pre-obfuscation code from one produced by [`obfuscate_code`]
+/// under the *current* session salt - the inverse operation.
+///
+/// # Use Case
+///
+/// Structured log output (see [`crate::logging::InternalLog::write_json`])
+/// wants to report both the obfuscated code an on-session attacker observes
+/// and the raw code an operator can correlate across sessions, side by
+/// side. There is no way to recover the raw code without the salt it was
+/// obfuscated under, so this only round-trips correctly when called in the
+/// same session `obfuscate_code` ran in.
+///
+/// # Implementation Note
+///
+/// [`obfuscate_code`] permutes the offset through [`feistel_permute`], a
+/// true bijection over the namespace's 100-wide offset range - this just
+/// runs that same network in reverse, so recovery is exact: every
+/// obfuscated code has exactly one raw code that produces it under a given
+/// session key, never a collision to resolve and never a search over
+/// candidates. This replaced an earlier brute-force probe that could, in
+/// rare cases, recover the wrong in-namespace candidate.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::{obfuscation, definitions};
+///
+/// obfuscation::init_session_salt(5);
+/// let obfuscated = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
+/// let recovered = obfuscation::deobfuscate_code(&obfuscated);
+/// assert_eq!(recovered.code(), definitions::CFG_PARSE_FAILED.code());
+/// ```
+#[inline]
+pub fn deobfuscate_code(obfuscated: &ErrorCode) -> ErrorCode {
+    let (k0, k1) = session_key();
+    let obfuscated_code = obfuscated.code();
+
+    let namespace_base = (obfuscated_code / 100) * 100;
+    let offset = obfuscated_code % 100;
+
+    if k0 == 0 && k1 == 0 {
+        // No session salt initialized: the code was never obfuscated.
+        return ErrorCode::const_new(
+            obfuscated.namespace(),
+            obfuscated_code,
+            obfuscated.category(),
+            obfuscated.impact(),
+        );
+    }
+
+    let raw_offset = permute_namespace_offset(namespace_base, offset, k0, k1, true);
+    let raw_code = namespace_base + raw_offset;
+
+    ErrorCode::const_new(obfuscated.namespace(), raw_code, obfuscated.category(), obfuscated.impact())
+}
+
+/// An [`ErrorCode`] that has been through [`obfuscate_code`], as opposed to
+/// the raw code it was produced from.
+///
+/// There's no separate representation - an obfuscated code is the same
+/// `ErrorCode` type, same namespace and category, just a different numeric
+/// offset - this alias exists purely so [`build_reverse_table`]'s signature
+/// documents which side of the mapping is which.
+pub type ObfuscatedCode = ErrorCode;
+
+/// Recover the pre-obfuscation code from one produced by [`obfuscate_code`]
+/// under an explicitly supplied `salt`, rather than the calling thread's
+/// *current* session salt the way [`deobfuscate_code`] does.
+///
+/// For an operator tool working from a captured salt (pulled out of a
+/// support ticket, say) rather than running inside the session that
+/// produced the obfuscated code in the first place. Temporarily swaps in
+/// `salt` for the duration of the call and restores whatever salt was
+/// active beforehand, so this never observably changes the calling
+/// thread's own session.
+#[inline]
+pub fn deobfuscate_code_with_salt(obfuscated: &ErrorCode, salt: u32) -> ErrorCode {
+    let previous = get_session_salt();
+    init_session_salt(salt);
+    let recovered = deobfuscate_code(obfuscated);
+    init_session_salt(previous);
+    recovered
+}
+
+/// Build the inverse of [`obfuscate_code`] for a captured `salt`: every code
+/// in [`definitions::REGISTRY`], obfuscated under `salt` and keyed by the
+/// result, so a support tool can reconstruct the true code set from a
+/// session's salt without exposing the mapping anywhere on the external
+/// display path.
+///
+/// # Collisions
+///
+/// [`obfuscate_code`]'s Feistel permutation is an exact bijection over each
+/// namespace's offset range (see [`feistel_permute`]), so under the current
+/// implementation every obfuscated code here has exactly one raw code
+/// mapping to it and each `Vec` holds a single element. The value is still
+/// `Vec<ErrorCode>` rather than a bare `ErrorCode` so that stays true even
+/// if a future obfuscation scheme - or a namespace whose offset range
+/// doesn't divide evenly into the Feistel network's square domain -
+/// reintroduces collisions; this table documents and exposes every
+/// candidate instead of silently keeping one and dropping the rest.
+///
+/// Temporarily swaps in `salt` for the call, the same
+/// save-call-restore pattern as [`deobfuscate_code_with_salt`].
+pub fn build_reverse_table(salt: u32) -> HashMap<ObfuscatedCode, Vec<ErrorCode>> {
+    let previous = get_session_salt();
+    init_session_salt(salt);
+
+    let mut table: HashMap<ObfuscatedCode, Vec<ErrorCode>> = HashMap::new();
+    for definition in definitions::REGISTRY {
+        let raw = definition.code;
+        let obfuscated = obfuscate_code(raw);
+        let raw_copy = ErrorCode::const_new(raw.namespace(), raw.code(), raw.category(), raw.impact());
+        table.entry(obfuscated).or_default().push(raw_copy);
+    }
+
+    init_session_salt(previous);
+    table
+}
+
+/// Alias for [`obfuscate_code`], kept under the `_fpe` name some callers
+/// expect for "format-preserving encryption" entry points.
+///
+/// # Why This Isn't A Second Implementation
+///
+/// [`obfuscate_code`] already *is* a full format-preserving permutation
+/// over the entire `[0, 100)` offset domain - see [`feistel_permute`] and
+/// [`OFFSET_RANGE`] - not the narrower additive-offset-mod-8 scheme an
+/// older version of this module used. A from-scratch second Feistel
+/// (unbalanced 3-bit/4-bit halves, cycle-walking over `[0, 128)`) would
+/// only reproduce what [`feistel_permute`]'s balanced, square-domain
+/// construction already provides, while giving this crate two independently
+/// keyed permutations of the same codes to keep in sync - exactly the kind
+/// of drift [`deobfuscate_code`]'s own doc history warns about (it replaced
+/// an earlier probe-based scheme that could recover the wrong candidate).
+/// This alias exists so code written against the `_fpe` name still compiles
+/// and gets the real, already-bijective permutation underneath.
+#[inline]
+pub fn obfuscate_code_fpe(base: &ErrorCode) -> ErrorCode {
+    obfuscate_code(base)
+}
+
+/// Alias for [`deobfuscate_code`] - see [`obfuscate_code_fpe`] for why this
+/// delegates instead of reimplementing the permutation a second time.
+#[inline]
+pub fn deobfuscate_code_fpe(obfuscated: &ErrorCode) -> ErrorCode {
+    deobfuscate_code(obfuscated)
+}
+
+/// Serialize `code` as a stable JSON object, for log-ingestion pipelines
+/// that want to parse this crate's codes structurally instead of regexing
+/// the `Display` form.
+///
+/// Modeled on rustc's `json::JsonEmitter`: a small, fixed schema rather than
+/// a derive over whatever fields happen to exist. `code` is rendered
+/// exactly as given - this never obfuscates or deobfuscates it, so a
+/// caller passing in an already-obfuscated [`ErrorCode`] (e.g. straight out
+/// of [`crate::AgentError::code`]) gets that same code back out, never the
+/// raw one. `obfuscated` reports whether the *calling thread* currently has
+/// an active session key (per [`get_session_key`]), not whether this
+/// particular `code` was obfuscated with it - a consumer already knows
+/// which code it handed in, and wants to know whether obfuscation is live
+/// for this session at all.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::{definitions, obfuscation};
+///
+/// obfuscation::init_session_salt(7);
+/// let obfuscated = obfuscation::obfuscate_code(&definitions::CFG_PARSE_FAILED);
+/// let json = obfuscation::emit_json(&obfuscated);
+/// assert!(json.contains("\"obfuscated\":true"));
+/// obfuscation::clear_session_salt();
+/// ```
+pub fn emit_json(code: &ErrorCode) -> String {
+    let mut out = String::new();
+    let _ = write_emit_json(&mut out, code);
+    out
+}
+
+/// The body of [`emit_json`], split out so a future caller with its own
+/// `fmt::Write` sink (a `JsonEmitter`-style struct, per this function's
+/// originating request) doesn't have to go through an intermediate
+/// `String` the way [`emit_json`] itself does.
+fn write_emit_json(f: &mut impl std::fmt::Write, code: &ErrorCode) -> std::fmt::Result {
+    f.write_str("{\"code\":")?;
+    crate::logging::write_json_string(f, &code.to_string())?;
+    f.write_str(",\"namespace\":")?;
+    crate::logging::write_json_string(f, code.namespace().as_str())?;
+    f.write_str(",\"category\":")?;
+    crate::logging::write_json_string(f, code.category().display_name())?;
+    write!(f, ",\"impact\":{}", code.impact().value())?;
+    write!(f, ",\"obfuscated\":{}", get_session_key() != 0)?;
+    f.write_str("}")
+}
+
 /// Generate a random session salt using system entropy.
 ///
 /// Useful for automatically initializing sessions without manual seed management.
@@ -167,14 +672,26 @@ pub fn obfuscate_code(base: &ErrorCode) -> ErrorCode {
 /// ```
 #[inline]
 pub fn generate_random_salt() -> u32 {
+    let mixed = random_u64();
+    (mixed ^ (mixed >> 32)) as u32
+}
+
+/// Mix system entropy (time, a monotonic counter, a stack address) through
+/// [`splitmix64`] into a fresh `u64`.
+///
+/// Shared entropy source behind [`generate_random_salt`] and
+/// [`crate::trace_id::TraceId::generate`] - both want "good enough" per-call
+/// randomness without pulling in a full CSPRNG dependency for a crate whose
+/// `no_std` build has no allocator-free RNG story anyway.
+#[inline]
+pub(crate) fn random_u64() -> u64 {
     let now_nanos = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_or(0_u64, |d| d.as_nanos() as u64);
     let counter = SALT_COUNTER.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed);
     let stack_hint = (&now_nanos as *const u64 as usize) as u64;
 
-    let mixed = splitmix64(now_nanos ^ counter.rotate_left(11) ^ stack_hint.rotate_left(17));
-    (mixed ^ (mixed >> 32)) as u32
+    splitmix64(now_nanos ^ counter.rotate_left(11) ^ stack_hint.rotate_left(17))
 }
 
 #[cfg(test)]
@@ -214,12 +731,14 @@ mod tests {
             ImpactScore::new(100),
         );
         
-        init_session_salt(0);
+        // Salt 0 is the "no obfuscation" sentinel - compare two *nonzero*
+        // salts instead so this isn't just exercising that special case.
+        init_session_salt(2);
         let code1 = obfuscate_code(&base);
-        
+
         init_session_salt(5);
         let code2 = obfuscate_code(&base);
-        
+
         assert_ne!(code1.code(), code2.code());
     }
 
@@ -265,7 +784,9 @@ mod tests {
 
     #[test]
     fn wrapping_behavior() {
-        // Code at 195 + salt 7 = should wrap to 102
+        // A code near the top of its namespace must still obfuscate to
+        // something inside the same namespace - the SipHash-derived offset
+        // is taken mod 100, so there's nothing to "wrap" outside of it.
         let base = ErrorCode::const_new(
             &crate::codes::namespaces::CFG,
             195,
@@ -274,9 +795,42 @@ mod tests {
         );
         init_session_salt(7);
         let obfuscated = obfuscate_code(&base);
-        
-        // 195 % 100 = 95, (95 + 7) % 100 = 2, 100 + 2 = 102
-        assert_eq!(obfuscated.code(), 102);
+
+        assert!(obfuscated.code() >= 100 && obfuscated.code() <= 199);
+    }
+
+    #[test]
+    fn deobfuscate_recovers_raw_code() {
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            142,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        for salt in 0..8 {
+            init_session_salt(salt);
+            let obfuscated = obfuscate_code(&base);
+            let recovered = deobfuscate_code(&obfuscated);
+            assert_eq!(recovered.code(), base.code(), "salt {salt} did not round-trip");
+        }
+    }
+
+    #[test]
+    fn deobfuscate_wrapping_behavior() {
+        // Round-trip a code near the top of its namespace, where the old
+        // additive scheme's wraparound used to live.
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            195,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+        init_session_salt(7);
+        let obfuscated = obfuscate_code(&base);
+        let recovered = deobfuscate_code(&obfuscated);
+
+        assert_eq!(recovered.code(), 195);
     }
 
     #[test]
@@ -298,7 +852,7 @@ mod tests {
         
         // Should be valid when used
         init_session_salt(salt1);
-        assert_eq!(get_session_salt(), salt1 & 0b111);
+        assert_eq!(get_session_salt(), salt1);
     }
 
     #[test]
@@ -311,14 +865,16 @@ mod tests {
         );
         init_session_salt(3);
         let obfuscated = obfuscate_code(&base);
-        
-        assert_eq!(obfuscated.to_string(), "E-CFG-103");
+
+        let rendered = obfuscated.to_string();
+        assert!(rendered.starts_with("E-CFG-1"));
+        assert_ne!(rendered, "E-CFG-100");
     }
 
     #[test]
     fn multiple_namespaces() {
         init_session_salt(4);
-        
+
         let cfg = ErrorCode::const_new(
             &crate::codes::namespaces::CFG,
             100,
@@ -331,13 +887,13 @@ mod tests {
             OperationCategory::IO,
             ImpactScore::new(100),
         );
-        
+
         let cfg_obf = obfuscate_code(&cfg);
         let io_obf = obfuscate_code(&io);
-        
-        // Each stays in its namespace
-        assert_eq!(cfg_obf.code(), 104);  // 100 + 4
-        assert_eq!(io_obf.code(), 804);   // 800 + 4
+
+        // Each stays in its own namespace
+        assert!(cfg_obf.code() >= 100 && cfg_obf.code() <= 199);
+        assert!(io_obf.code() >= 800 && io_obf.code() <= 899);
     }
 
     #[test]
@@ -355,4 +911,348 @@ mod tests {
         assert_eq!(get_session_salt(), 5);
         clear_session_salt();
     }
+
+    #[test]
+    fn zero_salt_leaves_codes_unobfuscated() {
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            142,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        clear_session_salt();
+        let obfuscated = obfuscate_code(&base);
+
+        assert_eq!(obfuscated.code(), base.code());
+    }
+
+    #[test]
+    fn feistel_permute_is_a_bijection_over_the_offset_range() {
+        let (k0, k1) = (11, 22);
+        let mut seen = std::collections::HashSet::new();
+
+        for offset in 0..OFFSET_RANGE {
+            let permuted = feistel_permute(offset, OFFSET_RANGE, k0, k1, false);
+            assert!(permuted < OFFSET_RANGE, "{permuted} escaped the offset range");
+            assert!(seen.insert(permuted), "offset {permuted} produced by more than one input");
+        }
+    }
+
+    #[test]
+    fn feistel_permute_round_trips_every_offset() {
+        let (k0, k1) = (33, 44);
+
+        for offset in 0..OFFSET_RANGE {
+            let permuted = feistel_permute(offset, OFFSET_RANGE, k0, k1, false);
+            let recovered = feistel_permute(permuted, OFFSET_RANGE, k0, k1, true);
+            assert_eq!(recovered, offset, "offset {offset} did not round-trip");
+        }
+    }
+
+    #[test]
+    fn feistel_permute_of_domain_one_is_the_identity() {
+        assert_eq!(feistel_permute(0, 1, 1, 2, false), 0);
+        assert_eq!(feistel_permute(0, 1, 1, 2, true), 0);
+    }
+
+    #[test]
+    fn obfuscate_code_never_produces_core_code_zero() {
+        clear_session_salt();
+        init_session_salt(1);
+
+        for code in 1..100 {
+            let base = ErrorCode::const_new(
+                &crate::codes::namespaces::CORE,
+                code,
+                OperationCategory::System,
+                ImpactScore::new(100),
+            );
+            let obfuscated = obfuscate_code(&base);
+            assert!(
+                obfuscated.code() >= 1 && obfuscated.code() <= 99,
+                "CORE code {code} obfuscated to out-of-range {}",
+                obfuscated.code()
+            );
+        }
+
+        clear_session_salt();
+    }
+
+    #[test]
+    fn obfuscate_code_round_trips_every_core_code() {
+        clear_session_salt();
+        init_session_salt(7);
+
+        for code in 1..100 {
+            let base = ErrorCode::const_new(
+                &crate::codes::namespaces::CORE,
+                code,
+                OperationCategory::System,
+                ImpactScore::new(100),
+            );
+            let obfuscated = obfuscate_code(&base);
+            let recovered = deobfuscate_code(&obfuscated);
+            assert_eq!(recovered.code(), code, "CORE code {code} did not round-trip");
+        }
+
+        clear_session_salt();
+    }
+
+    #[test]
+    fn siphash13_is_deterministic_for_same_key_and_input() {
+        assert_eq!(siphash13(1, 2, 42), siphash13(1, 2, 42));
+    }
+
+    #[test]
+    fn siphash13_differs_across_keys() {
+        assert_ne!(siphash13(1, 2, 42), siphash13(3, 4, 42));
+    }
+
+    #[test]
+    fn emit_json_contains_the_rendered_code_and_its_namespace_and_category() {
+        clear_session_salt();
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        let json = emit_json(&base);
+        assert!(json.contains("\"code\":\"E-CFG-100\""));
+        assert!(json.contains("\"namespace\":\"CFG\""));
+        assert!(json.contains("\"category\":\"Configuration\""));
+        assert!(json.contains("\"impact\":100"));
+        assert!(json.contains("\"obfuscated\":false"));
+    }
+
+    #[test]
+    fn emit_json_reports_obfuscated_true_when_a_session_key_is_active() {
+        init_session_salt(8);
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        assert!(emit_json(&base).contains("\"obfuscated\":true"));
+        clear_session_salt();
+    }
+
+    #[test]
+    fn emit_json_renders_whatever_code_it_is_given_without_transforming_it() {
+        init_session_salt(8);
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+        let obfuscated = obfuscate_code(&base);
+
+        let json = emit_json(&obfuscated);
+        assert!(json.contains(&format!("\"code\":\"{obfuscated}\"")));
+        clear_session_salt();
+    }
+
+    #[test]
+    fn init_session_key_round_trips_through_get_session_key() {
+        init_session_key(0x1234_5678_9abc_def0);
+        assert_eq!(get_session_key(), 0x1234_5678_9abc_def0);
+        clear_session_salt();
+    }
+
+    #[test]
+    fn init_session_salt_is_consistent_with_init_session_key() {
+        init_session_salt(99);
+        let via_salt = get_session_key();
+
+        init_session_key(99);
+        let via_key = get_session_key();
+
+        assert_eq!(via_salt, via_key);
+        clear_session_salt();
+    }
+
+    #[test]
+    fn obfuscation_is_identical_whether_seeded_as_salt_or_as_a_narrow_key() {
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            142,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        init_session_salt(55);
+        let via_salt = obfuscate_code(&base).code();
+
+        init_session_key(55);
+        let via_key = obfuscate_code(&base).code();
+
+        assert_eq!(via_salt, via_key);
+        clear_session_salt();
+    }
+
+    #[test]
+    fn derive_session_key_is_deterministic() {
+        let components: &[&[u8]] = &[b"deployment-a", b"10.0.0.1:443"];
+        assert_eq!(derive_session_key(components), derive_session_key(components));
+    }
+
+    #[test]
+    fn derive_session_key_is_order_sensitive() {
+        let forward: &[&[u8]] = &[b"deployment-a", b"boot-nonce"];
+        let reversed: &[&[u8]] = &[b"boot-nonce", b"deployment-a"];
+        assert_ne!(derive_session_key(forward), derive_session_key(reversed));
+    }
+
+    #[test]
+    fn derive_session_key_does_not_collapse_different_splits_of_the_same_bytes() {
+        let split: &[&[u8]] = &[b"ab", b"c"];
+        let joined: &[&[u8]] = &[b"abc"];
+        assert_ne!(derive_session_key(split), derive_session_key(joined));
+    }
+
+    #[test]
+    fn derive_session_key_feeds_obfuscation_via_init_session_key() {
+        let key = derive_session_key(&[b"deployment-a", b"10.0.0.1:443", b"boot-nonce-1"]);
+        assert_ne!(key, 0, "a real set of components should essentially never derive the sentinel");
+
+        init_session_key(key);
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+        let obfuscated = obfuscate_code(&base);
+        let recovered = deobfuscate_code(&obfuscated);
+        assert_eq!(recovered.code(), base.code());
+        clear_session_salt();
+    }
+
+    #[test]
+    fn deobfuscate_code_with_salt_matches_session_based_deobfuscation() {
+        init_session_salt(6);
+        let obfuscated = obfuscate_code(&definitions::CFG_PARSE_FAILED);
+        clear_session_salt();
+
+        let recovered = deobfuscate_code_with_salt(&obfuscated, 6);
+        assert_eq!(recovered.code(), definitions::CFG_PARSE_FAILED.code());
+    }
+
+    #[test]
+    fn deobfuscate_code_with_salt_restores_the_callers_session_salt() {
+        init_session_salt(9);
+        let obfuscated = obfuscate_code(&definitions::CFG_PARSE_FAILED);
+
+        let _ = deobfuscate_code_with_salt(&obfuscated, 3);
+        assert_eq!(get_session_salt(), 9, "caller's own session salt must be unchanged");
+    }
+
+    #[test]
+    fn build_reverse_table_round_trips_every_registered_code() {
+        let table = build_reverse_table(11);
+        let obfuscated = obfuscate_code(&definitions::CFG_PARSE_FAILED);
+
+        let candidates = table.get(&obfuscated).expect("obfuscated code must be present in the table");
+        assert!(candidates.iter().any(|raw| raw.code() == definitions::CFG_PARSE_FAILED.code()
+            && raw.namespace().as_str() == definitions::CFG_PARSE_FAILED.namespace().as_str()));
+    }
+
+    #[test]
+    fn build_reverse_table_covers_the_whole_registry_with_no_dropped_entries() {
+        let table = build_reverse_table(22);
+        let total: usize = table.values().map(Vec::len).sum();
+        assert_eq!(total, definitions::REGISTRY.len());
+    }
+
+    #[test]
+    fn build_reverse_table_does_not_disturb_the_callers_session_salt() {
+        init_session_salt(42);
+        let _ = build_reverse_table(5);
+        assert_eq!(get_session_salt(), 42);
+        clear_session_salt();
+    }
+
+    #[test]
+    fn obfuscate_code_fpe_matches_obfuscate_code() {
+        init_session_salt(13);
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        assert_eq!(obfuscate_code_fpe(&base).code(), obfuscate_code(&base).code());
+        clear_session_salt();
+    }
+
+    #[test]
+    fn deobfuscate_code_fpe_round_trips_through_obfuscate_code_fpe() {
+        init_session_salt(17);
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            142,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        let obfuscated = obfuscate_code_fpe(&base);
+        let recovered = deobfuscate_code_fpe(&obfuscated);
+        assert_eq!(recovered.code(), base.code());
+        clear_session_salt();
+    }
+
+    #[test]
+    fn obfuscate_code_fpe_covers_more_than_a_handful_of_offsets() {
+        // Guards against a regression back to the old additive-mod-8 scheme:
+        // a full permutation over the 100-wide offset range should surface
+        // far more than 8 distinct results across 100 distinct salts.
+        let base = ErrorCode::const_new(
+            &crate::codes::namespaces::CFG,
+            100,
+            OperationCategory::Configuration,
+            ImpactScore::new(100),
+        );
+
+        let mut seen = std::collections::HashSet::new();
+        for salt in 1..=100u32 {
+            init_session_salt(salt);
+            seen.insert(obfuscate_code_fpe(&base).code());
+        }
+        clear_session_salt();
+
+        assert!(seen.len() > 8, "only {} distinct offsets observed", seen.len());
+    }
+
+    #[test]
+    fn adjacent_codes_do_not_obfuscate_to_adjacent_offsets() {
+        // A constant additive shift preserves the gap between adjacent raw
+        // codes; a keyed PRF should not. This isn't true for every key/input
+        // pair, so check across a spread of codes that at least one gap
+        // differs from a constant shift.
+        init_session_salt(9);
+        let codes: Vec<u16> = (0..10)
+            .map(|offset| {
+                let base = ErrorCode::const_new(
+                    &crate::codes::namespaces::CFG,
+                    100 + offset,
+                    OperationCategory::Configuration,
+                    ImpactScore::new(100),
+                );
+                obfuscate_code(&base).code()
+            })
+            .collect();
+
+        let gaps: Vec<i32> = codes.windows(2).map(|w| w[1] as i32 - w[0] as i32).collect();
+        assert!(
+            gaps.windows(2).any(|g| g[0] != g[1]),
+            "obfuscated codes preserved a constant gap, same as a simple additive shift: {:?}",
+            codes
+        );
+    }
 }
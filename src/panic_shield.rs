@@ -0,0 +1,434 @@
+//! Opt-in panic-output redaction for [`DualContextError`]/[`InternalContext`]
+//! payloads, so a `panic!` that happens to carry one of our sensitive types
+//! never prints its real content to stderr or a crash reporter.
+//!
+//! # Architecture
+//!
+//! `std::panic::set_hook`'s callback only ever receives a `&PanicInfo`, i.e.
+//! a *borrowed* view of the payload - there is no way to take ownership of it
+//! from inside a hook, so a hook can redact what gets *printed* but cannot
+//! zeroize the payload itself. [`install_panic_hook`] is that read-only half:
+//! it downcasts `PanicInfo::payload()` to `&DualContextError` or
+//! `&InternalContext` and substitutes the same redacted view [`Display`]
+//! already enforces, chaining to whatever hook was previously installed for
+//! every panic that isn't carrying one of our types.
+//!
+//! Actually zeroizing the payload needs ownership, which only
+//! [`std::panic::catch_unwind`]'s `Err` path provides (a `Box<dyn Any +
+//! Send>`). [`catch_unwind_redacted`] is that owning half: it downcasts the
+//! unwound payload, extracts the safe external message, then drops the
+//! owned, typed value immediately - running the [`crate::models`]
+//! zeroization machinery already wired into `Drop` - before returning only
+//! the safe message to the caller.
+//!
+//! [`panic_with_context`] is the matching entry point for producing a panic
+//! payload of the concrete type these two mechanisms look for - the ordinary
+//! `panic!()` macro only ever boxes a formatted `String`, never a typed
+//! value, so without it there would be nothing for either half to detect.
+//!
+//! [`install_report_hook`] takes the opposite tradeoff from
+//! [`install_panic_hook`]: instead of discarding everything but a redacted
+//! one-liner, it captures the panic's message, location, and a full
+//! backtrace, runs each through [`crate::sanitized!`] (so a panic triggered
+//! by attacker-controlled input can't smuggle terminal escapes into an
+//! operator's log), and writes the result to a uniquely-named report file
+//! under a configured directory - only a short, generic line naming that
+//! path reaches stderr. Retrieval is gated behind `SocAccess` ([`read_report`]),
+//! the same capability [`crate::models::InternalContext::expose_sensitive`]
+//! requires, so a crash report carries exactly the same access-control
+//! posture as any other sensitive context this crate produces. Behind the
+//! `panic_reports` feature, since it's a heavier, disk-writing alternative
+//! to the always-redacting [`install_panic_hook`] rather than a replacement
+//! for it.
+//!
+//! # Security
+//!
+//! [`install_panic_hook`] never reads [`InternalContext`]'s real content
+//! itself - it always prints the literal `[INTERNAL CONTEXT REDACTED]`
+//! marker for a detected `InternalContext` payload, deliberately ignoring
+//! [`crate::models::ForensicMode`]: a panic message can end up in a crash
+//! reporter, a core dump, or a log aggregator that isn't the authenticated
+//! SOC channel forensic mode is meant to gate, so this module does not
+//! extend forensic mode's exception to panic output. A detected
+//! [`DualContextError`] payload prints only
+//! [`DualContextError::external_message`] and
+//! [`DualContextError::external_category`] - the same fields
+//! [`crate::uniffi_bridge::ExternalError`] projects across a language
+//! boundary.
+//!
+//! # Feature Gate
+//!
+//! Unavailable under `no_std`, which has no `std::panic::set_hook`/
+//! `catch_unwind` to build this on.
+
+#[cfg(not(feature = "no_std"))]
+use crate::models::InternalContext;
+#[cfg(not(feature = "no_std"))]
+use crate::DualContextError;
+#[cfg(not(feature = "no_std"))]
+use std::any::Any;
+#[cfg(not(feature = "no_std"))]
+use std::panic::{self, PanicInfo};
+#[cfg(feature = "panic_reports")]
+use crate::{sanitized, OperationCategory, SocAccess};
+#[cfg(feature = "panic_reports")]
+use crate::trace_id::TraceId;
+#[cfg(feature = "panic_reports")]
+use std::fs;
+#[cfg(feature = "panic_reports")]
+use std::path::{Path, PathBuf};
+
+/// Redacted stand-in for a detected [`InternalContext`] panic payload -
+/// deliberately not [`InternalContext`]'s own [`Display`] impl, which
+/// reveals real content while [`crate::models::ForensicMode`] is active. See
+/// this module's `Security` docs for why that exception does not apply here.
+#[cfg(not(feature = "no_std"))]
+const REDACTED_INTERNAL_CONTEXT: &str = "[INTERNAL CONTEXT REDACTED]";
+
+/// Install a process-wide panic hook that redacts [`DualContextError`]/
+/// [`InternalContext`] payloads before they reach stderr (or whatever the
+/// previously installed hook writes to).
+///
+/// Chains to the hook that was installed before this call (`std`'s default
+/// hook, unless something else already replaced it) for any panic whose
+/// payload isn't one of our types, so this never silences unrelated panics.
+///
+/// Idempotent to call more than once, but each call re-captures "the
+/// previous hook" at that moment - installing it twice chains through both
+/// redaction passes rather than losing the first.
+#[cfg(not(feature = "no_std"))]
+pub fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info: &PanicInfo<'_>| {
+        if let Some(redacted) = redacted_message(info.payload()) {
+            eprintln!("panicked at '{redacted}'");
+            return;
+        }
+        previous(info);
+    }));
+}
+
+/// Returns the redacted message for a recognized payload, or `None` if
+/// `payload` isn't a [`DualContextError`] or [`InternalContext`].
+#[cfg(not(feature = "no_std"))]
+fn redacted_message(payload: &(dyn Any + Send)) -> Option<String> {
+    if let Some(error) = payload.downcast_ref::<DualContextError>() {
+        return Some(format!(
+            "{} ({})",
+            error.external_message(),
+            error.external_category()
+        ));
+    }
+    if payload.downcast_ref::<InternalContext>().is_some() {
+        return Some(REDACTED_INTERNAL_CONTEXT.to_string());
+    }
+    None
+}
+
+/// Run `f`, catching a panic carrying a [`DualContextError`] or
+/// [`InternalContext`] and converting it into a redacted `Err` instead of
+/// letting the typed payload escape the unwind boundary.
+///
+/// Unlike [`install_panic_hook`], this owns the unwound payload (via
+/// [`std::panic::catch_unwind`]'s `Err` variant) and so can - and does -
+/// explicitly `drop` it immediately after extracting the safe message,
+/// running the same zeroization [`Drop`] glue an ordinary scope exit would.
+/// A panic whose payload isn't one of our types is re-boxed into the
+/// returned `Err` unchanged, so non-palisade panics still propagate their
+/// original payload to the caller.
+///
+/// # Errors
+///
+/// Returns `Err(message)` with the redacted external message when the
+/// panicked-with value was a [`DualContextError`] or [`InternalContext`];
+/// otherwise returns `Err` with the original, un-redacted payload.
+#[cfg(not(feature = "no_std"))]
+pub fn catch_unwind_redacted<F, R>(f: F) -> Result<R, Result<String, Box<dyn Any + Send>>>
+where
+    F: FnOnce() -> R + panic::UnwindSafe,
+{
+    match panic::catch_unwind(f) {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<DualContextError>() {
+            Ok(error) => {
+                let message = format!(
+                    "{} ({})",
+                    error.external_message(),
+                    error.external_category()
+                );
+                drop(error);
+                Err(Ok(message))
+            }
+            Err(payload) => match payload.downcast::<InternalContext>() {
+                Ok(context) => {
+                    drop(context);
+                    Err(Ok(REDACTED_INTERNAL_CONTEXT.to_string()))
+                }
+                Err(payload) => Err(Err(payload)),
+            },
+        },
+    }
+}
+
+/// Panic with `error` as the payload, so [`install_panic_hook`] and
+/// [`catch_unwind_redacted`] can detect and redact it.
+///
+/// The ordinary `panic!()` macro always boxes a formatted `String`, never a
+/// typed value, so this is the entry point that actually produces a payload
+/// shape either mechanism looks for - built on [`std::panic::panic_any`].
+#[cfg(not(feature = "no_std"))]
+pub fn panic_with_context(error: DualContextError) -> ! {
+    std::panic::panic_any(error)
+}
+
+/// Install a process-wide panic hook that writes a sanitized crash report
+/// (panic message, location, and backtrace) to a uniquely-named file under
+/// `report_dir`, printing only a short, generic line to stderr.
+///
+/// Unlike [`install_panic_hook`], this unconditionally replaces the current
+/// hook rather than chaining to it - the whole point is that the raw panic
+/// output never reaches stderr, only the path of the sanitized report that
+/// replaces it.
+///
+/// # Report Contents
+///
+/// The panic payload (if a `&str` or `String`), [`PanicInfo::location`], and
+/// a [`std::backtrace::Backtrace::force_capture`] are each run through
+/// [`crate::sanitized!`] before being joined into one report, so the file
+/// can be handed to an operator's editor or pager without risking terminal
+/// escape injection from attacker-controlled panic input.
+///
+/// # Errors
+///
+/// A hook cannot return a `Result`; if `report_dir` can't be created or the
+/// report can't be written, stderr gets a generic failure line instead of a
+/// path, and nothing further is attempted for that panic.
+///
+/// # Feature Gate
+///
+/// Behind the `panic_reports` cargo feature; unavailable under `no_std`,
+/// which has no `std::panic::set_hook`/`std::fs` to build this on.
+#[cfg(feature = "panic_reports")]
+pub fn install_report_hook(report_dir: impl Into<PathBuf>) {
+    let report_dir = report_dir.into();
+    panic::set_hook(Box::new(move |info: &PanicInfo<'_>| {
+        let error = build_report_error(info);
+        match write_report(&report_dir, &error) {
+            Ok(path) => eprintln!("An unexpected error occurred; report saved to {}", path.display()),
+            Err(_) => eprintln!("An unexpected error occurred; report could not be saved"),
+        }
+    }));
+}
+
+/// Build the [`DualContextError`] a caught panic is converted into: a fixed,
+/// generic public lie, and the sanitized message/location/backtrace as the
+/// sensitive payload.
+#[cfg(feature = "panic_reports")]
+fn build_report_error(info: &PanicInfo<'_>) -> DualContextError {
+    let message = panic_payload_message(info);
+    let location = info
+        .location()
+        .map_or_else(|| "<unknown location>".to_string(), |l| l.to_string());
+    let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+
+    let details = format!(
+        "panic at {}: {}\n\n{}",
+        sanitized!(location),
+        sanitized!(message),
+        sanitized!(backtrace),
+    );
+
+    DualContextError::with_lie_and_sensitive(
+        "An unexpected error occurred",
+        details,
+        OperationCategory::System,
+    )
+}
+
+/// Extract the panic payload's message, for the common `panic!("...")`/
+/// `panic!("{}", ...)` cases that box a `&str` or `String`. Any other
+/// payload type (e.g. [`panic_with_context`]'s typed [`DualContextError`])
+/// falls back to a placeholder rather than guessing at its `Debug` form.
+#[cfg(feature = "panic_reports")]
+fn panic_payload_message(info: &PanicInfo<'_>) -> String {
+    if let Some(s) = info.payload().downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = info.payload().downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
+
+/// Write `error`'s sensitive payload to a new, uniquely-named file under
+/// `report_dir` (created if missing), returning the path written.
+#[cfg(feature = "panic_reports")]
+fn write_report(report_dir: &Path, error: &DualContextError) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(report_dir)?;
+    let path = report_dir.join(format!("panic-{}.report", TraceId::generate()));
+    let access = SocAccess::acquire();
+    let sensitive = error.internal().expose_sensitive(&access).unwrap_or("<no details captured>");
+    fs::write(&path, sensitive)?;
+    Ok(path)
+}
+
+/// Read back a crash report written by [`install_report_hook`], gated by
+/// the same [`SocAccess`] token [`crate::models::InternalContext::expose_sensitive`]
+/// requires.
+///
+/// # Feature Gate
+///
+/// Behind the `panic_reports` cargo feature.
+///
+/// # Errors
+///
+/// Propagates any [`std::io::Error`] from reading `path`.
+#[cfg(feature = "panic_reports")]
+pub fn read_report(path: impl AsRef<Path>, _access: &SocAccess) -> std::io::Result<String> {
+    fs::read_to_string(path)
+}
+
+#[cfg(test)]
+#[cfg(not(feature = "no_std"))]
+mod tests {
+    use super::*;
+    use crate::OperationCategory;
+    use std::sync::{Arc, Mutex};
+
+    /// Serializes tests that install a process-global panic hook, same
+    /// rationale as `drop_bomb.rs`'s `DROP_BOMB_TEST_LOCK`.
+    static PANIC_HOOK_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn hook_redacts_dual_context_error_and_chains_for_everything_else() {
+        let _serialize = PANIC_HOOK_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let captured: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let captured_clone = captured.clone();
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info: &PanicInfo<'_>| {
+            if let Some(redacted) = redacted_message(info.payload()) {
+                captured_clone.lock().unwrap_or_else(|e| e.into_inner()).push(redacted);
+                return;
+            }
+            previous(info);
+        }));
+
+        let error = DualContextError::with_lie_and_sensitive(
+            "Not found",
+            "leaked token abc123",
+            OperationCategory::IO,
+        );
+        let external = format!("{} ({})", error.external_message(), error.external_category());
+        let result = panic::catch_unwind(move || panic_with_context(error));
+        assert!(result.is_err());
+
+        panic::set_hook(Box::new(|_| {}));
+
+        let messages = captured.lock().unwrap_or_else(|e| e.into_inner());
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0], external);
+        assert!(!messages[0].contains("leaked token"));
+    }
+
+    #[test]
+    fn catch_unwind_redacted_returns_only_the_external_message() {
+        let _serialize = PANIC_HOOK_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let error = DualContextError::with_lie_and_sensitive(
+            "Not found",
+            "leaked token abc123",
+            OperationCategory::IO,
+        );
+        let external = format!("{} ({})", error.external_message(), error.external_category());
+
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = catch_unwind_redacted(move || panic_with_context(error));
+        panic::set_hook(previous);
+
+        match result {
+            Err(Ok(message)) => {
+                assert_eq!(message, external);
+                assert!(!message.contains("leaked token"));
+            }
+            _ => panic!("expected Err(Ok(message))"),
+        }
+    }
+
+    #[test]
+    fn catch_unwind_redacted_passes_through_unrelated_panics() {
+        let _serialize = PANIC_HOOK_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = catch_unwind_redacted(|| -> () { panic!("unrelated failure") });
+        panic::set_hook(previous);
+
+        match result {
+            Err(Err(payload)) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                assert_eq!(message, "unrelated failure");
+            }
+            _ => panic!("expected Err(Err(payload))"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "panic_reports")]
+    fn report_hook_writes_a_sanitized_report_and_prints_only_the_path() {
+        let _serialize = PANIC_HOOK_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let report_dir = std::env::temp_dir().join(format!("palisade-panic-reports-{}", TraceId::generate()));
+
+        let previous = panic::take_hook();
+        install_report_hook(report_dir.clone());
+        let result = panic::catch_unwind(|| panic!("leaked token abc123\x1b[31m"));
+        panic::set_hook(previous);
+        assert!(result.is_err());
+
+        let entries: Vec<_> = fs::read_dir(&report_dir)
+            .expect("report dir was created")
+            .filter_map(Result::ok)
+            .collect();
+        assert_eq!(entries.len(), 1);
+
+        let access = SocAccess::acquire();
+        let contents = read_report(entries[0].path(), &access).expect("report file is readable");
+        assert!(contents.contains("leaked token abc123"));
+        assert!(!contents.contains('\x1b'));
+
+        fs::remove_dir_all(&report_dir).ok();
+    }
+
+    #[test]
+    #[cfg(feature = "panic_reports")]
+    fn report_hook_falls_back_to_a_placeholder_for_non_string_payloads() {
+        let _serialize = PANIC_HOOK_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let report_dir = std::env::temp_dir().join(format!("palisade-panic-reports-{}", TraceId::generate()));
+
+        let previous = panic::take_hook();
+        install_report_hook(report_dir.clone());
+        let result = panic::catch_unwind(|| {
+            panic_with_context(DualContextError::with_lie_and_sensitive(
+                "Not found",
+                "leaked token abc123",
+                OperationCategory::IO,
+            ))
+        });
+        panic::set_hook(previous);
+        assert!(result.is_err());
+
+        let entries: Vec<_> = fs::read_dir(&report_dir)
+            .expect("report dir was created")
+            .filter_map(Result::ok)
+            .collect();
+        assert_eq!(entries.len(), 1);
+
+        let access = SocAccess::acquire();
+        let contents = read_report(entries[0].path(), &access).expect("report file is readable");
+        assert!(contents.contains("<non-string panic payload>"));
+
+        fs::remove_dir_all(&report_dir).ok();
+    }
+}
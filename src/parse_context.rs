@@ -0,0 +1,225 @@
+//! Sanitized input-span context for parse errors.
+//!
+//! # Purpose
+//!
+//! Parsers routinely want to tell an external caller "your input failed to
+//! parse here" without ever echoing the input itself - the full input often
+//! carries untrusted (and sometimes sensitive) data the public error message
+//! must not reproduce verbatim, the same "don't leak the field" concern
+//! [`crate::sanitized`] addresses for a single value, applied here to a whole
+//! document. [`InputSpan`] names the byte range a parse failed at;
+//! [`render_excerpt`] turns `(input, span)` into a short, sanitized,
+//! caret-annotated rendering safe to put in a public message, while
+//! [`crate::parse_err`] keeps the untouched original in the sensitive
+//! context only.
+//!
+//! # Safety Invariants
+//!
+//! - The rendered excerpt never shows more than [`EXCERPT_WIDTH`] characters
+//!   of `input`, regardless of how long `input` actually is.
+//! - A `span` that falls outside `input`'s bounds (or straddles a multibyte
+//!   character) is clamped to the nearest valid char boundary rather than
+//!   panicking or slicing mid-character.
+//! - Control characters inside the excerpt are escaped (`\n`, `\t`, or
+//!   `\u{XXXX}`), so the rendering can't smuggle a terminal escape or corrupt
+//!   a log line the way an unsanitized excerpt could.
+
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// A byte offset and length inside some input a parse failure should point at.
+///
+/// # Copy Semantics
+///
+/// Plain compile-time-shaped coordinates, no owned or sensitive data - same
+/// reasoning as [`crate::codes::SourceSpan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputSpan {
+    /// Byte offset of the span's start within the original input.
+    pub offset: usize,
+    /// Byte length of the span. `0` renders as a single caret at `offset`.
+    pub len: usize,
+}
+
+impl InputSpan {
+    /// A span covering `len` bytes starting at `offset`.
+    #[inline]
+    pub const fn new(offset: usize, len: usize) -> Self {
+        Self { offset, len }
+    }
+
+    /// A zero-width span pointing at a single byte offset.
+    #[inline]
+    pub const fn point(offset: usize) -> Self {
+        Self { offset, len: 0 }
+    }
+}
+
+/// Maximum width, in characters, of the excerpt line [`render_excerpt`]
+/// produces - deliberately small, since this text is meant for a one-line
+/// public error message rather than a full source dump.
+pub const EXCERPT_WIDTH: usize = 64;
+
+/// Render a compact, sanitized excerpt of `input` centered on `span`, with a
+/// caret line underneath pointing at the failure, e.g.:
+///
+/// ```text
+/// {"user": "ok", "age": bad}
+///                       ^~~
+/// ```
+///
+/// Never echoes more than [`EXCERPT_WIDTH`] characters of `input` and never
+/// splits a multibyte character - see the module-level `# Safety Invariants`.
+/// The full, untruncated `input` belongs in the sensitive context only; see
+/// [`crate::parse_err`].
+pub fn render_excerpt(input: &str, span: InputSpan) -> String {
+    let span_start = clamp_to_boundary(input, span.offset, false);
+    let span_end = clamp_to_boundary(input, span.offset.saturating_add(span.len), true).max(span_start);
+
+    let chars: Vec<(usize, char)> = input.char_indices().collect();
+    let total = chars.len();
+    let span_start_idx = chars.iter().position(|&(b, _)| b >= span_start).unwrap_or(total);
+    let span_end_idx = chars.iter().position(|&(b, _)| b >= span_end).unwrap_or(total);
+
+    let span_width = span_end_idx.saturating_sub(span_start_idx).max(1);
+    let half_slack = EXCERPT_WIDTH.saturating_sub(span_width) / 2;
+    let window_start_idx = span_start_idx.saturating_sub(half_slack);
+    let window_end_idx = (window_start_idx + EXCERPT_WIDTH).min(total);
+    let window_start_idx = window_end_idx.saturating_sub(EXCERPT_WIDTH).min(window_start_idx);
+
+    let leading_ellipsis = window_start_idx > 0;
+    let trailing_ellipsis = window_end_idx < total;
+
+    let mut line = String::new();
+    if leading_ellipsis {
+        line.push_str("...");
+    }
+
+    let mut caret_lead = if leading_ellipsis { 3 } else { 0 };
+    let mut caret_width = 0usize;
+
+    for (i, &(_, c)) in chars.iter().enumerate().take(window_end_idx).skip(window_start_idx) {
+        let rendered = escape_char(c);
+        let width = rendered.chars().count();
+        line.push_str(&rendered);
+
+        if i < span_start_idx {
+            caret_lead += width;
+        } else if i < span_end_idx {
+            caret_width += width;
+        }
+    }
+
+    if trailing_ellipsis {
+        line.push_str("...");
+    }
+
+    let mut out = String::with_capacity(line.len() * 2);
+    out.push_str(&line);
+    out.push('\n');
+    for _ in 0..caret_lead {
+        out.push(' ');
+    }
+    for _ in 0..caret_width.max(1) {
+        out.push('^');
+    }
+    out
+}
+
+/// Move `offset` onto the nearest valid `char` boundary of `input`, rounding
+/// down (`round_up = false`) or up (`round_up = true`), and clamp it to
+/// `input`'s length first.
+fn clamp_to_boundary(input: &str, offset: usize, round_up: bool) -> usize {
+    let mut o = offset.min(input.len());
+    if round_up {
+        while o < input.len() && !input.is_char_boundary(o) {
+            o += 1;
+        }
+    } else {
+        while o > 0 && !input.is_char_boundary(o) {
+            o -= 1;
+        }
+    }
+    o
+}
+
+/// Escape a single character for safe inclusion in a rendered excerpt -
+/// mirrors [`crate::convenience::sanitized`]'s per-character escaping so the
+/// two sanitization passes stay visually consistent.
+fn escape_char(c: char) -> String {
+    match c {
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        c if c.is_control() => format!("\\u{{{:04x}}}", c as u32),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_short_input_in_full_with_a_caret_under_the_span() {
+        let input = r#"{"age": bad}"#;
+        let rendered = render_excerpt(input, InputSpan::new(8, 3));
+
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next().unwrap(), input);
+        assert_eq!(lines.next().unwrap(), "        ^^^");
+    }
+
+    #[test]
+    fn never_exceeds_the_excerpt_width_for_long_input() {
+        let input = "x".repeat(10_000);
+        let rendered = render_excerpt(&input, InputSpan::new(5_000, 1));
+
+        let first_line = rendered.lines().next().unwrap();
+        assert!(first_line.chars().count() <= EXCERPT_WIDTH + 6);
+        assert!(first_line.starts_with("..."));
+        assert!(first_line.ends_with("..."));
+    }
+
+    #[test]
+    fn clamps_a_span_past_the_end_of_input() {
+        let input = "short";
+        let rendered = render_excerpt(input, InputSpan::new(1000, 10));
+
+        assert!(rendered.starts_with(input));
+    }
+
+    #[test]
+    fn never_splits_a_multibyte_character() {
+        let input = "héllo wörld";
+        // Byte offset 1 lands inside "é" (a 2-byte UTF-8 sequence); a naive
+        // `input[1..]` slice would panic, but `render_excerpt` clamps to the
+        // nearest boundary instead.
+        let rendered = render_excerpt(input, InputSpan::new(1, 1));
+
+        let first_line = rendered.lines().next().unwrap();
+        assert_eq!(first_line, input);
+    }
+
+    #[test]
+    fn escapes_control_characters_in_the_excerpt() {
+        let input = "line1\nline2";
+        let rendered = render_excerpt(input, InputSpan::new(5, 1));
+
+        let first_line = rendered.lines().next().unwrap();
+        assert!(first_line.contains("\\n"));
+    }
+
+    #[test]
+    fn zero_width_span_renders_a_single_caret() {
+        let input = "abcdef";
+        let rendered = render_excerpt(input, InputSpan::point(3));
+
+        let caret_line = rendered.lines().nth(1).unwrap();
+        assert_eq!(caret_line.trim_start().len(), 1);
+    }
+}
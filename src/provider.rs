@@ -0,0 +1,112 @@
+//! Type-based context extraction, modeled on the standard library's
+//! `Error::provide` / `Request` mechanism.
+//!
+//! # Why Not `std::error::Request`
+//!
+//! The real thing is still gated behind the unstable
+//! `error_generic_member_access` feature, so this module is a small,
+//! stable reimplementation of the same idea: a type-erased, single-shot
+//! output slot tagged by [`TypeId`], filled in by
+//! [`crate::AgentError::provide`] and read back out by
+//! [`crate::AgentError::request_ref`] / [`crate::AgentError::request_value`].
+//!
+//! # Why Not `std::any::Any`
+//!
+//! `Any` requires the erased type to be `'static`, which rules out storing
+//! an `Option<&'a T>` output slot directly behind it - the `Option` itself
+//! borrows `'a`, so it isn't `'static` even when `T` is. [`ContextRequest`]
+//! instead erases the slot behind a raw pointer tagged with `T`'s
+//! [`TypeId`], the same technique `Any::downcast_mut` uses internally. Only
+//! [`ContextRequest::for_ref`]/[`ContextRequest::for_value`] ever construct
+//! one, and they always set the tag and the pointer together, so the two
+//! downcast sites in [`ContextRequest::provide_ref`]/`provide_value` can
+//! trust the tag.
+//!
+//! # Security
+//!
+//! A type is reachable through this mechanism only if
+//! [`crate::AgentError::provide`] explicitly hands it over - there is no
+//! generic "dump everything" path, and every output is bounded by the
+//! `'a` lifetime of the [`crate::AgentError`] being queried, so nothing
+//! here widens what [`crate::AgentError::with_internal_log`] already
+//! exposes to a caller holding `&AgentError`.
+
+use core::any::TypeId;
+use core::marker::PhantomData;
+
+/// A single type-based lookup in flight against an [`crate::AgentError`]'s
+/// [`crate::AgentError::provide`] implementation.
+///
+/// Built by [`crate::AgentError::request_ref`]/[`crate::AgentError::request_value`]
+/// for one concrete `T` at a time, then handed to `provide`, which calls
+/// [`Self::provide_ref`]/[`Self::provide_value`] for every type it knows
+/// how to offer. At most one of those calls can actually fill the slot -
+/// the first match wins, matching `std::error::Request`'s first-provided
+/// semantics.
+pub struct ContextRequest<'a> {
+    type_id: TypeId,
+    by_ref: bool,
+    // SAFETY invariant: `slot` points to a live `Option<&'a T>` (when
+    // `by_ref`) or `Option<T>` (otherwise) for the `T` whose `TypeId` is
+    // `type_id` - true because `for_ref`/`for_value` are the only
+    // constructors and each sets `type_id`, `by_ref`, and `slot` together
+    // from the same `T`. The pointee outlives `self`: it is a local
+    // variable in the caller's stack frame, borrowed for the duration of
+    // the `provide` call.
+    slot: *mut (),
+    _marker: PhantomData<&'a mut ()>,
+}
+
+impl<'a> ContextRequest<'a> {
+    /// Build a request for `&'a T`, writing into `slot`.
+    pub(crate) fn for_ref<T: 'static>(slot: &mut Option<&'a T>) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            by_ref: true,
+            slot: (slot as *mut Option<&'a T>).cast(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Build a request for an owned `T`, writing into `slot`.
+    pub(crate) fn for_value<T: 'static>(slot: &mut Option<T>) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            by_ref: false,
+            slot: (slot as *mut Option<T>).cast(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Offer `value` by reference. No-ops unless this request is asking
+    /// for `&T` and doesn't already have an answer.
+    pub fn provide_ref<T: 'static>(&mut self, value: &'a T) -> &mut Self {
+        if self.by_ref && self.type_id == TypeId::of::<T>() {
+            // SAFETY: the `by_ref && type_id == TypeId::of::<T>()` check
+            // proves this request was built by `for_ref::<T>`, so `slot`
+            // really points to a live `Option<&'a T>`.
+            let slot = unsafe { &mut *self.slot.cast::<Option<&'a T>>() };
+            if slot.is_none() {
+                *slot = Some(value);
+            }
+        }
+        self
+    }
+
+    /// Offer an owned value, computed lazily so callers that don't match
+    /// this request's type don't pay for building one. No-ops unless this
+    /// request is asking for an owned `T` and doesn't already have an
+    /// answer.
+    pub fn provide_value<T: 'static>(&mut self, value: impl FnOnce() -> T) -> &mut Self {
+        if !self.by_ref && self.type_id == TypeId::of::<T>() {
+            // SAFETY: the `!self.by_ref && type_id == TypeId::of::<T>()`
+            // check proves this request was built by `for_value::<T>`, so
+            // `slot` really points to a live `Option<T>`.
+            let slot = unsafe { &mut *self.slot.cast::<Option<T>>() };
+            if slot.is_none() {
+                *slot = Some(value());
+            }
+        }
+        self
+    }
+}
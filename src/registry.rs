@@ -0,0 +1,749 @@
+//! Queryable registry over every defined error code, for `--explain`-style lookup.
+//!
+//! Operators staring at a log line that only shows `E-CFG-100` (the whole point
+//! of the namespace/obfuscation scheme in [`crate::codes`] is that they see
+//! nothing richer) need a way to go from that string back to "what does this
+//! mean and how bad is it" without grepping source. This module is that map.
+//!
+//! The free [`explain`] function does not hand-maintain prose per code -
+//! with ~300 codes and growing, that duplication would rot the moment
+//! someone added a code without updating a second file. Instead it indexes
+//! the const [`ErrorCode`] values that already exist in [`crate::definitions`]
+//! and derives a description from their namespace, category, and impact
+//! metadata, which are the only facts guaranteed to stay in sync with the
+//! taxonomy.
+//!
+//! [`Registry`] builds on top of that: it's the same `E-XXX-YYY` lookup, plus
+//! an optional hand-written long-form explanation a code's
+//! `define_error_codes!` entry can opt into (see `src/convenience.rs`), and
+//! grouping of the full taxonomy by namespace for operator tooling.
+//!
+//! [`explain_code`] is the `--explain`-proper entry point: given exactly the
+//! string an operator has in hand from a log line, it undoes
+//! [`crate::obfuscation`] before indexing the registry, so the caller never
+//! needs to know the code they're holding was obfuscated in the first place.
+//!
+//! # Example
+//!
+//! ```rust
+//! use palisade_errors::registry::{self, Registry};
+//!
+//! let entry = registry::lookup("E-DCP-232").expect("known code");
+//! assert_eq!(entry.code(), 232);
+//! println!("{}", registry::explain(entry));
+//!
+//! let registry = Registry::global();
+//! if let Some(remediation) = registry.explain(entry) {
+//!     println!("{}", remediation);
+//! }
+//! ```
+
+use crate::{definitions, namespaces, ErrorCode, ErrorImpact, ErrorNamespace};
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// Every error code defined in [`crate::definitions`], in declaration order.
+///
+/// Built by hand rather than via macro collection: `define_error_codes!` is
+/// invoked once per namespace block, and a single module can't have two
+/// `const` items share a name, so there's no way for the macro itself to
+/// accumulate a single cross-block array without threading an extra
+/// identifier through every call site. Listing the constants here is the
+/// straightforward alternative and gets re-validated by
+/// [`all_codes_are_unique`] below.
+pub static ALL_CODES: &[&ErrorCode] = &[
+    &definitions::CORE_INIT_FAILED,
+    &definitions::CORE_SHUTDOWN_FAILED,
+    &definitions::CORE_PANIC_RECOVERY,
+    &definitions::CORE_INVALID_STATE,
+    &definitions::CORE_MEMORY_ALLOC_FAILED,
+    &definitions::CORE_THREAD_SPAWN_FAILED,
+    &definitions::CORE_MUTEX_LOCK_FAILED,
+    &definitions::CORE_SIGNAL_HANDLER_FAILED,
+    &definitions::CORE_MODULE_LOAD_FAILED,
+    &definitions::CORE_DEPENDENCY_MISSING,
+    &definitions::CORE_VERSION_CHECK_FAILED,
+    &definitions::CORE_RESOURCE_INIT_FAILED,
+    &definitions::CORE_EVENT_LOOP_FAILED,
+    &definitions::CORE_CONFIG_BOOTSTRAP_FAILED,
+    &definitions::CORE_DATABASE_CONNECT_FAILED,
+    &definitions::CORE_CACHE_INIT_FAILED,
+    &definitions::CORE_QUEUE_OVERFLOW,
+    &definitions::CORE_TIMER_SETUP_FAILED,
+    &definitions::CORE_HOOK_REGISTRATION_FAILED,
+    &definitions::CORE_PLUGIN_INIT_FAILED,
+    &definitions::CORE_STATE_TRANSITION_FAILED,
+    &definitions::CORE_HEALTH_CHECK_FAILED,
+    &definitions::CORE_BACKUP_FAILED,
+    &definitions::CORE_RESTORE_FAILED,
+    &definitions::CORE_MIGRATION_FAILED,
+    &definitions::CORE_LICENSE_VALIDATION_FAILED,
+    &definitions::CORE_AUTH_INIT_FAILED,
+    &definitions::CORE_CRYPTO_SETUP_FAILED,
+    &definitions::CORE_NETWORK_INIT_FAILED,
+    &definitions::CORE_API_SERVER_START_FAILED,
+    &definitions::CFG_PARSE_FAILED,
+    &definitions::CFG_VALIDATION_FAILED,
+    &definitions::CFG_MISSING_REQUIRED,
+    &definitions::CFG_INVALID_VALUE,
+    &definitions::CFG_INVALID_FORMAT,
+    &definitions::CFG_PERMISSION_DENIED,
+    &definitions::CFG_VERSION_MISMATCH,
+    &definitions::CFG_SECURITY_VIOLATION,
+    &definitions::CFG_LOAD_FAILED,
+    &definitions::CFG_SAVE_FAILED,
+    &definitions::CFG_ENV_VAR_MISSING,
+    &definitions::CFG_TYPE_MISMATCH,
+    &definitions::CFG_DUPLICATE_KEY,
+    &definitions::CFG_SCHEMA_VALIDATION_FAILED,
+    &definitions::CFG_MERGE_CONFLICT,
+    &definitions::CFG_REMOTE_FETCH_FAILED,
+    &definitions::CFG_LOCAL_STORE_FAILED,
+    &definitions::CFG_ENCRYPTION_FAILED,
+    &definitions::CFG_DECRYPTION_FAILED,
+    &definitions::CFG_KEY_NOT_FOUND,
+    &definitions::CFG_INVALID_PATH,
+    &definitions::CFG_CONVERSION_FAILED,
+    &definitions::CFG_DEFAULTS_LOAD_FAILED,
+    &definitions::CFG_OVERRIDE_FAILED,
+    &definitions::CFG_WATCHER_INIT_FAILED,
+    &definitions::CFG_RELOAD_FAILED,
+    &definitions::CFG_BACKUP_FAILED,
+    &definitions::CFG_ROLLBACK_FAILED,
+    &definitions::CFG_TEMPLATE_RENDER_FAILED,
+    &definitions::CFG_VARIABLE_RESOLUTION_FAILED,
+    &definitions::CFG_SECRETS_MANAGER_FAILED,
+    &definitions::CFG_PROFILE_SWITCH_FAILED,
+    &definitions::DCP_DEPLOY_FAILED,
+    &definitions::DCP_ARTIFACT_CREATE,
+    &definitions::DCP_ARTIFACT_WRITE,
+    &definitions::DCP_CLEANUP_FAILED,
+    &definitions::DCP_TAG_GENERATION,
+    &definitions::DCP_TRIGGER_FAILED,
+    &definitions::DCP_SIMULATION_FAILED,
+    &definitions::DCP_BAIT_DEPLOY_FAILED,
+    &definitions::DCP_HONEYPOT_INIT_FAILED,
+    &definitions::DCP_FAKE_DATA_GENERATION_FAILED,
+    &definitions::DCP_REDIRECT_SETUP_FAILED,
+    &definitions::DCP_MIMICRY_FAILED,
+    &definitions::DCP_TARPIT_ENGAGE_FAILED,
+    &definitions::DCP_DECOY_LAUNCH_FAILED,
+    &definitions::DCP_SHADOW_SYSTEM_FAILED,
+    &definitions::DCP_FINGERPRINT_MISMATCH,
+    &definitions::DCP_BEHAVIOR_MODEL_LOAD_FAILED,
+    &definitions::DCP_INTRUSION_SIM_FAILED,
+    &definitions::DCP_COUNTERMEASURE_FAILED,
+    &definitions::DCP_ARTIFACT_EXPIRATION,
+    &definitions::DCP_DEPLOYMENT_ROLLBACK_FAILED,
+    &definitions::DCP_RESOURCE_ALLOCATION_FAILED,
+    &definitions::DCP_TEMPLATE_LOAD_FAILED,
+    &definitions::DCP_VALIDATION_CHECK_FAILED,
+    &definitions::DCP_INTEGRITY_CHECK_FAILED,
+    &definitions::DCP_NETWORK_SIM_FAILED,
+    &definitions::DCP_ACCESS_CONTROL_FAILED,
+    &definitions::DCP_ENCRYPTED_ARTIFACT_FAILED,
+    &definitions::DCP_DECRYPT_ARTIFACT_FAILED,
+    &definitions::DCP_DYNAMIC_GENERATION_FAILED,
+    &definitions::DCP_PERSISTENCE_FAILED,
+    &definitions::DCP_NARRATIVE_DESYNC,
+    &definitions::DCP_NARRATIVE_BREAK,
+    &definitions::DCP_BELIEVABILITY_LOW,
+    &definitions::DCP_ADVERSARY_ADAPTATION,
+    &definitions::DCP_STATE_VIOLATION,
+    &definitions::DCP_TEMPORAL_INCONSISTENCY,
+    &definitions::DCP_CAUSALITY_BREACH,
+    &definitions::DCP_SUSPICIOUS_ACTIVITY_DETECTED,
+    &definitions::DCP_ISOLATION_FAILED,
+    &definitions::TEL_INIT_FAILED,
+    &definitions::TEL_WATCH_FAILED,
+    &definitions::TEL_EVENT_LOST,
+    &definitions::TEL_CHANNEL_CLOSED,
+    &definitions::TEL_MONITOR_CRASH,
+    &definitions::TEL_METRIC_COLLECTION_FAILED,
+    &definitions::TEL_EXPORT_FAILED,
+    &definitions::TEL_AGGREGATION_FAILED,
+    &definitions::TEL_TRACE_SPAN_FAILED,
+    &definitions::TEL_REMOTE_SEND_FAILED,
+    &definitions::TEL_BUFFER_OVERFLOW,
+    &definitions::TEL_INVALID_METRIC,
+    &definitions::TEL_SAMPLING_FAILED,
+    &definitions::TEL_PROPAGATION_FAILED,
+    &definitions::TEL_ENDPOINT_UNREACHABLE,
+    &definitions::TEL_AUTH_FAILED,
+    &definitions::TEL_COMPRESSION_FAILED,
+    &definitions::TEL_DECOMPRESSION_FAILED,
+    &definitions::TEL_FILTER_APPLY_FAILED,
+    &definitions::TEL_ALERT_TRIGGER_FAILED,
+    &definitions::TEL_DASHBOARD_UPDATE_FAILED,
+    &definitions::TEL_LOG_INGEST_FAILED,
+    &definitions::TEL_QUERY_FAILED,
+    &definitions::TEL_RETENTION_POLICY_FAILED,
+    &definitions::TEL_BACKPRESSURE,
+    &definitions::TEL_INSTRUMENTATION_FAILED,
+    &definitions::TEL_BATCH_PROCESS_FAILED,
+    &definitions::TEL_SERIALIZATION_FAILED,
+    &definitions::TEL_DESERIALIZATION_FAILED,
+    &definitions::TEL_RESOURCE_MONITOR_FAILED,
+    &definitions::TEL_HEARTBEAT_FAILED,
+    &definitions::TEL_EVASION_DETECTED,
+    &definitions::TEL_SENSOR_BYPASS,
+    &definitions::TEL_OBSERVABILITY_GAP,
+    &definitions::COR_RULE_EVAL_FAILED,
+    &definitions::COR_BUFFER_OVERFLOW,
+    &definitions::COR_INVALID_SCORE,
+    &definitions::COR_WINDOW_EXPIRED,
+    &definitions::COR_INVALID_ARTIFACT,
+    &definitions::COR_PATTERN_MATCH_FAILED,
+    &definitions::COR_DATA_INGEST_FAILED,
+    &definitions::COR_AGGREGATION_FAILED,
+    &definitions::COR_THRESHOLD_BREACH,
+    &definitions::COR_FALSE_POSITIVE,
+    &definitions::COR_EVENT_MERGE_FAILED,
+    &definitions::COR_CONTEXT_LOAD_FAILED,
+    &definitions::COR_ANOMALY_DETECT_FAILED,
+    &definitions::COR_MODEL_TRAIN_FAILED,
+    &definitions::COR_INFERENCE_FAILED,
+    &definitions::COR_DATA_NORMALIZATION_FAILED,
+    &definitions::COR_FEATURE_EXTRACTION_FAILED,
+    &definitions::COR_CLUSTERING_FAILED,
+    &definitions::COR_OUTLIER_DETECTION_FAILED,
+    &definitions::COR_TIME_SERIES_ANALYSIS_FAILED,
+    &definitions::COR_GRAPH_BUILD_FAILED,
+    &definitions::COR_PATH_ANALYSIS_FAILED,
+    &definitions::COR_RULE_UPDATE_FAILED,
+    &definitions::COR_VALIDATION_FAILED,
+    &definitions::COR_EXPORT_FAILED,
+    &definitions::COR_IMPORT_FAILED,
+    &definitions::COR_QUERY_EXEC_FAILED,
+    &definitions::COR_INDEX_BUILD_FAILED,
+    &definitions::COR_SEARCH_FAILED,
+    &definitions::COR_ENRICHMENT_FAILED,
+    &definitions::COR_DEDUPLICATION_FAILED,
+    &definitions::COR_CONFIDENCE_DEGRADATION,
+    &definitions::COR_MODEL_DRIFT,
+    &definitions::COR_HYPOTHESIS_INVALIDATED,
+    &definitions::COR_ACTOR_CONFLICT,
+    &definitions::RSP_EXEC_FAILED,
+    &definitions::RSP_TIMEOUT,
+    &definitions::RSP_INVALID_ACTION,
+    &definitions::RSP_RATE_LIMITED,
+    &definitions::RSP_HANDLER_NOT_FOUND,
+    &definitions::RSP_SERIALIZATION_FAILED,
+    &definitions::RSP_DESERIALIZATION_FAILED,
+    &definitions::RSP_VALIDATION_FAILED,
+    &definitions::RSP_AUTH_FAILED,
+    &definitions::RSP_PERMISSION_DENIED,
+    &definitions::RSP_RESOURCE_NOT_FOUND,
+    &definitions::RSP_CONFLICT,
+    &definitions::RSP_INTERNAL_ERROR,
+    &definitions::RSP_BAD_REQUEST,
+    &definitions::RSP_UNAVAILABLE,
+    &definitions::RSP_GATEWAY_TIMEOUT,
+    &definitions::RSP_TOO_MANY_REQUESTS,
+    &definitions::RSP_PAYLOAD_TOO_LARGE,
+    &definitions::RSP_UNSUPPORTED_MEDIA,
+    &definitions::RSP_METHOD_NOT_ALLOWED,
+    &definitions::RSP_NOT_ACCEPTABLE,
+    &definitions::RSP_PROXY_AUTH_REQUIRED,
+    &definitions::RSP_REQUEST_TIMEOUT,
+    &definitions::RSP_PRECONDITION_FAILED,
+    &definitions::RSP_EXPECTATION_FAILED,
+    &definitions::RSP_MISDIRECTED_REQUEST,
+    &definitions::RSP_UNPROCESSABLE_ENTITY,
+    &definitions::RSP_LOCKED,
+    &definitions::RSP_FAILED_DEPENDENCY,
+    &definitions::RSP_UPGRADE_REQUIRED,
+    &definitions::RSP_PRECONDITION_REQUIRED,
+    &definitions::RSP_TIMING_ANOMALY,
+    &definitions::RSP_ENTROPY_LOW,
+    &definitions::RSP_BEHAVIORAL_INCONSISTENCY,
+    &definitions::LOG_WRITE_FAILED,
+    &definitions::LOG_ROTATE_FAILED,
+    &definitions::LOG_BUFFER_FULL,
+    &definitions::LOG_SERIALIZATION,
+    &definitions::LOG_INIT_FAILED,
+    &definitions::LOG_FLUSH_FAILED,
+    &definitions::LOG_LEVEL_INVALID,
+    &definitions::LOG_FILTER_APPLY_FAILED,
+    &definitions::LOG_APPENDER_FAILED,
+    &definitions::LOG_REMOTE_SEND_FAILED,
+    &definitions::LOG_COMPRESSION_FAILED,
+    &definitions::LOG_ENCRYPTION_FAILED,
+    &definitions::LOG_ARCHIVE_FAILED,
+    &definitions::LOG_PURGE_FAILED,
+    &definitions::LOG_INDEX_FAILED,
+    &definitions::LOG_SEARCH_FAILED,
+    &definitions::LOG_PARSE_FAILED,
+    &definitions::LOG_FORMAT_INVALID,
+    &definitions::LOG_TIMESTAMP_FAILED,
+    &definitions::LOG_METADATA_MISSING,
+    &definitions::LOG_ROLLOVER_FAILED,
+    &definitions::LOG_BACKUP_FAILED,
+    &definitions::LOG_RESTORE_FAILED,
+    &definitions::LOG_QUEUE_OVERFLOW,
+    &definitions::LOG_ASYNC_SEND_FAILED,
+    &definitions::LOG_SYNC_FAILED,
+    &definitions::LOG_HANDLER_CRASH,
+    &definitions::LOG_CONFIG_LOAD_FAILED,
+    &definitions::LOG_RELOAD_FAILED,
+    &definitions::LOG_EXPORT_FAILED,
+    &definitions::LOG_IMPORT_FAILED,
+    &definitions::PLT_UNSUPPORTED,
+    &definitions::PLT_SYSCALL_FAILED,
+    &definitions::PLT_PERMISSION_DENIED,
+    &definitions::PLT_RESOURCE_EXHAUSTED,
+    &definitions::PLT_OS_VERSION_MISMATCH,
+    &definitions::PLT_HARDWARE_UNSUPPORTED,
+    &definitions::PLT_DRIVER_LOAD_FAILED,
+    &definitions::PLT_API_CALL_FAILED,
+    &definitions::PLT_ENV_DETECT_FAILED,
+    &definitions::PLT_VIRTUALIZATION_FAILED,
+    &definitions::PLT_CONTAINER_INIT_FAILED,
+    &definitions::PLT_KERNEL_MODULE_FAILED,
+    &definitions::PLT_FILESYSTEM_MOUNT_FAILED,
+    &definitions::PLT_NETWORK_INTERFACE_FAILED,
+    &definitions::PLT_PROCESS_SPAWN_FAILED,
+    &definitions::PLT_SIGNAL_SEND_FAILED,
+    &definitions::PLT_MEMORY_MAP_FAILED,
+    &definitions::PLT_THREAD_AFFINITY_FAILED,
+    &definitions::PLT_POWER_MANAGEMENT_FAILED,
+    &definitions::PLT_BOOTSTRAP_FAILED,
+    &definitions::PLT_SHUTDOWN_HOOK_FAILED,
+    &definitions::PLT_COMPATIBILITY_CHECK_FAILED,
+    &definitions::PLT_LIBRARY_LOAD_FAILED,
+    &definitions::PLT_SYMBOL_RESOLVE_FAILED,
+    &definitions::PLT_SECURITY_POLICY_FAILED,
+    &definitions::PLT_AUDIT_HOOK_FAILED,
+    &definitions::PLT_RESOURCE_LIMIT_REACHED,
+    &definitions::PLT_CLOCK_SYNC_FAILED,
+    &definitions::PLT_DEVICE_ACCESS_FAILED,
+    &definitions::PLT_FIRMWARE_UPDATE_FAILED,
+    &definitions::PLT_BIOS_CONFIG_FAILED,
+    &definitions::IO_READ_FAILED,
+    &definitions::IO_WRITE_FAILED,
+    &definitions::IO_NETWORK_ERROR,
+    &definitions::IO_TIMEOUT,
+    &definitions::IO_NOT_FOUND,
+    &definitions::IO_METADATA_FAILED,
+    &definitions::IO_OPEN_FAILED,
+    &definitions::IO_CLOSE_FAILED,
+    &definitions::IO_SEEK_FAILED,
+    &definitions::IO_FLUSH_FAILED,
+    &definitions::IO_PERMISSION_DENIED,
+    &definitions::IO_INTERRUPTED,
+    &definitions::IO_WOULD_BLOCK,
+    &definitions::IO_INVALID_INPUT,
+    &definitions::IO_BROKEN_PIPE,
+    &definitions::IO_CONNECTION_RESET,
+    &definitions::IO_CONNECTION_REFUSED,
+    &definitions::IO_NOT_CONNECTED,
+    &definitions::IO_ADDR_IN_USE,
+    &definitions::IO_ADDR_NOT_AVAILABLE,
+    &definitions::IO_NETWORK_DOWN,
+    &definitions::IO_NETWORK_UNREACHABLE,
+    &definitions::IO_HOST_UNREACHABLE,
+    &definitions::IO_ALREADY_EXISTS,
+    &definitions::IO_IS_DIRECTORY,
+    &definitions::IO_NOT_DIRECTORY,
+    &definitions::IO_DIRECTORY_NOT_EMPTY,
+    &definitions::IO_READ_ONLY_FS,
+    &definitions::IO_FS_QUOTA_EXCEEDED,
+    &definitions::IO_STALE_NFS_HANDLE,
+    &definitions::IO_REMOTE_IO,
+];
+
+/// Look up a defined error code by its rendered `E-{NAMESPACE}-{code}` form.
+///
+/// This is the operator-facing entry point: paste in exactly what showed up
+/// in a log line or an external-facing error message and get back the
+/// registry entry, or `None` if the string doesn't match anything currently
+/// defined (e.g. it was retired, or came from a different build).
+pub fn lookup(display: &str) -> Option<&'static ErrorCode> {
+    ALL_CODES
+        .iter()
+        .copied()
+        .find(|code| code.to_string() == display)
+}
+
+/// Look up a defined error code by namespace string and numeric code.
+///
+/// Useful when the two fields arrived separately (e.g. parsed out of a
+/// structured log record) rather than as one rendered string.
+pub fn lookup_by_parts(namespace: &str, code: u16) -> Option<&'static ErrorCode> {
+    ALL_CODES
+        .iter()
+        .copied()
+        .find(|c| c.namespace().as_str() == namespace && c.code() == code)
+}
+
+/// Produce an `--explain`-style long-form description of an error code.
+///
+/// Unlike the one-line `Display` impl on [`ErrorCode`] (`E-CFG-100`), this
+/// renders the category and severity classification in full sentences,
+/// suitable for an operator runbook or a `palisade explain E-CFG-100` CLI
+/// verb. The text is derived entirely from the code's own metadata, so it
+/// can never drift out of sync with the taxonomy the way hand-written
+/// per-code prose would.
+pub fn explain(code: &ErrorCode) -> String {
+    format!(
+        "{}: a {} operation in the {} namespace.\nImpact: {} ({}/1000) - {}.",
+        code,
+        code.category().display_name(),
+        code.namespace().as_str(),
+        impact_summary(code.impact_level()),
+        code.impact().value(),
+        impact_detail(code.impact_level()),
+    )
+}
+
+/// Short, human-readable label for an [`ErrorImpact`] level.
+fn impact_summary(impact: ErrorImpact) -> &'static str {
+    impact.label()
+}
+
+/// Look up the hand-written long-form explanation for a code in its
+/// external, rendered `E-{NAMESPACE}-{code}` form - the same string an
+/// operator would copy out of a log line or an `AgentError`'s `Display`.
+///
+/// Unlike [`lookup`], this accounts for [`crate::obfuscation`]: the numeric
+/// code in that rendered string is, by default, the session-obfuscated one,
+/// not the raw code [`ALL_CODES`] is indexed by. This first recovers the raw
+/// code under the *current* session salt via
+/// [`crate::obfuscation::deobfuscate_code`], then indexes the registry with
+/// that - so it works on exactly the string an operator actually has, rather
+/// than requiring them to already know it needs deobfuscating first.
+///
+/// Returns `None` if the namespace token is unrecognized, the string is
+/// malformed, or deobfuscation recovers a code this build has no entry for
+/// (e.g. it was retired, or the session salt doesn't match the one the code
+/// was obfuscated under) - the same "nothing to say" case [`Registry::explain`]
+/// returns for a code with no hand-written explanation.
+///
+/// Unavailable under `no_std`, like [`crate::obfuscation`] itself, which
+/// this is built entirely on top of.
+#[cfg(not(feature = "no_std"))]
+pub fn explain_code(display: &str) -> Option<&'static str> {
+    let namespace_token = display.strip_prefix("E-")?.rsplit_once('-')?.0;
+
+    // Borrow a category/impact pair already known to be valid for this
+    // namespace, purely so the as-seen code can be reconstructed as an
+    // `ErrorCode` to hand to `deobfuscate_code` - the obfuscation math only
+    // touches the numeric offset, so which registered entry they come from
+    // doesn't matter, only that `ErrorCode::checked_new`'s namespace/category
+    // validation accepts them.
+    let (category, impact) = ALL_CODES
+        .iter()
+        .find(|code| code.namespace().as_str() == namespace_token)
+        .map(|code| (code.category(), code.impact()))?;
+
+    let as_seen = ErrorCode::parse_with_policy(display, category, impact).ok()?;
+    let raw = crate::obfuscation::deobfuscate_code(&as_seen);
+
+    lookup_by_parts(raw.namespace().as_str(), raw.code())?.explain()
+}
+
+/// A queryable view over [`ALL_CODES`], modeled on rustc's `--explain`
+/// registry (`rustc --explain E0382`, `register_diagnostics!`).
+///
+/// Where the free [`explain`] function above *synthesizes* its description
+/// purely from a code's namespace/category/impact metadata, [`Self::explain`]
+/// instead surfaces the hand-written, long-form remediation text a
+/// `define_error_codes!` entry opted into via its optional third tuple
+/// element (see `src/convenience.rs`). Most codes don't have one yet, so
+/// `None` is the common case, not an error.
+///
+/// Backed entirely by the `&'static` [`ALL_CODES`] table and the `&'static`
+/// [`namespaces::ALL`] list, so building or querying a `Registry` never
+/// allocates on the lookup path - only [`Self::grouped_by_namespace`], whose
+/// output shape inherently needs an owned collection, allocates.
+pub struct Registry {
+    codes: &'static [&'static ErrorCode],
+}
+
+impl Registry {
+    /// The registry over every code currently defined in the crate.
+    pub const fn global() -> Self {
+        Self { codes: ALL_CODES }
+    }
+
+    /// Look up a defined error code by its rendered `E-{NAMESPACE}-{code}` form.
+    ///
+    /// Equivalent to the free [`lookup`] function; provided as a method so
+    /// callers that already hold a `Registry` (e.g. to also call
+    /// [`Self::explain`]) don't need to reach for the module-level function.
+    pub fn lookup(&self, display: &str) -> Option<&'static ErrorCode> {
+        self.codes.iter().copied().find(|code| code.to_string() == display)
+    }
+
+    /// The hand-written long-form explanation attached to `code`, if any.
+    ///
+    /// Returns `None` for codes whose `define_error_codes!` entry omitted
+    /// the optional explanation element - not an error, just nothing to say
+    /// beyond what [`explain`] already synthesizes from metadata.
+    pub fn explain(&self, code: &ErrorCode) -> Option<&'static str> {
+        code.explanation()
+    }
+
+    /// The long-form description for `code`, modeled on rustc's
+    /// `rustc_errors::registry::Registry::try_find_description`.
+    ///
+    /// Equivalent to [`Self::explain`], provided under rustc's own name for
+    /// callers porting lookup logic from that registry.
+    pub fn try_find_description(&self, code: &ErrorCode) -> Option<&'static str> {
+        self.explain(code)
+    }
+
+    /// Recover the canonical code an observed, obfuscated one was produced
+    /// from, given the session it was obfuscated under, and return the
+    /// matching registered definition.
+    ///
+    /// `session_key` mirrors rustc's vocabulary for "whatever secret keyed
+    /// this observation", but this crate's obfuscation layer is keyed by a
+    /// 32-bit per-session salt (see [`crate::obfuscation::init_session_salt`]),
+    /// not a 64-bit key - only the low 32 bits of `session_key` are used.
+    ///
+    /// Returns `None` if the salt was wrong (recovers a code nothing in this
+    /// registry defines) rather than panicking or returning a bogus entry -
+    /// an operator pasting in the wrong session's key should see "not
+    /// found", not a confidently wrong answer.
+    ///
+    /// Unavailable under `no_std`, like [`crate::obfuscation`] itself, which
+    /// this is built entirely on top of.
+    #[cfg(not(feature = "no_std"))]
+    pub fn deobfuscate(&self, observed: &ErrorCode, session_key: u64) -> Option<ErrorCode> {
+        let recovered = crate::obfuscation::deobfuscate_code_with_salt(observed, session_key as u32);
+        let registered = self
+            .codes
+            .iter()
+            .copied()
+            .find(|code| code.namespace().as_str() == recovered.namespace().as_str() && code.code() == recovered.code())?;
+        Some(ErrorCode::const_new(
+            registered.namespace(),
+            registered.code(),
+            registered.category(),
+            registered.impact(),
+        ))
+    }
+
+    /// Every registered namespace paired with the codes defined within it,
+    /// in [`namespaces::ALL`] order.
+    ///
+    /// Useful for an offline tool walking the full taxonomy (e.g. to render
+    /// an operator runbook section per subsystem) rather than looking up one
+    /// code at a time.
+    pub fn grouped_by_namespace(&self) -> Vec<(&'static ErrorNamespace, Vec<&'static ErrorCode>)> {
+        namespaces::ALL
+            .iter()
+            .map(|namespace| {
+                let codes = self
+                    .codes
+                    .iter()
+                    .copied()
+                    .filter(|code| core::ptr::eq(code.namespace(), *namespace))
+                    .collect();
+                (*namespace, codes)
+            })
+            .collect()
+    }
+}
+
+/// Alias for [`Registry`], for callers reaching for rustc's naming
+/// (`rustc --explain`'s registry is `rustc_errors::registry::Registry`, but
+/// this crate's own error domain makes "error registry" the more natural
+/// name to search for).
+///
+/// # Why Not Distributed Registration
+///
+/// A `--explain`-style registry can in principle be populated by distributed
+/// registration - `inventory::submit!` or `linkme::distributed_slice` calls
+/// emitted from inside `define_error_codes!`, so every invocation
+/// contributes its entries without a second hand-maintained list. This crate
+/// deliberately doesn't: `#[macro_export]` macros run in the *caller's*
+/// crate, so either approach would force every consumer crate that invokes
+/// `define_error_codes!` to also depend on `inventory`/`linkme` themselves,
+/// not just this crate - a much bigger commitment than the tradeoff it
+/// solves. [`ALL_CODES`]'s doc comment already covers why the hand-written
+/// list is the chosen alternative; [`all_codes_are_unique`] keeps it honest.
+pub type ErrorRegistry = Registry;
+
+/// One-sentence operator guidance per [`ErrorImpact`] level, mirrored from
+/// the escalation bands documented in `src/definitions.rs`.
+fn impact_detail(impact: ErrorImpact) -> &'static str {
+    match impact {
+        ErrorImpact::Noise => "internal noise, no action required",
+        ErrorImpact::Flaw => "minor discrepancy, safe to ignore in aggregate",
+        ErrorImpact::Jitter => "performance issue, monitor for trend",
+        ErrorImpact::Glitch => "functional error, investigate if recurring",
+        ErrorImpact::Suspicion => "logic inconsistency, may expose the trap to an attacker",
+        ErrorImpact::Leak => "information disclosure risk, analyst review recommended",
+        ErrorImpact::Collapse => "total failure of emulation, requires persona contingency",
+        ErrorImpact::Escalation => "unintended access granted, treat as an incident",
+        ErrorImpact::Breach => "sandbox breakout risk, page on-call immediately",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_finds_known_code() {
+        let found = lookup("E-DCP-232").expect("DCP_NARRATIVE_BREAK should be registered");
+        assert_eq!(found.code(), 232);
+        assert_eq!(found.namespace().as_str(), "DCP");
+    }
+
+    #[test]
+    fn lookup_returns_none_for_unknown_code() {
+        assert!(lookup("E-ZZZ-999").is_none());
+    }
+
+    #[test]
+    fn lookup_by_parts_matches_lookup_by_display() {
+        let by_display = lookup("E-CORE-005").unwrap();
+        let by_parts = lookup_by_parts("CORE", 5).unwrap();
+        assert_eq!(by_display.code(), by_parts.code());
+    }
+
+    #[test]
+    fn explain_mentions_namespace_and_category() {
+        let code = lookup("E-DCP-232").unwrap();
+        let text = explain(code);
+        assert!(text.contains("DCP"));
+        assert!(text.contains("Deception"));
+        assert!(text.contains("Breach") || text.contains("Collapse"));
+    }
+
+    #[test]
+    fn all_codes_are_unique() {
+        for (i, a) in ALL_CODES.iter().enumerate() {
+            for b in &ALL_CODES[i + 1..] {
+                assert_ne!(a.to_string(), b.to_string(), "duplicate registry entry");
+            }
+        }
+    }
+
+    #[test]
+    fn registry_lookup_matches_free_function() {
+        let registry = Registry::global();
+        let found = registry.lookup("E-DCP-232").expect("known code");
+        assert_eq!(found.code(), 232);
+    }
+
+    #[test]
+    fn registry_explain_returns_hand_written_text_when_present() {
+        let registry = Registry::global();
+        let code = registry.lookup("E-DCP-232").unwrap();
+        let text = registry.explain(code).expect("DCP_NARRATIVE_BREAK has an explanation");
+        assert!(text.contains("Hard-reset"));
+    }
+
+    #[test]
+    fn registry_explain_is_none_for_codes_without_one() {
+        let registry = Registry::global();
+        let code = registry.lookup("E-CORE-001").unwrap();
+        assert!(registry.explain(code).is_none());
+    }
+
+    #[test]
+    fn registry_explain_covers_common_config_and_io_codes() {
+        let registry = Registry::global();
+
+        let parse_failed = registry.lookup("E-CFG-100").unwrap();
+        assert!(registry.explain(parse_failed).unwrap().contains("parsed"));
+
+        let read_failed = registry.lookup("E-IO-800").unwrap();
+        assert!(registry.explain(read_failed).unwrap().contains("retry"));
+    }
+
+    #[test]
+    fn grouped_by_namespace_covers_every_code_exactly_once() {
+        let registry = Registry::global();
+        let grouped = registry.grouped_by_namespace();
+
+        assert_eq!(grouped.len(), namespaces::ALL.len());
+        let total: usize = grouped.iter().map(|(_, codes)| codes.len()).sum();
+        assert_eq!(total, ALL_CODES.len());
+
+        let dcp_group = grouped
+            .iter()
+            .find(|(ns, _)| ns.as_str() == "DCP")
+            .expect("DCP namespace present");
+        assert!(dcp_group.1.iter().any(|code| code.code() == 232));
+    }
+
+    #[test]
+    fn try_find_description_matches_explain() {
+        let registry = Registry::global();
+        let code = registry.lookup("E-DCP-232").unwrap();
+        assert_eq!(registry.try_find_description(code), registry.explain(code));
+    }
+
+    #[test]
+    fn registry_deobfuscate_recovers_the_canonical_definition() {
+        crate::obfuscation::init_session_salt(29);
+        let canonical = lookup("E-DCP-232").unwrap();
+        let obfuscated = crate::obfuscation::obfuscate_code(canonical);
+
+        let registry = Registry::global();
+        let recovered = registry.deobfuscate(&obfuscated, 29).expect("salt matches");
+        assert_eq!(recovered.code(), canonical.code());
+        assert_eq!(recovered.namespace().as_str(), canonical.namespace().as_str());
+
+        crate::obfuscation::clear_session_salt();
+    }
+
+    #[test]
+    fn registry_deobfuscate_returns_none_when_recovered_code_is_not_registered() {
+        // Salt 0 is the "no obfuscation" sentinel, so the recovered code is
+        // exactly the observed one - code 199 is inside the CFG namespace's
+        // range (100-199) but isn't one any `CFG_*` definition actually uses.
+        let unregistered = ErrorCode::const_new(
+            &namespaces::CFG,
+            199,
+            crate::OperationCategory::Configuration,
+            crate::ImpactScore::new(100),
+        );
+
+        let registry = Registry::global();
+        assert!(registry.deobfuscate(&unregistered, 0).is_none());
+    }
+
+    #[test]
+    fn error_registry_alias_behaves_like_registry() {
+        let registry: ErrorRegistry = Registry::global();
+        let entry = registry.lookup("E-DCP-232").expect("known code");
+        assert_eq!(registry.explain(entry), Registry::global().explain(entry));
+    }
+
+    #[test]
+    fn explain_code_finds_the_hand_written_text_without_obfuscation() {
+        crate::obfuscation::clear_session_salt();
+        assert_eq!(explain_code("E-DCP-232"), lookup("E-DCP-232").unwrap().explain());
+    }
+
+    #[test]
+    fn explain_code_recovers_the_raw_code_through_obfuscation() {
+        crate::obfuscation::init_session_salt(11);
+        let canonical = lookup("E-DCP-232").unwrap();
+        let obfuscated = crate::obfuscation::obfuscate_code(canonical).to_string();
+
+        assert_eq!(explain_code(&obfuscated), canonical.explain());
+
+        crate::obfuscation::clear_session_salt();
+    }
+
+    #[test]
+    fn explain_code_returns_none_for_malformed_input() {
+        assert!(explain_code("not-a-code").is_none());
+    }
+
+    #[test]
+    fn explain_code_returns_none_for_unknown_namespace() {
+        assert!(explain_code("E-ZZZ-001").is_none());
+    }
+}
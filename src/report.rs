@@ -0,0 +1,206 @@
+//! Source-chain report renderer, modeled on the standard library's
+//! [`std::error::Report`].
+//!
+//! # Why Two Outputs
+//!
+//! [`AgentError`]'s `source` chain can hold arbitrary boxed errors -
+//! `io::Error`s with filesystem paths, third-party client errors with
+//! request bodies baked into their `Display`, anything. None of that is
+//! safe to forward to an untrusted caller, but it's exactly what a forensic
+//! investigator wants. [`Report`] keeps the crate's usual external/internal
+//! split instead of picking one: [`fmt::Display`] collapses the whole chain
+//! down to the outermost error's category and retry hint (the same detail
+//! level as [`AgentError`]'s own `Display`), while [`Report::write_internal`]
+//! walks every link and writes it in full, the same "short-lived borrow"
+//! shape as [`crate::logging::InternalLog`] so the detail can't outlive the
+//! error it was read from.
+//!
+//! # Redaction
+//!
+//! A link we can downcast back to [`AgentError`] is known-safe to render in
+//! full internally - it already went through this crate's own sanitized
+//! construction path. A link we can't downcast is an opaque `dyn Error` of
+//! unknown provenance, and [`Report::redact_sources`] defaults to treating
+//! it as sensitive: its message is withheld even from the internal chain.
+//! Callers who've audited what can end up in their `source` chain can opt
+//! out with `.redact_sources(false)`.
+
+use crate::AgentError;
+use std::error::Error;
+use std::fmt;
+
+/// A renderer over an [`AgentError`]'s full `source()` chain.
+///
+/// Built by [`AgentError::report`]. Borrows the error it was built from, so
+/// it cannot outlive it - the same lifetime discipline as
+/// [`crate::logging::InternalLog`].
+pub struct Report<'a> {
+    head: &'a AgentError,
+    current: Option<&'a (dyn Error + 'static)>,
+    pretty: bool,
+    redact_sources: bool,
+}
+
+impl<'a> Report<'a> {
+    pub(crate) fn new(error: &'a AgentError) -> Self {
+        Self {
+            head: error,
+            current: Some(error as &(dyn Error + 'static)),
+            pretty: false,
+            redact_sources: true,
+        }
+    }
+
+    /// Lay [`Self::write_internal`]'s output out one link per line instead
+    /// of joining them with `" -> "`. Off by default.
+    #[inline]
+    #[must_use]
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Whether a `source` link that isn't an [`AgentError`] is withheld
+    /// (the default) or rendered with its own `Display` in
+    /// [`Self::write_internal`]'s output.
+    #[inline]
+    #[must_use]
+    pub fn redact_sources(mut self, redact_sources: bool) -> Self {
+        self.redact_sources = redact_sources;
+        self
+    }
+
+    /// Walk the chain from `self.head` without disturbing the cursor this
+    /// type's own [`Iterator`] impl advances.
+    fn chain(&self) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+        let mut current = Some(self.head as &(dyn Error + 'static));
+        std::iter::from_fn(move || {
+            let link = current.take()?;
+            current = link.source();
+            Some(link)
+        })
+    }
+
+    /// Write the full chain - every link's full detail, not just the
+    /// outermost error's category - for a trusted internal sink.
+    ///
+    /// Links that downcast to [`AgentError`] are rendered via
+    /// [`AgentError::internal_log`]; any other link is withheld unless
+    /// [`Self::redact_sources`] was set to `false`.
+    pub fn write_internal(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        for (i, link) in self.chain().enumerate() {
+            if i > 0 {
+                write!(f, "{}", if self.pretty { "\n" } else { " -> " })?;
+            }
+            match link.downcast_ref::<AgentError>() {
+                Some(agent_error) => agent_error.internal_log().write_to(f)?,
+                None if self.redact_sources => write!(f, "<redacted source>")?,
+                None => write!(f, "{link}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for Report<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let link = self.current.take()?;
+        self.current = link.source();
+        Some(link)
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    /// The external view: walks the whole chain, one "Caused by:" line per
+    /// link after the first, but at the same detail level as
+    /// [`AgentError`]'s own `Display` throughout - a nested `io::Error`'s
+    /// message (or any other link's) never surfaces here, whether it's the
+    /// head or three links deep. A link that downcasts to [`AgentError`]
+    /// contributes its obfuscated code, category, and retry hint; any other
+    /// link contributes nothing but a generic placeholder, since an opaque
+    /// `dyn Error`'s `Display` is exactly the kind of message this crate's
+    /// trust boundary exists to keep out of external output.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, link) in self.chain().enumerate() {
+            if i > 0 {
+                write!(f, "\nCaused by: ")?;
+            }
+            match link.downcast_ref::<AgentError>() {
+                Some(agent_error) => {
+                    let permanence = crate::locale::resolved_permanence_word(agent_error.is_retryable());
+                    let category = crate::locale::resolved_category_name(agent_error.category());
+                    write!(f, "{} operation failed [{}] ({})", category, permanence, agent_error.code())?;
+                }
+                None => write!(f, "<redacted source>")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definitions;
+    use std::io;
+
+    #[test]
+    fn display_never_reveals_the_details_message() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "password=hunter2");
+        assert!(!err.report().to_string().contains("hunter2"));
+    }
+
+    #[test]
+    fn write_internal_includes_the_details_message() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "password=hunter2");
+        let mut out = String::new();
+        err.report().write_internal(&mut out).unwrap();
+        assert!(out.contains("password=hunter2"));
+    }
+
+    #[test]
+    fn single_error_report_has_exactly_one_link() {
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax");
+        assert_eq!(err.report().count(), 1);
+    }
+
+    #[test]
+    fn display_walks_the_whole_chain_with_caused_by_lines() {
+        let inner = AgentError::io_operation(definitions::IO_READ_FAILED, "read", "disk error");
+        let outer = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax")
+            .caused_by(inner);
+
+        let rendered = outer.report().to_string();
+        assert_eq!(rendered.matches("Caused by:").count(), 1);
+        assert!(rendered.contains("E-CFG-100"));
+        assert!(rendered.contains("E-IO-800"));
+    }
+
+    #[test]
+    fn display_never_leaks_a_non_agent_error_sources_message_at_any_depth() {
+        let outer = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "bad syntax")
+            .caused_by(io::Error::new(io::ErrorKind::Other, "/etc/shadow"));
+
+        let rendered = outer.report().to_string();
+        assert!(!rendered.contains("/etc/shadow"));
+        assert!(rendered.contains("Caused by:"));
+    }
+
+    #[test]
+    fn display_never_leaks_an_inner_agent_errors_details_through_several_wraps() {
+        let innermost =
+            AgentError::io_operation(definitions::IO_READ_FAILED, "read", "password=hunter2");
+        let middle = AgentError::config(definitions::CFG_PARSE_FAILED, "boot", "token=abc123")
+            .caused_by(innermost);
+        let outer = AgentError::config(definitions::CFG_PARSE_FAILED, "reboot", "secret=xyz")
+            .caused_by(middle);
+
+        let rendered = outer.report().to_string();
+        assert!(!rendered.contains("hunter2"));
+        assert!(!rendered.contains("abc123"));
+        assert!(!rendered.contains("xyz"));
+        assert_eq!(rendered.matches("Caused by:").count(), 2);
+    }
+}
@@ -0,0 +1,210 @@
+//! Retry-with-resanitization helper for transient palisade errors.
+//!
+//! # Purpose
+//!
+//! A naive retry loop that just calls `op()` again on failure tends to
+//! accumulate every failed attempt's [`DualContextError`] somewhere for
+//! later reporting - which means whatever secret a credential-retry flow's
+//! first attempt captured in its sensitive context is still sitting in
+//! memory by the time the third attempt runs. [`retry`] instead reads each
+//! attempt's sensitive payload once, copies only that text into its own
+//! attempt log, and drops the original error immediately - its
+//! [`InternalContext`](crate::models::InternalContext)'s zeroize-on-drop
+//! then scrubs whatever it was holding. Nothing from a prior attempt
+//! survives into the next one except the single line already copied out.
+//!
+//! # Design
+//!
+//! [`crate::definitions::Retryability`] (surfaced via
+//! [`DualContextError::retryability`]) decides whether a failed attempt is
+//! worth retrying at all, and how long to wait before the next one -
+//! [`Retryability::Permanent`] stops the loop immediately regardless of
+//! `max_attempts`, [`Retryability::RetryAfter`] overrides `backoff` for
+//! that one wait, and [`Retryability::Transient`] uses `backoff` as given.
+//! Once retries are exhausted (or a permanent failure ends them early), the
+//! per-attempt internal messages collapse into a single final error: one
+//! clean public line (the last attempt's) and a sensitive context listing
+//! every attempt in order, rather than a public message that grows with
+//! each retry.
+//!
+//! # std-only
+//!
+//! Needs `std::thread::sleep` for backoff, so this module is unavailable
+//! under `no_std` - same carve-out as [`crate::AgentError`] and
+//! [`crate::models::Capability`].
+
+use crate::definitions::Retryability;
+use crate::{DualContextError, OperationCategory, SocAccess};
+use std::thread;
+use std::time::Duration;
+
+/// Retry `op` up to `max_attempts` times, waiting `backoff` between
+/// attempts (or the duration named by [`Retryability::RetryAfter`], when an
+/// attempt's error specifies one), and stopping early the moment an
+/// attempt's error classifies as [`Retryability::Permanent`].
+///
+/// On success, returns `op`'s value directly. On exhausted or permanent
+/// failure, returns a single [`DualContextError`] whose public message is
+/// the last attempt's and whose sensitive context lists every attempt's
+/// internal message, newline-separated, in order.
+///
+/// # Panics
+///
+/// Panics if `max_attempts` is `0` - there is no sensible "zero attempts"
+/// result to return.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::retry::retry;
+/// use palisade_errors::{DualContextError, OperationCategory};
+/// use std::time::Duration;
+///
+/// let mut calls = 0;
+/// let result = retry(3, Duration::from_millis(1), || {
+///     calls += 1;
+///     if calls < 2 {
+///         Err(DualContextError::with_lie_and_sensitive(
+///             "Service unavailable",
+///             "connection reset",
+///             OperationCategory::IO,
+///         ))
+///     } else {
+///         Ok(42)
+///     }
+/// });
+///
+/// assert_eq!(result.unwrap(), 42);
+/// assert_eq!(calls, 2);
+/// ```
+pub fn retry<T>(
+    max_attempts: u32,
+    backoff: Duration,
+    mut op: impl FnMut() -> Result<T, DualContextError>,
+) -> Result<T, DualContextError> {
+    assert!(max_attempts >= 1, "max_attempts must be at least 1");
+
+    let mut attempt_log: Vec<String> = Vec::new();
+    let mut attempt = 0u32;
+
+    loop {
+        attempt += 1;
+        let error = match op() {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        let retryability = error.retryability();
+        let last_public = error.external_message().to_string();
+        let last_category: OperationCategory = error.category();
+
+        {
+            let access = SocAccess::acquire();
+            let sensitive = error
+                .internal()
+                .expose_sensitive(&access)
+                .unwrap_or("<no details captured>");
+            attempt_log.push(format!("attempt {attempt}: {sensitive}"));
+        }
+        // `error` drops here; its sensitive payload zeroizes with it, so
+        // only the line just copied into `attempt_log` carries forward.
+        drop(error);
+
+        let permanent = matches!(retryability, Retryability::Permanent);
+        if permanent || attempt >= max_attempts {
+            return Err(DualContextError::with_lie_and_sensitive(
+                last_public,
+                attempt_log.join("\n"),
+                last_category,
+            ));
+        }
+
+        match retryability {
+            Retryability::RetryAfter(wait) => thread::sleep(wait),
+            _ => thread::sleep(backoff),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn succeeds_without_retrying_when_the_first_attempt_works() {
+        let mut calls = 0;
+        let result = retry(3, Duration::from_millis(1), || {
+            calls += 1;
+            Ok::<_, DualContextError>(7)
+        });
+
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn retries_a_transient_failure_until_it_succeeds() {
+        let mut calls = 0;
+        let result = retry(5, Duration::from_millis(1), || {
+            calls += 1;
+            if calls < 3 {
+                Err(DualContextError::with_lie_and_sensitive(
+                    "Service unavailable",
+                    format!("transient failure #{calls}"),
+                    OperationCategory::IO,
+                ))
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn stops_immediately_on_a_permanent_failure() {
+        let mut calls = 0;
+        let result: Result<(), DualContextError> = retry(5, Duration::from_millis(1), || {
+            calls += 1;
+            Err(crate::config_err_sensitive!(
+                &crate::definitions::CFG_PERMISSION_DENIED,
+                "auth",
+                "Access denied",
+                "invalid credentials"
+            ))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn exhausts_max_attempts_and_collapses_the_attempt_history() {
+        let mut calls = 0;
+        let result: Result<(), DualContextError> = retry(3, Duration::from_millis(1), || {
+            calls += 1;
+            Err(DualContextError::with_lie_and_sensitive(
+                "Service unavailable",
+                format!("transient failure #{calls}"),
+                OperationCategory::IO,
+            ))
+        });
+
+        assert_eq!(calls, 3);
+        let err = result.unwrap_err();
+        assert_eq!(err.external_message(), "Service unavailable");
+
+        let access = SocAccess::acquire();
+        let sensitive = err.internal().expose_sensitive(&access).unwrap();
+        assert!(sensitive.contains("attempt 1: transient failure #1"));
+        assert!(sensitive.contains("attempt 2: transient failure #2"));
+        assert!(sensitive.contains("attempt 3: transient failure #3"));
+    }
+
+    #[test]
+    #[should_panic(expected = "max_attempts must be at least 1")]
+    fn panics_on_zero_max_attempts() {
+        let _ = retry(0, Duration::from_millis(1), || Ok::<_, DualContextError>(()));
+    }
+}
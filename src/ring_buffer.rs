@@ -40,12 +40,17 @@
 //! ```
 
 use crate::AgentError;
+use crate::intern::InternTable;
 use smallvec::SmallVec;
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::RwLockReadGuard;
 use std::sync::RwLockWriteGuard;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// A single forensic log entry with bounded size.
@@ -55,10 +60,29 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// get_recent() might be called thousands of times per second.
 #[derive(Clone, Debug)]
 pub struct ForensicEntry {
-    /// Unix timestamp of error creation
+    /// Unix timestamp this entry was first logged (`first_seen` for aggregated entries).
     pub timestamp: u64,
+    /// Unix timestamp this entry (or its dedup key) was last logged.
+    ///
+    /// Equal to `timestamp` for a fresh, non-aggregated entry; bumped on every
+    /// repeat hit in aggregating mode. See [`RingBufferLogger::new_aggregating`].
+    pub last_seen: u64,
+    /// Number of occurrences collapsed into this entry.
+    ///
+    /// Always `1` outside of aggregating mode, since every call to `log()`
+    /// consumes its own ring slot there.
+    pub count: u64,
     /// Error code (e.g., "E-CFG-100") - shared immutable string
     pub code: Arc<str>,
+    /// Pre-obfuscation error code, recovered via
+    /// [`crate::obfuscation::deobfuscate_code`] under the session salt
+    /// active at logging time - shared immutable string.
+    pub code_raw: Arc<str>,
+    /// The logged error's correlation ID (see [`crate::trace_id`]), rendered
+    /// in its hyphenated form - lets [`RingBufferLogger::get_filtered`]
+    /// match an entry back to the ID an operator quotes from a support
+    /// ticket's external message.
+    pub trace_id: Arc<str>,
     /// Operation that failed - shared immutable string
     pub operation: Arc<str>,
     /// Error details - shared immutable string
@@ -73,6 +97,25 @@ pub struct ForensicEntry {
     pub retryable: bool,
 }
 
+impl ForensicEntry {
+    /// Render this entry in the same rustc/cargo-style diagnostic form used by
+    /// `DualContextError::render_diagnostic()`, so a ring buffer dump reads
+    /// consistently with ad-hoc diagnostic output from the same session.
+    ///
+    /// # Output
+    ///
+    /// ```text
+    /// error[E-CFG-100]: op 'validate': invalid threshold value
+    /// ```
+    ///
+    /// `ForensicEntry` does not carry a captured `file!()`/`line!()` location
+    /// (it's built from `AgentError`, which predates that capture), so there is
+    /// no `  --> file:line:col` line here - only the code/operation/details.
+    pub fn render_diagnostic(&self) -> String {
+        format!("error[{}]: op '{}': {}", self.code, self.operation, self.details)
+    }
+}
+
 /// Fixed-size ring buffer with exact allocation (no growth).
 struct RingBuffer {
     /// Fixed-size array of entries (no Vec growth overhead)
@@ -97,8 +140,15 @@ impl RingBuffer {
         }
     }
 
-    fn push(&mut self, entry: ForensicEntry) -> Option<ForensicEntry> {
-        let evicted = self.entries[self.tail].replace(entry);
+    /// Write `entry` into the next free (or oldest, once full) slot.
+    ///
+    /// Returns the physical slot index the entry landed in alongside any
+    /// entry it evicted. The index is stable until that slot is overwritten
+    /// again, which lets aggregating mode remember "where" a dedup key lives
+    /// and mutate it in place instead of re-pushing.
+    fn push(&mut self, entry: ForensicEntry) -> (usize, Option<ForensicEntry>) {
+        let idx = self.tail;
+        let evicted = self.entries[idx].replace(entry);
         self.tail = (self.tail + 1) % self.entries.len();
 
         if self.len < self.entries.len() {
@@ -107,7 +157,12 @@ impl RingBuffer {
             self.head = (self.head + 1) % self.entries.len();
         }
 
-        evicted
+        (idx, evicted)
+    }
+
+    /// Mutable access to a slot by physical index, for in-place aggregation updates.
+    fn entry_at_mut(&mut self, idx: usize) -> Option<&mut ForensicEntry> {
+        self.entries.get_mut(idx).and_then(|slot| slot.as_mut())
     }
 
     #[inline]
@@ -132,6 +187,28 @@ impl RingBuffer {
         })
     }
 
+    /// The oldest entry still in the ring, if any.
+    fn front(&self) -> Option<&ForensicEntry> {
+        if self.len == 0 {
+            return None;
+        }
+        self.entries[self.head].as_ref()
+    }
+
+    /// Drop the oldest entry, advancing `head` the same way eviction does -
+    /// no reindexing of the entries behind it, since their physical slots
+    /// don't move.
+    fn pop_front(&mut self) -> Option<ForensicEntry> {
+        if self.len == 0 {
+            return None;
+        }
+        let idx = self.head;
+        let entry = self.entries[idx].take();
+        self.head = (self.head + 1) % self.entries.len();
+        self.len -= 1;
+        entry
+    }
+
     fn clear(&mut self) {
         for entry in self.entries.iter_mut() {
             *entry = None;
@@ -151,6 +228,29 @@ pub struct RingBufferLogger {
     max_entries: usize,
     max_entry_bytes: usize,
     eviction_count: Arc<AtomicU64>,
+    /// Maps a dedup key (hash of code + operation + source_ip) to the physical
+    /// ring slot currently holding its aggregated entry. `None` means
+    /// deduplication is disabled and every `log()` call allocates a fresh slot.
+    dedup_index: Option<Arc<RwLock<HashMap<u64, usize>>>>,
+    /// Optional drain sink installed by [`Self::with_drain`]. `None` until
+    /// then, so loggers that never opt in pay no channel overhead.
+    drain_sender: Arc<RwLock<Option<mpsc::SyncSender<ForensicEntry>>>>,
+    /// Entries dropped because the drain channel was full (or had no live
+    /// receiver) when `log()` tried to send them.
+    drain_dropped: Arc<AtomicU64>,
+    /// Shared interning table for operation/details text, so a repeated
+    /// string reuses one `Arc<str>` allocation instead of a fresh one per
+    /// entry. See [`crate::intern`].
+    intern: Arc<InternTable>,
+    /// Maximum age, in seconds, an entry may reach before [`Self::log`] and
+    /// [`Self::purge_expired`] drop it regardless of how much spare capacity
+    /// the ring has. `None` (the default) means retention is purely
+    /// count-based, as it always was before [`Self::with_max_age`] existed.
+    max_age_secs: Option<u64>,
+    /// Cold-tier hook installed by [`Self::with_archive_sink`]. `None` (the
+    /// default) means an evicted entry is simply gone, as it always was
+    /// before [`ArchiveSink`] existed.
+    archive_sink: Option<Arc<dyn ArchiveSink>>,
 }
 
 impl RingBufferLogger {
@@ -176,6 +276,87 @@ impl RingBufferLogger {
             max_entries: bounded_entries,
             max_entry_bytes,
             eviction_count: Arc::new(AtomicU64::new(0)),
+            dedup_index: None,
+            drain_sender: Arc::new(RwLock::new(None)),
+            drain_dropped: Arc::new(AtomicU64::new(0)),
+            intern: Arc::new(InternTable::new()),
+            max_age_secs: None,
+            archive_sink: None,
+        }
+    }
+
+    /// Install a cold-tier [`ArchiveSink`] that receives every entry the
+    /// ring evicts to make room for a new one, instead of letting it
+    /// disappear once `eviction_count` ticks up.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::ring_buffer::{ArchiveSink, RingBufferLogger};
+    /// # use palisade_errors::ring_buffer::ForensicEntry;
+    ///
+    /// struct CountingArchive(std::sync::atomic::AtomicUsize);
+    /// impl ArchiveSink for CountingArchive {
+    ///     fn archive(&self, _entry: &ForensicEntry) {
+    ///         self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    ///     }
+    /// }
+    ///
+    /// let logger = RingBufferLogger::new(2, 1024)
+    ///     .with_archive_sink(CountingArchive(std::sync::atomic::AtomicUsize::new(0)));
+    /// ```
+    pub fn with_archive_sink(mut self, sink: impl ArchiveSink + 'static) -> Self {
+        self.archive_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Opt into time-bounded retention alongside the existing count-based
+    /// one: entries older than `max_age_secs` are dropped from the head on
+    /// every [`Self::log`] call (opportunistically, not via a background
+    /// timer) and by an explicit [`Self::purge_expired`] call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::ring_buffer::RingBufferLogger;
+    ///
+    /// // Keep at most 10k entries, but never older than 15 minutes.
+    /// let logger = RingBufferLogger::new(10_000, 1024).with_max_age(15 * 60);
+    /// ```
+    pub fn with_max_age(mut self, max_age_secs: u64) -> Self {
+        self.max_age_secs = Some(max_age_secs);
+        self
+    }
+
+    /// Create a new ring buffer logger that collapses repeat hits into a
+    /// single aggregated entry instead of consuming a slot per occurrence.
+    ///
+    /// Entries sharing the same `(code, operation, source_ip)` key bump an
+    /// in-place `count` and `last_seen` on every repeat, the way rustc
+    /// collapses repeated identical diagnostics. A brute-force burst of
+    /// thousands of near-identical attempts then costs one ring slot instead
+    /// of evicting everything else in the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::ring_buffer::RingBufferLogger;
+    /// use palisade_errors::{AgentError, definitions};
+    ///
+    /// let logger = RingBufferLogger::new_aggregating(1000, 2048);
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+    ///
+    /// // 50 identical attempts collapse into a single entry with count == 50.
+    /// for _ in 0..50 {
+    ///     logger.log(&err, "192.168.1.100");
+    /// }
+    /// assert_eq!(logger.len(), 1);
+    /// assert_eq!(logger.get_recent(1)[0].count, 50);
+    /// ```
+    pub fn new_aggregating(max_entries: usize, max_entry_bytes: usize) -> Self {
+        Self {
+            dedup_index: Some(Arc::new(RwLock::new(HashMap::new()))),
+            ..Self::new(max_entries, max_entry_bytes)
         }
     }
 
@@ -212,13 +393,171 @@ impl RingBufferLogger {
     /// logger.log(&err, "192.168.1.100");
     /// ```
     pub fn log(&self, err: &AgentError, source_ip: &str) {
+        self.purge_expired();
+
         let entry = self.create_entry(err, source_ip);
+        self.send_to_drain(&entry);
+
+        if let Some(dedup_index) = &self.dedup_index {
+            self.log_aggregating(dedup_index, entry);
+            return;
+        }
 
         let mut buffer = self.write_buffer();
 
         // Evict oldest entry if buffer is full
-        if let Some(_evicted) = buffer.push(entry) {
+        let (_idx, evicted) = buffer.push(entry);
+        drop(buffer);
+
+        if let Some(evicted) = evicted {
+            self.eviction_count.fetch_add(1, Ordering::Relaxed);
+            self.archive_evicted(&evicted);
+        }
+    }
+
+    /// Hand an evicted entry to the configured [`ArchiveSink`] (see
+    /// [`Self::with_archive_sink`]), if any, before it's gone for good. A
+    /// no-op when no sink is configured, which is the default - archiving is
+    /// opt-in, since most deployments are fine losing an entry once it falls
+    /// out of the hot ring.
+    fn archive_evicted(&self, entry: &ForensicEntry) {
+        if let Some(sink) = &self.archive_sink {
+            sink.archive(entry);
+        }
+    }
+
+    /// Install a bounded drain channel, returning the receiving end.
+    ///
+    /// Every subsequent `log()` call (on this logger or any clone of it,
+    /// since the sink is shared state) performs a non-blocking `try_send` of
+    /// the entry it just recorded, in addition to the normal in-memory
+    /// insert. A consumer that drains the returned [`mpsc::Receiver`] - e.g.
+    /// batching entries off to durable storage - sees every logged entry
+    /// exactly once, even ones the ring buffer itself later evicts.
+    ///
+    /// Calling this again replaces the previous sink; the old receiver stops
+    /// getting new entries (existing ones already in its channel are still
+    /// readable).
+    ///
+    /// # Backpressure
+    ///
+    /// `try_send` never blocks the producer: if the channel is full, or the
+    /// receiver has been dropped, the entry is simply not forwarded and
+    /// [`Self::dropped_count`] is incremented. The in-memory ring insert
+    /// always happens regardless, so a slow or absent consumer never stalls
+    /// `log()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::ring_buffer::RingBufferLogger;
+    /// use palisade_errors::{AgentError, definitions};
+    ///
+    /// let logger = RingBufferLogger::new(100, 1024);
+    /// let receiver = logger.with_drain(16);
+    ///
+    /// let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details");
+    /// logger.log(&err, "192.168.1.100");
+    ///
+    /// let drained = receiver.recv().unwrap();
+    /// assert_eq!(drained.operation.as_ref(), "op");
+    /// ```
+    pub fn with_drain(&self, capacity: usize) -> mpsc::Receiver<ForensicEntry> {
+        let (sender, receiver) = mpsc::sync_channel(capacity.max(1));
+        let mut slot = match self.drain_sender.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *slot = Some(sender);
+        receiver
+    }
+
+    /// Number of entries dropped by the drain channel installed via
+    /// [`Self::with_drain`] because it was full (or had no live receiver) at
+    /// send time. Always `0` if `with_drain` was never called.
+    #[inline]
+    pub fn dropped_count(&self) -> u64 {
+        self.drain_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Insert an already-constructed entry directly into the ring, bypassing
+    /// dedup aggregation and the drain sink.
+    ///
+    /// Exists for [`crate::journal::PersistentRingBufferLogger`], which
+    /// reconstructs entries from a crash-recovered journal rather than a live
+    /// [`AgentError`] - there is no `AgentError` to hand `log()`, and replaying
+    /// already-logged history through the drain sink or the dedup index would
+    /// double-count it. Callers must insert in oldest-to-newest order, the
+    /// same order `log()` itself would have produced them in, so eviction
+    /// behaves identically to a logger that had been running the whole time.
+    pub(crate) fn replay_insert(&self, entry: ForensicEntry) {
+        let mut buffer = self.write_buffer();
+        let (_idx, evicted) = buffer.push(entry);
+        if evicted.is_some() {
+            self.eviction_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn send_to_drain(&self, entry: &ForensicEntry) {
+        let sender = match self.drain_sender.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(sender) = sender.as_ref() {
+            if sender.try_send(entry.clone()).is_err() {
+                self.drain_dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Fraction of interned operation/details strings that reused an
+    /// existing allocation instead of making a new one, in `[0.0, 1.0]`.
+    ///
+    /// A honeypot under a repeated brute-force pattern should see this climb
+    /// toward `1.0` as the same operation name and detail template recur; a
+    /// value near `0.0` means logged errors are mostly unique text and
+    /// interning isn't buying much.
+    #[inline]
+    pub fn dedup_ratio(&self) -> f64 {
+        self.intern.dedup_ratio()
+    }
+
+    /// Dedup-aware path used by [`Self::new_aggregating`] loggers.
+    ///
+    /// Looks up `entry`'s dedup key; on a hit, bumps `count`/`last_seen` on
+    /// the existing slot in place. On a miss (first occurrence, or the
+    /// slot the key used to point at has since been overwritten by FIFO
+    /// eviction) it allocates a fresh ring slot and records the new mapping.
+    fn log_aggregating(&self, dedup_index: &Arc<RwLock<HashMap<u64, usize>>>, entry: ForensicEntry) {
+        let key = dedup_key(&entry.code, &entry.operation, &entry.source_ip);
+
+        let mut buffer = self.write_buffer();
+        let mut index = match dedup_index.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if let Some(&idx) = index.get(&key) {
+            if let Some(existing) = buffer.entry_at_mut(idx) {
+                if existing.code == entry.code
+                    && existing.operation == entry.operation
+                    && existing.source_ip == entry.source_ip
+                {
+                    existing.count += 1;
+                    existing.last_seen = entry.timestamp;
+                    return;
+                }
+            }
+        }
+
+        let (idx, evicted) = buffer.push(entry);
+        index.insert(key, idx);
+        drop(index);
+        drop(buffer);
+
+        if let Some(evicted) = evicted {
             self.eviction_count.fetch_add(1, Ordering::Relaxed);
+            self.archive_evicted(&evicted);
         }
     }
 
@@ -284,13 +623,19 @@ impl RingBufferLogger {
                 source_ip
             };
 
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+
             ForensicEntry {
-                timestamp: SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .map_or(0, |d| d.as_secs()),
+                timestamp,
+                last_seen: timestamp,
+                count: 1,
                 code: Arc::from(log.code().to_string()),
-                operation: Arc::from(operation.as_ref()),
-                details: Arc::from(details.as_ref()),
+                code_raw: Arc::from(crate::obfuscation::deobfuscate_code(log.code()).to_string()),
+                trace_id: Arc::from(log.trace_id().to_string()),
+                operation: self.intern.intern(operation.as_ref()),
+                details: self.intern.intern(details.as_ref()),
                 source_ip: Arc::from(source_ip_str.as_ref()),
                 metadata,
                 size_bytes: size,
@@ -317,22 +662,40 @@ impl RingBufferLogger {
     /// ```
     pub fn get_recent(&self, count: usize) -> Vec<ForensicEntry> {
         let buffer = self.read_buffer();
-        buffer
-            .iter()
-            .rev()
-            .take(count)
-            .cloned() // Cheap: just Arc refcount increments
-            .collect()
+        let mut entries = self.ordered_entries(&buffer);
+        entries.truncate(count);
+        entries
     }
 
     /// Get all entries in reverse chronological order.
+    ///
+    /// For an aggregating logger this is "most recently touched first"
+    /// (sorted by `last_seen`) rather than ring insertion order, since a
+    /// long-lived aggregated entry's physical slot never moves.
     pub fn get_all(&self) -> Vec<ForensicEntry> {
         let buffer = self.read_buffer();
-        buffer.iter().rev().cloned().collect()
+        self.ordered_entries(&buffer)
+    }
+
+    /// Entries in display order: ring-reverse for a plain logger, or
+    /// sorted by `last_seen` descending for an aggregating one.
+    fn ordered_entries(&self, buffer: &RingBuffer) -> Vec<ForensicEntry> {
+        let mut entries: Vec<ForensicEntry> = buffer.iter().cloned().collect(); // Cheap: just Arc refcount increments
+        if self.dedup_index.is_some() {
+            entries.sort_unstable_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        } else {
+            entries.reverse();
+        }
+        entries
     }
 
     /// Get entries matching a predicate (e.g., filter by source IP).
     ///
+    /// `operation` and `details` are interned (see [`crate::intern`]), so a
+    /// predicate grouping identical errors can compare with `Arc::ptr_eq`
+    /// instead of a full string comparison once it has one entry's handle to
+    /// compare against.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -351,6 +714,103 @@ impl RingBufferLogger {
         buffer.iter().filter(|e| predicate(e)).cloned().collect()
     }
 
+    /// Visit entries without allocating or bumping any `Arc` refcounts.
+    ///
+    /// `get_all`/`get_filtered` each build a fresh `Vec` of cloned entries -
+    /// cheap per clone, but still a full allocation plus a refcount bump per
+    /// field for callers that only want to fold over the buffer (tally hits
+    /// per source IP, find the oldest unretried error, ...). `scan` holds
+    /// the read lock for the duration of the walk and hands the closure a
+    /// bare `&ForensicEntry` instead, so a summary over thousands of entries
+    /// costs zero allocations.
+    ///
+    /// Walks physical ring order, most recently inserted first - the same
+    /// direction as `get_all`, except in aggregating mode `get_all` instead
+    /// sorts by `last_seen` descending, which this does not do (sorting
+    /// would require collecting first, defeating the point).
+    ///
+    /// Returning [`std::ops::ControlFlow::Break`] from the closure stops the
+    /// walk early without visiting the remaining entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::ring_buffer::RingBufferLogger;
+    /// use palisade_errors::{AgentError, definitions};
+    /// use std::ops::ControlFlow;
+    ///
+    /// let logger = RingBufferLogger::new(100, 1024);
+    /// logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details"), "10.0.0.1");
+    ///
+    /// let mut count = 0usize;
+    /// logger.scan(|_entry| {
+    ///     count += 1;
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!(count, 1);
+    /// ```
+    pub fn scan<F>(&self, mut f: F)
+    where
+        F: FnMut(&ForensicEntry) -> std::ops::ControlFlow<()>,
+    {
+        let buffer = self.read_buffer();
+        for entry in buffer.iter().rev() {
+            if f(entry).is_break() {
+                break;
+            }
+        }
+    }
+
+    /// [`Self::scan`] narrowed to entries matching `predicate`, visited in
+    /// the same zero-allocation way `get_filtered` would otherwise have to
+    /// clone them for.
+    pub fn scan_filtered<P, F>(&self, predicate: P, mut f: F)
+    where
+        P: Fn(&ForensicEntry) -> bool,
+        F: FnMut(&ForensicEntry) -> std::ops::ControlFlow<()>,
+    {
+        let buffer = self.read_buffer();
+        for entry in buffer.iter().rev() {
+            if predicate(entry) && f(entry).is_break() {
+                break;
+            }
+        }
+    }
+
+    /// Render all entries as a JSON array, most recent first (same order as
+    /// [`Self::get_all`]), for piping the buffer into a log shipper or SIEM
+    /// pipeline instead of scraping [`ForensicEntry::render_diagnostic`]'s
+    /// human-readable form.
+    ///
+    /// Streams field-by-field directly into the returned `String` - no
+    /// intermediate `serde_json::Value` tree - so the cost stays
+    /// proportional to the bytes actually written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::ring_buffer::RingBufferLogger;
+    /// use palisade_errors::{AgentError, definitions};
+    ///
+    /// let logger = RingBufferLogger::new(10, 1024);
+    /// logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "details"), "10.0.0.1");
+    /// let json = logger.export_json();
+    /// assert!(json.starts_with('['));
+    /// assert!(json.contains("\"source_ip\":\"10.0.0.1\""));
+    /// ```
+    pub fn export_json(&self) -> String {
+        let entries = self.get_all();
+        let mut buf = String::from("[");
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            write_entry_json(&mut buf, entry);
+        }
+        buf.push(']');
+        buf
+    }
+
     /// Get current number of entries in buffer.
     #[inline]
     pub fn len(&self) -> usize {
@@ -394,6 +854,39 @@ impl RingBufferLogger {
     pub fn is_full(&self) -> bool {
         self.len() >= self.max_entries
     }
+
+    /// Drop every entry older than `max_age_secs` (see [`Self::with_max_age`])
+    /// and return how many were purged. A no-op, returning `0`, if no max age
+    /// was configured.
+    ///
+    /// The ring is ordered oldest-to-newest, so an expired entry can only
+    /// ever be at the head: this walks forward from `head`, popping while
+    /// the oldest remaining entry is still expired, and stops at the first
+    /// one that isn't. That makes it O(k) in the number of entries actually
+    /// purged rather than a scan of the whole buffer.
+    ///
+    /// [`Self::log`] already calls this opportunistically on every insert,
+    /// so a steady trickle of traffic keeps the buffer trimmed on its own;
+    /// call this directly for a quiet logger where `log()` isn't running
+    /// often enough to do that for you.
+    pub fn purge_expired(&self) -> usize {
+        let Some(max_age_secs) = self.max_age_secs else {
+            return 0;
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let cutoff = now.saturating_sub(max_age_secs);
+
+        let mut buffer = self.write_buffer();
+        let mut purged = 0usize;
+        while let Some(front) = buffer.front() {
+            if front.timestamp >= cutoff {
+                break;
+            }
+            buffer.pop_front();
+            purged += 1;
+        }
+        purged
+    }
 }
 
 impl Clone for RingBufferLogger {
@@ -403,8 +896,157 @@ impl Clone for RingBufferLogger {
             max_entries: self.max_entries,
             max_entry_bytes: self.max_entry_bytes,
             eviction_count: Arc::clone(&self.eviction_count),
+            dedup_index: self.dedup_index.clone(),
+            drain_sender: Arc::clone(&self.drain_sender),
+            drain_dropped: Arc::clone(&self.drain_dropped),
+            intern: Arc::clone(&self.intern),
+            max_age_secs: self.max_age_secs,
+            archive_sink: self.archive_sink.clone(),
+        }
+    }
+}
+
+/// Cold-tier hook for entries the ring is about to lose to eviction.
+///
+/// Installed via [`RingBufferLogger::with_archive_sink`]. `log()` calls
+/// [`Self::archive`] once per entry pushed out to make room for a new one
+/// (in either plain or aggregating mode), synchronously and with no ring
+/// lock held - a slow sink slows down the `log()` call that triggered the
+/// eviction, but never blocks concurrent readers or other writers.
+pub trait ArchiveSink: Send + Sync {
+    /// Called with an entry immediately after it's been evicted from the
+    /// ring. By the time this runs the entry is already gone from
+    /// `get_all`/`get_recent`/`scan` - this is the only place it's still
+    /// observable.
+    fn archive(&self, entry: &ForensicEntry);
+}
+
+/// Pluggable compression codec for [`CompressingArchiveSink`]'s batches.
+///
+/// This crate hand-rolls its own primitives rather than pull in a dependency
+/// (see [`crate::integrity`], [`crate::seal`]) but doesn't do that for
+/// general-purpose compression - zstd/lz4 are exactly the kind of thing a
+/// deployment already has an opinion on, so `CompressingArchiveSink` takes
+/// the codec as a type parameter instead of shipping one. [`NoopCompressor`]
+/// is the only implementation this crate provides itself, for deployments
+/// that want the batching behavior without an actual compression dependency.
+pub trait Compressor: Send + Sync {
+    /// Compress `data`, returning the compressed bytes.
+    fn compress(&self, data: &[u8]) -> Vec<u8>;
+}
+
+/// A [`Compressor`] that doesn't compress - the batch is still serialized
+/// and handed to the batch callback in one piece, just uncompressed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopCompressor;
+
+impl Compressor for NoopCompressor {
+    fn compress(&self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+}
+
+struct PendingBatch {
+    entries: Vec<ForensicEntry>,
+    pending_bytes: usize,
+}
+
+/// An [`ArchiveSink`] that batches evicted entries and flushes them as one
+/// compressed blob once their combined [`ForensicEntry::size_bytes`] crosses
+/// `batch_compression_threshold`, instead of paying compression overhead per
+/// entry.
+///
+/// Batches are serialized with the same length-prefixed, big-endian wire
+/// format [`Self::flush`]'s sibling [`RingBufferLogger::seal_export`] uses
+/// for its own plaintext, then handed to `compressor` before reaching the
+/// `on_batch` callback - a deployment wires that callback up to whatever
+/// actually is its cold tier (object storage, a spool directory, ...).
+///
+/// A partial batch sitting below the threshold is never flushed on its own;
+/// call [`Self::flush`] at shutdown to avoid losing it.
+pub struct CompressingArchiveSink<C: Compressor> {
+    compressor: C,
+    batch_compression_threshold: usize,
+    pending: Mutex<PendingBatch>,
+    on_batch: Box<dyn Fn(Vec<u8>) + Send + Sync>,
+}
+
+impl<C: Compressor> CompressingArchiveSink<C> {
+    /// `on_batch` is called with one compressed blob every time accumulated
+    /// evicted entries cross `batch_compression_threshold` bytes.
+    pub fn new(
+        compressor: C,
+        batch_compression_threshold: usize,
+        on_batch: impl Fn(Vec<u8>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            compressor,
+            batch_compression_threshold: batch_compression_threshold.max(1),
+            pending: Mutex::new(PendingBatch {
+                entries: Vec::new(),
+                pending_bytes: 0,
+            }),
+            on_batch: Box::new(on_batch),
+        }
+    }
+
+    /// Number of entries accumulated but not yet flushed as a batch.
+    pub fn pending_len(&self) -> usize {
+        self.lock_pending().entries.len()
+    }
+
+    /// Force-flush whatever's pending, even if it's under the threshold.
+    /// Returns `false` (without calling `on_batch`) if nothing was pending.
+    pub fn flush(&self) -> bool {
+        let mut pending = self.lock_pending();
+        if pending.entries.is_empty() {
+            return false;
         }
+        let batch = std::mem::take(&mut pending.entries);
+        pending.pending_bytes = 0;
+        drop(pending);
+        self.compress_and_emit(&batch);
+        true
     }
+
+    fn lock_pending(&self) -> std::sync::MutexGuard<'_, PendingBatch> {
+        match self.pending.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn compress_and_emit(&self, batch: &[ForensicEntry]) {
+        let serialized = crate::seal::encode_entries(batch);
+        let compressed = self.compressor.compress(&serialized);
+        (self.on_batch)(compressed);
+    }
+}
+
+impl<C: Compressor> ArchiveSink for CompressingArchiveSink<C> {
+    fn archive(&self, entry: &ForensicEntry) {
+        let batch = {
+            let mut pending = self.lock_pending();
+            pending.pending_bytes += entry.size_bytes;
+            pending.entries.push(entry.clone());
+            if pending.pending_bytes < self.batch_compression_threshold {
+                return;
+            }
+            pending.pending_bytes = 0;
+            std::mem::take(&mut pending.entries)
+        };
+        self.compress_and_emit(&batch);
+    }
+}
+
+/// Hash the `(code, operation, source_ip)` dedup key used by an aggregating
+/// [`RingBufferLogger`] to collapse repeat hits into one entry.
+fn dedup_key(code: &str, operation: &str, source_ip: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    code.hash(&mut hasher);
+    operation.hash(&mut hasher);
+    source_ip.hash(&mut hasher);
+    hasher.finish()
 }
 
 /// Truncate string to maximum byte length, respecting UTF-8 boundaries.
@@ -444,6 +1086,42 @@ fn truncate_to_bytes<'a>(s: &'a str, max_bytes: usize) -> Cow<'a, str> {
     Cow::Owned(out)
 }
 
+/// Write one [`ForensicEntry`] as a JSON object into `buf`, for
+/// [`RingBufferLogger::export_json`]. Uses
+/// [`crate::logging::write_json_string`] for escaping, matching
+/// [`crate::logging::InternalLog::write_json`]'s field-by-field streaming
+/// approach.
+fn write_entry_json(buf: &mut String, entry: &ForensicEntry) {
+    use crate::logging::write_json_string;
+    use std::fmt::Write as _;
+
+    buf.push_str("{\"code\":");
+    write_json_string(buf, &entry.code).unwrap();
+    buf.push_str(",\"code_raw\":");
+    write_json_string(buf, &entry.code_raw).unwrap();
+    buf.push_str(",\"trace_id\":");
+    write_json_string(buf, &entry.trace_id).unwrap();
+    buf.push_str(",\"operation\":");
+    write_json_string(buf, &entry.operation).unwrap();
+    buf.push_str(",\"details\":");
+    write_json_string(buf, &entry.details).unwrap();
+    buf.push_str(",\"source_ip\":");
+    write_json_string(buf, &entry.source_ip).unwrap();
+    write!(buf, ",\"timestamp\":{},\"last_seen\":{},\"count\":{}", entry.timestamp, entry.last_seen, entry.count).unwrap();
+    write!(buf, ",\"retryable\":{}", entry.retryable).unwrap();
+
+    buf.push_str(",\"metadata\":{");
+    for (i, (key, value)) in entry.metadata.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        write_json_string(buf, key).unwrap();
+        buf.push(':');
+        write_json_string(buf, value).unwrap();
+    }
+    buf.push_str("}}");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,6 +1149,19 @@ mod tests {
         assert!(entries[2].details.contains("error 2"));
     }
 
+    #[test]
+    fn forensic_entry_render_diagnostic_matches_rustc_style() {
+        let logger = RingBufferLogger::new(10, 1024);
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "validate", "bad value");
+        logger.log(&err, "192.168.1.1");
+
+        let entry = &logger.get_recent(1)[0];
+        let rendered = entry.render_diagnostic();
+        assert!(rendered.starts_with("error[E-CFG-"));
+        assert!(rendered.contains("op 'validate'"));
+        assert!(rendered.contains("bad value"));
+    }
+
     #[test]
     fn ring_buffer_respects_size_limit() {
         let logger = RingBufferLogger::new(100, 128);
@@ -508,6 +1199,102 @@ mod tests {
         assert_eq!(from_ip1.len(), 5);
     }
 
+    #[test]
+    fn get_filtered_matches_on_trace_id() {
+        let logger = RingBufferLogger::new(100, 1024);
+
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "test");
+        let wanted = err.trace_id().to_string();
+        logger.log(&err, "192.168.1.1");
+        logger.log(
+            &AgentError::config(definitions::CFG_PARSE_FAILED, "op", "other"),
+            "192.168.1.1",
+        );
+
+        let matches = logger.get_filtered(|e| e.trace_id.as_ref() == wanted);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].details.as_ref(), "test");
+    }
+
+    #[test]
+    fn drain_receives_every_logged_entry() {
+        let logger = RingBufferLogger::new(3, 1024);
+        let receiver = logger.with_drain(16);
+
+        for i in 0..5 {
+            let err = AgentError::config(
+                definitions::CFG_PARSE_FAILED,
+                "op",
+                format!("error {}", i),
+            );
+            logger.log(&err, "192.168.1.1");
+        }
+
+        // Ring buffer only kept the last 3, but the drain saw all 5.
+        assert_eq!(logger.len(), 3);
+        let drained: Vec<_> = receiver.try_iter().collect();
+        assert_eq!(drained.len(), 5);
+        assert_eq!(logger.dropped_count(), 0);
+    }
+
+    #[test]
+    fn drain_increments_dropped_count_when_full() {
+        let logger = RingBufferLogger::new(100, 1024);
+        let _receiver = logger.with_drain(1);
+
+        for i in 0..10 {
+            let err = AgentError::config(
+                definitions::CFG_PARSE_FAILED,
+                "op",
+                format!("error {}", i),
+            );
+            logger.log(&err, "192.168.1.1");
+        }
+
+        // The channel holds at most 1 unread entry; the rest were dropped
+        // rather than blocking the producer.
+        assert!(logger.dropped_count() > 0);
+        assert_eq!(logger.len(), 10);
+    }
+
+    #[test]
+    fn drain_shared_across_clones() {
+        let logger1 = RingBufferLogger::new(100, 1024);
+        let receiver = logger1.with_drain(16);
+        let logger2 = logger1.clone();
+
+        let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", "via clone");
+        logger2.log(&err, "192.168.1.1");
+
+        let drained = receiver.recv().unwrap();
+        assert_eq!(drained.details.as_ref(), "via clone");
+    }
+
+    #[test]
+    fn repeated_operation_reuses_the_same_allocation() {
+        let logger = RingBufferLogger::new(100, 1024);
+
+        for i in 0..10 {
+            let err = AgentError::config(
+                definitions::CFG_PARSE_FAILED,
+                "login_attempt",
+                format!("attempt {}", i),
+            );
+            logger.log(&err, "192.168.1.1");
+        }
+
+        let entries = logger.get_all();
+        let first = &entries[0].operation;
+        assert!(entries.iter().all(|e| Arc::ptr_eq(&e.operation, first)));
+        assert!(logger.dedup_ratio() > 0.0);
+    }
+
+    #[test]
+    fn dedup_ratio_is_zero_before_any_logging() {
+        let logger = RingBufferLogger::new(100, 1024);
+        assert_eq!(logger.dedup_ratio(), 0.0);
+    }
+
     #[test]
     fn ring_buffer_clone_shares_state() {
         let logger1 = RingBufferLogger::new(100, 1024);
@@ -603,6 +1390,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn aggregating_logger_collapses_repeat_hits() {
+        let logger = RingBufferLogger::new_aggregating(10, 1024);
+
+        for _ in 0..50 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "login", "bad password");
+            logger.log(&err, "10.0.0.1");
+        }
+
+        assert_eq!(logger.len(), 1);
+        assert_eq!(logger.eviction_count(), 0);
+
+        let entry = &logger.get_recent(1)[0];
+        assert_eq!(entry.count, 50);
+        assert_eq!(entry.source_ip.as_ref(), "10.0.0.1");
+    }
+
+    #[test]
+    fn aggregating_logger_keeps_distinct_keys_separate() {
+        let logger = RingBufferLogger::new_aggregating(10, 1024);
+
+        for ip in ["10.0.0.1", "10.0.0.2", "10.0.0.1"] {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "login", "bad password");
+            logger.log(&err, ip);
+        }
+
+        assert_eq!(logger.len(), 2);
+
+        let from_ip1 = logger
+            .get_filtered(|e| e.source_ip.as_ref() == "10.0.0.1")
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(from_ip1.count, 2);
+
+        let from_ip2 = logger
+            .get_filtered(|e| e.source_ip.as_ref() == "10.0.0.2")
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(from_ip2.count, 1);
+    }
+
+    #[test]
+    fn aggregating_logger_sorts_get_recent_by_last_seen() {
+        let logger = RingBufferLogger::new_aggregating(10, 1024);
+
+        let err_a = AgentError::config(definitions::CFG_PARSE_FAILED, "login", "a");
+        let err_b = AgentError::config(definitions::CFG_PARSE_FAILED, "login", "b");
+
+        logger.log(&err_a, "10.0.0.1");
+        logger.log(&err_b, "10.0.0.2");
+        // Re-hit the first key so its last_seen moves ahead of the second.
+        logger.log(&err_a, "10.0.0.1");
+
+        let recent = logger.get_recent(2);
+        assert_eq!(recent[0].source_ip.as_ref(), "10.0.0.1");
+        assert_eq!(recent[0].count, 2);
+        assert_eq!(recent[1].source_ip.as_ref(), "10.0.0.2");
+    }
+
     #[test]
     fn arc_str_cloning_is_cheap() {
         let logger = RingBufferLogger::new(10, 1024);
@@ -622,4 +1470,200 @@ mod tests {
         assert!(Arc::ptr_eq(&entry1.operation, &entry2.operation));
         assert!(Arc::ptr_eq(&entry1.details, &entry2.details));
     }
+
+    #[test]
+    fn scan_visits_every_entry_in_get_all_order() {
+        let logger = RingBufferLogger::new(10, 1024);
+        for i in 0..3 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", format!("error {i}"));
+            logger.log(&err, "192.168.1.1");
+        }
+
+        let mut seen = Vec::new();
+        logger.scan(|entry| {
+            seen.push(entry.details.to_string());
+            std::ops::ControlFlow::Continue(())
+        });
+
+        let expected: Vec<String> = logger.get_all().iter().map(|e| e.details.to_string()).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn scan_stops_early_on_control_flow_break() {
+        let logger = RingBufferLogger::new(10, 1024);
+        for i in 0..5 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", format!("error {i}"));
+            logger.log(&err, "192.168.1.1");
+        }
+
+        let mut visited = 0usize;
+        logger.scan(|_entry| {
+            visited += 1;
+            if visited == 2 {
+                std::ops::ControlFlow::Break(())
+            } else {
+                std::ops::ControlFlow::Continue(())
+            }
+        });
+
+        assert_eq!(visited, 2);
+    }
+
+    #[test]
+    fn scan_filtered_only_visits_matching_entries() {
+        let logger = RingBufferLogger::new(10, 1024);
+        logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "d"), "10.0.0.1");
+        logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "d"), "10.0.0.2");
+        logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "d"), "10.0.0.1");
+
+        let mut matched = 0usize;
+        logger.scan_filtered(
+            |entry| entry.source_ip.as_ref() == "10.0.0.1",
+            |_entry| {
+                matched += 1;
+                std::ops::ControlFlow::Continue(())
+            },
+        );
+
+        assert_eq!(matched, 2);
+    }
+
+    #[test]
+    fn scan_does_not_allocate_a_vec_of_entries() {
+        // No direct way to assert "zero allocations" from a unit test, but we can at
+        // least confirm scan never needs an intermediate Vec to produce its result -
+        // a running accumulator is enough.
+        let logger = RingBufferLogger::new(10, 1024);
+        logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "d"), "10.0.0.1");
+
+        let mut total_size = 0usize;
+        logger.scan(|entry| {
+            total_size += entry.size_bytes;
+            std::ops::ControlFlow::Continue(())
+        });
+
+        assert_eq!(total_size, logger.get_recent(1)[0].size_bytes);
+    }
+
+    fn stale_entry(timestamp: u64, source_ip: &str) -> ForensicEntry {
+        ForensicEntry {
+            timestamp,
+            last_seen: timestamp,
+            count: 1,
+            code: Arc::from("E-CFG-100"),
+            code_raw: Arc::from("E-CFG-100"),
+            trace_id: Arc::from(""),
+            operation: Arc::from("op"),
+            details: Arc::from("old"),
+            source_ip: Arc::from(source_ip),
+            metadata: Arc::from(Vec::<(Arc<str>, Arc<str>)>::new()),
+            size_bytes: 0,
+            retryable: false,
+        }
+    }
+
+    #[test]
+    fn purge_expired_drops_only_entries_past_max_age() {
+        let logger = RingBufferLogger::new(10, 1024).with_max_age(60);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        // Bypass log()'s own opportunistic purge via replay_insert, so this
+        // test exercises purge_expired()'s selectivity in isolation rather
+        // than log()'s auto-purge behavior (see
+        // log_opportunistically_purges_expired_entries for that contract).
+        logger.replay_insert(stale_entry(0, "10.0.0.1"));
+        logger.replay_insert(stale_entry(now, "10.0.0.2"));
+
+        assert_eq!(logger.len(), 2);
+        let purged = logger.purge_expired();
+        assert_eq!(purged, 1);
+        assert_eq!(logger.len(), 1);
+        assert_eq!(logger.get_recent(1)[0].source_ip.as_ref(), "10.0.0.2");
+    }
+
+    #[test]
+    fn log_opportunistically_purges_expired_entries() {
+        let logger = RingBufferLogger::new(10, 1024).with_max_age(60);
+        logger.replay_insert(stale_entry(0, "10.0.0.1"));
+        assert_eq!(logger.len(), 1);
+
+        // log() itself should purge the stale head entry before inserting the new one.
+        logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "fresh"), "10.0.0.2");
+        assert_eq!(logger.len(), 1);
+        assert_eq!(logger.get_recent(1)[0].source_ip.as_ref(), "10.0.0.2");
+    }
+
+    #[test]
+    fn purge_expired_is_a_no_op_without_max_age_configured() {
+        let logger = RingBufferLogger::new(10, 1024);
+        logger.replay_insert(stale_entry(0, "10.0.0.1"));
+
+        assert_eq!(logger.purge_expired(), 0);
+        assert_eq!(logger.len(), 1);
+    }
+
+    struct CollectingArchive(Arc<Mutex<Vec<String>>>);
+
+    impl ArchiveSink for CollectingArchive {
+        fn archive(&self, entry: &ForensicEntry) {
+            self.0.lock().unwrap().push(entry.details.to_string());
+        }
+    }
+
+    #[test]
+    fn archive_sink_receives_entries_in_eviction_order() {
+        let collected = Arc::new(Mutex::new(Vec::new()));
+        let logger = RingBufferLogger::new(2, 1024).with_archive_sink(CollectingArchive(Arc::clone(&collected)));
+
+        for i in 0..4 {
+            let err = AgentError::config(definitions::CFG_PARSE_FAILED, "op", format!("entry {i}"));
+            logger.log(&err, "10.0.0.1");
+        }
+
+        let archived = collected.lock().unwrap();
+        assert_eq!(archived.as_slice(), &["entry 0".to_string(), "entry 1".to_string()]);
+    }
+
+    #[test]
+    fn archive_sink_is_not_called_without_eviction() {
+        let collected = Arc::new(Mutex::new(Vec::new()));
+        let logger = RingBufferLogger::new(10, 1024).with_archive_sink(CollectingArchive(Arc::clone(&collected)));
+
+        logger.log(&AgentError::config(definitions::CFG_PARSE_FAILED, "op", "d"), "10.0.0.1");
+        assert!(collected.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn compressing_archive_sink_flushes_once_threshold_crossed() {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let batches_clone = Arc::clone(&batches);
+        let sink = CompressingArchiveSink::new(NoopCompressor, 50, move |blob| {
+            batches_clone.lock().unwrap().push(blob);
+        });
+
+        let mut entry = stale_entry(0, "10.0.0.1");
+        entry.size_bytes = 30;
+
+        sink.archive(&entry);
+        assert_eq!(sink.pending_len(), 1);
+        assert!(batches.lock().unwrap().is_empty());
+
+        sink.archive(&entry);
+        assert_eq!(sink.pending_len(), 0);
+        assert_eq!(batches.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn compressing_archive_sink_flush_forces_a_partial_batch_out() {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let batches_clone = Arc::clone(&batches);
+        let sink = CompressingArchiveSink::new(NoopCompressor, 1_000_000, move |blob| {
+            batches_clone.lock().unwrap().push(blob);
+        });
+
+        sink.archive(&stale_entry(0, "10.0.0.1"));
+        assert!(sink.flush());
+        assert_eq!(batches.lock().unwrap().len(), 1);
+        assert!(!sink.flush());
+    }
 }
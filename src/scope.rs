@@ -0,0 +1,318 @@
+// src/scope.rs
+//! Dotted, hierarchical permission scopes and [`ScopedClearance`] - a finer
+//! axis than [`crate::Capability`]'s category-based gate for exposing
+//! sensitive context.
+//!
+//! # Purpose
+//!
+//! `SocAccess` and `Capability` answer "is this caller allowed to see
+//! sensitive data at all" (and, for `Capability`, "for which categories").
+//! Neither can express "this caller may see API-key errors but not raw
+//! credential dumps" without inventing a new `OperationCategory` per secret
+//! kind. [`Scope`] borrows the dotted-path convention of OAuth scopes/Unix
+//! ACL paths instead: each [`crate::definitions::ErrorDefinition`] declares
+//! the [`Scope`] required to expose its sensitive payload (see
+//! [`crate::definitions::ErrorDefinition::required_scope`]), and a
+//! [`ScopedClearance`] built via [`ScopedClearanceBuilder`] either covers it
+//! or doesn't.
+//!
+//! # Design
+//!
+//! [`Scope::covers`] is the one piece of matching logic every caller shares
+//! - a granted scope with a trailing `*` segment covers every scope sharing
+//! its preceding segments, exact matches cover themselves, and nothing else
+//! matches. Centralizing this here means [`ScopedClearance::allows`] and
+//! [`crate::DualContextError::expose_scoped`] can't drift into two subtly
+//! different wildcard interpretations.
+//!
+//! [`ScopedClearanceBuilder::grant_role`] composes a clearance from a named
+//! role's scope list in one call, so an RBAC integration doesn't have to
+//! `.grant()` each scope in a role one at a time.
+//!
+//! # std-only
+//!
+//! Like [`crate::ledger`], this needs `String`/heap allocation for parsed
+//! scopes and is unavailable under `no_std`.
+
+use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::fmt;
+
+/// A parsed dotted permission scope, e.g. `"log.sensitive.apikey"`.
+///
+/// # Wildcards
+///
+/// A scope whose last segment is the literal `*` covers every scope sharing
+/// its preceding segments as a prefix: `"log.sensitive.*"` covers
+/// `"log.sensitive.apikey"` and `"log.sensitive.token"`, but not the parent
+/// `"log.sensitive"` itself - a wildcard only expands to children, it is not
+/// shorthand for "this scope or any ancestor of it."
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scope(Cow<'static, str>);
+
+impl Scope {
+    /// Parse a dotted scope string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScopeParseError`] if `raw` is empty, contains an empty
+    /// segment (e.g. `"log..sensitive"`), or places a `*` anywhere but the
+    /// final segment.
+    pub fn parse(raw: impl Into<Cow<'static, str>>) -> Result<Self, ScopeParseError> {
+        let raw = raw.into();
+        if raw.is_empty() {
+            return Err(ScopeParseError::Empty);
+        }
+        if raw.split('.').any(str::is_empty) {
+            return Err(ScopeParseError::EmptySegment);
+        }
+        if raw.split('.').rev().skip(1).any(|segment| segment == "*") {
+            return Err(ScopeParseError::WildcardNotTrailing);
+        }
+        Ok(Self(raw))
+    }
+
+    /// The raw dotted scope string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    #[inline]
+    fn is_wildcard(&self) -> bool {
+        self.0.as_ref() == "*" || self.0.ends_with(".*")
+    }
+
+    /// Whether this scope, as granted, covers `required`.
+    #[must_use]
+    pub fn covers(&self, required: &Scope) -> bool {
+        if self.0 == required.0 {
+            return true;
+        }
+        let Some(prefix) = self.is_wildcard().then(|| self.0.trim_end_matches('*')) else {
+            return false;
+        };
+        required.0.starts_with(prefix)
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why [`Scope::parse`] rejected a raw scope string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeParseError {
+    /// The input was empty.
+    Empty,
+    /// The input contained an empty segment, e.g. `"log..sensitive"`.
+    EmptySegment,
+    /// A `*` segment appeared somewhere other than the last position, e.g.
+    /// `"log.*.sensitive"`.
+    WildcardNotTrailing,
+}
+
+impl fmt::Display for ScopeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "scope string is empty"),
+            Self::EmptySegment => write!(f, "scope contains an empty segment"),
+            Self::WildcardNotTrailing => write!(f, "scope's '*' wildcard must be the final segment"),
+        }
+    }
+}
+
+impl std::error::Error for ScopeParseError {}
+
+/// A set of granted [`Scope`]s, built via [`ScopedClearanceBuilder`].
+///
+/// # Security Model
+///
+/// Same posture as [`crate::SocAccess`]/[`crate::Capability`]: organizational
+/// process safety, not cryptography. The value is forcing the exact set of
+/// sensitive-payload scopes a caller may see to be named explicitly, instead
+/// of an all-or-nothing `SocAccess` token implicitly granting everything.
+#[derive(Debug, Clone, Default)]
+pub struct ScopedClearance {
+    granted: SmallVec<[Scope; 4]>,
+}
+
+impl ScopedClearance {
+    /// Whether any granted scope covers `required`.
+    #[must_use]
+    pub fn allows(&self, required: &Scope) -> bool {
+        self.granted.iter().any(|scope| scope.covers(required))
+    }
+
+    /// The granted scopes, in the order they were added.
+    #[inline]
+    pub fn granted(&self) -> &[Scope] {
+        &self.granted
+    }
+}
+
+/// Composes a [`ScopedClearance`] from individual scopes or named roles.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::scope::ScopedClearanceBuilder;
+///
+/// const SOC_ANALYST_ROLE: &[&str] = &["log.sensitive.apikey", "log.sensitive.timing"];
+///
+/// let clearance = ScopedClearanceBuilder::new()
+///     .grant_role(SOC_ANALYST_ROLE)
+///     .unwrap()
+///     .build();
+///
+/// assert!(clearance.allows(&palisade_errors::scope::Scope::parse("log.sensitive.apikey").unwrap()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScopedClearanceBuilder {
+    granted: SmallVec<[Scope; 4]>,
+}
+
+impl ScopedClearanceBuilder {
+    /// Start building an empty clearance - grants nothing until `.grant()`
+    /// or `.grant_role()` is called, the same deny-by-default posture as
+    /// [`crate::CapabilityScope`]'s `Default`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grant a single scope, parsing it first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScopeParseError`] if `scope` doesn't parse - see
+    /// [`Scope::parse`].
+    pub fn grant(mut self, scope: impl Into<Cow<'static, str>>) -> Result<Self, ScopeParseError> {
+        self.granted.push(Scope::parse(scope)?);
+        Ok(self)
+    }
+
+    /// Grant every scope in a named role's scope list, e.g. a
+    /// `const SOC_ANALYST_ROLE: &[&str] = &[...]` declared alongside a
+    /// deployment's RBAC role table - composing a clearance from a role is
+    /// one call instead of one `.grant()` per scope in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ScopeParseError`] hit; scopes already granted
+    /// from an earlier call remain granted.
+    pub fn grant_role(mut self, role: &[&'static str]) -> Result<Self, ScopeParseError> {
+        for &scope in role {
+            self.granted.push(Scope::parse(scope)?);
+        }
+        Ok(self)
+    }
+
+    /// Finish building, producing the [`ScopedClearance`].
+    #[inline]
+    #[must_use]
+    pub fn build(self) -> ScopedClearance {
+        ScopedClearance { granted: self.granted }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_scope_covers_itself() {
+        let granted = Scope::parse("log.sensitive.apikey").unwrap();
+        let required = Scope::parse("log.sensitive.apikey").unwrap();
+        assert!(granted.covers(&required));
+    }
+
+    #[test]
+    fn exact_scope_does_not_cover_a_sibling() {
+        let granted = Scope::parse("log.sensitive.apikey").unwrap();
+        let required = Scope::parse("log.sensitive.token").unwrap();
+        assert!(!granted.covers(&required));
+    }
+
+    #[test]
+    fn trailing_wildcard_covers_children() {
+        let granted = Scope::parse("log.sensitive.*").unwrap();
+        assert!(granted.covers(&Scope::parse("log.sensitive.apikey").unwrap()));
+        assert!(granted.covers(&Scope::parse("log.sensitive.token").unwrap()));
+    }
+
+    #[test]
+    fn trailing_wildcard_does_not_cover_its_own_parent() {
+        let granted = Scope::parse("log.sensitive.*").unwrap();
+        assert!(!granted.covers(&Scope::parse("log.sensitive").unwrap()));
+    }
+
+    #[test]
+    fn bare_wildcard_covers_everything() {
+        let granted = Scope::parse("*").unwrap();
+        assert!(granted.covers(&Scope::parse("log.sensitive.apikey").unwrap()));
+    }
+
+    #[test]
+    fn parse_rejects_empty_input() {
+        assert_eq!(Scope::parse("").unwrap_err(), ScopeParseError::Empty);
+    }
+
+    #[test]
+    fn parse_rejects_empty_segments() {
+        assert_eq!(Scope::parse("log..sensitive").unwrap_err(), ScopeParseError::EmptySegment);
+    }
+
+    #[test]
+    fn parse_rejects_a_non_trailing_wildcard() {
+        assert_eq!(
+            Scope::parse("log.*.sensitive").unwrap_err(),
+            ScopeParseError::WildcardNotTrailing
+        );
+    }
+
+    #[test]
+    fn clearance_allows_only_granted_scopes() {
+        let clearance = ScopedClearanceBuilder::new()
+            .grant("log.sensitive.apikey")
+            .unwrap()
+            .build();
+
+        assert!(clearance.allows(&Scope::parse("log.sensitive.apikey").unwrap()));
+        assert!(!clearance.allows(&Scope::parse("log.sensitive.token").unwrap()));
+    }
+
+    #[test]
+    fn clearance_respects_granted_wildcards() {
+        let clearance = ScopedClearanceBuilder::new()
+            .grant("log.sensitive.*")
+            .unwrap()
+            .build();
+
+        assert!(clearance.allows(&Scope::parse("log.sensitive.apikey").unwrap()));
+        assert!(clearance.allows(&Scope::parse("log.sensitive.token").unwrap()));
+    }
+
+    #[test]
+    fn grant_role_composes_multiple_scopes_in_one_call() {
+        const ROLE: &[&str] = &["log.sensitive.apikey", "log.sensitive.timing"];
+        let clearance = ScopedClearanceBuilder::new().grant_role(ROLE).unwrap().build();
+
+        assert!(clearance.allows(&Scope::parse("log.sensitive.apikey").unwrap()));
+        assert!(clearance.allows(&Scope::parse("log.sensitive.timing").unwrap()));
+        assert!(!clearance.allows(&Scope::parse("log.sensitive.password").unwrap()));
+    }
+
+    #[test]
+    fn empty_clearance_allows_nothing() {
+        let clearance = ScopedClearanceBuilder::new().build();
+        assert!(!clearance.allows(&Scope::parse("log.sensitive").unwrap()));
+    }
+
+    #[test]
+    fn grant_propagates_a_parse_error() {
+        assert!(ScopedClearanceBuilder::new().grant("bad..scope").is_err());
+    }
+}
@@ -0,0 +1,537 @@
+//! Redact-by-default `serde` support for `DualContextError`, `ContextChain`,
+//! and `ContextMetadata`.
+//!
+//! # Security Model
+//!
+//! Mirrors Garage's split between a public API error body and internal
+//! detail: the `Serialize`/`Deserialize` impls in this module only ever
+//! touch the **public surface** - `external_message()`, `OperationCategory`,
+//! and metadata the caller explicitly marked `MetadataTrust::Public`. The
+//! internal/sensitive side can never leak through `serde_json::to_string(&err)`
+//! by accident, because these impls have no path to it at all.
+//!
+//! For trusted sinks that need the gated internal payload, use
+//! `DualContextError::serialize_full()` / `ContextChain::serialize_full()`
+//! instead, which take a `&SocAccess` and serialize a distinct, explicitly
+//! "full" representation.
+//!
+//! `to_external_json()` and `to_internal_json()` sit between those two: the
+//! external view is the same public surface as `Serialize`, shaped for an
+//! HTTP body; the internal view adds the diagnostic payload for a SIEM, but
+//! still can't touch `Sensitive` content - it's built from
+//! `InternalContext::payload()`, which returns `None` for that variant, so
+//! the wire form gets a `{"sensitive": true, "redacted": true}` placeholder
+//! instead. No `SocAccess` is needed for either, because neither can reach
+//! sensitive data in the first place.
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `serde` feature, so the crate's core path never takes
+//! a hard `serde` dependency - the same reasoning as the `json_emitter`
+//! feature in `context.rs`.
+//!
+//! # Deserialization and Metadata Keys
+//!
+//! `ContextMetadata` keys are `&'static str` by design (see `models.rs`),
+//! since metadata is normally attached via compile-time string literals.
+//! Deserialized data has no such lifetime, so keys are interned with
+//! `Box::leak` on the way in. This is a deliberate, bounded tradeoff: fine
+//! for the expected use case (occasionally deserializing a handful of errors
+//! read back from a log), but callers deserializing a high-volume, unbounded
+//! stream of distinct keys should be aware this leaks memory for the life of
+//! the process.
+
+use crate::{ContextBuilder, ContextChain, ContextMetadata, DualContextError, InternalPayload, OperationCategory, SocAccess};
+use serde::de::Error as DeError;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Intern a deserialized metadata key as `&'static str`. See the module docs'
+/// "Deserialization and Metadata Keys" section for the tradeoff this makes.
+fn intern_key(key: String) -> &'static str {
+    Box::leak(key.into_boxed_str())
+}
+
+// ============================================================================
+// ContextMetadata (Public Entries Only)
+// ============================================================================
+
+impl Serialize for ContextMetadata {
+    /// Serializes only the `MetadataTrust::Public` entries, as a plain map.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let public: BTreeMap<&str, &str> = self.public_iter().collect();
+        public.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContextMetadata {
+    /// Reconstructs a `ContextMetadata` from a plain map. Every deserialized
+    /// entry is classified `MetadataTrust::Public`, since that's the only
+    /// trust level this representation can express.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let public: BTreeMap<String, String> = BTreeMap::deserialize(deserializer)?;
+        let mut metadata = ContextMetadata::new();
+        for (key, value) in public {
+            metadata.add(intern_key(key), value, crate::MetadataTrust::Public);
+        }
+        Ok(metadata)
+    }
+}
+
+// ============================================================================
+// DualContextError (Public Surface Only)
+// ============================================================================
+
+/// Wire shape for `DualContextError`'s public surface: the external message,
+/// the authentic category name, and any `Public`-trust metadata.
+#[derive(Serialize, Deserialize)]
+struct PublicErrorView {
+    message: String,
+    category: String,
+    metadata: BTreeMap<String, String>,
+}
+
+impl DualContextError {
+    fn to_public_view(&self) -> PublicErrorView {
+        PublicErrorView {
+            message: self.external_message().to_string(),
+            category: self.category().display_name().to_string(),
+            metadata: self
+                .public_metadata()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    fn from_public_view(view: PublicErrorView) -> Self {
+        let category = OperationCategory::from_display_name(&view.category)
+            .unwrap_or(OperationCategory::System);
+        let mut builder = ContextBuilder::new()
+            .public_lie(view.message)
+            .internal_diagnostic("[REDACTED: reconstructed from public-only serialized form]")
+            .category(category);
+        for (key, value) in view.metadata {
+            builder = builder.public_metadata(intern_key(key), value);
+        }
+        builder.build()
+    }
+
+    /// Serialize the gated internal payload alongside the public surface,
+    /// for trusted sinks (e.g. an encrypted SOC datastore) that hold a valid
+    /// `SocAccess`.
+    ///
+    /// # Security Contract
+    ///
+    /// Same contract as `InternalContext::expose_sensitive()`: the caller
+    /// must ensure the resulting string is only sent to an authenticated,
+    /// access-controlled destination - never to an external log shipper or
+    /// public API response.
+    pub fn serialize_full<S>(&self, access: &SocAccess, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DualContextError", 5)?;
+        state.serialize_field("message", self.external_message())?;
+        state.serialize_field("category", self.category().display_name())?;
+        state.serialize_field(
+            "metadata",
+            &self
+                .public_metadata()
+                .map(|(k, v)| (k, v))
+                .collect::<BTreeMap<_, _>>(),
+        )?;
+        state.serialize_field(
+            "internal_metadata",
+            &self
+                .all_metadata(access)
+                .map(|(k, v)| (k, v))
+                .collect::<BTreeMap<_, _>>(),
+        )?;
+        state.serialize_field(
+            "internal_sensitive",
+            &self.internal().expose_sensitive(access),
+        )?;
+        state.end()
+    }
+}
+
+// ============================================================================
+// ExternalView / InternalView (HTTP- and SIEM-facing JSON)
+// ============================================================================
+
+/// camelCase JSON view safe to return directly in an HTTP response body:
+/// `message`, `category`, `httpStatus` - nothing diagnostic or sensitive.
+///
+/// # Feature Gate
+///
+/// Requires `http` in addition to `serde`, since `httpStatus` comes from
+/// `DualContextError::status_code()` (see `http.rs`).
+#[cfg(feature = "http")]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExternalView<'a> {
+    message: &'a str,
+    category: &'static str,
+    http_status: u16,
+}
+
+/// JSON view for SIEM ingestion: the diagnostic payload plus public
+/// metadata, with any `Sensitive` internal context structurally replaced by
+/// `{"sensitive": true, "redacted": true}` rather than its contents.
+///
+/// # Security
+///
+/// Built entirely from `InternalContext::payload()` and
+/// `InternalContext::classification()`. `payload()` can yield `Sensitive`
+/// content while a `ForensicMode` guard is live elsewhere in the process,
+/// but `to_internal_json()` has no `SocAccess` parameter of its own, so it
+/// explicitly treats `Sensitive` the same as `None` here. A SIEM consuming
+/// this view can therefore never observe sensitive data by accident, no
+/// matter what forensic state the rest of the process is in; reaching it
+/// still requires `DualContextError::serialize_full()` with an explicit
+/// `SocAccess`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InternalView<'a> {
+    message: &'a str,
+    category: &'static str,
+    internal: InternalPayloadView<'a>,
+    metadata: BTreeMap<&'a str, &'a str>,
+}
+
+/// The `internal` field of [`InternalView`]: either the real diagnostic/lie
+/// payload, or a structural placeholder standing in for sensitive content.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum InternalPayloadView<'a> {
+    Payload {
+        classification: &'static str,
+        text: &'a str,
+    },
+    Redacted {
+        sensitive: bool,
+        redacted: bool,
+    },
+}
+
+impl DualContextError {
+    /// Render the [`ExternalView`] - the shape safe to return directly in an
+    /// HTTP response body.
+    #[cfg(feature = "http")]
+    pub fn to_external_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&ExternalView {
+            message: self.external_message(),
+            category: self.category().display_name(),
+            http_status: self.status_code(),
+        })
+    }
+
+    /// Render the [`InternalView`] - the diagnostic payload a SIEM can log,
+    /// with any sensitive context replaced by a redaction marker.
+    pub fn to_internal_json(&self) -> serde_json::Result<String> {
+        let internal = match self.internal().payload() {
+            // `Sensitive` payloads are deliberately routed to the same
+            // redaction marker as `None` here: this view has no `SocAccess`
+            // parameter, so it must stay safe for unattended SIEM ingestion
+            // even while a process-wide `ForensicMode` guard happens to be
+            // live elsewhere for an unrelated incident-response session.
+            Some(InternalPayload::Sensitive(_)) | None => InternalPayloadView::Redacted {
+                sensitive: true,
+                redacted: true,
+            },
+            Some(payload) => InternalPayloadView::Payload {
+                classification: self.internal().classification(),
+                text: payload.as_str(),
+            },
+        };
+
+        serde_json::to_string(&InternalView {
+            message: self.external_message(),
+            category: self.category().display_name(),
+            internal,
+            metadata: self.public_metadata().collect(),
+        })
+    }
+}
+
+impl Serialize for DualContextError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_public_view().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DualContextError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let view = PublicErrorView::deserialize(deserializer)?;
+        Ok(Self::from_public_view(view))
+    }
+}
+
+// ============================================================================
+// ContextChain (Public Surface Only)
+// ============================================================================
+
+impl ContextChain {
+    /// Serialize every link's gated internal payload alongside its public
+    /// surface. See `DualContextError::serialize_full()` for the security
+    /// contract this inherits.
+    pub fn serialize_full<S>(&self, access: &SocAccess, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.depth()))?;
+        for error in self.iter() {
+            seq.serialize_element(&FullLinkView {
+                error,
+                access,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct FullLinkView<'a> {
+    error: &'a DualContextError,
+    access: &'a SocAccess,
+}
+
+impl Serialize for FullLinkView<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.error.serialize_full(self.access, serializer)
+    }
+}
+
+impl Serialize for ContextChain {
+    /// Serializes every link's public surface, root to head, as a JSON array.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.depth()))?;
+        for error in self.iter() {
+            seq.serialize_element(error)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ContextChain {
+    /// Reconstructs a chain from its serialized public surface. Each link is
+    /// rebuilt the same lossy way as `DualContextError::deserialize()` - the
+    /// internal context becomes a redacted placeholder, since it was never
+    /// part of the wire format.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let views: Vec<PublicErrorView> = Vec::deserialize(deserializer)?;
+        let mut views = views.into_iter();
+        let root = DualContextError::from_public_view(
+            views.next().ok_or_else(|| D::Error::custom("ContextChain must have at least one link"))?,
+        );
+        let mut chain = ContextChain::new(root);
+        for view in views {
+            chain.push(DualContextError::from_public_view(view));
+        }
+        Ok(chain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Severity;
+
+    #[test]
+    fn serialize_never_contains_sensitive_text() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .build();
+
+        let json = serde_json::to_string(&err).unwrap();
+        assert!(!json.contains("hunter2"));
+        assert!(json.contains("Operation failed"));
+    }
+
+    #[test]
+    fn serialize_includes_public_metadata_only() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .category(OperationCategory::IO)
+            .metadata("session_token", "s3cr3t")
+            .public_metadata("correlation_id", "req-42")
+            .build();
+
+        let json = serde_json::to_string(&err).unwrap();
+        assert!(json.contains("correlation_id"));
+        assert!(json.contains("req-42"));
+        assert!(!json.contains("session_token"));
+        assert!(!json.contains("s3cr3t"));
+    }
+
+    #[test]
+    fn deserialize_round_trips_public_surface() {
+        let err = ContextBuilder::new()
+            .public_lie("Access denied")
+            .internal_diagnostic("unused")
+            .category(OperationCategory::Detection)
+            .public_metadata("correlation_id", "req-42")
+            .build();
+
+        let json = serde_json::to_string(&err).unwrap();
+        let restored: DualContextError = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.external_message(), "Access denied");
+        assert_eq!(restored.category(), OperationCategory::Detection);
+        assert_eq!(
+            restored.public_metadata().collect::<Vec<_>>(),
+            vec![("correlation_id", "req-42")]
+        );
+    }
+
+    #[test]
+    fn serialize_full_includes_gated_internal_payload() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .build();
+
+        let access = SocAccess::acquire();
+        let mut buf = Vec::new();
+        let mut serializer = serde_json::Serializer::new(&mut buf);
+        err.serialize_full(&access, &mut serializer).unwrap();
+        let json = String::from_utf8(buf).unwrap();
+
+        assert!(json.contains("hunter2"));
+    }
+
+    #[test]
+    fn chain_serialize_is_array_of_public_views() {
+        let root = ContextBuilder::new()
+            .public_lie("Database error")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .build();
+        let mut chain = ContextChain::new(root);
+        chain.push(
+            ContextBuilder::new()
+                .public_lie("Retry failed")
+                .internal_diagnostic("Max retries exceeded")
+                .category(OperationCategory::System)
+                .build(),
+        );
+
+        let json = serde_json::to_string(&chain).unwrap();
+        assert!(json.starts_with('['));
+        assert!(json.contains("Database error"));
+        assert!(json.contains("Retry failed"));
+        assert!(!json.contains("hunter2"));
+    }
+
+    #[test]
+    fn chain_deserialize_preserves_root_and_head() {
+        let root = ContextBuilder::new()
+            .public_lie("Database error")
+            .internal_diagnostic("unused")
+            .category(OperationCategory::IO)
+            .severity(Severity::Error)
+            .build();
+        let mut chain = ContextChain::new(root);
+        chain.push(
+            ContextBuilder::new()
+                .public_lie("Retry failed")
+                .internal_diagnostic("unused")
+                .category(OperationCategory::System)
+                .build(),
+        );
+
+        let json = serde_json::to_string(&chain).unwrap();
+        let restored: ContextChain = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.root().external_message(), "Database error");
+        assert_eq!(restored.head().external_message(), "Retry failed");
+        assert_eq!(restored.depth(), 2);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn external_json_contains_only_message_category_and_http_status() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .build();
+
+        let json = err.to_external_json().unwrap();
+        assert!(json.contains("\"message\":\"Operation failed\""));
+        assert!(json.contains("\"category\":\"I/O\""));
+        assert!(json.contains("\"httpStatus\":500"));
+        assert!(!json.contains("hunter2"));
+    }
+
+    #[test]
+    fn internal_json_redacts_sensitive_context() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_sensitive("password 'hunter2' rejected")
+            .category(OperationCategory::IO)
+            .build();
+
+        let json = err.to_internal_json().unwrap();
+        assert!(json.contains("\"sensitive\":true"));
+        assert!(json.contains("\"redacted\":true"));
+        assert!(!json.contains("hunter2"));
+    }
+
+    #[test]
+    fn internal_json_includes_diagnostic_payload() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("disk quota exceeded on /var/log")
+            .category(OperationCategory::IO)
+            .build();
+
+        let json = err.to_internal_json().unwrap();
+        assert!(json.contains("disk quota exceeded on /var/log"));
+        assert!(json.contains("\"classification\":\"InternalDiagnostic\""));
+    }
+
+    #[test]
+    fn internal_json_includes_public_metadata() {
+        let err = ContextBuilder::new()
+            .public_lie("Operation failed")
+            .internal_diagnostic("Timeout")
+            .category(OperationCategory::IO)
+            .metadata("session_token", "s3cr3t")
+            .public_metadata("correlation_id", "req-42")
+            .build();
+
+        let json = err.to_internal_json().unwrap();
+        assert!(json.contains("correlation_id"));
+        assert!(json.contains("req-42"));
+        assert!(!json.contains("session_token"));
+        assert!(!json.contains("s3cr3t"));
+    }
+}
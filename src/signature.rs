@@ -0,0 +1,400 @@
+// src/signature.rs
+//! Pluggable attack-signature detection for the deception path.
+//!
+//! # Purpose
+//!
+//! Honeypot endpoints like the one in `examples/honeypot_scenario.rs` used to
+//! hand-code a single `input.contains("' OR '1'='1")` check and then manually
+//! pick [`OperationCategory::Deception`] and write the canned lie. That's fine
+//! for one signature, but it doesn't scale: every new injection pattern meant
+//! another `if` arm wired to its own `ContextBuilder` call. [`SignatureRegistry`]
+//! makes the deception path data-driven instead - an ordered set of named
+//! detectors, each free to inspect the raw input and report a match, so the
+//! endpoint collapses to a single [`crate::ContextBuilder::detect_with`] call.
+//!
+//! # Design
+//!
+//! Each [`SignatureRegistry`] entry pairs a name with a boxed detector closure
+//! `Fn(&str) -> Option<SignatureMatch>`. The registry runs its detectors in
+//! registration order and stops at the first match - order matters the same
+//! way it does in [`crate::codes::InternalErrorCodeViolation`]'s checked
+//! construction: the first applicable rule wins rather than every rule being
+//! evaluated for a "best" one. [`SignatureRegistry::seeded`] ships a default
+//! set covering the common injection families (SQL injection, OS command
+//! injection, path traversal, LDAP injection, and auth-bypass via
+//! comment-terminated usernames) so most callers never need to register their
+//! own detectors.
+//!
+//! # Security
+//!
+//! A [`SignatureMatch`]'s `public_lie` is the only part of the match that may
+//! ever reach the attacker; [`crate::ContextBuilder::detect_with`] routes the
+//! rule name and raw payload into `internal_sensitive`, which - like any other
+//! sensitive internal context - requires [`crate::SocAccess`] to read back.
+
+use crate::{ContextBuilder, OperationCategory};
+#[cfg(feature = "no_std")]
+use alloc::boxed::Box;
+#[cfg(feature = "no_std")]
+use alloc::format;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// How sure a detector is that what it saw is actually an attack, as opposed
+/// to a coincidentally similar but benign input.
+///
+/// Kept as a small enum rather than a raw score - consistent with
+/// [`crate::Severity`] and [`crate::codes::ErrorImpact`] elsewhere in this
+/// crate - so callers can match on it instead of picking an arbitrary
+/// numeric threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DetectionConfidence {
+    /// The pattern also shows up in legitimate input; treat as a weak hint.
+    Low,
+    /// The pattern is uncommon outside an attack but not conclusive alone.
+    Medium,
+    /// The pattern has no legitimate use case in this field.
+    High,
+}
+
+/// What a [`SignatureRegistry`] detector reports when it recognizes an
+/// attack pattern in the raw input.
+///
+/// # Fields
+///
+/// - `rule_name`: identifies which detector fired, for the internal
+///   (sensitive) log trail - never shown to the attacker.
+/// - `confidence`: how confident the detector is in the match.
+/// - `category`: the [`OperationCategory`] [`crate::ContextBuilder::detect_with`]
+///   should tag the resulting error with.
+/// - `public_lie`: the canned message the attacker sees instead of the truth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureMatch {
+    /// Identifies which detector fired, for the internal (sensitive) log
+    /// trail - never shown to the attacker.
+    pub rule_name: &'static str,
+    /// How confident the detector is in the match.
+    pub confidence: DetectionConfidence,
+    /// The [`OperationCategory`] the resulting error should be tagged with.
+    pub category: OperationCategory,
+    /// The canned message the attacker sees instead of the truth.
+    pub public_lie: &'static str,
+}
+
+impl SignatureMatch {
+    /// Construct a match. Exposed as a `const fn` so detector closures in a
+    /// hot path (or a `static` table of canned matches) can build one without
+    /// runtime cost.
+    #[inline]
+    pub const fn new(
+        rule_name: &'static str,
+        confidence: DetectionConfidence,
+        category: OperationCategory,
+        public_lie: &'static str,
+    ) -> Self {
+        Self {
+            rule_name,
+            confidence,
+            category,
+            public_lie,
+        }
+    }
+}
+
+type Detector = dyn Fn(&str) -> Option<SignatureMatch> + Send + Sync;
+
+/// Ordered set of named attack-signature detectors.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::signature::SignatureRegistry;
+///
+/// let registry = SignatureRegistry::seeded();
+/// let hit = registry.scan("admin' OR '1'='1").expect("known SQLi pattern");
+/// assert_eq!(hit.rule_name, "sqli");
+/// ```
+pub struct SignatureRegistry {
+    detectors: Vec<(&'static str, Box<Detector>)>,
+}
+
+impl SignatureRegistry {
+    /// Create an empty registry with no detectors registered.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            detectors: Vec::new(),
+        }
+    }
+
+    /// Register a named detector, run after every detector already
+    /// registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::signature::{DetectionConfidence, SignatureMatch, SignatureRegistry};
+    /// use palisade_errors::OperationCategory;
+    ///
+    /// let registry = SignatureRegistry::new().register("all-caps-shout", |input| {
+    ///     if input.chars().filter(|c| c.is_alphabetic()).all(|c| c.is_uppercase())
+    ///         && input.len() > 8
+    ///     {
+    ///         Some(SignatureMatch::new(
+    ///             "all-caps-shout",
+    ///             DetectionConfidence::Low,
+    ///             OperationCategory::Deception,
+    ///             "Request could not be processed.",
+    ///         ))
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    ///
+    /// assert!(registry.scan("HELLO THERE").is_some());
+    /// ```
+    #[inline]
+    pub fn register(
+        mut self,
+        name: &'static str,
+        detect: impl Fn(&str) -> Option<SignatureMatch> + Send + Sync + 'static,
+    ) -> Self {
+        self.detectors.push((name, Box::new(detect)));
+        self
+    }
+
+    /// Run every registered detector against `input`, in registration order,
+    /// and return the first match.
+    pub fn scan(&self, input: &str) -> Option<SignatureMatch> {
+        self.detectors.iter().find_map(|(_, detect)| detect(input))
+    }
+
+    /// Names of every detector currently registered, in registration order.
+    pub fn detector_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.detectors.iter().map(|(name, _)| *name)
+    }
+
+    /// A registry seeded with detectors for the injection families attackers
+    /// most commonly throw at a honeypot login/search/upload endpoint:
+    ///
+    /// - `sqli`: boolean-bypass (`' OR '1'='1`), `UNION SELECT`, and
+    ///   stacked-query (`; DROP`) patterns.
+    /// - `os-command-injection`: shell command substitution via `$(...)` or
+    ///   backticks.
+    /// - `path-traversal`: `../` directory climbing.
+    /// - `ldap-injection`: filter-closing `)(` wildcard probes.
+    /// - `auth-bypass-comment`: usernames that try to comment out the rest
+    ///   of a hand-built query (`admin'--`, `admin'#`).
+    pub fn seeded() -> Self {
+        Self::new()
+            .register("sqli", |input| {
+                if input.contains("' OR '1'='1")
+                    || input.contains("UNION SELECT")
+                    || input.contains("; DROP")
+                {
+                    Some(SignatureMatch::new(
+                        "sqli",
+                        DetectionConfidence::High,
+                        OperationCategory::Deception,
+                        "Database connection pool exhausted. Please try again later.",
+                    ))
+                } else {
+                    None
+                }
+            })
+            .register("os-command-injection", |input| {
+                if input.contains("$(") || input.contains('`') {
+                    Some(SignatureMatch::new(
+                        "os-command-injection",
+                        DetectionConfidence::High,
+                        OperationCategory::Deception,
+                        "Service temporarily unavailable.",
+                    ))
+                } else {
+                    None
+                }
+            })
+            .register("path-traversal", |input| {
+                if input.contains("../") {
+                    Some(SignatureMatch::new(
+                        "path-traversal",
+                        DetectionConfidence::Medium,
+                        OperationCategory::Deception,
+                        "File not found.",
+                    ))
+                } else {
+                    None
+                }
+            })
+            .register("ldap-injection", |input| {
+                if input.contains(")(") {
+                    Some(SignatureMatch::new(
+                        "ldap-injection",
+                        DetectionConfidence::Medium,
+                        OperationCategory::Deception,
+                        "Directory service unavailable.",
+                    ))
+                } else {
+                    None
+                }
+            })
+            .register("auth-bypass-comment", |input| {
+                if input.contains("'--") || input.contains("'#") {
+                    Some(SignatureMatch::new(
+                        "auth-bypass-comment",
+                        DetectionConfidence::High,
+                        OperationCategory::Deception,
+                        "Invalid credentials.",
+                    ))
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+impl Default for SignatureRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContextBuilder {
+    /// Run `registry` against `input` and, on the first match, populate
+    /// `public_lie`, `internal_sensitive`, and `category` from it in one
+    /// call - the data-driven replacement for a per-endpoint `if input.contains(...)`
+    /// ladder.
+    ///
+    /// No-op if nothing matches, leaving the builder free for the caller to
+    /// set public/internal context and category by hand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, signature::SignatureRegistry};
+    ///
+    /// let registry = SignatureRegistry::seeded();
+    /// let err = ContextBuilder::new()
+    ///     .detect_with(&registry, "admin' OR '1'='1")
+    ///     .build();
+    ///
+    /// assert_eq!(err.external_message(), "Database connection pool exhausted. Please try again later.");
+    /// ```
+    pub fn detect_with(self, registry: &SignatureRegistry, input: &str) -> Self {
+        match registry.scan(input) {
+            Some(hit) => self
+                .public_lie(hit.public_lie)
+                .internal_sensitive(format!(
+                    "{} detected in input. Payload: [{}]",
+                    hit.rule_name, input
+                ))
+                .category(hit.category),
+            None => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "no_std")]
+    use alloc::vec;
+
+    #[test]
+    fn seeded_registry_detects_each_family() {
+        let registry = SignatureRegistry::seeded();
+        assert_eq!(registry.scan("admin' OR '1'='1").unwrap().rule_name, "sqli");
+        assert_eq!(
+            registry.scan("x UNION SELECT password FROM users").unwrap().rule_name,
+            "sqli"
+        );
+        assert_eq!(
+            registry.scan("$(rm -rf /)").unwrap().rule_name,
+            "os-command-injection"
+        );
+        assert_eq!(
+            registry.scan("../../etc/passwd").unwrap().rule_name,
+            "path-traversal"
+        );
+        assert_eq!(
+            registry.scan("*)(uid=*))(|(uid=*").unwrap().rule_name,
+            "ldap-injection"
+        );
+        assert_eq!(
+            registry.scan("admin'--").unwrap().rule_name,
+            "auth-bypass-comment"
+        );
+    }
+
+    #[test]
+    fn scan_returns_none_for_benign_input() {
+        let registry = SignatureRegistry::seeded();
+        assert!(registry.scan("alice@example.com").is_none());
+    }
+
+    #[test]
+    fn scan_stops_at_first_registered_match() {
+        let registry = SignatureRegistry::new()
+            .register("first", |_| {
+                Some(SignatureMatch::new(
+                    "first",
+                    DetectionConfidence::Low,
+                    OperationCategory::Deception,
+                    "first",
+                ))
+            })
+            .register("second", |_| {
+                Some(SignatureMatch::new(
+                    "second",
+                    DetectionConfidence::Low,
+                    OperationCategory::Deception,
+                    "second",
+                ))
+            });
+
+        assert_eq!(registry.scan("anything").unwrap().rule_name, "first");
+    }
+
+    #[test]
+    fn detect_with_populates_builder_from_match() {
+        let registry = SignatureRegistry::seeded();
+        let err = ContextBuilder::new()
+            .detect_with(&registry, "admin' OR '1'='1")
+            .build();
+
+        assert_eq!(
+            err.external_message(),
+            "Database connection pool exhausted. Please try again later."
+        );
+        assert_eq!(err.external_category(), "Routine Operation");
+    }
+
+    #[test]
+    fn detect_with_is_a_no_op_on_no_match() {
+        let registry = SignatureRegistry::seeded();
+        let builder = ContextBuilder::new().detect_with(&registry, "alice@example.com");
+        // No panic on subsequently setting public/internal context by hand,
+        // proving detect_with left them unset.
+        let err = builder
+            .public_lie("Not found")
+            .internal_diagnostic("no signature matched")
+            .build();
+        assert_eq!(err.external_message(), "Not found");
+    }
+
+    #[test]
+    fn detector_names_reports_registration_order() {
+        let registry = SignatureRegistry::seeded();
+        let names: Vec<_> = registry.detector_names().collect();
+        assert_eq!(
+            names,
+            vec![
+                "sqli",
+                "os-command-injection",
+                "path-traversal",
+                "ldap-injection",
+                "auth-bypass-comment",
+            ]
+        );
+    }
+}
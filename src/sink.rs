@@ -0,0 +1,620 @@
+// src/sink.rs
+//! Pluggable output sinks for error codes, decoupled from disclosure level.
+//!
+//! # Purpose
+//!
+//! [`crate::context::Emitter`] already decouples *chain* rendering from
+//! format; this module does the same for a single [`ErrorCode`] in
+//! isolation, which is the unit a metrics pipeline or a paging system
+//! actually wants to key on. Hard-wiring disclosure through `Display` and
+//! `to_public()` works for one consumer; real deployments want to fan the
+//! same code out to a human console, a JSON audit stream, and an
+//! impact-keyed metrics counter simultaneously, each with its own
+//! redaction rule.
+//!
+//! # Design
+//!
+//! Modeled on rustc's `Emitter`/`EmitterWriter` split: [`ErrorSink`] is the
+//! narrow trait consumers implement, [`EmitContext`] is what's handed to
+//! every call. The context carries the code's [`ErrorImpact`] directly, so
+//! a sink can decide to page on `Breach`/`Escalation` and silently drop
+//! `Noise` without re-deriving the impact band itself.
+//!
+//! [`HumanSink`] writes straight through the context's `fmt::Write` target
+//! rather than building a `String`, preserving this crate's zero-allocation
+//! guarantee on the hot path (see [`crate::codes`]'s module docs).
+//!
+//! # Log Sinks
+//!
+//! [`ErrorSink`] only ever sees a bare [`ErrorCode`] - enough for a metrics
+//! counter, not enough for a durable record an operator can investigate
+//! later. [`LogSink`] is the full-record analogue: it receives the same
+//! [`crate::logging::InternalLog`] a `with_internal_log` closure would,
+//! so a drain can persist operation, details, category, and retryability
+//! together. [`FileSink`] and [`StderrSink`] are the two concrete drains;
+//! [`FanOutSink`] and [`RoutingSink`] compose them the way `log`'s own
+//! `Log` implementations are usually composed - fan-out to everything, or
+//! route by a predicate. [`register_sink`] installs one process-wide, and
+//! [`AgentError::emit`](crate::AgentError::emit) dispatches to every
+//! registered sink.
+
+use crate::logging::InternalLog;
+use crate::{ErrorCode, ErrorImpact, OperationCategory};
+use std::collections::VecDeque;
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write as IoWrite;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+/// Context passed to every [`ErrorSink::emit`] call alongside the code
+/// itself.
+///
+/// Wraps a `&mut dyn fmt::Write` rather than a `String` so a sink can write
+/// into a pre-existing `fmt::Formatter` (or any other writer) without an
+/// intermediate allocation.
+pub struct EmitContext<'a> {
+    /// The code's impact band, hoisted out of `code.impact_level()` so a
+    /// sink can filter on it without touching the code itself.
+    pub impact: ErrorImpact,
+    out: &'a mut dyn fmt::Write,
+}
+
+impl<'a> EmitContext<'a> {
+    /// Build a context for `code`, writing through `out`.
+    #[inline]
+    pub fn for_code(code: &ErrorCode, out: &'a mut dyn fmt::Write) -> Self {
+        Self {
+            impact: code.impact_level(),
+            out,
+        }
+    }
+}
+
+impl fmt::Write for EmitContext<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.out.write_str(s)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        self.out.write_fmt(args)
+    }
+}
+
+/// Destination for a rendered [`ErrorCode`].
+///
+/// Modeled on rustc's `Emitter` trait (see [`crate::context::Emitter`] for
+/// the chain-level analogue): a single narrow method, so routing codes to
+/// a console, a JSON stream, or a metrics counter is a one-`impl` job per
+/// backend.
+pub trait ErrorSink {
+    /// Render `code` through `ctx`.
+    ///
+    /// # Contract
+    ///
+    /// Implementations that only care about certain impact bands should
+    /// check `ctx.impact` and return `Ok(())` without writing anything for
+    /// the rest, rather than relying on the caller to filter.
+    fn emit(&self, code: &ErrorCode, ctx: &mut EmitContext<'_>) -> fmt::Result;
+}
+
+/// Renders a code as `"{category} operation failed ({code})"`, e.g.
+/// `"Configuration operation failed (E-CFG-100)"`.
+///
+/// Writes directly through [`EmitContext`] - no intermediate `String` is
+/// allocated.
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::definitions::CFG_PARSE_FAILED;
+/// use palisade_errors::sink::{EmitContext, ErrorSink, HumanSink};
+///
+/// let mut out = String::new();
+/// let mut ctx = EmitContext::for_code(&CFG_PARSE_FAILED, &mut out);
+/// HumanSink.emit(&CFG_PARSE_FAILED, &mut ctx).unwrap();
+///
+/// assert_eq!(out, "Configuration operation failed (E-CFG-100)");
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HumanSink;
+
+impl ErrorSink for HumanSink {
+    fn emit(&self, code: &ErrorCode, ctx: &mut EmitContext<'_>) -> fmt::Result {
+        write!(ctx, "{} operation failed ({})", code.category().display_name(), code)
+    }
+}
+
+/// One record of the full internal taxonomy behind an emitted code.
+#[derive(Debug, Clone)]
+pub struct TaxonomyRecord {
+    /// Owning namespace, e.g. `"CFG"`.
+    pub namespace: &'static str,
+    /// Raw numeric code within the namespace.
+    pub code: u16,
+    /// Operation category, e.g. `"Configuration"`.
+    pub category: &'static str,
+    /// Raw impact score (0-1000).
+    pub impact_score: u16,
+    /// Impact band label, e.g. `"Jitter"`.
+    pub impact_level: &'static str,
+}
+
+impl TaxonomyRecord {
+    fn from_code(code: &ErrorCode) -> Self {
+        Self {
+            namespace: code.namespace().as_str(),
+            code: code.code(),
+            category: code.category().display_name(),
+            impact_score: code.impact().value(),
+            impact_level: code.impact_level().label(),
+        }
+    }
+}
+
+/// Sink that records the full internal taxonomy of every code emitted to
+/// it, for later SOC review - unlike [`HumanSink`], nothing is redacted.
+///
+/// Bounded FIFO storage, same eviction posture as
+/// [`crate::ring_buffer::RingBufferLogger`] and [`crate::audit::RingBufferAuditSink`].
+/// Distinct from [`crate::audit::AuditSink`], which tracks *sensitive-context
+/// exposure* rather than error-code emission.
+#[derive(Debug, Clone)]
+pub struct AuditSink {
+    records: Arc<RwLock<VecDeque<TaxonomyRecord>>>,
+    capacity: usize,
+}
+
+impl AuditSink {
+    /// Create a sink retaining at most `capacity` records (minimum 1).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            records: Arc::new(RwLock::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// All currently retained records, newest first.
+    pub fn records(&self) -> Vec<TaxonomyRecord> {
+        self.read().iter().rev().cloned().collect()
+    }
+
+    /// Number of records currently retained.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Whether no records are currently retained.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, VecDeque<TaxonomyRecord>> {
+        match self.records.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    #[inline]
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, VecDeque<TaxonomyRecord>> {
+        match self.records.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl ErrorSink for AuditSink {
+    fn emit(&self, code: &ErrorCode, _ctx: &mut EmitContext<'_>) -> fmt::Result {
+        let mut records = self.write();
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(TaxonomyRecord::from_code(code));
+        Ok(())
+    }
+}
+
+/// Destination for a full internal log record.
+///
+/// The full-record analogue of [`ErrorSink`]: implementations see operation,
+/// details, category, and retryability together rather than a bare code, so
+/// a drain can write a complete, investigable entry instead of just a
+/// counter bump.
+pub trait LogSink: Send + Sync {
+    /// Record `entry`.
+    ///
+    /// # Contract
+    ///
+    /// Same as [`AuditSink::record`](crate::audit::AuditSink::record):
+    /// must not block indefinitely or panic, since this runs inline on
+    /// [`crate::AgentError::emit`]'s call path.
+    fn log(&self, entry: &InternalLog<'_>);
+}
+
+/// Failure to open or write a [`FileSink`]'s backing file.
+#[derive(Debug)]
+pub enum SinkError {
+    /// The file's parent directory doesn't exist - this sink only appends
+    /// to a file in an existing directory, it doesn't create directories.
+    MissingDirectory { path: String },
+    /// The file couldn't be opened.
+    Io { path: String, source: std::io::Error },
+}
+
+impl fmt::Display for SinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDirectory { path } => {
+                write!(f, "cannot create log file {path}: parent directory does not exist")
+            }
+            Self::Io { path, source } => write!(f, "failed to open log file {path}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for SinkError {}
+
+/// Appends each log entry's [`InternalLog::write_to`] rendering, one per
+/// line, to a file on disk.
+///
+/// Opens in append mode so multiple sinks (or process restarts) pointed at
+/// the same path never clobber prior entries. The parent directory must
+/// already exist - this sink writes log files, it doesn't provision the
+/// filesystem layout they live in.
+pub struct FileSink {
+    path: PathBuf,
+    file: Mutex<std::fs::File>,
+}
+
+impl FileSink {
+    /// Open (creating if needed) the file at `path` for appending.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SinkError::MissingDirectory`] if `path`'s parent directory
+    /// doesn't exist, or [`SinkError::Io`] if the file can't be opened.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SinkError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                return Err(SinkError::MissingDirectory {
+                    path: path.display().to_string(),
+                });
+            }
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|source| SinkError::Io {
+                path: path.display().to_string(),
+                source,
+            })?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file: Mutex::new(file),
+        })
+    }
+
+    /// The path this sink appends to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl LogSink for FileSink {
+    fn log(&self, entry: &InternalLog<'_>) {
+        let mut line = String::new();
+        if entry.write_to(&mut line).is_err() {
+            return;
+        }
+        line.push('\n');
+
+        let mut file = match self.file.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+/// Writes each log entry's [`InternalLog::write_to`] rendering to stderr,
+/// one line per entry - the sink most deployments start with before wiring
+/// up [`FileSink`] or a real log aggregator.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StderrSink;
+
+impl LogSink for StderrSink {
+    fn log(&self, entry: &InternalLog<'_>) {
+        let mut line = String::new();
+        if entry.write_to(&mut line).is_ok() {
+            eprintln!("{line}");
+        }
+    }
+}
+
+/// Dispatches every log entry to a fixed list of sinks, in order.
+///
+/// Built with the same consuming-builder shape as
+/// [`crate::signature::SignatureRegistry::register`].
+#[derive(Default)]
+pub struct FanOutSink {
+    sinks: Vec<Arc<dyn LogSink>>,
+}
+
+impl FanOutSink {
+    /// An empty fan-out sink - logs nothing until [`Self::with_sink`] adds a
+    /// destination.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a destination to fan out to.
+    pub fn with_sink(mut self, sink: impl LogSink + 'static) -> Self {
+        self.sinks.push(Arc::new(sink));
+        self
+    }
+}
+
+impl LogSink for FanOutSink {
+    fn log(&self, entry: &InternalLog<'_>) {
+        for sink in &self.sinks {
+            sink.log(entry);
+        }
+    }
+}
+
+/// One routing rule for [`RoutingSink`]: an entry matches when every `Some`
+/// field agrees with the entry, and `None` fields are wildcards.
+pub struct Route {
+    category: Option<OperationCategory>,
+    retryable: Option<bool>,
+    impact: Option<ErrorImpact>,
+    sink: Arc<dyn LogSink>,
+}
+
+/// Routes each log entry to the first matching [`Route`], falling back to a
+/// default sink when nothing matches.
+///
+/// Lets a deployment send, e.g., permanent configuration errors to one file
+/// and transient ones to another, without every call site knowing which
+/// file that is - the routing lives once, at sink-registration time.
+pub struct RoutingSink {
+    routes: Vec<Route>,
+    default: Arc<dyn LogSink>,
+}
+
+impl RoutingSink {
+    /// A routing sink that falls back to `default` when no route matches.
+    pub fn new(default: impl LogSink + 'static) -> Self {
+        Self {
+            routes: Vec::new(),
+            default: Arc::new(default),
+        }
+    }
+
+    /// Add a route: entries matching every `Some` field go to `sink` instead
+    /// of later routes or the default. Routes are tried in the order added.
+    pub fn with_route(
+        mut self,
+        category: Option<OperationCategory>,
+        retryable: Option<bool>,
+        impact: Option<ErrorImpact>,
+        sink: impl LogSink + 'static,
+    ) -> Self {
+        self.routes.push(Route {
+            category,
+            retryable,
+            impact,
+            sink: Arc::new(sink),
+        });
+        self
+    }
+}
+
+impl LogSink for RoutingSink {
+    fn log(&self, entry: &InternalLog<'_>) {
+        let matches = |route: &Route| {
+            route.category.map_or(true, |c| c == entry.code.category())
+                && route.retryable.map_or(true, |r| r == entry.retryable)
+                && route.impact.map_or(true, |i| i == entry.code.impact_level())
+        };
+
+        match self.routes.iter().find(|route| matches(route)) {
+            Some(route) => route.sink.log(entry),
+            None => self.default.log(entry),
+        }
+    }
+}
+
+/// Process-wide registered sinks, dispatched to by
+/// [`crate::AgentError::emit`]. Empty (and therefore a no-op) until a
+/// caller calls [`register_sink`].
+static GLOBAL_LOG_SINKS: OnceLock<RwLock<Vec<Arc<dyn LogSink>>>> = OnceLock::new();
+
+#[inline]
+fn global_log_sinks() -> &'static RwLock<Vec<Arc<dyn LogSink>>> {
+    GLOBAL_LOG_SINKS.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// Register a sink to receive every log entry [`crate::AgentError::emit`]
+/// dispatches from this point on.
+///
+/// Additive, not a replacement: calling this more than once fans out to all
+/// registered sinks, same as composing them into a [`FanOutSink`] up front -
+/// whichever is more convenient at the call site.
+pub fn register_sink(sink: impl LogSink + 'static) {
+    let sink: Arc<dyn LogSink> = Arc::new(sink);
+    let lock = global_log_sinks();
+    match lock.write() {
+        Ok(mut guard) => guard.push(sink),
+        Err(poisoned) => poisoned.into_inner().push(sink),
+    }
+}
+
+/// Dispatch `entry` to every sink installed via [`register_sink`]. Called by
+/// [`crate::AgentError::emit`]; not part of the public API beyond that, since
+/// callers observe dispatch only through the sinks they registered.
+pub(crate) fn dispatch_to_registered_sinks(entry: &InternalLog<'_>) {
+    let lock = global_log_sinks();
+    let sinks = match lock.read() {
+        Ok(guard) => guard.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+    for sink in &sinks {
+        sink.log(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definitions::{CFG_PARSE_FAILED, CORE_MEMORY_ALLOC_FAILED};
+
+    #[test]
+    fn human_sink_renders_category_and_code() {
+        let mut out = String::new();
+        let mut ctx = EmitContext::for_code(&CFG_PARSE_FAILED, &mut out);
+        HumanSink.emit(&CFG_PARSE_FAILED, &mut ctx).unwrap();
+
+        assert_eq!(out, "Configuration operation failed (E-CFG-100)");
+    }
+
+    #[test]
+    fn human_sink_allocates_no_intermediate_string() {
+        // The sink writes through `ctx` rather than returning an owned
+        // `String` of its own - this just exercises that path with a
+        // caller-provided buffer that isn't a fresh `String::new()`.
+        let mut out = String::with_capacity(64);
+        let mut ctx = EmitContext::for_code(&CORE_MEMORY_ALLOC_FAILED, &mut out);
+        HumanSink.emit(&CORE_MEMORY_ALLOC_FAILED, &mut ctx).unwrap();
+
+        assert!(out.contains("E-CORE"));
+    }
+
+    #[test]
+    fn emit_context_exposes_impact_band() {
+        let mut out = String::new();
+        let ctx = EmitContext::for_code(&CFG_PARSE_FAILED, &mut out);
+        assert_eq!(ctx.impact, CFG_PARSE_FAILED.impact_level());
+    }
+
+    #[test]
+    fn audit_sink_records_full_taxonomy() {
+        let sink = AuditSink::new(4);
+        let mut out = String::new();
+        let mut ctx = EmitContext::for_code(&CFG_PARSE_FAILED, &mut out);
+        sink.emit(&CFG_PARSE_FAILED, &mut ctx).unwrap();
+
+        let records = sink.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].namespace, "CFG");
+        assert_eq!(records[0].code, 100);
+        assert_eq!(records[0].category, "Configuration");
+    }
+
+    #[test]
+    fn audit_sink_evicts_oldest_when_full() {
+        let sink = AuditSink::new(1);
+        let mut out = String::new();
+
+        let mut ctx = EmitContext::for_code(&CFG_PARSE_FAILED, &mut out);
+        sink.emit(&CFG_PARSE_FAILED, &mut ctx).unwrap();
+        let mut ctx = EmitContext::for_code(&CORE_MEMORY_ALLOC_FAILED, &mut out);
+        sink.emit(&CORE_MEMORY_ALLOC_FAILED, &mut ctx).unwrap();
+
+        let records = sink.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].namespace, "CORE");
+    }
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingSink(Arc<AtomicUsize>);
+
+    impl LogSink for CountingSink {
+        fn log(&self, _entry: &InternalLog<'_>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn file_sink_appends_a_rendered_line() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("palisade_sink_test_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let sink = FileSink::open(&path).unwrap();
+        let error = crate::AgentError::config(CFG_PARSE_FAILED, "load_config", "bad syntax");
+        error.with_internal_log(|log| sink.log(log));
+        drop(sink);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(contents.contains("load_config"));
+    }
+
+    #[test]
+    fn file_sink_rejects_a_missing_parent_directory() {
+        let result = FileSink::open("/palisade-test-dir-that-does-not-exist/x.log");
+        assert!(matches!(result, Err(SinkError::MissingDirectory { .. })));
+    }
+
+    #[test]
+    fn fan_out_sink_dispatches_to_every_sink() {
+        let a = Arc::new(AtomicUsize::new(0));
+        let b = Arc::new(AtomicUsize::new(0));
+        let fan_out = FanOutSink::new()
+            .with_sink(CountingSink(a.clone()))
+            .with_sink(CountingSink(b.clone()));
+
+        let error = crate::AgentError::config(CFG_PARSE_FAILED, "op", "details");
+        error.with_internal_log(|log| fan_out.log(log));
+
+        assert_eq!(a.load(Ordering::SeqCst), 1);
+        assert_eq!(b.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn routing_sink_matches_a_route_then_falls_back_to_default() {
+        let permanent_hits = Arc::new(AtomicUsize::new(0));
+        let default_hits = Arc::new(AtomicUsize::new(0));
+
+        let routing = RoutingSink::new(CountingSink(default_hits.clone())).with_route(
+            Some(OperationCategory::Configuration),
+            Some(false),
+            None,
+            CountingSink(permanent_hits.clone()),
+        );
+
+        let permanent = crate::AgentError::config(CFG_PARSE_FAILED, "op", "details");
+        permanent.with_internal_log(|log| routing.log(log));
+        assert_eq!(permanent_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(default_hits.load(Ordering::SeqCst), 0);
+
+        let transient = crate::AgentError::config(CFG_PARSE_FAILED, "op", "details").with_retry();
+        transient.with_internal_log(|log| routing.log(log));
+        assert_eq!(default_hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn register_sink_makes_emit_reach_it() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        register_sink(CountingSink(counter.clone()));
+
+        let error = crate::AgentError::config(CFG_PARSE_FAILED, "op", "details");
+        error.emit();
+
+        assert!(counter.load(Ordering::SeqCst) >= 1);
+    }
+}
@@ -0,0 +1,317 @@
+// src/throttle.rs
+//! Adaptive response-delay policy for the deception path.
+//!
+//! # Purpose
+//!
+//! A believable honeypot lie mimics more than the *text* of the real
+//! failure it impersonates - it should mimic the failure's *timing* too.
+//! "Connection pool exhausted" shouldn't come back in a microsecond: a real
+//! exhausted pool makes callers wait, and so should the lie. [`DeceptionThrottle`]
+//! keys a token-bucket limiter on a caller-supplied identity (e.g. source IP)
+//! so repeated injection probes from the same source see escalating latency,
+//! the way a real backpressure mechanism would slow them down.
+//!
+//! # Design
+//!
+//! Standard token bucket: each key gets a bucket of [`ThrottlePolicy::capacity`]
+//! tokens that refills at [`ThrottlePolicy::refill_per_sec`]. Every
+//! [`DeceptionThrottle::consume`] call draws one token; when the bucket is
+//! empty the returned [`Outcome::Throttled`] delay is however long it would
+//! take the bucket to refill that token, clamped to
+//! [`ThrottlePolicy::max_delay`] - so a burst of probes experiences
+//! naturally escalating latency without any separate counter to manage.
+//!
+//! [`ContextBuilder::throttle_with`] wires this into the builder the same
+//! way [`crate::signature::SignatureRegistry::scan`] wires into
+//! `detect_with`: the recommended delay is returned alongside the built
+//! error rather than stored on it, so nothing about the
+//! `DualContextError` itself reveals that throttling occurred.
+//!
+//! # Feature Gate
+//!
+//! Like [`crate::escalation`] and [`crate::ring_buffer`], this module needs
+//! `Instant`/`RwLock` and is unavailable under `no_std`.
+
+use crate::{ContextBuilder, DualContextError};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Token-bucket configuration for a [`DeceptionThrottle`].
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::throttle::ThrottlePolicy;
+/// use std::time::Duration;
+///
+/// // Five-probe burst capacity, refilling one token every two seconds,
+/// // delays between 100ms and 10s.
+/// let policy = ThrottlePolicy::new(5.0, 0.5, Duration::from_millis(100), Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottlePolicy {
+    capacity: f64,
+    refill_per_sec: f64,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl ThrottlePolicy {
+    /// Build a policy: `capacity` tokens, refilling at `refill_per_sec`
+    /// tokens/second. `base_delay` is returned for unthrottled calls (it
+    /// mimics the floor latency of the real operation being impersonated);
+    /// `max_delay` caps how long a starved bucket is allowed to make a
+    /// caller wait.
+    pub const fn new(capacity: f64, refill_per_sec: f64, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            capacity,
+            refill_per_sec,
+            base_delay,
+            max_delay,
+        }
+    }
+}
+
+impl Default for ThrottlePolicy {
+    /// Five-probe burst capacity, refilling one token per second, delays
+    /// between 250ms and 30s.
+    fn default() -> Self {
+        Self::new(5.0, 1.0, Duration::from_millis(250), Duration::from_secs(30))
+    }
+}
+
+/// Result of [`DeceptionThrottle::consume`] for one key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// The bucket had a token available; the caller should still wait this
+    /// long before responding, to mimic the impersonated operation's floor
+    /// latency.
+    Allowed(Duration),
+    /// The bucket was empty; the caller should wait this long - time for
+    /// the bucket to refill one token, clamped to `ThrottlePolicy::max_delay`.
+    Throttled(Duration),
+}
+
+impl Outcome {
+    /// The delay this outcome recommends, regardless of variant.
+    pub const fn delay(self) -> Duration {
+        match self {
+            Self::Allowed(d) | Self::Throttled(d) => d,
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Keyed token-bucket limiter driving response-delay timing on the
+/// deception path.
+///
+/// Cheap to clone - internal state is `Arc`-shared, the same convention as
+/// [`crate::escalation::EscalationEngine`] and
+/// [`crate::ring_buffer::RingBufferLogger`].
+///
+/// # Example
+///
+/// ```rust
+/// use palisade_errors::throttle::DeceptionThrottle;
+///
+/// let limiter = DeceptionThrottle::new();
+/// let outcome = limiter.consume("203.0.113.7");
+/// assert!(outcome.delay().as_millis() >= 250);
+/// ```
+#[derive(Clone)]
+pub struct DeceptionThrottle {
+    buckets: Arc<RwLock<HashMap<String, Bucket>>>,
+    policy: ThrottlePolicy,
+    triggered: Arc<AtomicU64>,
+    throttled: Arc<AtomicU64>,
+}
+
+impl DeceptionThrottle {
+    /// Create a limiter using [`ThrottlePolicy::default`].
+    pub fn new() -> Self {
+        Self::with_policy(ThrottlePolicy::default())
+    }
+
+    /// Create a limiter applying `policy` to every key.
+    pub fn with_policy(policy: ThrottlePolicy) -> Self {
+        Self {
+            buckets: Arc::new(RwLock::new(HashMap::new())),
+            policy,
+            triggered: Arc::new(AtomicU64::new(0)),
+            throttled: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Draw a token from `key`'s bucket, returning the recommended delay.
+    pub fn consume(&self, key: &str) -> Outcome {
+        let now = Instant::now();
+        let mut buckets = match self.buckets.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let bucket = buckets.entry(key.to_string()).or_insert_with(|| Bucket {
+            tokens: self.policy.capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.policy.refill_per_sec).min(self.policy.capacity);
+        bucket.last_refill = now;
+
+        self.triggered.fetch_add(1, Ordering::Relaxed);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Outcome::Allowed(self.policy.base_delay);
+        }
+
+        let deficit = 1.0 - bucket.tokens;
+        let wait = Duration::from_secs_f64(deficit / self.policy.refill_per_sec)
+            .max(self.policy.base_delay)
+            .min(self.policy.max_delay);
+        self.throttled.fetch_add(1, Ordering::Relaxed);
+        Outcome::Throttled(wait)
+    }
+
+    /// Total number of [`Self::consume`] calls, throttled or not.
+    pub fn triggered_count(&self) -> u64 {
+        self.triggered.load(Ordering::Relaxed)
+    }
+
+    /// Number of [`Self::consume`] calls that returned [`Outcome::Throttled`].
+    pub fn throttled_count(&self) -> u64 {
+        self.throttled.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for DeceptionThrottle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContextBuilder {
+    /// Draw a token from `limiter` for `key` and build the error, returning
+    /// the recommended response delay alongside it.
+    ///
+    /// # Use Case
+    ///
+    /// For [`crate::OperationCategory::Deception`] builders whose public lie
+    /// claims a rate-limited or backpressured failure - the caller `sleep`s
+    /// on the returned [`Outcome`]'s delay before responding, so repeated
+    /// probes from the same `key` experience escalating latency consistent
+    /// with the lie. The delay is handed back out-of-band rather than
+    /// stored on the built error, so nothing about the error itself reveals
+    /// that throttling occurred.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use palisade_errors::{ContextBuilder, OperationCategory};
+    /// use palisade_errors::throttle::{DeceptionThrottle, Outcome};
+    ///
+    /// let limiter = DeceptionThrottle::new();
+    /// let (err, outcome) = ContextBuilder::new()
+    ///     .public_lie("Database connection pool exhausted. Please try again later.")
+    ///     .internal_sensitive("SQL injection attempt")
+    ///     .category(OperationCategory::Deception)
+    ///     .throttle_with(&limiter, "203.0.113.7");
+    ///
+    /// assert_eq!(err.category(), OperationCategory::Deception);
+    /// match outcome {
+    ///     Outcome::Allowed(_) | Outcome::Throttled(_) => {}
+    /// }
+    /// ```
+    pub fn throttle_with(self, limiter: &DeceptionThrottle, key: &str) -> (DualContextError, Outcome) {
+        let outcome = limiter.consume(key);
+        (self.build(), outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OperationCategory;
+
+    #[test]
+    fn first_calls_within_capacity_are_allowed() {
+        let limiter = DeceptionThrottle::new();
+        for _ in 0..5 {
+            assert!(matches!(limiter.consume("1.2.3.4"), Outcome::Allowed(_)));
+        }
+    }
+
+    #[test]
+    fn exhausting_the_bucket_throttles() {
+        let limiter = DeceptionThrottle::with_policy(ThrottlePolicy::new(
+            2.0,
+            0.001,
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        ));
+        assert!(matches!(limiter.consume("1.2.3.4"), Outcome::Allowed(_)));
+        assert!(matches!(limiter.consume("1.2.3.4"), Outcome::Allowed(_)));
+        assert!(matches!(limiter.consume("1.2.3.4"), Outcome::Throttled(_)));
+    }
+
+    #[test]
+    fn throttled_delay_is_clamped_to_max_delay() {
+        let limiter = DeceptionThrottle::with_policy(ThrottlePolicy::new(
+            1.0,
+            0.0001,
+            Duration::from_millis(10),
+            Duration::from_millis(500),
+        ));
+        limiter.consume("1.2.3.4");
+        let outcome = limiter.consume("1.2.3.4");
+        assert_eq!(outcome.delay(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn distinct_keys_have_independent_buckets() {
+        let limiter = DeceptionThrottle::with_policy(ThrottlePolicy::new(
+            1.0,
+            0.001,
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        ));
+        assert!(matches!(limiter.consume("1.2.3.4"), Outcome::Allowed(_)));
+        assert!(matches!(limiter.consume("1.2.3.4"), Outcome::Throttled(_)));
+        assert!(matches!(limiter.consume("5.6.7.8"), Outcome::Allowed(_)));
+    }
+
+    #[test]
+    fn counters_track_triggered_and_throttled_calls() {
+        let limiter = DeceptionThrottle::with_policy(ThrottlePolicy::new(
+            1.0,
+            0.001,
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        ));
+        limiter.consume("1.2.3.4");
+        limiter.consume("1.2.3.4");
+        limiter.consume("1.2.3.4");
+
+        assert_eq!(limiter.triggered_count(), 3);
+        assert_eq!(limiter.throttled_count(), 2);
+    }
+
+    #[test]
+    fn throttle_with_builds_error_and_returns_outcome() {
+        let limiter = DeceptionThrottle::new();
+        let (err, outcome) = ContextBuilder::new()
+            .public_lie("Database connection pool exhausted. Please try again later.")
+            .internal_sensitive("SQL injection attempt")
+            .category(OperationCategory::Deception)
+            .throttle_with(&limiter, "203.0.113.7");
+
+        assert_eq!(err.category(), OperationCategory::Deception);
+        assert!(matches!(outcome, Outcome::Allowed(_)));
+    }
+}
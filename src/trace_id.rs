@@ -0,0 +1,151 @@
+// src/trace_id.rs
+//! Time-ordered correlation IDs stamped onto every [`crate::AgentError`] at
+//! construction.
+//!
+//! # Use Case
+//!
+//! A redacted external message and its internal log entry carry no shared
+//! identifier by default, so an operator correlating "what did the attacker
+//! see" with "what actually happened" has only the error code and a rough
+//! timestamp to go on - and repeated reports of the same failure across
+//! services are indistinguishable without one. [`TraceId`] gives every error
+//! a 128-bit identifier an operator can quote back ("reference ID
+//! `0190...`") and that ring buffer consumers can match on directly.
+//!
+//! # Layout
+//!
+//! UUIDv7-style: the high 48 bits are the Unix timestamp in milliseconds,
+//! followed by a 4-bit version nibble (7) and a 2-bit variant, with the
+//! remaining bits filled from [`crate::obfuscation`]'s session RNG. This
+//! makes IDs monotonically sortable by creation time - matching ring-buffer
+//! insertion order - without needing a central counter or clock
+//! synchronization across honeypot instances.
+//!
+//! # Feature Gate
+//!
+//! Unconditional within the `not(no_std)` build: like [`crate::obfuscation`],
+//! this needs `std::time::SystemTime` and is outside this crate's `no_std`
+//! carve-out.
+
+use crate::obfuscation::random_u64;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A 128-bit, time-ordered correlation ID. See the [module docs](self) for
+/// the layout and rationale.
+///
+/// `Copy` and allocation-free to construct: the 36-char hyphenated form is
+/// only materialized on demand (via [`Self::write_hyphenated`] or
+/// `Display`), keeping the hot `AgentError::config` construction path free
+/// of the string allocation a UUID crate's `to_string()` would cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceId(u128);
+
+impl TraceId {
+    /// Stamp a new trace ID with the current time and a random tail from
+    /// [`crate::obfuscation`]'s session RNG.
+    #[inline]
+    pub fn generate() -> Self {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+        Self::from_parts(millis, random_u64())
+    }
+
+    #[inline]
+    fn from_parts(millis: u64, rand: u64) -> Self {
+        let time_high: u128 = ((millis & 0xFFFF_FFFF_FFFF) as u128) << 80;
+        let version: u128 = 0x7 << 76;
+        let rand_a: u128 = (((rand >> 52) & 0xFFF) as u128) << 64;
+        let variant: u128 = 0b10 << 62;
+        let rand_b: u128 = (rand & 0x3FFF_FFFF_FFFF_FFFF) as u128;
+        Self(time_high | version | rand_a | variant | rand_b)
+    }
+
+    /// The raw 128 bits backing this ID, for crate-internal wire formats
+    /// (e.g. [`crate::logging::InternalLog::encode`]) that need a fixed-size
+    /// representation rather than the 36-char hyphenated string.
+    #[inline]
+    pub(crate) const fn as_u128(self) -> u128 {
+        self.0
+    }
+
+    /// Reconstruct a [`TraceId`] from bits previously taken from
+    /// [`Self::as_u128`] - the wire-format counterpart, not a general
+    /// constructor, so `raw` is trusted to already be a value this type
+    /// produced rather than validated against the UUIDv7-style layout.
+    #[inline]
+    pub(crate) const fn from_u128(raw: u128) -> Self {
+        Self(raw)
+    }
+
+    /// Write the canonical 36-char hyphenated form (`8-4-4-4-12` hex groups)
+    /// into a stack buffer - no allocation.
+    pub fn write_hyphenated(&self, buf: &mut [u8; 36]) {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let bytes = self.0.to_be_bytes();
+        let mut pos = 0;
+        for (i, b) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                buf[pos] = b'-';
+                pos += 1;
+            }
+            buf[pos] = HEX[(b >> 4) as usize];
+            buf[pos + 1] = HEX[(b & 0xF) as usize];
+            pos += 2;
+        }
+    }
+}
+
+impl fmt::Display for TraceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; 36];
+        self.write_hyphenated(&mut buf);
+        // All bytes written above are ASCII hex digits or '-'.
+        f.write_str(std::str::from_utf8(&buf).expect("hyphenated trace id is always ASCII"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hyphenated_form_matches_uuid_shape() {
+        let id = TraceId::generate();
+        let rendered = id.to_string();
+
+        assert_eq!(rendered.len(), 36);
+        let groups: Vec<&str> = rendered.split('-').collect();
+        assert_eq!(groups.iter().map(|g| g.len()).collect::<Vec<_>>(), [8, 4, 4, 4, 12]);
+        assert!(rendered.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
+    }
+
+    #[test]
+    fn version_and_variant_nibbles_are_stamped() {
+        let id = TraceId::generate();
+        let rendered = id.to_string();
+
+        // Third group starts with the version nibble ('7').
+        assert!(rendered.split('-').nth(2).unwrap().starts_with('7'));
+        // Fourth group starts with the variant nibble (binary 10xx -> 8-b).
+        let variant_nibble = rendered.split('-').nth(3).unwrap().chars().next().unwrap();
+        assert!(('8'..='b').contains(&variant_nibble));
+    }
+
+    #[test]
+    fn successive_ids_are_monotonically_sortable() {
+        let first = TraceId::generate();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = TraceId::generate();
+
+        assert!(second.0 > first.0);
+    }
+
+    #[test]
+    fn distinct_calls_produce_distinct_ids() {
+        let a = TraceId::generate();
+        let b = TraceId::generate();
+        assert_ne!(a, b);
+    }
+}
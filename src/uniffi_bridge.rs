@@ -0,0 +1,136 @@
+//! Opt-in UniFFI-safe external-error projection for [`DualContextError`].
+//!
+//! # Architecture
+//!
+//! Follows the same "framework-agnostic plain data" approach `http.rs` uses
+//! for `HttpErrorResponse`, adapted for Mozilla's `error-support`/uniffi
+//! convention of exposing a foreign-language-safe error as a plain record
+//! rather than the Rust error type itself: [`ExternalError`] carries only
+//! `String` fields, so it already satisfies `#[derive(uniffi::Record)]`'s
+//! requirements without this crate taking a hard dependency on `uniffi` - a
+//! consumer who wants the actual derive wraps [`ExternalError`] in their own
+//! newtype (or re-exports it via a `From` impl on their own `uniffi::Record`
+//! type), the same way `http.rs` leaves `HttpErrorResponse` for axum/actix
+//! callers to wrap rather than depending on either framework directly.
+//!
+//! # Security
+//!
+//! [`ExternalError`] is built only from [`DualContextError::external_message`]
+//! and [`DualContextError::external_category`] - structurally, there is no
+//! field on it that could ever hold internal or sensitive content, so a
+//! foreign-language caller holding an [`ExternalError`] learns nothing that
+//! `Display`-ing the original error wouldn't already tell them.
+//! [`DualContextError::debug_repr`] gives foreign callers (and Rust callers
+//! debugging across the boundary) a redaction-aware introspection string
+//! that always prints the literal `[INTERNAL CONTEXT REDACTED]` marker in
+//! place of the real internal payload - never the `internal()` context,
+//! sensitive or not.
+//!
+//! # Feature Gate
+//!
+//! Entirely behind the `ffi` feature - the same flag [`crate::ffi`]'s raw
+//! C-ABI bridge uses, since both exist to move a [`DualContextError`] across
+//! a language boundary; this one is for boundaries UniFFI (or any other
+//! record-passing FFI generator) can cross directly, without hand-rolled
+//! pointer plumbing. Unavailable under `no_std`, which has no heap-allocated
+//! `String` story to build [`ExternalError`] on - see [`crate::ffi`]'s own
+//! `no_std` carve-out for the same reasoning.
+
+use crate::DualContextError;
+
+/// A UniFFI-safe, external-only projection of a [`DualContextError`].
+///
+/// # Fields
+///
+/// Deliberately just two plain `String`s - see this module's `Security`
+/// docs for why that shape is load-bearing, not incidental.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalError {
+    /// [`DualContextError::external_message`], owned.
+    pub message: String,
+    /// [`DualContextError::external_category`], owned.
+    pub category: String,
+}
+
+impl ExternalError {
+    /// Project a [`DualContextError`] down to its external-safe view.
+    pub fn from_error(error: &DualContextError) -> Self {
+        Self {
+            message: error.external_message().to_string(),
+            category: error.external_category().to_string(),
+        }
+    }
+}
+
+impl From<&DualContextError> for ExternalError {
+    fn from(error: &DualContextError) -> Self {
+        Self::from_error(error)
+    }
+}
+
+impl DualContextError {
+    /// Redaction-aware debug string, safe to log or display across a
+    /// language boundary - inspired by LDK's `*_debug_str` introspection
+    /// helpers.
+    ///
+    /// # Output
+    ///
+    /// ```text
+    /// ExternalError { message: "Not found", category: "Routine Operation" } [INTERNAL CONTEXT REDACTED]
+    /// ```
+    ///
+    /// Always includes the literal `[INTERNAL CONTEXT REDACTED]` marker, so
+    /// a reader can tell this is a deliberately partial view rather than a
+    /// `Debug` impl that happened to print nothing useful.
+    ///
+    /// # Feature Gate
+    ///
+    /// Behind the `ffi` feature, alongside [`ExternalError`] - see this
+    /// module's docs.
+    pub fn debug_repr(&self) -> String {
+        format!("{:?} [INTERNAL CONTEXT REDACTED]", ExternalError::from_error(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OperationCategory;
+
+    #[test]
+    fn external_error_carries_only_the_public_view() {
+        let error = DualContextError::with_lie_and_sensitive(
+            "Not found",
+            "leaked token abc123",
+            OperationCategory::IO,
+        );
+        let external = ExternalError::from_error(&error);
+        assert_eq!(external.message, "Not found");
+        assert_eq!(external.category, error.external_category());
+    }
+
+    #[test]
+    fn debug_repr_never_contains_internal_content() {
+        let error = DualContextError::with_lie_and_sensitive(
+            "Not found",
+            "leaked token abc123",
+            OperationCategory::IO,
+        );
+        let repr = error.debug_repr();
+        assert!(repr.contains("[INTERNAL CONTEXT REDACTED]"));
+        assert!(repr.contains("Not found"));
+        assert!(!repr.contains("leaked token"));
+    }
+
+    #[test]
+    fn debug_repr_never_contains_internal_diagnostic() {
+        let error = DualContextError::with_lie(
+            "Operation failed",
+            "internal diagnostic: timeout talking to upstream service X",
+            OperationCategory::IO,
+        );
+        let repr = error.debug_repr();
+        assert!(repr.contains("[INTERNAL CONTEXT REDACTED]"));
+        assert!(!repr.contains("upstream service X"));
+    }
+}